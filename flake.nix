@@ -93,7 +93,7 @@
                     name = "infrastructure";
                     tag = "latest";
                     created = "now";
-                    copyToRoot = [ bin pkgs.exiftool pkgs.cacert ];
+                    copyToRoot = [ bin pkgs.exiftool pkgs.ffmpeg-headless pkgs.cacert ];
                     config = {
                         Cmd = [ "${bin}/bin/infrastructure" ];
                     };
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    album::{Album, AlbumId},
+    error::EntityNotFoundSnafu,
+    medium::MediumId,
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    album::ports::{AlbumRepository, PublishAlbumEvent},
+    error::ApplicationResult,
+    medium::ports::MediumRepository,
+};
+
+#[derive(Debug)]
+pub struct AddAlbumMediaCommand {
+    pub user_id: UserId,
+    pub album_id: AlbumId,
+    pub medium_ids: Vec<MediumId>,
+}
+
+#[derive(new)]
+pub struct AddAlbumMediaHandler {
+    album_repository: Arc<dyn AlbumRepository>,
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishAlbumEvent>,
+}
+
+impl AddAlbumMediaHandler {
+    /// A medium belongs to one album at most, media of another album are
+    /// taken out of it first
+    #[instrument(skip(self), fields(user_id = %command.user_id, album_id = %command.album_id))]
+    pub async fn handle(&self, command: AddAlbumMediaCommand) -> ApplicationResult<Album> {
+        let mut album = self.find_album(command.album_id, command.user_id).await?;
+
+        for medium_id in command.medium_ids {
+            self.medium_repository
+                .find_by_id(medium_id, command.user_id)
+                .await?
+                .filter(|medium| !medium.is_deleted())
+                .context(EntityNotFoundSnafu {
+                    entity: "Medium",
+                    id: medium_id,
+                })?;
+
+            let Some(event) = album.add_medium(medium_id)? else {
+                debug!(%medium_id, "Medium is in the album already");
+                continue;
+            };
+
+            if let Some(previous_id) = self
+                .album_repository
+                .find_by_medium_id(medium_id, command.user_id)
+                .await?
+            {
+                let mut previous = self.find_album(previous_id, command.user_id).await?;
+                if let Some(removed) = previous.remove_medium(medium_id)? {
+                    self.event_bus.publish(removed).await?;
+                    debug!(%medium_id, album_id = %previous_id, "Medium moved out of album");
+                }
+            }
+
+            self.event_bus.publish(event).await?;
+        }
+
+        info!(count = album.medium_ids.len(), "Media added to album");
+
+        Ok(album)
+    }
+
+    async fn find_album(&self, album_id: AlbumId, user_id: UserId) -> ApplicationResult<Album> {
+        Ok(self
+            .album_repository
+            .find_by_id(album_id, user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Album",
+                id: album_id,
+            })?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use domain::{album::events::AlbumMediumAddedEvent, error::DomainError, medium::Medium};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        album::ports::MockAlbumRepository, error::ApplicationError,
+        event_bus::testing::RecordingPublisher, medium::ports::MockMediumRepository,
+    };
+
+    #[tokio::test]
+    async fn test_trashed_media_are_not_added() {
+        let user_id = Uuid::new_v4();
+        let album = Album {
+            id: Uuid::new_v4(),
+            owner_id: user_id,
+            ..Default::default()
+        };
+        let album_id = album.id;
+        let mut album_repository = MockAlbumRepository::new();
+        album_repository
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(album.clone())));
+        let mut medium_repository = MockMediumRepository::new();
+        medium_repository
+            .expect_find_by_id()
+            .returning(|id, owner_id| {
+                Ok(Some(Medium {
+                    id,
+                    owner_id,
+                    deleted_at: Some(Utc::now()),
+                    ..Default::default()
+                }))
+            });
+        let publisher = Arc::new(RecordingPublisher::default());
+        let handler = AddAlbumMediaHandler::new(
+            Arc::new(album_repository),
+            Arc::new(medium_repository),
+            publisher.clone(),
+        );
+
+        let result = handler
+            .handle(AddAlbumMediaCommand {
+                user_id,
+                album_id,
+                medium_ids: vec![Uuid::new_v4()],
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain {
+                source: DomainError::EntityNotFound { .. }
+            })
+        ));
+        assert!(publisher.events::<AlbumMediumAddedEvent>().is_empty());
+    }
+}
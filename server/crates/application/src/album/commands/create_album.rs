@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{album::Album, user::UserId};
+use tracing::{info, instrument};
+
+use crate::{album::ports::PublishAlbumEvent, error::ApplicationResult};
+
+#[derive(Debug)]
+pub struct CreateAlbumCommand {
+    pub user_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+#[derive(new)]
+pub struct CreateAlbumHandler {
+    event_bus: Arc<dyn PublishAlbumEvent>,
+}
+
+impl CreateAlbumHandler {
+    #[instrument(skip(self, command), fields(user_id = %command.user_id))]
+    pub async fn handle(&self, command: CreateAlbumCommand) -> ApplicationResult<Album> {
+        let (album, event) = Album::new(command.user_id, command.title, command.description)?;
+
+        self.event_bus.publish(event).await?;
+
+        info!(album_id = %album.id, "Album created");
+
+        Ok(album)
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{album::AlbumId, error::EntityNotFoundSnafu, user::UserId};
+use snafu::OptionExt;
+use tracing::{error, info, instrument};
+
+use crate::{
+    album::ports::{AlbumRepository, PublishAlbumEvent},
+    error::ApplicationResult,
+};
+
+#[derive(Debug)]
+pub struct DeleteAlbumCommand {
+    pub user_id: UserId,
+    pub album_id: AlbumId,
+}
+
+#[derive(new)]
+pub struct DeleteAlbumHandler {
+    album_repository: Arc<dyn AlbumRepository>,
+    event_bus: Arc<dyn PublishAlbumEvent>,
+}
+
+impl DeleteAlbumHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, album_id = %command.album_id))]
+    pub async fn handle(&self, command: DeleteAlbumCommand) -> ApplicationResult<()> {
+        let mut album = self
+            .album_repository
+            .find_by_id(command.album_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Album",
+                id: command.album_id,
+            })?;
+
+        let event = album.delete()?;
+
+        self.event_bus.publish(event).await.map_err(|e| {
+            error!(error = %e, "Failed to publish AlbumDeletedEvent");
+            e
+        })?;
+
+        info!("Album deleted, its media are kept");
+
+        Ok(())
+    }
+}
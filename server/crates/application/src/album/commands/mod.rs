@@ -0,0 +1,11 @@
+pub mod add_album_media;
+pub mod create_album;
+pub mod delete_album;
+pub mod remove_album_media;
+pub mod update_album;
+
+pub use add_album_media::*;
+pub use create_album::*;
+pub use delete_album::*;
+pub use remove_album_media::*;
+pub use update_album::*;
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    album::{Album, AlbumId},
+    error::EntityNotFoundSnafu,
+    medium::MediumId,
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    album::ports::{AlbumRepository, PublishAlbumEvent},
+    error::ApplicationResult,
+};
+
+#[derive(Debug)]
+pub struct RemoveAlbumMediaCommand {
+    pub user_id: UserId,
+    pub album_id: AlbumId,
+    pub medium_ids: Vec<MediumId>,
+}
+
+#[derive(new)]
+pub struct RemoveAlbumMediaHandler {
+    album_repository: Arc<dyn AlbumRepository>,
+    event_bus: Arc<dyn PublishAlbumEvent>,
+}
+
+impl RemoveAlbumMediaHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, album_id = %command.album_id))]
+    pub async fn handle(&self, command: RemoveAlbumMediaCommand) -> ApplicationResult<Album> {
+        let mut album = self
+            .album_repository
+            .find_by_id(command.album_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Album",
+                id: command.album_id,
+            })?;
+
+        for medium_id in command.medium_ids {
+            match album.remove_medium(medium_id)? {
+                Some(event) => self.event_bus.publish(event).await?,
+                None => debug!(%medium_id, "Medium is not in the album"),
+            }
+        }
+
+        info!(count = album.medium_ids.len(), "Media removed from album");
+
+        Ok(album)
+    }
+}
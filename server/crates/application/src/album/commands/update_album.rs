@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    album::{Album, AlbumId},
+    error::EntityNotFoundSnafu,
+    medium::MediumId,
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    album::ports::{AlbumRepository, PublishAlbumEvent},
+    error::ApplicationResult,
+};
+
+/// Values left out stay as they are, an empty description removes it
+#[derive(Debug)]
+pub struct UpdateAlbumCommand {
+    pub user_id: UserId,
+    pub album_id: AlbumId,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub cover_medium_id: Option<MediumId>,
+}
+
+#[derive(new)]
+pub struct UpdateAlbumHandler {
+    album_repository: Arc<dyn AlbumRepository>,
+    event_bus: Arc<dyn PublishAlbumEvent>,
+}
+
+impl UpdateAlbumHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, album_id = %command.album_id))]
+    pub async fn handle(&self, command: UpdateAlbumCommand) -> ApplicationResult<Album> {
+        let mut album = self
+            .album_repository
+            .find_by_id(command.album_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Album",
+                id: command.album_id,
+            })?;
+
+        let title = command.title.unwrap_or_else(|| album.title.clone());
+        let description = command.description.or_else(|| album.description.clone());
+        match album.rename(title, description)? {
+            Some(event) => self.event_bus.publish(event).await?,
+            None => debug!("Title and description unchanged"),
+        }
+
+        if let Some(cover_medium_id) = command.cover_medium_id {
+            if let Some(event) = album.change_cover(cover_medium_id)? {
+                self.event_bus.publish(event).await?;
+            }
+        }
+
+        info!("Album updated");
+
+        Ok(album)
+    }
+}
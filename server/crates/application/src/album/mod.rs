@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::{album::ports::PublishAlbumEvent, medium::ports::MediumRepository};
+
+pub mod commands;
+pub mod ports;
+pub mod queries;
+
+pub use ports::AlbumRepository;
+
+pub struct AlbumApplicationHandlers {
+    pub create_album: Arc<commands::CreateAlbumHandler>,
+    pub update_album: Arc<commands::UpdateAlbumHandler>,
+    pub delete_album: Arc<commands::DeleteAlbumHandler>,
+    pub add_album_media: Arc<commands::AddAlbumMediaHandler>,
+    pub remove_album_media: Arc<commands::RemoveAlbumMediaHandler>,
+    pub find_album: Arc<queries::FindAlbumHandler>,
+    pub find_all_albums: Arc<queries::FindAllAlbumsHandler>,
+}
+
+impl AlbumApplicationHandlers {
+    pub fn new(
+        album_repository: Arc<dyn AlbumRepository>,
+        medium_repository: Arc<dyn MediumRepository>,
+        event_bus: Arc<dyn PublishAlbumEvent>,
+    ) -> Self {
+        Self {
+            create_album: Arc::new(commands::CreateAlbumHandler::new(event_bus.clone())),
+            update_album: Arc::new(commands::UpdateAlbumHandler::new(
+                album_repository.clone(),
+                event_bus.clone(),
+            )),
+            delete_album: Arc::new(commands::DeleteAlbumHandler::new(
+                album_repository.clone(),
+                event_bus.clone(),
+            )),
+            add_album_media: Arc::new(commands::AddAlbumMediaHandler::new(
+                album_repository.clone(),
+                medium_repository,
+                event_bus.clone(),
+            )),
+            remove_album_media: Arc::new(commands::RemoveAlbumMediaHandler::new(
+                album_repository.clone(),
+                event_bus,
+            )),
+            find_album: Arc::new(queries::FindAlbumHandler::new(album_repository.clone())),
+            find_all_albums: Arc::new(queries::FindAllAlbumsHandler::new(album_repository)),
+        }
+    }
+}
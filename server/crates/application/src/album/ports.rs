@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use domain::{
+    album::{
+        events::{
+            AlbumCoverChangedEvent, AlbumCreatedEvent, AlbumDeletedEvent, AlbumMediumAddedEvent,
+            AlbumMediumRemovedEvent, AlbumRenamedEvent,
+        },
+        Album, AlbumId,
+    },
+    error::DomainResult,
+    medium::MediumId,
+    user::UserId,
+};
+
+use crate::event_bus::PublishEvent;
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait AlbumRepository: Send + Sync {
+    /// The album of the user with its media, `None` if it is deleted
+    async fn find_by_id(&self, id: AlbumId, user_id: UserId) -> DomainResult<Option<Album>>;
+    /// Albums of the user that are not deleted, newest first
+    async fn find_all(&self, user_id: UserId) -> DomainResult<Vec<Album>>;
+    /// The album of the user the medium is in
+    async fn find_by_medium_id(
+        &self,
+        medium_id: MediumId,
+        user_id: UserId,
+    ) -> DomainResult<Option<AlbumId>>;
+}
+
+pub trait PublishAlbumEvent:
+    PublishEvent<AlbumCreatedEvent>
+    + PublishEvent<AlbumRenamedEvent>
+    + PublishEvent<AlbumCoverChangedEvent>
+    + PublishEvent<AlbumMediumAddedEvent>
+    + PublishEvent<AlbumMediumRemovedEvent>
+    + PublishEvent<AlbumDeletedEvent>
+{
+}
+
+impl<T> PublishAlbumEvent for T where
+    T: PublishEvent<AlbumCreatedEvent>
+        + PublishEvent<AlbumRenamedEvent>
+        + PublishEvent<AlbumCoverChangedEvent>
+        + PublishEvent<AlbumMediumAddedEvent>
+        + PublishEvent<AlbumMediumRemovedEvent>
+        + PublishEvent<AlbumDeletedEvent>
+{
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    album::{Album, AlbumId},
+    error::EntityNotFoundSnafu,
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, instrument};
+
+use crate::{album::ports::AlbumRepository, error::ApplicationResult};
+
+#[derive(Debug)]
+pub struct FindAlbumQuery {
+    pub user_id: UserId,
+    pub album_id: AlbumId,
+}
+
+#[derive(new)]
+pub struct FindAlbumHandler {
+    album_repository: Arc<dyn AlbumRepository>,
+}
+
+impl FindAlbumHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id, album_id = %query.album_id))]
+    pub async fn handle(&self, query: FindAlbumQuery) -> ApplicationResult<Album> {
+        let album = self
+            .album_repository
+            .find_by_id(query.album_id, query.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Album",
+                id: query.album_id,
+            })?;
+
+        debug!("Album retrieved successfully");
+
+        Ok(album)
+    }
+}
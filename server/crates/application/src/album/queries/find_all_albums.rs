@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{album::Album, user::UserId};
+use tracing::{info, instrument};
+
+use crate::{album::ports::AlbumRepository, error::ApplicationResult};
+
+#[derive(Debug)]
+pub struct FindAllAlbumsQuery {
+    pub user_id: UserId,
+}
+
+#[derive(new)]
+pub struct FindAllAlbumsHandler {
+    album_repository: Arc<dyn AlbumRepository>,
+}
+
+impl FindAllAlbumsHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id))]
+    pub async fn handle(&self, query: FindAllAlbumsQuery) -> ApplicationResult<Vec<Album>> {
+        let albums = self.album_repository.find_all(query.user_id).await?;
+
+        info!(count = albums.len(), "Albums retrieved");
+
+        Ok(albums)
+    }
+}
@@ -0,0 +1,5 @@
+mod find_album;
+mod find_all_albums;
+
+pub use find_album::{FindAlbumHandler, FindAlbumQuery};
+pub use find_all_albums::{FindAllAlbumsHandler, FindAllAlbumsQuery};
@@ -1,4 +1,5 @@
 use byte_unit::Byte;
+use chrono::Duration;
 
 #[derive(Debug, Clone)]
 pub struct AuthConfig {
@@ -12,3 +13,33 @@ pub struct QuotaConfig {
     pub default_user_quota: Byte,
     pub max_user_quota: Byte,
 }
+
+#[derive(Debug, Clone)]
+pub struct LivePhotoConfig {
+    /// Maximum time between the uploads of a still and its video
+    pub pairing_window: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateConfig {
+    /// What happens to an upload whose file is already in the library
+    pub upload_policy: DuplicatePolicy,
+    /// Maximum number of differing bits between the perceptual hashes of
+    /// near-duplicates
+    pub similarity_threshold: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Refuse the upload and point to the existing medium
+    Reject,
+    /// Drop the upload and hand out the existing medium as if it was created
+    Link,
+}
+
+/// Settings of the medium handlers
+#[derive(Debug, Clone)]
+pub struct MediumConfig {
+    pub live_photo: LivePhotoConfig,
+    pub duplicates: DuplicateConfig,
+}
@@ -1,6 +1,9 @@
 use std::backtrace::Backtrace;
 
-use domain::error::{format_error_with_backtrace as format_domain_error, DomainError};
+use domain::{
+    error::{format_error_with_backtrace as format_domain_error, DomainError},
+    medium::MediumId,
+};
 use snafu::{ErrorCompat, Snafu};
 
 #[derive(Snafu, Debug)]
@@ -26,6 +29,15 @@ pub enum ApplicationError {
 
     #[snafu(display("Concurrency conflict: {message}"))]
     Conflict { message: String },
+
+    #[snafu(display("Forbidden: {message}"))]
+    Forbidden { message: String },
+
+    #[snafu(display("Unsupported media type: {message}"))]
+    UnsupportedMediaType { message: String },
+
+    #[snafu(display("Medium {medium_id} already contains this file"))]
+    Duplicate { medium_id: MediumId },
 }
 
 pub type ApplicationResult<T> = Result<T, ApplicationError>;
@@ -10,3 +10,36 @@ pub trait PublishEvent<E: DomainEvent>: Send + Sync {
 
 // Re-export from event_sourcing — application listeners implement this directly
 pub use event_sourcing::bus::EventProcessor;
+
+#[cfg(test)]
+pub(crate) mod testing {
+    use std::{any::Any, sync::Mutex};
+
+    use super::*;
+
+    /// Keeps the published events for the test to look at
+    #[derive(Default)]
+    pub(crate) struct RecordingPublisher {
+        events: Mutex<Vec<Box<dyn Any + Send>>>,
+    }
+
+    #[async_trait]
+    impl<E: DomainEvent> PublishEvent<E> for RecordingPublisher {
+        async fn publish(&self, event: E) -> ApplicationResult<()> {
+            self.events.lock().unwrap().push(Box::new(event));
+            Ok(())
+        }
+    }
+
+    impl RecordingPublisher {
+        /// The published events of type `E`, in order
+        pub(crate) fn events<E: DomainEvent + Clone>(&self) -> Vec<E> {
+            let events = self.events.lock().unwrap();
+            events
+                .iter()
+                .filter_map(|event| event.downcast_ref::<E>())
+                .cloned()
+                .collect()
+        }
+    }
+}
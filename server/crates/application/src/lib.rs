@@ -1,9 +1,12 @@
+pub mod album;
 pub mod config;
 pub mod error;
 pub mod event_bus;
 pub mod medium;
 pub mod metadata;
 pub mod projection;
+pub mod smart_album;
 pub mod system;
+pub mod tag;
 pub mod task;
 pub mod user;
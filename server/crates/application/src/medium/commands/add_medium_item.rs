@@ -0,0 +1,127 @@
+use std::{path::PathBuf, sync::Arc};
+
+use byte_unit::Byte;
+use derive_new::new;
+use domain::{
+    error::{format_error_with_backtrace as format_domain_error, EntityNotFoundSnafu},
+    medium::{
+        storage::{FileLocation, StorageTier},
+        Filename, MediumId, MediumItemCreateRequest, MediumItemId, MediumItemType, Priority,
+    },
+    user::UserId,
+};
+use mime::Mime;
+use snafu::OptionExt;
+use tokio::io::AsyncRead;
+use tracing::{debug, error, info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApplicationError, ApplicationResult},
+    medium::ports::{FileStorage, MediumRepository, PublishMediumEvent},
+    user::QuotaManager,
+};
+
+pub struct AddMediumItemCommand {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+    pub medium_item_type: MediumItemType,
+    pub stream: Box<dyn AsyncRead + Send + Unpin>,
+    pub file_size: Byte,
+    pub mime_type: Mime,
+    pub filename: String,
+    pub priority: Option<i32>,
+}
+
+/// Adds an edit, preview or sidecar to an existing medium. The file lands in
+/// temporary storage and is moved to permanent storage by the
+/// `MoveToPermanentStorageListener` once the item is created.
+#[derive(new)]
+pub struct AddMediumItemHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    file_storage: Arc<dyn FileStorage>,
+    quota_manager: Arc<QuotaManager>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl AddMediumItemHandler {
+    #[instrument(skip(self, command), fields(
+        user_id = %command.user_id,
+        medium_id = %command.medium_id,
+        item_type = ?command.medium_item_type,
+        file_size = %command.file_size.as_u64(),
+        mime_type = %command.mime_type,
+        filename = %command.filename
+    ))]
+    pub async fn handle(&self, command: AddMediumItemCommand) -> ApplicationResult<MediumItemId> {
+        info!("Adding item to medium");
+
+        let mut medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        let filename = Filename::new(&command.filename)?;
+        let priority = command.priority.map(Priority::new).unwrap_or_default();
+
+        let temp_location = FileLocation::new(
+            StorageTier::Temporary,
+            PathBuf::from(format!("{}.{}", Uuid::new_v4(), filename.extension())),
+        );
+
+        // Validates the item against the medium before any quota is reserved
+        let created_event = medium.add_item(MediumItemCreateRequest {
+            owner_id: command.user_id,
+            medium_item_type: command.medium_item_type,
+            mime: command.mime_type,
+            filename,
+            filesize: command.file_size,
+            priority,
+            dimensions: None,
+            locations: vec![temp_location.clone()],
+            checksum: None,
+        })?;
+        let item_id = created_event.item_id;
+
+        self.quota_manager
+            .with_quota(command.user_id, command.file_size, || async {
+                debug!(
+                    item_id = %item_id,
+                    temp_location = ?temp_location.relative_path,
+                    "Storing file and persisting events"
+                );
+
+                // Store file to temporary storage
+                self.file_storage
+                    .store_file_stream(&temp_location, command.stream)
+                    .await
+                    .map_err(|e| {
+                        error!(
+                            item_id = %item_id,
+                            error = %format_domain_error(&e),
+                            "File storage failed"
+                        );
+                        ApplicationError::Domain { source: e }
+                    })?;
+
+                // Publish event — persists to event store, then dispatches to listeners
+                self.event_bus.publish(created_event).await.map_err(|e| {
+                    error!(
+                        item_id = %item_id,
+                        error = %e,
+                        "Failed to publish event"
+                    );
+                    e
+                })?;
+
+                info!(item_id = %item_id, "Medium item added successfully");
+
+                Ok(item_id)
+            })
+            .await
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::{MediumId, Tag},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PublishMediumEvent},
+};
+
+#[derive(Debug)]
+pub struct AddMediumTagsCommand {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+    pub tags: Vec<String>,
+}
+
+#[derive(new)]
+pub struct AddMediumTagsHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl AddMediumTagsHandler {
+    /// Tag the medium, returns all of its tags
+    #[instrument(skip(self), fields(user_id = %command.user_id, medium_id = %command.medium_id))]
+    pub async fn handle(&self, command: AddMediumTagsCommand) -> ApplicationResult<Vec<Tag>> {
+        let tags = Tag::parse_all(&command.tags)?;
+        let mut medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        if let Some(event) = medium.add_tags(tags)? {
+            let count = event.tags.len();
+            self.event_bus.publish(event).await.map_err(|e| {
+                error!(error = %e, "Failed to publish MediumTagsAddedEvent");
+                e
+            })?;
+            info!(count, "Medium tagged");
+        }
+
+        Ok(medium.tags)
+    }
+}
@@ -12,7 +12,7 @@ use uuid::Uuid;
 use crate::{
     error::ApplicationResult,
     event_bus::PublishEvent,
-    medium::ports::{FileStorage, MediumRepository},
+    medium::ports::{FileStorage, MediumRepository, PublishMediumEvent},
 };
 
 pub trait PublishCleanupEvent:
@@ -38,6 +38,7 @@ pub struct CleanupExpiredTempStorageHandler {
     medium_repository: Arc<dyn MediumRepository>,
     file_storage: Arc<dyn FileStorage>,
     event_bus: Arc<dyn PublishCleanupEvent>,
+    medium_event_bus: Arc<dyn PublishMediumEvent>,
 }
 
 impl CleanupExpiredTempStorageHandler {
@@ -96,11 +97,10 @@ impl CleanupExpiredTempStorageHandler {
                         .await?;
 
                     if let Some(medium) = medium.as_mut() {
-                        if let Some(item) = medium.find_item_mut(location.item_id) {
-                            item.remove_location(StorageTier::Temporary);
-                            self.medium_repository.save(medium).await?;
-                            cleaned += 1;
-                        }
+                        let event = medium
+                            .remove_item_location(location.item_id, StorageTier::Temporary)?;
+                        self.medium_event_bus.publish(event).await?;
+                        cleaned += 1;
                     }
                 }
                 Err(e) => {
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::{FileLocation, PreviewSize},
+};
+use snafu::OptionExt;
+use tracing::{debug, error, info};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PerceptualHasher, PublishMediumEvent, UnhashedMedium},
+};
+
+pub struct ComputePerceptualHashesCommand {
+    /// Maximum number of media hashed in one sweep
+    pub batch_size: i64,
+}
+
+/// Hashes the thumbnails of media that have none yet, near-duplicates are
+/// detected by comparing the hashes
+#[derive(new)]
+pub struct ComputePerceptualHashesHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    perceptual_hasher: Arc<dyn PerceptualHasher>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl ComputePerceptualHashesHandler {
+    pub async fn handle(
+        &self,
+        command: ComputePerceptualHashesCommand,
+    ) -> ApplicationResult<usize> {
+        debug!(
+            batch_size = command.batch_size,
+            "Starting perceptual hash sweep"
+        );
+
+        let unhashed = self
+            .medium_repository
+            .find_unhashed(command.batch_size)
+            .await?;
+
+        if unhashed.is_empty() {
+            debug!("No media without perceptual hash found");
+            return Ok(0);
+        }
+
+        let mut hashed = 0;
+
+        for medium in &unhashed {
+            match self.compute(medium).await {
+                Ok(()) => hashed += 1,
+                Err(e) => {
+                    error!(
+                        medium_id = %medium.medium_id,
+                        owner_id = %medium.owner_id,
+                        error = %e,
+                        "Failed to compute perceptual hash, skipping"
+                    );
+                }
+            }
+        }
+
+        info!(
+            hashed,
+            total = unhashed.len(),
+            "Perceptual hash sweep completed"
+        );
+
+        Ok(hashed)
+    }
+
+    async fn compute(&self, unhashed: &UnhashedMedium) -> ApplicationResult<()> {
+        let mut medium = self
+            .medium_repository
+            .find_by_id(unhashed.medium_id, unhashed.owner_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: unhashed.medium_id,
+            })?;
+
+        let location: FileLocation = medium
+            .find_preview(PreviewSize::Thumbnail)
+            .and_then(|item| item.fastest_location())
+            .cloned()
+            .context(EntityNotFoundSnafu {
+                entity: "Preview",
+                id: medium.id,
+            })?;
+
+        let perceptual_hash = self.perceptual_hasher.hash(&location).await?;
+        let event = medium.record_perceptual_hash(perceptual_hash);
+
+        self.event_bus.publish(event).await?;
+
+        debug!(
+            medium_id = %medium.id,
+            perceptual_hash = perceptual_hash.value(),
+            "Perceptual hash computed"
+        );
+
+        Ok(())
+    }
+}
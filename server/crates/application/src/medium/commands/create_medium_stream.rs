@@ -15,13 +15,14 @@ use domain::{
 };
 use mime::Mime;
 use tokio::io::AsyncRead;
-use tracing::{debug, error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
 use crate::{
-    error::{ApplicationError, ApplicationResult},
+    config::{DuplicateConfig, DuplicatePolicy},
+    error::{ApplicationError, ApplicationResult, DuplicateSnafu},
     event_bus::PublishEvent,
-    medium::ports::FileStorage,
+    medium::ports::{FileStorage, MediumRepository},
     user::QuotaManager,
 };
 
@@ -38,11 +39,21 @@ pub struct CreateMediumStreamCommand {
     pub camera_model: Option<String>,
 }
 
+/// What became of an upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadOutcome {
+    Created(MediumId),
+    /// The file was already uploaded before, see [`DuplicatePolicy::Link`]
+    Linked(MediumId),
+}
+
 #[derive(new)]
 pub struct CreateMediumStreamHandler {
+    medium_repository: Arc<dyn MediumRepository>,
     file_storage: Arc<dyn FileStorage>,
     quota_manager: Arc<QuotaManager>,
     event_bus: Arc<dyn PublishEvent<MediumCreatedEvent>>,
+    config: Arc<DuplicateConfig>,
 }
 
 impl CreateMediumStreamHandler {
@@ -52,16 +63,20 @@ impl CreateMediumStreamHandler {
         mime_type = %command.mime_type,
         filename = %command.filename
     ))]
-    pub async fn handle(&self, command: CreateMediumStreamCommand) -> ApplicationResult<MediumId> {
+    pub async fn handle(
+        &self,
+        command: CreateMediumStreamCommand,
+    ) -> ApplicationResult<UploadOutcome> {
         info!("Creating medium from stream");
 
-        self.quota_manager
+        let result = self
+            .quota_manager
             .with_quota(command.user_id, command.file_size, || async {
-                let medium_type = command
-                    .medium_type
-                    .unwrap_or_else(|| MediumType::from(command.mime_type.clone()));
                 let filename = Filename::new(&command.filename)
                     .map_err(|e| ApplicationError::Domain { source: e })?;
+                let medium_type = command
+                    .medium_type
+                    .unwrap_or_else(|| MediumType::detect(&command.mime_type, &filename));
                 let priority = command.priority.map(Priority::new).unwrap_or_default();
 
                 let temp_file_id = Uuid::new_v4();
@@ -70,6 +85,48 @@ impl CreateMediumStreamHandler {
                     PathBuf::from(format!("{}.{}", temp_file_id, filename.extension())),
                 );
 
+                debug!(
+                    temp_location = ?temp_location.relative_path,
+                    "Storing file to temporary storage"
+                );
+
+                // Store file to temporary storage
+                self.file_storage
+                    .store_file_stream(&temp_location, command.stream)
+                    .await
+                    .map_err(|e| {
+                        error!(
+                            temp_location = ?temp_location.relative_path,
+                            error = %format_domain_error(&e),
+                            "File storage failed"
+                        );
+                        ApplicationError::Domain { source: e }
+                    })?;
+
+                let checksum = self
+                    .file_storage
+                    .get_file_metadata(&temp_location)
+                    .await?
+                    .checksum;
+                if let Some(existing) = self
+                    .medium_repository
+                    .find_by_checksum(&checksum, command.user_id)
+                    .await?
+                {
+                    if let Err(e) = self.file_storage.delete_file(&temp_location).await {
+                        warn!(
+                            path = ?temp_location.relative_path,
+                            error = %e,
+                            "Failed to delete duplicate upload"
+                        );
+                    }
+                    // Fails the operation so the reserved quota is released
+                    return DuplicateSnafu {
+                        medium_id: existing,
+                    }
+                    .fail();
+                }
+
                 let medium_item_request = MediumItemCreateRequest {
                     owner_id: command.user_id,
                     medium_item_type: MediumItemType::Original,
@@ -78,7 +135,8 @@ impl CreateMediumStreamHandler {
                     filesize: command.file_size,
                     priority,
                     dimensions: None,
-                    locations: vec![temp_location.clone()],
+                    locations: vec![temp_location],
+                    checksum: Some(checksum),
                 };
 
                 let medium_request = MediumCreateRequest {
@@ -92,25 +150,6 @@ impl CreateMediumStreamHandler {
                 let (medium, created_event) = Medium::new(medium_request)?;
                 let medium_id = medium.id;
 
-                debug!(
-                    medium_id = %medium_id,
-                    temp_location = ?temp_location.relative_path,
-                    "Storing file and persisting events"
-                );
-
-                // Store file to temporary storage
-                self.file_storage
-                    .store_file_stream(&temp_location, command.stream)
-                    .await
-                    .map_err(|e| {
-                        error!(
-                            medium_id = %medium_id,
-                            error = %format_domain_error(&e),
-                            "File storage failed"
-                        );
-                        ApplicationError::Domain { source: e }
-                    })?;
-
                 // Publish event — persists to event store, then dispatches to listeners
                 self.event_bus.publish(created_event).await.map_err(|e| {
                     error!(
@@ -128,6 +167,16 @@ impl CreateMediumStreamHandler {
 
                 Ok(medium_id)
             })
-            .await
+            .await;
+
+        match result {
+            Err(ApplicationError::Duplicate { medium_id })
+                if self.config.upload_policy == DuplicatePolicy::Link =>
+            {
+                info!(medium_id = %medium_id, "Upload linked to existing medium");
+                Ok(UploadOutcome::Linked(medium_id))
+            }
+            result => result.map(UploadOutcome::Created),
+        }
     }
 }
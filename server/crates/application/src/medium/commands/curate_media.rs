@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::{EntityNotFoundSnafu, ValidationSnafu},
+    medium::{CurationPatch, MediumId},
+    user::UserId,
+};
+use snafu::{ensure, OptionExt};
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PublishMediumEvent},
+};
+
+#[derive(Debug)]
+pub struct CurateMediaCommand {
+    pub user_id: UserId,
+    pub medium_ids: Vec<MediumId>,
+    pub patch: CurationPatch,
+}
+
+/// Sets the favorite flag, rating, color label or reject flag of many media
+/// at once
+#[derive(new)]
+pub struct CurateMediaHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl CurateMediaHandler {
+    const MAX_MEDIA: usize = 500;
+
+    /// Returns the number of media whose curation changed
+    #[instrument(skip(self), fields(user_id = %command.user_id, media = command.medium_ids.len()))]
+    pub async fn handle(&self, command: CurateMediaCommand) -> ApplicationResult<u64> {
+        ensure!(
+            !command.medium_ids.is_empty(),
+            ValidationSnafu {
+                message: "No media to curate"
+            }
+        );
+        ensure!(
+            command.medium_ids.len() <= Self::MAX_MEDIA,
+            ValidationSnafu {
+                message: format!("Cannot curate more than {} media at once", Self::MAX_MEDIA),
+            }
+        );
+        ensure!(
+            !command.patch.is_empty(),
+            ValidationSnafu {
+                message: "Nothing to change"
+            }
+        );
+        command.patch.validate()?;
+
+        let mut medium_ids = command.medium_ids.clone();
+        medium_ids.sort();
+        medium_ids.dedup();
+
+        // Load all media first so a missing one leaves the others untouched
+        let mut media = Vec::with_capacity(medium_ids.len());
+        for id in medium_ids {
+            let medium = self
+                .medium_repository
+                .find_by_id(id, command.user_id)
+                .await?
+                .context(EntityNotFoundSnafu {
+                    entity: "Medium",
+                    id,
+                })?;
+            ensure!(
+                !medium.is_deleted(),
+                ValidationSnafu {
+                    message: format!("Medium {id} is in the trash"),
+                }
+            );
+            media.push(medium);
+        }
+
+        let mut changed = 0;
+        for mut medium in media {
+            if let Some(event) = medium.curate(&command.patch)? {
+                self.event_bus.publish(event).await.map_err(|e| {
+                    error!(medium_id = %medium.id, error = %e, "Failed to publish MediumCurationChangedEvent");
+                    e
+                })?;
+                changed += 1;
+            }
+        }
+
+        info!(changed, "Media curated");
+
+        Ok(changed)
+    }
+}
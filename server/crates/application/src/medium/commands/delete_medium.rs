@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::EntityNotFoundSnafu, medium::MediumId, user::UserId};
+use snafu::OptionExt;
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PublishMediumEvent},
+};
+
+#[derive(Debug)]
+pub struct DeleteMediumCommand {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+}
+
+/// Moves a medium to the trash. Files and quota stay untouched until the
+/// medium is purged after the retention period.
+#[derive(new)]
+pub struct DeleteMediumHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl DeleteMediumHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, medium_id = %command.medium_id))]
+    pub async fn handle(&self, command: DeleteMediumCommand) -> ApplicationResult<()> {
+        let mut medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        let event = medium.delete()?;
+
+        self.event_bus.publish(event).await.map_err(|e| {
+            error!(error = %e, "Failed to publish MediumDeletedEvent");
+            e
+        })?;
+
+        info!("Medium moved to trash");
+
+        Ok(())
+    }
+}
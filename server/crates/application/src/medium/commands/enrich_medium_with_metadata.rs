@@ -4,11 +4,12 @@ use chrono::{DateTime, FixedOffset};
 use derive_new::new;
 use domain::{
     error::EntityNotFoundSnafu,
-    medium::{camera::GpsCoordinates, MediumId},
+    medium::{camera::GpsCoordinates, MediumId, Tag},
+    metadata::TimezoneSource,
     user::UserId,
 };
 use snafu::OptionExt;
-use tracing::{debug, warn};
+use tracing::{debug, error, warn};
 
 use crate::{
     error::ApplicationResult,
@@ -19,9 +20,19 @@ pub struct EnrichMediumWithMetadataCommand {
     pub medium_id: MediumId,
     pub owner_id: UserId,
     pub taken_at: Option<DateTime<FixedOffset>>,
+    pub taken_at_timezone_source: Option<TimezoneSource>,
     pub camera_make: Option<String>,
     pub camera_model: Option<String>,
     pub gps_coordinates: Option<GpsCoordinates>,
+    /// `xmp:Rating` of the file or its sidecar, taken over as the initial
+    /// rating of the medium
+    pub rating: Option<i8>,
+    /// `xmp:Label` of the sidecar, taken over as the initial color label
+    pub label: Option<String>,
+    /// Keywords of the sidecar, they become tags of the medium
+    pub keywords: Vec<String>,
+    /// Keywords dropped from the sidecar, their tags are taken off the medium
+    pub removed_keywords: Vec<String>,
 }
 
 #[derive(new)]
@@ -43,28 +54,229 @@ impl EnrichMediumWithMetadataHandler {
 
         let event = medium.update_basic_metadata(
             command.taken_at,
+            command.taken_at_timezone_source,
             command.camera_make,
             command.camera_model,
             command.gps_coordinates,
         );
+        let curation_event =
+            medium.adopt_embedded_curation(command.rating, command.label.as_deref());
+        // Trashed media keep their tags until they are restored
+        let (untagged_event, tagged_event) = if medium.is_deleted() {
+            (None, None)
+        } else {
+            let removed = Self::parse_keywords(&command.removed_keywords);
+            let added = Self::parse_keywords(&command.keywords);
+            (medium.remove_tags(&removed)?, medium.add_tags(added)?)
+        };
+
+        // Events are published before the read model is saved, so the
+        // projection never holds tags or curation the event store has not
+        // recorded
+        self.event_bus.publish(event).await.map_err(|e| {
+            error!(medium_id = %command.medium_id, error = %e, "Failed to publish MediumUpdatedEvent");
+            e
+        })?;
+
+        if let Some(event) = curation_event {
+            self.event_bus.publish(event).await.map_err(|e| {
+                error!(medium_id = %command.medium_id, error = %e, "Failed to publish MediumCurationChangedEvent");
+                e
+            })?;
+        }
+
+        if let Some(event) = untagged_event {
+            self.event_bus.publish(event).await.map_err(|e| {
+                error!(medium_id = %command.medium_id, error = %e, "Failed to publish MediumTagsRemovedEvent");
+                e
+            })?;
+        }
+
+        if let Some(event) = tagged_event {
+            self.event_bus.publish(event).await.map_err(|e| {
+                error!(medium_id = %command.medium_id, error = %e, "Failed to publish MediumTagsAddedEvent");
+                e
+            })?;
+        }
 
         self.medium_repository.save(&medium).await?;
 
         debug!(
             medium_id = %command.medium_id,
             taken_at = ?command.taken_at,
+            timezone_source = ?command.taken_at_timezone_source,
             has_gps = command.gps_coordinates.is_some(),
             "Medium enriched with metadata"
         );
 
-        if let Err(e) = self.event_bus.publish(event).await {
-            warn!(
-                medium_id = %command.medium_id,
-                error = %e,
-                "Failed to publish MediumUpdatedEvent"
-            );
+        Ok(())
+    }
+
+    /// Keywords that are no valid tag, e.g. empty ones, are skipped
+    fn parse_keywords(keywords: &[String]) -> Vec<Tag> {
+        keywords
+            .iter()
+            .filter_map(|keyword| match Tag::new(keyword) {
+                Ok(tag) => Some(tag),
+                Err(e) => {
+                    warn!(keyword, error = %e, "Skipping sidecar keyword that is no valid tag");
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use async_trait::async_trait;
+    use domain::{
+        event::DomainEvent,
+        medium::{
+            events::{MediumCurationChangedEvent, MediumTagsAddedEvent, MediumTagsRemovedEvent},
+            Medium,
+        },
+    };
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::{
+        error::ConflictSnafu,
+        event_bus::{testing::RecordingPublisher, PublishEvent},
+        medium::ports::MockMediumRepository,
+    };
+
+    fn tag(value: &str) -> Tag {
+        Tag::new(value).unwrap()
+    }
+
+    fn command(
+        keywords: Vec<String>,
+        removed_keywords: Vec<String>,
+    ) -> EnrichMediumWithMetadataCommand {
+        EnrichMediumWithMetadataCommand {
+            medium_id: Uuid::new_v4(),
+            owner_id: Uuid::new_v4(),
+            taken_at: None,
+            taken_at_timezone_source: None,
+            camera_make: None,
+            camera_model: None,
+            gps_coordinates: None,
+            rating: None,
+            label: None,
+            keywords,
+            removed_keywords,
         }
+    }
 
-        Ok(())
+    /// Fails to publish events of type `F`, as if the event store rejected them
+    struct FailingPublisher<F>(std::marker::PhantomData<F>);
+
+    #[async_trait]
+    impl<F: DomainEvent, E: DomainEvent> PublishEvent<E> for FailingPublisher<F> {
+        async fn publish(&self, event: E) -> ApplicationResult<()> {
+            if (&event as &dyn Any).is::<F>() {
+                return ConflictSnafu {
+                    message: "Stream changed",
+                }
+                .fail();
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sidecar_keywords_become_tag_events() {
+        let mut medium_repository = MockMediumRepository::new();
+        medium_repository
+            .expect_find_by_id()
+            .returning(|id, owner_id| {
+                Ok(Some(Medium {
+                    id,
+                    owner_id,
+                    tags: vec![tag("Travel/Porto"), tag("Family")],
+                    ..Default::default()
+                }))
+            });
+        medium_repository.expect_save().returning(|_| Ok(()));
+        let publisher = Arc::new(RecordingPublisher::default());
+        let handler =
+            EnrichMediumWithMetadataHandler::new(Arc::new(medium_repository), publisher.clone());
+
+        handler
+            .handle(command(
+                vec!["Travel/Lisbon".into(), "Family".into(), "x".repeat(101)],
+                vec!["Travel/Porto".into()],
+            ))
+            .await
+            .unwrap();
+
+        let removed = publisher.events::<MediumTagsRemovedEvent>();
+        let added = publisher.events::<MediumTagsAddedEvent>();
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].tags, vec![tag("Travel/Porto")]);
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].tags, vec![tag("Travel/Lisbon")]);
+        assert_eq!(
+            added[0].metadata.expected_version,
+            removed[0].metadata.expected_version + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_tag_event_is_not_saved() {
+        // No save is expected, the mock panics if the medium is saved
+        let mut medium_repository = MockMediumRepository::new();
+        medium_repository
+            .expect_find_by_id()
+            .returning(|id, owner_id| {
+                Ok(Some(Medium {
+                    id,
+                    owner_id,
+                    ..Default::default()
+                }))
+            });
+        let handler = EnrichMediumWithMetadataHandler::new(
+            Arc::new(medium_repository),
+            Arc::new(FailingPublisher::<MediumTagsAddedEvent>(Default::default())),
+        );
+
+        let result = handler
+            .handle(command(vec!["Travel/Lisbon".into()], vec![]))
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_curation_event_is_not_saved() {
+        // No save is expected, the mock panics if the medium is saved
+        let mut medium_repository = MockMediumRepository::new();
+        medium_repository
+            .expect_find_by_id()
+            .returning(|id, owner_id| {
+                Ok(Some(Medium {
+                    id,
+                    owner_id,
+                    ..Default::default()
+                }))
+            });
+        let handler = EnrichMediumWithMetadataHandler::new(
+            Arc::new(medium_repository),
+            Arc::new(FailingPublisher::<MediumCurationChangedEvent>(
+                Default::default(),
+            )),
+        );
+
+        let result = handler
+            .handle(EnrichMediumWithMetadataCommand {
+                rating: Some(4),
+                ..command(vec![], vec![])
+            })
+            .await;
+
+        assert!(result.is_err());
     }
 }
@@ -0,0 +1,376 @@
+use std::sync::Arc;
+
+use byte_unit::Byte;
+use derive_new::new;
+use domain::{
+    error::{format_error_with_backtrace as format_domain_error, EntityNotFoundSnafu},
+    medium::{
+        events::{
+            PreviewGenerationCompletedEvent, PreviewGenerationFailedEvent,
+            PreviewGenerationStartedEvent,
+        },
+        FileLocation, Filename, Medium, MediumId, MediumItem, MediumItemCreateRequest,
+        MediumItemId, MediumItemType, MediumType, PreviewSize, Priority, StoragePathService,
+    },
+    user::UserId,
+};
+use mime::Mime;
+use snafu::OptionExt;
+use tracing::{debug, error, info, instrument};
+
+use crate::{
+    error::{format_error_with_backtrace, ApplicationResult},
+    medium::ports::{
+        EmbeddedPreviewExtractor, FileStorage, MediumRepository, PosterFrameGenerator,
+        PreviewRenderer, PublishMediumEvent, RenderedPreview, SourcePreview,
+    },
+};
+
+pub struct GeneratePreviewsCommand {
+    pub medium_id: MediumId,
+    pub source_item_id: MediumItemId,
+    pub user_id: UserId,
+    pub file_location: FileLocation,
+    pub mime: Mime,
+    pub medium_type: MediumType,
+}
+
+/// Renders the standard [`PreviewSize`]s of a medium and stores them as
+/// `Preview` items on the cache tier. RAW media are rendered from the JPEG
+/// embedded in the file and videos from a poster frame, both are kept as a
+/// `Preview` item as well.
+#[derive(new)]
+pub struct GeneratePreviewsHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    file_storage: Arc<dyn FileStorage>,
+    preview_renderer: Arc<dyn PreviewRenderer>,
+    embedded_extractor: Arc<dyn EmbeddedPreviewExtractor>,
+    poster_frame_generator: Arc<dyn PosterFrameGenerator>,
+    storage_path_service: Arc<StoragePathService>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl GeneratePreviewsHandler {
+    #[instrument(skip(self, command), fields(
+        medium_id = %command.medium_id,
+        source_item_id = %command.source_item_id,
+        user_id = %command.user_id,
+    ))]
+    pub async fn handle(&self, command: GeneratePreviewsCommand) -> ApplicationResult<()> {
+        info!("Starting preview generation");
+
+        self.event_bus
+            .publish(PreviewGenerationStartedEvent::new(
+                command.medium_id,
+                command.source_item_id,
+                command.user_id,
+            ))
+            .await?;
+
+        match self.generate(&command).await {
+            Ok(previews_generated) => {
+                info!(previews_generated, "Preview generation completed");
+                self.event_bus
+                    .publish(PreviewGenerationCompletedEvent::new(
+                        command.medium_id,
+                        command.source_item_id,
+                        command.user_id,
+                        previews_generated,
+                    ))
+                    .await?;
+                Ok(())
+            }
+            Err(e) => {
+                let error_msg = format_error_with_backtrace(&e);
+                error!(error = %error_msg, "Preview generation failed");
+                self.event_bus
+                    .publish(PreviewGenerationFailedEvent::new(
+                        command.medium_id,
+                        command.source_item_id,
+                        command.user_id,
+                        error_msg,
+                    ))
+                    .await?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn generate(&self, command: &GeneratePreviewsCommand) -> ApplicationResult<usize> {
+        let mut extracted = None;
+        let source = if let Some(filename) = command.medium_type.source_preview_filename() {
+            let Some(preview) = self
+                .extract_source(command.medium_type, &command.file_location)
+                .await?
+            else {
+                debug!(medium_type = ?command.medium_type, "Nothing to render previews from");
+                return Ok(0);
+            };
+            let location = self
+                .store_source(command.medium_id, filename, &preview)
+                .await?;
+            extracted = Some((location.clone(), preview));
+            location
+        } else if self.preview_renderer.supports(&command.mime) {
+            command.file_location.clone()
+        } else {
+            debug!(mime = %command.mime, "No previews for this file type");
+            return Ok(0);
+        };
+
+        let previews = self
+            .preview_renderer
+            .render(&source, &PreviewSize::all())
+            .await?;
+
+        let mut medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        if medium.is_deleted() {
+            debug!("Medium is in the trash, discarding previews");
+            return Ok(0);
+        }
+
+        let mut generated = 0;
+        if let Some((location, preview)) = extracted {
+            if medium.find_source_preview().is_none() {
+                self.attach_source(&mut medium, location, &preview).await?;
+                generated += 1;
+            }
+        }
+
+        let items = self.attach(&mut medium, previews).await?;
+        Ok(generated + items.len())
+    }
+
+    /// Renders a single size from the leading item of `medium` and records it
+    #[instrument(skip(self, medium), fields(medium_id = %medium.id, %size))]
+    pub async fn render_missing(
+        &self,
+        medium: &mut Medium,
+        size: PreviewSize,
+    ) -> ApplicationResult<MediumItem> {
+        let source = self.source_location(medium).await?;
+        let previews = self.preview_renderer.render(&source, &[size]).await?;
+        self.attach(medium, previews).await?;
+
+        info!("Rendered missing preview on demand");
+        Ok(medium
+            .find_preview(size)
+            .context(EntityNotFoundSnafu {
+                entity: "Preview",
+                id: medium.id,
+            })?
+            .clone())
+    }
+
+    /// Renders a recorded preview again after its cached file was evicted
+    #[instrument(skip(self, medium, location), fields(medium_id = %medium.id, %size))]
+    pub async fn restore(
+        &self,
+        medium: &mut Medium,
+        size: PreviewSize,
+        location: &FileLocation,
+    ) -> ApplicationResult<()> {
+        let source = self.source_location(medium).await?;
+        let preview = self
+            .preview_renderer
+            .render(&source, &[size])
+            .await?
+            .into_iter()
+            .next()
+            .context(EntityNotFoundSnafu {
+                entity: "Preview",
+                id: medium.id,
+            })?;
+
+        self.file_storage
+            .store_file(location, preview.content)
+            .await?;
+
+        info!("Restored evicted preview");
+        Ok(())
+    }
+
+    /// Location the previews of `medium` are rendered from. That is the
+    /// leading item, provided it can be decoded, or for RAW and video media
+    /// the extracted JPEG, which is extracted again if it is missing or was
+    /// evicted.
+    async fn source_location(&self, medium: &mut Medium) -> ApplicationResult<FileLocation> {
+        let source_filename = medium.medium_type.source_preview_filename();
+        if source_filename.is_some() {
+            let cached = medium
+                .find_source_preview()
+                .and_then(|item| item.fastest_location())
+                .cloned();
+            if let Some(location) = cached {
+                if self.file_storage.stat_file(&location).await.is_ok() {
+                    return Ok(location);
+                }
+            }
+        }
+
+        let leading = medium
+            .find_item(medium.leading_item_id)
+            .filter(|item| source_filename.is_some() || self.preview_renderer.supports(&item.mime))
+            .context(EntityNotFoundSnafu {
+                entity: "Preview",
+                id: medium.id,
+            })?;
+        let location = leading
+            .fastest_location()
+            .context(EntityNotFoundSnafu {
+                entity: "FileLocation",
+                id: leading.id,
+            })?
+            .clone();
+
+        let Some(filename) = source_filename else {
+            return Ok(location);
+        };
+
+        let preview = self
+            .extract_source(medium.medium_type, &location)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Preview",
+                id: medium.id,
+            })?;
+        let location = self.store_source(medium.id, filename, &preview).await?;
+        if medium.find_source_preview().is_none() {
+            self.attach_source(medium, location.clone(), &preview)
+                .await?;
+        }
+
+        info!("Extracted source preview on demand");
+        Ok(location)
+    }
+
+    /// Extracts the embedded JPEG of a RAW file or grabs a frame of a video
+    async fn extract_source(
+        &self,
+        medium_type: MediumType,
+        location: &FileLocation,
+    ) -> ApplicationResult<Option<SourcePreview>> {
+        let preview = match medium_type {
+            MediumType::Raw => self.embedded_extractor.extract(location).await?,
+            MediumType::Video => self.poster_frame_generator.generate(location).await?,
+            _ => None,
+        };
+        Ok(preview)
+    }
+
+    /// Stores an extracted JPEG on the cache tier
+    async fn store_source(
+        &self,
+        medium_id: MediumId,
+        filename: &str,
+        preview: &SourcePreview,
+    ) -> ApplicationResult<FileLocation> {
+        let location = FileLocation::cache(
+            self.storage_path_service
+                .generate_source_preview_path(medium_id, filename),
+        );
+        self.file_storage
+            .store_file(&location, preview.content.clone())
+            .await?;
+        Ok(location)
+    }
+
+    /// Adds the stored extracted JPEG to the medium
+    async fn attach_source(
+        &self,
+        medium: &mut Medium,
+        location: FileLocation,
+        preview: &SourcePreview,
+    ) -> ApplicationResult<MediumItemId> {
+        let filename =
+            medium
+                .medium_type
+                .source_preview_filename()
+                .context(EntityNotFoundSnafu {
+                    entity: "Preview",
+                    id: medium.id,
+                })?;
+        let event = medium
+            .add_item(MediumItemCreateRequest {
+                owner_id: medium.owner_id,
+                medium_item_type: MediumItemType::Preview,
+                mime: mime::IMAGE_JPEG,
+                filename: Filename::new(filename)?,
+                filesize: Byte::from_u64(preview.content.len() as u64),
+                priority: Priority::default(),
+                dimensions: Some(preview.dimensions),
+                locations: vec![location],
+                checksum: None,
+            })
+            .inspect_err(|e| {
+                error!(error = %format_domain_error(e), "Failed to add source preview item");
+            })?;
+
+        debug!(item_id = %event.item_id, %filename, "Stored source preview");
+        let item_id = event.item_id;
+        self.event_bus.publish(event).await?;
+        Ok(item_id)
+    }
+
+    /// Stores the rendered files on the cache tier and adds them to the
+    /// medium. Sizes the medium already has are skipped, so replays add nothing.
+    async fn attach(
+        &self,
+        medium: &mut Medium,
+        previews: Vec<RenderedPreview>,
+    ) -> ApplicationResult<Vec<MediumItemId>> {
+        let mut item_ids = Vec::with_capacity(previews.len());
+
+        for preview in previews {
+            if medium.find_preview(preview.size).is_some() {
+                debug!(size = %preview.size, "Preview already exists");
+                continue;
+            }
+
+            let location = FileLocation::cache(
+                self.storage_path_service
+                    .generate_preview_path(medium.id, preview.size),
+            );
+            let filesize = Byte::from_u64(preview.content.len() as u64);
+
+            self.file_storage
+                .store_file(&location, preview.content)
+                .await?;
+
+            let event = medium
+                .add_item(MediumItemCreateRequest {
+                    owner_id: medium.owner_id,
+                    medium_item_type: MediumItemType::Preview,
+                    mime: mime::IMAGE_JPEG,
+                    filename: preview.size.filename(),
+                    filesize,
+                    priority: Priority::default(),
+                    dimensions: Some(preview.dimensions),
+                    locations: vec![location.clone()],
+                    checksum: None,
+                })
+                .inspect_err(|e| {
+                    error!(error = %format_domain_error(e), "Failed to add preview item");
+                })?;
+
+            debug!(
+                size = %preview.size,
+                item_id = %event.item_id,
+                path = ?location.relative_path,
+                "Stored preview"
+            );
+            item_ids.push(event.item_id);
+            self.event_bus.publish(event).await?;
+        }
+
+        Ok(item_ids)
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::{EntityNotFoundSnafu, ValidationSnafu},
+    medium::MediumId,
+    user::UserId,
+};
+use snafu::{ensure, OptionExt};
+use tracing::{info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::{commands::StackMediumHandler, ports::MediumRepository},
+};
+
+#[derive(Debug)]
+pub struct MergeMediaCommand {
+    pub user_id: UserId,
+    /// The stack the other media are merged into
+    pub medium_id: MediumId,
+    pub medium_ids: Vec<MediumId>,
+}
+
+/// Manually stacks media the automatic stacking did not recognise as
+/// captured together
+#[derive(new)]
+pub struct MergeMediaHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    stack_medium: Arc<StackMediumHandler>,
+}
+
+impl MergeMediaHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, medium_id = %command.medium_id))]
+    pub async fn handle(&self, command: MergeMediaCommand) -> ApplicationResult<()> {
+        ensure!(
+            !command.medium_ids.is_empty() && !command.medium_ids.contains(&command.medium_id),
+            ValidationSnafu {
+                message: "A medium can only be merged with other media"
+            }
+        );
+
+        let mut target = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        for id in &command.medium_ids {
+            let mut other = self
+                .medium_repository
+                .find_by_id(*id, command.user_id)
+                .await?
+                .context(EntityNotFoundSnafu {
+                    entity: "Medium",
+                    id: *id,
+                })?;
+            self.stack_medium
+                .stack(&mut target, &mut other, false)
+                .await?;
+        }
+
+        info!(merged = command.medium_ids.len(), "Media merged into stack");
+
+        Ok(())
+    }
+}
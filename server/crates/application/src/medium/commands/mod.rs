@@ -1,9 +1,37 @@
+pub mod add_medium_item;
+pub mod add_medium_tags;
 pub mod cleanup_expired_temp_storage;
+pub mod compute_perceptual_hashes;
 pub mod create_medium_stream;
+pub mod curate_media;
+pub mod delete_medium;
 pub mod enrich_medium_with_metadata;
+pub mod generate_previews;
+pub mod merge_media;
 pub mod move_to_permanent_storage;
+pub mod pair_live_photo;
+pub mod purge_expired_trash;
+pub mod remove_medium_tags;
+pub mod resolve_duplicates;
+pub mod restore_medium;
+pub mod split_medium;
+pub mod stack_medium;
 
+pub use add_medium_item::*;
+pub use add_medium_tags::*;
 pub use cleanup_expired_temp_storage::*;
+pub use compute_perceptual_hashes::*;
 pub use create_medium_stream::*;
+pub use curate_media::*;
+pub use delete_medium::*;
 pub use enrich_medium_with_metadata::*;
+pub use generate_previews::*;
+pub use merge_media::*;
 pub use move_to_permanent_storage::*;
+pub use pair_live_photo::*;
+pub use purge_expired_trash::*;
+pub use remove_medium_tags::*;
+pub use resolve_duplicates::*;
+pub use restore_medium::*;
+pub use split_medium::*;
+pub use stack_medium::*;
@@ -1,11 +1,11 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use derive_new::new;
 use domain::{
     error::format_error_with_backtrace as format_domain_error,
     medium::{
         storage::{FileLocation, StorageTier},
-        MediumId, MediumItemId, StoragePathService,
+        Medium, MediumId, MediumItem, MediumItemId, StoragePathService,
     },
     user::UserId,
 };
@@ -13,7 +13,7 @@ use tracing::{debug, error, info, instrument};
 
 use crate::{
     error::ApplicationResult,
-    medium::ports::{FileStorage, MediumRepository},
+    medium::ports::{FileStorage, MediumRepository, PublishMediumEvent},
 };
 
 pub struct MoveToPermanentStorageCommand {
@@ -26,6 +26,7 @@ pub struct MoveToPermanentStorageHandler {
     medium_repository: Arc<dyn MediumRepository>,
     file_storage: Arc<dyn FileStorage>,
     storage_path_service: Arc<StoragePathService>,
+    event_bus: Arc<dyn PublishMediumEvent>,
 }
 
 struct CopyOperation {
@@ -52,36 +53,37 @@ impl MoveToPermanentStorageHandler {
             }
         };
 
+        // Permanent copies follow their path when e.g. the capture date changed
+        self.relocate_permanent_items(&mut medium).await?;
+
         // Pass 1: Determine which items need copying and compute destinations
-        let operations: Vec<CopyOperation> = medium
-            .items
-            .iter()
-            .filter_map(|item| {
-                // Skip if item already has a permanent location
-                let has_permanent = item
-                    .locations
-                    .iter()
-                    .any(|l| l.storage_tier == StorageTier::Permanent);
-                if has_permanent {
-                    return None;
-                }
+        let mut operations: Vec<CopyOperation> = Vec::new();
+        for item in &medium.items {
+            // Skip if item already has a permanent location
+            let has_permanent = item
+                .locations
+                .iter()
+                .any(|l| l.storage_tier == StorageTier::Permanent);
+            if has_permanent {
+                continue;
+            }
 
-                let temp_location = item
-                    .locations
-                    .iter()
-                    .find(|l| l.storage_tier == StorageTier::Temporary)?;
+            let Some(temp_location) = item
+                .locations
+                .iter()
+                .find(|l| l.storage_tier == StorageTier::Temporary)
+            else {
+                continue;
+            };
 
-                let permanent_path = self
-                    .storage_path_service
-                    .generate_permanent_path(&medium, item);
+            let permanent_path = self.free_permanent_path(&medium, item, None).await?;
 
-                Some(CopyOperation {
-                    item_id: item.id,
-                    src: temp_location.clone(),
-                    dest: FileLocation::permanent(permanent_path),
-                })
-            })
-            .collect();
+            operations.push(CopyOperation {
+                item_id: item.id,
+                src: temp_location.clone(),
+                dest: FileLocation::permanent(permanent_path),
+            });
+        }
 
         if operations.is_empty() {
             debug!("No items to copy, all already in permanent storage");
@@ -120,39 +122,136 @@ impl MoveToPermanentStorageHandler {
             completed_copies.push(op);
         }
 
-        // Pass 3: Update event entities — add permanent location alongside temp
-        for op in &operations {
-            if let Some(item) = medium.find_item_mut(op.item_id) {
-                item.add_location(op.dest.clone());
+        // Pass 3: Record the permanent location alongside temp as events
+        for (index, op) in operations.iter().enumerate() {
+            let published = match medium.add_item_location(op.item_id, op.dest.clone()) {
+                Ok(event) => self.event_bus.publish(event).await,
+                Err(e) => Err(e.into()),
+            };
+
+            if let Err(publish_err) = published {
+                error!(
+                    medium_id = %command.medium_id,
+                    item_id = %op.item_id,
+                    error = %publish_err,
+                    "Failed to record permanent location, rolling back"
+                );
+                // Rollback: delete copied files whose location was not recorded
+                for op in &operations[index..] {
+                    if let Err(rollback_err) = self.file_storage.delete_file(&op.dest).await {
+                        error!(
+                            item_id = %op.item_id,
+                            error = %format_domain_error(&rollback_err),
+                            "CRITICAL: Failed to delete copied file during rollback. Manual cleanup required"
+                        );
+                    }
+                }
+                return Err(publish_err);
             }
         }
 
-        // Pass 4: Persist
-        if let Err(save_err) = self.medium_repository.save(&medium).await {
-            error!(
-                medium_id = %command.medium_id,
-                error = %format_domain_error(&save_err),
-                "Failed to save medium after copying files, rolling back"
+        info!(
+            medium_id = %command.medium_id,
+            items_copied = operations.len(),
+            "Medium items copied to permanent storage"
+        );
+
+        Ok(())
+    }
+
+    /// Moves the permanent copies whose path no longer matches the storage
+    /// pattern to the path it gives now
+    async fn relocate_permanent_items(&self, medium: &mut Medium) -> ApplicationResult<()> {
+        let mut operations: Vec<CopyOperation> = Vec::new();
+        for item in &medium.items {
+            let Some(permanent_location) = item
+                .locations
+                .iter()
+                .find(|l| l.storage_tier == StorageTier::Permanent)
+            else {
+                continue;
+            };
+
+            let permanent_path = self
+                .free_permanent_path(medium, item, Some(&permanent_location.relative_path))
+                .await?;
+
+            if permanent_location.relative_path != permanent_path {
+                operations.push(CopyOperation {
+                    item_id: item.id,
+                    src: permanent_location.clone(),
+                    dest: FileLocation::permanent(permanent_path),
+                });
+            }
+        }
+
+        for op in &operations {
+            debug!(
+                item_id = %op.item_id,
+                src = ?op.src.relative_path,
+                dest = ?op.dest.relative_path,
+                "Relocating medium item in permanent storage"
             );
-            // Rollback: delete all copied files
-            for op in &operations {
-                if let Err(rollback_err) = self.file_storage.delete_file(&op.dest).await {
+
+            self.file_storage.move_file(&op.src, &op.dest).await?;
+
+            let published = match medium.add_item_location(op.item_id, op.dest.clone()) {
+                Ok(event) => self.event_bus.publish(event).await,
+                Err(e) => Err(e.into()),
+            };
+
+            if let Err(publish_err) = published {
+                error!(
+                    medium_id = %medium.id,
+                    item_id = %op.item_id,
+                    error = %publish_err,
+                    "Failed to record relocated file, moving it back"
+                );
+                if let Err(rollback_err) = self.file_storage.move_file(&op.dest, &op.src).await {
                     error!(
                         item_id = %op.item_id,
                         error = %format_domain_error(&rollback_err),
-                        "CRITICAL: Failed to delete copied file during rollback. Manual cleanup required"
+                        "CRITICAL: Failed to move relocated file back. Manual cleanup required"
                     );
                 }
+                return Err(publish_err);
             }
-            return Err(save_err.into());
         }
 
-        info!(
-            medium_id = %command.medium_id,
-            items_copied = operations.len(),
-            "Medium items copied to permanent storage"
-        );
+        if !operations.is_empty() {
+            info!(
+                medium_id = %medium.id,
+                items_relocated = operations.len(),
+                "Medium items relocated in permanent storage"
+            );
+        }
 
         Ok(())
     }
+
+    /// The permanent path the storage pattern gives for the item, with the
+    /// item id appended when another file already takes it. `current` is the
+    /// path the item is stored at, which is never taken by another file.
+    async fn free_permanent_path(
+        &self,
+        medium: &Medium,
+        item: &MediumItem,
+        current: Option<&PathBuf>,
+    ) -> ApplicationResult<PathBuf> {
+        let path = self
+            .storage_path_service
+            .generate_permanent_path(medium, item);
+        let taken = current != Some(&path)
+            && self
+                .file_storage
+                .file_exists(&FileLocation::permanent(path.clone()))
+                .await?;
+        if !taken {
+            return Ok(path);
+        }
+
+        Ok(self
+            .storage_path_service
+            .generate_deduplicated_path(&path, item))
+    }
 }
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    medium::{FileLocation, Medium, MediumId, MediumType},
+    user::UserId,
+};
+use tracing::{debug, info, instrument, warn};
+
+use crate::{
+    config::LivePhotoConfig,
+    error::ApplicationResult,
+    medium::ports::{FileStorage, MediumRepository, PublishMediumEvent},
+};
+
+pub struct PairLivePhotoCommand {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    /// Apple content identifier the still and the video of a Live Photo share
+    pub content_identifier: String,
+}
+
+/// Merges the still and the video of a Live Photo into a single `LivePhoto`
+/// medium once both were uploaded, whichever of them arrives first.
+#[derive(new)]
+pub struct PairLivePhotoHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    file_storage: Arc<dyn FileStorage>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+    config: Arc<LivePhotoConfig>,
+}
+
+impl PairLivePhotoHandler {
+    #[instrument(skip(self, command), fields(
+        medium_id = %command.medium_id,
+        user_id = %command.owner_id,
+        content_identifier = %command.content_identifier,
+    ))]
+    pub async fn handle(&self, command: PairLivePhotoCommand) -> ApplicationResult<()> {
+        let Some(medium) = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.owner_id)
+            .await?
+        else {
+            debug!("Medium not found, skipping Live Photo pairing");
+            return Ok(());
+        };
+
+        let Some(partner_type) = counterpart(medium.medium_type) else {
+            debug!(medium_type = ?medium.medium_type, "Medium cannot be part of a Live Photo");
+            return Ok(());
+        };
+        if medium.is_deleted() {
+            debug!("Medium is in the trash, skipping Live Photo pairing");
+            return Ok(());
+        }
+
+        let Some(partner) = self
+            .find_partner(&medium, partner_type, &command.content_identifier)
+            .await?
+        else {
+            debug!("Other half of the Live Photo not uploaded yet");
+            return Ok(());
+        };
+
+        let (mut still, mut video) = if medium.medium_type == MediumType::Photo {
+            (medium, partner)
+        } else {
+            (partner, medium)
+        };
+
+        // Previews rendered for the video on its own are of no use anymore
+        let dropped: Vec<FileLocation> = video
+            .items
+            .iter()
+            .filter(|item| item.is_cache_only())
+            .flat_map(|item| item.locations.iter().cloned())
+            .collect();
+
+        let (paired, merged) = still.pair_live_photo(&mut video)?;
+
+        // Move the items before the video medium is removed from the read model
+        self.event_bus.publish(paired).await?;
+        self.event_bus.publish(merged).await?;
+
+        for location in &dropped {
+            if let Err(e) = self.file_storage.delete_file(location).await {
+                warn!(
+                    video_medium_id = %video.id,
+                    path = ?location.relative_path,
+                    error = %e,
+                    "Failed to delete preview of merged video"
+                );
+            }
+        }
+
+        info!(
+            still_medium_id = %still.id,
+            video_medium_id = %video.id,
+            "Paired Live Photo"
+        );
+        Ok(())
+    }
+
+    /// The oldest medium of the other type sharing the content identifier
+    /// that was uploaded within the pairing window
+    async fn find_partner(
+        &self,
+        medium: &Medium,
+        partner_type: MediumType,
+        content_identifier: &str,
+    ) -> ApplicationResult<Option<Medium>> {
+        let candidates = self
+            .medium_repository
+            .find_by_content_identifier(content_identifier, medium.owner_id)
+            .await?;
+
+        for id in candidates.into_iter().filter(|id| *id != medium.id) {
+            let Some(candidate) = self
+                .medium_repository
+                .find_by_id(id, medium.owner_id)
+                .await?
+            else {
+                continue;
+            };
+
+            let apart = (candidate.created_at - medium.created_at).abs();
+            if candidate.medium_type == partner_type && apart <= self.config.pairing_window {
+                return Ok(Some(candidate));
+            }
+            debug!(
+                candidate_id = %id,
+                medium_type = ?candidate.medium_type,
+                apart_seconds = apart.num_seconds(),
+                "Skipping Live Photo candidate"
+            );
+        }
+
+        Ok(None)
+    }
+}
+
+/// Type of the other half of a Live Photo
+fn counterpart(medium_type: MediumType) -> Option<MediumType> {
+    match medium_type {
+        MediumType::Photo => Some(MediumType::Video),
+        MediumType::Video => Some(MediumType::Photo),
+        _ => None,
+    }
+}
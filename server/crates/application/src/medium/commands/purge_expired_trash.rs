@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use domain::{error::EntityNotFoundSnafu, medium::FileLocation};
+use snafu::OptionExt;
+use tracing::{error, info, warn};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{ExpiredTrashedMedium, FileStorage, MediumRepository, PublishMediumEvent},
+    user::QuotaManager,
+};
+
+pub struct PurgeExpiredTrashCommand {
+    /// Media trashed before this point in time are purged
+    pub cutoff: DateTime<Utc>,
+}
+
+#[derive(new)]
+pub struct PurgeExpiredTrashHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    file_storage: Arc<dyn FileStorage>,
+    quota_manager: Arc<QuotaManager>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl PurgeExpiredTrashHandler {
+    pub async fn handle(&self, command: PurgeExpiredTrashCommand) -> ApplicationResult<usize> {
+        info!(cutoff = %command.cutoff, "Starting trash purge sweep");
+
+        let expired = self
+            .medium_repository
+            .find_expired_trash(command.cutoff)
+            .await?;
+
+        if expired.is_empty() {
+            info!("No expired trashed media found");
+            return Ok(0);
+        }
+
+        let mut purged = 0;
+
+        for medium in &expired {
+            match self.purge(medium).await {
+                Ok(()) => purged += 1,
+                Err(e) => {
+                    error!(
+                        medium_id = %medium.medium_id,
+                        owner_id = %medium.owner_id,
+                        error = %e,
+                        "Failed to purge trashed medium, skipping"
+                    );
+                }
+            }
+        }
+
+        info!(purged, total = expired.len(), "Trash purge sweep completed");
+
+        Ok(purged)
+    }
+
+    async fn purge(&self, expired: &ExpiredTrashedMedium) -> ApplicationResult<()> {
+        let mut medium = self
+            .medium_repository
+            .find_by_id(expired.medium_id, expired.owner_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: expired.medium_id,
+            })?;
+
+        let locations: Vec<FileLocation> = medium
+            .items
+            .iter()
+            .flat_map(|item| item.locations.iter().cloned())
+            .collect();
+
+        let event = medium.purge()?;
+
+        for location in &locations {
+            if let Err(e) = self.file_storage.delete_file(location).await {
+                warn!(
+                    medium_id = %expired.medium_id,
+                    storage_tier = ?location.storage_tier,
+                    path = ?location.relative_path,
+                    error = %e,
+                    "Failed to delete file of purged medium"
+                );
+            }
+        }
+
+        let released_bytes = event.released_bytes;
+        let event_id = event.metadata.event_id;
+
+        self.event_bus.publish(event).await?;
+
+        self.quota_manager
+            .release(expired.owner_id, released_bytes, event_id)
+            .await?;
+
+        info!(
+            medium_id = %expired.medium_id,
+            released_bytes = %released_bytes.as_u64(),
+            "Trashed medium purged"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::{MediumId, Tag},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PublishMediumEvent},
+};
+
+#[derive(Debug)]
+pub struct RemoveMediumTagsCommand {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+    pub tags: Vec<String>,
+}
+
+#[derive(new)]
+pub struct RemoveMediumTagsHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl RemoveMediumTagsHandler {
+    /// Untag the medium, returns the tags it keeps
+    #[instrument(skip(self), fields(user_id = %command.user_id, medium_id = %command.medium_id))]
+    pub async fn handle(&self, command: RemoveMediumTagsCommand) -> ApplicationResult<Vec<Tag>> {
+        let tags = Tag::parse_all(&command.tags)?;
+        let mut medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        if let Some(event) = medium.remove_tags(&tags)? {
+            let count = event.tags.len();
+            self.event_bus.publish(event).await.map_err(|e| {
+                error!(error = %e, "Failed to publish MediumTagsRemovedEvent");
+                e
+            })?;
+            info!(count, "Medium untagged");
+        }
+
+        Ok(medium.tags)
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::{EntityNotFoundSnafu, ValidationSnafu},
+    medium::MediumId,
+    user::UserId,
+};
+use snafu::{ensure, OptionExt};
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::{
+        ports::{MediumRepository, PublishMediumEvent},
+        queries::{FindDuplicatesHandler, FindDuplicatesQuery},
+    },
+};
+
+#[derive(Debug)]
+pub struct ResolveDuplicatesCommand {
+    pub user_id: UserId,
+    /// The medium that stays in the library
+    pub keep_medium_id: MediumId,
+    /// The duplicates of it, they are moved to the trash
+    pub medium_ids: Vec<MediumId>,
+}
+
+/// Resolves a cluster of duplicates by keeping one medium and moving the
+/// others to the trash, from where they can still be restored
+#[derive(new)]
+pub struct ResolveDuplicatesHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+    find_duplicates: Arc<FindDuplicatesHandler>,
+}
+
+impl ResolveDuplicatesHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, keep_medium_id = %command.keep_medium_id))]
+    pub async fn handle(&self, command: ResolveDuplicatesCommand) -> ApplicationResult<()> {
+        let mut trashed: Vec<MediumId> = Vec::with_capacity(command.medium_ids.len());
+        for id in &command.medium_ids {
+            if *id != command.keep_medium_id && !trashed.contains(id) {
+                trashed.push(*id);
+            }
+        }
+        ensure!(
+            !trashed.is_empty(),
+            ValidationSnafu {
+                message: "No duplicates to move to the trash"
+            }
+        );
+
+        // Only media the duplicate lookup clusters with the kept one may be
+        // trashed, media in the trash are never part of a cluster
+        let clusters = self
+            .find_duplicates
+            .handle(FindDuplicatesQuery {
+                user_id: command.user_id,
+            })
+            .await?;
+        let cluster = clusters
+            .iter()
+            .find(|cluster| cluster.medium_ids.contains(&command.keep_medium_id));
+        ensure!(
+            cluster.is_some_and(|cluster| trashed.iter().all(|id| cluster.medium_ids.contains(id))),
+            ValidationSnafu {
+                message: "The media are not duplicates of the medium to keep"
+            }
+        );
+
+        let kept = self
+            .medium_repository
+            .find_by_id(command.keep_medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.keep_medium_id,
+            })?;
+        ensure!(
+            !kept.is_deleted(),
+            ValidationSnafu {
+                message: "The medium to keep is in the trash"
+            }
+        );
+
+        // Load and delete all media first so a missing or trashed one leaves
+        // the cluster untouched
+        let mut deletions = Vec::with_capacity(trashed.len());
+        for id in &trashed {
+            let mut medium = self
+                .medium_repository
+                .find_by_id(*id, command.user_id)
+                .await?
+                .context(EntityNotFoundSnafu {
+                    entity: "Medium",
+                    id: *id,
+                })?;
+            deletions.push((medium.id, medium.delete()?));
+        }
+
+        for (medium_id, event) in deletions {
+            self.event_bus.publish(event).await.map_err(|e| {
+                error!(medium_id = %medium_id, error = %e, "Failed to publish MediumDeletedEvent");
+                e
+            })?;
+        }
+
+        info!(trashed = trashed.len(), "Duplicates moved to trash");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::EntityNotFoundSnafu, medium::MediumId, user::UserId};
+use snafu::OptionExt;
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PublishMediumEvent},
+};
+
+#[derive(Debug)]
+pub struct RestoreMediumCommand {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+}
+
+#[derive(new)]
+pub struct RestoreMediumHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl RestoreMediumHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, medium_id = %command.medium_id))]
+    pub async fn handle(&self, command: RestoreMediumCommand) -> ApplicationResult<()> {
+        let mut medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        let event = medium.restore()?;
+
+        self.event_bus.publish(event).await.map_err(|e| {
+            error!(error = %e, "Failed to publish MediumRestoredEvent");
+            e
+        })?;
+
+        info!("Medium restored from trash");
+
+        Ok(())
+    }
+}
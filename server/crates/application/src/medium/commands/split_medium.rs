@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::{MediumId, MediumItemId},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PublishMediumEvent},
+};
+
+#[derive(Debug)]
+pub struct SplitMediumCommand {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+    /// Items that are taken out of the stack into a new medium
+    pub item_ids: Vec<MediumItemId>,
+}
+
+#[derive(new)]
+pub struct SplitMediumHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl SplitMediumHandler {
+    /// Returns the id of the new medium
+    #[instrument(skip(self), fields(user_id = %command.user_id, medium_id = %command.medium_id))]
+    pub async fn handle(&self, command: SplitMediumCommand) -> ApplicationResult<MediumId> {
+        let mut medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        let (new_medium, split_off, split) = medium.split(&command.item_ids)?;
+
+        // Move the items to the new medium before the stack forgets them
+        self.event_bus.publish(split_off).await.map_err(|e| {
+            error!(error = %e, "Failed to publish MediumSplitOffEvent");
+            e
+        })?;
+        self.event_bus.publish(split).await.map_err(|e| {
+            error!(error = %e, "Failed to publish MediumSplitEvent");
+            e
+        })?;
+
+        info!(new_medium_id = %new_medium.id, "Medium split");
+
+        Ok(new_medium.id)
+    }
+}
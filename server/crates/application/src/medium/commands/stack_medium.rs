@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset};
+use derive_new::new;
+use domain::{
+    medium::{FileLocation, Medium, MediumId, MediumItemId},
+    user::UserId,
+};
+use tracing::{debug, info, instrument, warn};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{FileStorage, MediumRepository, PublishMediumEvent, StackCriteria},
+};
+
+pub struct StackMediumCommand {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub taken_at: Option<DateTime<FixedOffset>>,
+    pub camera_serial_number: Option<String>,
+    pub burst_id: Option<String>,
+}
+
+/// Stacks a medium with the media captured together with it, i.e. the RAW
+/// and JPEG of a shot or the frames of a burst, once its metadata is known.
+/// The oldest of them is kept.
+#[derive(new)]
+pub struct StackMediumHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    file_storage: Arc<dyn FileStorage>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl StackMediumHandler {
+    #[instrument(skip(self, command), fields(
+        medium_id = %command.medium_id,
+        user_id = %command.owner_id,
+    ))]
+    pub async fn handle(&self, command: StackMediumCommand) -> ApplicationResult<()> {
+        let Some(medium) = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.owner_id)
+            .await?
+        else {
+            debug!("Medium not found, skipping stacking");
+            return Ok(());
+        };
+        if !medium.medium_type.is_stackable() || medium.is_deleted() {
+            debug!(medium_type = ?medium.medium_type, "Medium cannot be stacked");
+            return Ok(());
+        }
+
+        let burst = command.burst_id.is_some();
+        let criteria = match (command.burst_id, command.taken_at) {
+            (Some(burst_id), _) => StackCriteria::Burst { burst_id },
+            (None, Some(taken_at)) => {
+                let Some(leading) = medium.find_item(medium.leading_item_id) else {
+                    return Ok(());
+                };
+                StackCriteria::Shot {
+                    taken_at,
+                    camera_serial_number: command.camera_serial_number,
+                    filename_stem: leading.filename.stem().to_string(),
+                }
+            }
+            (None, None) => {
+                debug!("No capture time, nothing to stack with");
+                return Ok(());
+            }
+        };
+
+        let mut stack = vec![medium];
+        for id in self
+            .medium_repository
+            .find_stack_candidates(&criteria, command.owner_id)
+            .await?
+        {
+            if id == command.medium_id {
+                continue;
+            }
+            let Some(candidate) = self
+                .medium_repository
+                .find_by_id(id, command.owner_id)
+                .await?
+            else {
+                continue;
+            };
+            if candidate.medium_type.is_stackable() && !candidate.is_deleted() {
+                stack.push(candidate);
+            }
+        }
+        if stack.len() < 2 {
+            debug!(?criteria, "Nothing to stack with");
+            return Ok(());
+        }
+
+        stack.sort_by_key(|medium| medium.created_at);
+        let mut media = stack.into_iter();
+        let mut target = media.next().expect("Stack has at least two media");
+        for mut other in media {
+            self.stack(&mut target, &mut other, burst).await?;
+        }
+
+        info!(
+            target_medium_id = %target.id,
+            medium_type = ?target.medium_type,
+            items = target.items.len(),
+            "Stacked media"
+        );
+        Ok(())
+    }
+
+    /// Stacks `other` onto `target` and deletes the previews that were dropped
+    pub(crate) async fn stack(
+        &self,
+        target: &mut Medium,
+        other: &mut Medium,
+        burst: bool,
+    ) -> ApplicationResult<()> {
+        let previews: Vec<(MediumItemId, FileLocation)> = target
+            .items
+            .iter()
+            .chain(other.items.iter())
+            .filter(|item| item.is_cache_only())
+            .flat_map(|item| item.locations.iter().map(|l| (item.id, l.clone())))
+            .collect();
+
+        let (stacked, merged) = target.stack(other, burst)?;
+
+        // Move the items before the other medium is removed from the read model
+        self.event_bus.publish(stacked).await?;
+        self.event_bus.publish(merged).await?;
+
+        for (item_id, location) in &previews {
+            if target.find_item(*item_id).is_some() {
+                continue;
+            }
+            if let Err(e) = self.file_storage.delete_file(location).await {
+                warn!(
+                    %item_id,
+                    path = ?location.relative_path,
+                    error = %e,
+                    "Failed to delete preview dropped by stacking"
+                );
+            }
+        }
+
+        debug!(
+            target_medium_id = %target.id,
+            merged_medium_id = %other.id,
+            "Stacked medium"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::metadata::events::MetadataExtractedEvent;
+use tracing::{debug, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    event_bus::EventProcessor,
+    medium::commands::{PairLivePhotoCommand, PairLivePhotoHandler},
+};
+
+#[derive(new)]
+pub struct LivePhotoPairingListener {
+    handler: Arc<PairLivePhotoHandler>,
+}
+
+#[async_trait]
+impl EventProcessor<MetadataExtractedEvent> for LivePhotoPairingListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "LivePhotoPairingListener::MetadataExtractedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataExtractedEvent) -> ApplicationResult<()> {
+        // Only the still and the video of a Live Photo carry the identifier
+        let Some(content_identifier) = event.metadata.content_identifier.clone() else {
+            return Ok(());
+        };
+
+        debug!(
+            medium_id = %event.medium_id,
+            %content_identifier,
+            "Looking for the other half of the Live Photo"
+        );
+
+        self.handler
+            .handle(PairLivePhotoCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+                content_identifier,
+            })
+            .await
+    }
+}
@@ -2,7 +2,14 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use derive_new::new;
-use domain::{medium::camera::GpsCoordinates, metadata::events::MetadataExtractedEvent};
+use domain::{
+    medium::{camera::GpsCoordinates, MediumId},
+    metadata::{
+        events::{MetadataExtractedEvent, MetadataOverriddenEvent, MetadataSidecarAppliedEvent},
+        Metadata,
+    },
+    user::UserId,
+};
 use tracing::{debug, info, instrument};
 
 use crate::{
@@ -16,6 +23,51 @@ pub struct MediumMetadataEnrichmentListener {
     handler: Arc<EnrichMediumWithMetadataHandler>,
 }
 
+impl MediumMetadataEnrichmentListener {
+    /// Copies the effective values, overrides before extracted ones, onto the medium
+    fn command_of(
+        medium_id: MediumId,
+        owner_id: UserId,
+        metadata: &Metadata,
+    ) -> EnrichMediumWithMetadataCommand {
+        // Extract relevant fields from metadata (anti-corruption layer)
+        let (taken_at, taken_at_timezone_source) = match metadata.capture_date() {
+            Some(capture_date) => (Some(capture_date), metadata.capture_timezone_source()),
+            None => (metadata.file_info.file_modified_at, None),
+        };
+
+        let gps_coordinates =
+            metadata
+                .gps_coordinates()
+                .and_then(|(latitude, longitude, altitude)| {
+                    GpsCoordinates::new(latitude, longitude, altitude).ok()
+                });
+
+        EnrichMediumWithMetadataCommand {
+            medium_id,
+            owner_id,
+            taken_at,
+            taken_at_timezone_source,
+            camera_make: metadata.camera_make().map(String::from),
+            camera_model: metadata.camera_model().map(String::from),
+            gps_coordinates,
+            rating: metadata.rating(),
+            label: metadata.label().map(String::from),
+            keywords: Vec::new(),
+            removed_keywords: Vec::new(),
+        }
+    }
+
+    async fn enrich(&self, command: EnrichMediumWithMetadataCommand) -> ApplicationResult<()> {
+        let medium_id = command.medium_id;
+        self.handler.handle(command).await?;
+
+        debug!("Enriched medium metadata for medium_id={}", medium_id);
+
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl EventProcessor<MetadataExtractedEvent> for MediumMetadataEnrichmentListener {
     type Error = crate::error::ApplicationError;
@@ -30,44 +82,66 @@ impl EventProcessor<MetadataExtractedEvent> for MediumMetadataEnrichmentListener
             event.medium_id, event.leading_item_id,
         );
 
-        // Extract relevant fields from metadata (anti-corruption layer)
-        let taken_at = event
-            .metadata
-            .camera_info
-            .as_ref()
-            .and_then(|c| c.capture_date)
-            .or(event.metadata.file_info.file_modified_at);
-
-        let camera_make = event
-            .metadata
-            .camera_info
-            .as_ref()
-            .and_then(|c| c.make.clone());
-
-        let camera_model = event
-            .metadata
-            .camera_info
-            .as_ref()
-            .and_then(|c| c.model.clone());
+        self.enrich(Self::command_of(
+            event.medium_id,
+            event.owner_id,
+            &event.metadata,
+        ))
+        .await
+    }
+}
 
-        let gps_coordinates =
-            event.metadata.location.as_ref().and_then(|loc| {
-                GpsCoordinates::new(loc.latitude, loc.longitude, loc.altitude).ok()
-            });
-
-        self.handler
-            .handle(EnrichMediumWithMetadataCommand {
-                medium_id: event.medium_id,
-                owner_id: event.owner_id,
-                taken_at,
-                camera_make,
-                camera_model,
-                gps_coordinates,
-            })
-            .await?;
-
-        debug!("Enriched medium metadata for medium_id={}", event.medium_id);
+#[async_trait]
+impl EventProcessor<MetadataOverriddenEvent> for MediumMetadataEnrichmentListener {
+    type Error = crate::error::ApplicationError;
 
-        Ok(())
+    #[instrument(
+        name = "MediumMetadataEnrichmentListener::MetadataOverriddenEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataOverriddenEvent) -> ApplicationResult<()> {
+        info!(
+            "Enriching medium metadata with overrides for medium_id={}",
+            event.medium_id,
+        );
+
+        self.enrich(Self::command_of(
+            event.medium_id,
+            event.owner_id,
+            &event.metadata,
+        ))
+        .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataSidecarAppliedEvent> for MediumMetadataEnrichmentListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "MediumMetadataEnrichmentListener::MetadataSidecarAppliedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataSidecarAppliedEvent) -> ApplicationResult<()> {
+        // Of the values on the medium only the location, the curation and the
+        // tags are taken from sidecars
+        let sidecar = event.metadata.sidecar.as_ref();
+        if event.location.is_none()
+            && event.removed_keywords.is_empty()
+            && sidecar
+                .is_none_or(|s| s.rating.is_none() && s.label.is_none() && s.keywords.is_empty())
+        {
+            return Ok(());
+        }
+
+        info!(
+            "Enriching medium metadata with sidecar for medium_id={}",
+            event.medium_id,
+        );
+
+        let mut command = Self::command_of(event.medium_id, event.owner_id, &event.metadata);
+        command.keywords = sidecar.map(|s| s.keywords.clone()).unwrap_or_default();
+        command.removed_keywords = event.removed_keywords.clone();
+        self.enrich(command).await
     }
 }
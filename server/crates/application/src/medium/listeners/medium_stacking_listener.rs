@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::metadata::events::MetadataExtractedEvent;
+use tracing::{debug, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    event_bus::EventProcessor,
+    medium::commands::{StackMediumCommand, StackMediumHandler},
+};
+
+#[derive(new)]
+pub struct MediumStackingListener {
+    handler: Arc<StackMediumHandler>,
+}
+
+#[async_trait]
+impl EventProcessor<MetadataExtractedEvent> for MediumStackingListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "MediumStackingListener::MetadataExtractedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataExtractedEvent) -> ApplicationResult<()> {
+        let metadata = &event.metadata;
+        let taken_at = metadata.capture_date();
+        // Without a capture time or burst there is nothing to group the medium by
+        if taken_at.is_none() && metadata.burst_id.is_none() {
+            return Ok(());
+        }
+
+        debug!(
+            medium_id = %event.medium_id,
+            burst_id = ?metadata.burst_id,
+            "Looking for media captured together"
+        );
+
+        self.handler
+            .handle(StackMediumCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+                taken_at,
+                camera_serial_number: metadata.camera_serial_number.clone(),
+                burst_id: metadata.burst_id.clone(),
+            })
+            .await
+    }
+}
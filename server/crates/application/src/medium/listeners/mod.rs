@@ -1,5 +1,11 @@
+mod live_photo_pairing_listener;
 mod medium_metadata_enrichment_listener;
+mod medium_stacking_listener;
 mod move_to_permanent_storage_listener;
+mod preview_generation_listener;
 
+pub use live_photo_pairing_listener::*;
 pub use medium_metadata_enrichment_listener::*;
+pub use medium_stacking_listener::*;
 pub use move_to_permanent_storage_listener::*;
+pub use preview_generation_listener::*;
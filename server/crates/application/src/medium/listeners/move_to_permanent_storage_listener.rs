@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use derive_new::new;
-use domain::medium::events::MediumUpdatedEvent;
+use domain::medium::events::{
+    LivePhotoPairedEvent, MediaStackedEvent, MediumItemCreatedEvent, MediumSplitOffEvent,
+    MediumUpdatedEvent,
+};
 use tracing::{info, instrument};
 
 use crate::{
@@ -40,3 +43,107 @@ impl EventProcessor<MediumUpdatedEvent> for MoveToPermanentStorageListener {
             .await
     }
 }
+
+#[async_trait]
+impl EventProcessor<MediumItemCreatedEvent> for MoveToPermanentStorageListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "MoveToPermanentStorageListener::MediumItemCreatedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediumItemCreatedEvent) -> ApplicationResult<()> {
+        info!(
+            medium_id = %event.medium_id,
+            item_id = %event.item_id,
+            user_id = %event.user_id,
+            "Moving new medium item to permanent storage for medium_id={}",
+            event.medium_id,
+        );
+
+        self.handler
+            .handle(MoveToPermanentStorageCommand {
+                medium_id: event.medium_id,
+                user_id: event.user_id,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<LivePhotoPairedEvent> for MoveToPermanentStorageListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "MoveToPermanentStorageListener::LivePhotoPairedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &LivePhotoPairedEvent) -> ApplicationResult<()> {
+        info!(
+            medium_id = %event.medium_id,
+            video_medium_id = %event.video_medium_id,
+            user_id = %event.owner_id,
+            "Moving paired video items to permanent storage for medium_id={}",
+            event.medium_id,
+        );
+
+        self.handler
+            .handle(MoveToPermanentStorageCommand {
+                medium_id: event.medium_id,
+                user_id: event.owner_id,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MediaStackedEvent> for MoveToPermanentStorageListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "MoveToPermanentStorageListener::MediaStackedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediaStackedEvent) -> ApplicationResult<()> {
+        info!(
+            medium_id = %event.medium_id,
+            source_medium_id = %event.source_medium_id,
+            user_id = %event.owner_id,
+            "Moving stacked items to permanent storage for medium_id={}",
+            event.medium_id,
+        );
+
+        self.handler
+            .handle(MoveToPermanentStorageCommand {
+                medium_id: event.medium_id,
+                user_id: event.owner_id,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MediumSplitOffEvent> for MoveToPermanentStorageListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "MoveToPermanentStorageListener::MediumSplitOffEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediumSplitOffEvent) -> ApplicationResult<()> {
+        info!(
+            medium_id = %event.medium_id,
+            split_from_medium_id = %event.split_from_medium_id,
+            user_id = %event.owner_id,
+            "Moving split off items to permanent storage for medium_id={}",
+            event.medium_id,
+        );
+
+        self.handler
+            .handle(MoveToPermanentStorageCommand {
+                medium_id: event.medium_id,
+                user_id: event.owner_id,
+            })
+            .await
+    }
+}
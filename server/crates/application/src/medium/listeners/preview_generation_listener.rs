@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::medium::events::{MediumCreatedEvent, MediumSplitOffEvent};
+use tracing::{info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    event_bus::EventProcessor,
+    medium::commands::{GeneratePreviewsCommand, GeneratePreviewsHandler},
+};
+
+#[derive(new)]
+pub struct PreviewGenerationListener {
+    handler: Arc<GeneratePreviewsHandler>,
+}
+
+#[async_trait]
+impl EventProcessor<MediumCreatedEvent> for PreviewGenerationListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "PreviewGenerationListener::MediumCreatedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediumCreatedEvent) -> ApplicationResult<()> {
+        let item = &event.initial_item;
+        info!(
+            medium_id = %event.medium_id,
+            user_id = %event.user_id,
+            "Generating previews for medium_id={} (source_item_id={})",
+            event.medium_id,
+            item.id,
+        );
+
+        self.handler
+            .handle(GeneratePreviewsCommand {
+                medium_id: event.medium_id,
+                source_item_id: item.id,
+                user_id: event.user_id,
+                file_location: item
+                    .locations
+                    .first()
+                    .expect("Item must have a location")
+                    .clone(),
+                mime: item.mime.clone(),
+                medium_type: event.medium_type,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MediumSplitOffEvent> for PreviewGenerationListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "PreviewGenerationListener::MediumSplitOffEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediumSplitOffEvent) -> ApplicationResult<()> {
+        // The previews stayed with the stack the items were split off from
+        let Some(item) = event
+            .items
+            .iter()
+            .find(|item| item.id == event.leading_item_id)
+        else {
+            return Ok(());
+        };
+        info!(
+            medium_id = %event.medium_id,
+            user_id = %event.owner_id,
+            "Generating previews for split off medium_id={} (source_item_id={})",
+            event.medium_id,
+            item.id,
+        );
+
+        self.handler
+            .handle(GeneratePreviewsCommand {
+                medium_id: event.medium_id,
+                source_item_id: item.id,
+                user_id: event.owner_id,
+                file_location: item
+                    .locations
+                    .first()
+                    .expect("Item must have a location")
+                    .clone(),
+                mime: item.mime.clone(),
+                medium_type: event.medium_type,
+            })
+            .await
+    }
+}
@@ -1,11 +1,10 @@
 use std::sync::Arc;
 
-use commands::PublishCleanupEvent;
-use domain::medium::{events::MediumCreatedEvent, StoragePathService};
+use domain::medium::StoragePathService;
 
 use crate::{
-    event_bus::PublishEvent,
-    medium::ports::{FileStorage, MediumRepository, PublishMediumEvent},
+    config::MediumConfig,
+    medium::ports::{FileStorage, MediumRepository, PreviewServices, PublishMediumEvent},
     user::QuotaManager,
 };
 
@@ -16,11 +15,29 @@ pub mod queries;
 
 pub struct MediumApplicationHandlers {
     pub create_medium_stream: Arc<commands::CreateMediumStreamHandler>,
+    pub add_medium_item: Arc<commands::AddMediumItemHandler>,
     pub find_all_media: Arc<queries::FindAllMediaHandler>,
     pub find_medium: Arc<queries::FindMediumHandler>,
+    pub find_medium_item_content: Arc<queries::FindMediumItemContentHandler>,
     pub enrich_medium_with_metadata: Arc<commands::EnrichMediumWithMetadataHandler>,
     pub move_to_permanent_storage: Arc<commands::MoveToPermanentStorageHandler>,
     pub cleanup_expired_temp_storage: Arc<commands::CleanupExpiredTempStorageHandler>,
+    pub delete_medium: Arc<commands::DeleteMediumHandler>,
+    pub restore_medium: Arc<commands::RestoreMediumHandler>,
+    pub add_medium_tags: Arc<commands::AddMediumTagsHandler>,
+    pub remove_medium_tags: Arc<commands::RemoveMediumTagsHandler>,
+    pub curate_media: Arc<commands::CurateMediaHandler>,
+    pub purge_expired_trash: Arc<commands::PurgeExpiredTrashHandler>,
+    pub find_trash: Arc<queries::FindTrashHandler>,
+    pub generate_previews: Arc<commands::GeneratePreviewsHandler>,
+    pub find_medium_preview: Arc<queries::FindMediumPreviewHandler>,
+    pub pair_live_photo: Arc<commands::PairLivePhotoHandler>,
+    pub stack_medium: Arc<commands::StackMediumHandler>,
+    pub merge_media: Arc<commands::MergeMediaHandler>,
+    pub split_medium: Arc<commands::SplitMediumHandler>,
+    pub find_duplicates: Arc<queries::FindDuplicatesHandler>,
+    pub resolve_duplicates: Arc<commands::ResolveDuplicatesHandler>,
+    pub compute_perceptual_hashes: Arc<commands::ComputePerceptualHashesHandler>,
 }
 
 impl MediumApplicationHandlers {
@@ -29,32 +46,125 @@ impl MediumApplicationHandlers {
         file_storage: Arc<dyn FileStorage>,
         quota_manager: Arc<QuotaManager>,
         event_bus: Arc<dyn PublishMediumEvent>,
-        cleanup_event_bus: Arc<dyn PublishCleanupEvent>,
         storage_path_service: Arc<StoragePathService>,
-        medium_event_bus: Arc<dyn PublishEvent<MediumCreatedEvent>>,
+        previews: PreviewServices,
+        config: Arc<MediumConfig>,
     ) -> Self {
+        let duplicate_config = Arc::new(config.duplicates.clone());
+        let generate_previews = Arc::new(commands::GeneratePreviewsHandler::new(
+            medium_repository.clone(),
+            file_storage.clone(),
+            previews.renderer,
+            previews.embedded_extractor,
+            previews.poster_frame_generator,
+            storage_path_service.clone(),
+            event_bus.clone(),
+        ));
+        let find_duplicates = Arc::new(queries::FindDuplicatesHandler::new(
+            medium_repository.clone(),
+            duplicate_config.clone(),
+        ));
+        let stack_medium = Arc::new(commands::StackMediumHandler::new(
+            medium_repository.clone(),
+            file_storage.clone(),
+            event_bus.clone(),
+        ));
+
         Self {
             create_medium_stream: Arc::new(commands::CreateMediumStreamHandler::new(
+                medium_repository.clone(),
                 file_storage.clone(),
-                quota_manager,
-                medium_event_bus,
+                quota_manager.clone(),
+                event_bus.clone(),
+                duplicate_config,
+            )),
+            add_medium_item: Arc::new(commands::AddMediumItemHandler::new(
+                medium_repository.clone(),
+                file_storage.clone(),
+                quota_manager.clone(),
+                event_bus.clone(),
             )),
             find_all_media: Arc::new(queries::FindAllMediaHandler::new(medium_repository.clone())),
             find_medium: Arc::new(queries::FindMediumHandler::new(medium_repository.clone())),
+            find_trash: Arc::new(queries::FindTrashHandler::new(medium_repository.clone())),
+            find_medium_preview: Arc::new(queries::FindMediumPreviewHandler::new(
+                medium_repository.clone(),
+                file_storage.clone(),
+                generate_previews.clone(),
+            )),
+            generate_previews,
+            find_medium_item_content: Arc::new(queries::FindMediumItemContentHandler::new(
+                medium_repository.clone(),
+                file_storage.clone(),
+            )),
             enrich_medium_with_metadata: Arc::new(commands::EnrichMediumWithMetadataHandler::new(
                 medium_repository.clone(),
-                event_bus,
+                event_bus.clone(),
+            )),
+            pair_live_photo: Arc::new(commands::PairLivePhotoHandler::new(
+                medium_repository.clone(),
+                file_storage.clone(),
+                event_bus.clone(),
+                Arc::new(config.live_photo.clone()),
+            )),
+            merge_media: Arc::new(commands::MergeMediaHandler::new(
+                medium_repository.clone(),
+                stack_medium.clone(),
+            )),
+            stack_medium,
+            split_medium: Arc::new(commands::SplitMediumHandler::new(
+                medium_repository.clone(),
+                event_bus.clone(),
+            )),
+            resolve_duplicates: Arc::new(commands::ResolveDuplicatesHandler::new(
+                medium_repository.clone(),
+                event_bus.clone(),
+                find_duplicates.clone(),
+            )),
+            find_duplicates,
+            compute_perceptual_hashes: Arc::new(commands::ComputePerceptualHashesHandler::new(
+                medium_repository.clone(),
+                previews.perceptual_hasher,
+                event_bus.clone(),
+            )),
+            delete_medium: Arc::new(commands::DeleteMediumHandler::new(
+                medium_repository.clone(),
+                event_bus.clone(),
+            )),
+            restore_medium: Arc::new(commands::RestoreMediumHandler::new(
+                medium_repository.clone(),
+                event_bus.clone(),
+            )),
+            add_medium_tags: Arc::new(commands::AddMediumTagsHandler::new(
+                medium_repository.clone(),
+                event_bus.clone(),
+            )),
+            remove_medium_tags: Arc::new(commands::RemoveMediumTagsHandler::new(
+                medium_repository.clone(),
+                event_bus.clone(),
+            )),
+            curate_media: Arc::new(commands::CurateMediaHandler::new(
+                medium_repository.clone(),
+                event_bus.clone(),
+            )),
+            purge_expired_trash: Arc::new(commands::PurgeExpiredTrashHandler::new(
+                medium_repository.clone(),
+                file_storage.clone(),
+                quota_manager,
+                event_bus.clone(),
             )),
             move_to_permanent_storage: Arc::new(commands::MoveToPermanentStorageHandler::new(
                 medium_repository.clone(),
                 file_storage.clone(),
                 storage_path_service,
+                event_bus.clone(),
             )),
             cleanup_expired_temp_storage: Arc::new(
                 commands::CleanupExpiredTempStorageHandler::new(
                     medium_repository,
                     file_storage,
-                    cleanup_event_bus,
+                    event_bus.clone(),
+                    event_bus,
                 ),
             ),
         }
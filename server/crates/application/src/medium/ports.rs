@@ -1,20 +1,32 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 
 use async_trait::async_trait;
 use byte_unit::Byte;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, FixedOffset, Utc};
 use domain::{
     error::DomainResult,
     medium::{
-        events::{MediumCreatedEvent, MediumUpdatedEvent},
-        FileLocation, FileMetadata, Medium, MediumFilter, MediumId, MediumItemId, MediumListItem,
+        events::{
+            LivePhotoPairedEvent, MediaStackedEvent, MediumCreatedEvent,
+            MediumCurationChangedEvent, MediumDeletedEvent, MediumItemCreatedEvent,
+            MediumItemLocationAddedEvent, MediumItemLocationRemovedEvent, MediumMergedEvent,
+            MediumPurgedEvent, MediumRestoredEvent, MediumSplitEvent, MediumSplitOffEvent,
+            MediumTagsAddedEvent, MediumTagsRemovedEvent, MediumUpdatedEvent,
+            PerceptualHashComputedEvent, PreviewGenerationCompletedEvent,
+            PreviewGenerationFailedEvent, PreviewGenerationStartedEvent,
+        },
+        Dimensions, FileLocation, FileMetadata, FileStat, Medium, MediumFilter, MediumId,
+        MediumItemId, MediumListItem, PerceptualHash, PreviewSize,
     },
+    shared::crypto::Sha256,
     user::UserId,
 };
-use tokio::io::AsyncRead;
+use mime::Mime;
+use tokio::io::{AsyncRead, AsyncSeek};
 
-use crate::event_bus::PublishEvent;
+use crate::{event_bus::PublishEvent, medium::commands::PublishCleanupEvent};
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait MediumRepository: Send + Sync {
     async fn find_by_id(&self, id: MediumId, user_id: UserId) -> DomainResult<Option<Medium>>;
@@ -30,6 +42,92 @@ pub trait MediumRepository: Send + Sync {
         &self,
         created_before: DateTime<Utc>,
     ) -> DomainResult<Vec<ExpiredTempLocation>>;
+    async fn find_trash(&self, user_id: UserId) -> DomainResult<Vec<MediumListItem>>;
+    async fn find_expired_trash(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> DomainResult<Vec<ExpiredTrashedMedium>>;
+    /// Media of the user not in the trash whose metadata carries the given
+    /// Live Photo content identifier, oldest first
+    async fn find_by_content_identifier(
+        &self,
+        content_identifier: &str,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>>;
+    /// Media of the user not in the trash whose metadata matches the
+    /// criteria, oldest first
+    async fn find_stack_candidates(
+        &self,
+        criteria: &StackCriteria,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>>;
+    /// The oldest medium of the user not in the trash with an original of
+    /// the given checksum
+    async fn find_by_checksum(
+        &self,
+        checksum: &Sha256,
+        user_id: UserId,
+    ) -> DomainResult<Option<MediumId>>;
+    /// Groups of media of the user not in the trash whose originals share a
+    /// checksum, each oldest first
+    async fn find_checksum_duplicates(&self, user_id: UserId) -> DomainResult<Vec<Vec<MediumId>>>;
+    /// Perceptual hashes of the media of the user not in the trash, oldest first
+    async fn find_perceptual_hashes(
+        &self,
+        user_id: UserId,
+    ) -> DomainResult<Vec<(MediumId, PerceptualHash)>>;
+    /// Media not in the trash that have a thumbnail but no perceptual hash yet
+    async fn find_unhashed(&self, limit: i64) -> DomainResult<Vec<UnhashedMedium>>;
+    /// Media of the user not in the trash with a capture date that match the
+    /// criteria, oldest first
+    async fn find_capture_date_shift_candidates(
+        &self,
+        criteria: &CaptureDateShiftCriteria,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>>;
+    /// Media of the user not in the trash without GPS coordinates whose
+    /// capture date lies in the range, oldest first
+    async fn find_geotagging_candidates(
+        &self,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<GeotaggingCandidate>>;
+}
+
+/// What the files of a single capture have in common
+#[derive(Debug, Clone)]
+pub enum StackCriteria {
+    /// The frames of a burst carry the same identifier
+    Burst { burst_id: String },
+    /// The RAW and JPEG of a shot share the capture time, the camera and the
+    /// filename without its extension
+    Shot {
+        taken_at: DateTime<FixedOffset>,
+        camera_serial_number: Option<String>,
+        filename_stem: String,
+    },
+}
+
+/// Which media a batch shift of capture dates applies to, every given
+/// criterion has to match
+#[derive(Debug, Clone, Default)]
+pub struct CaptureDateShiftCriteria {
+    pub medium_ids: Vec<MediumId>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+}
+
+impl CaptureDateShiftCriteria {
+    pub fn is_empty(&self) -> bool {
+        self.medium_ids.is_empty()
+            && self.start_date.is_none()
+            && self.end_date.is_none()
+            && self.camera_make.is_none()
+            && self.camera_model.is_none()
+    }
 }
 
 pub struct ExpiredTempLocation {
@@ -39,6 +137,21 @@ pub struct ExpiredTempLocation {
     pub temp_location: FileLocation,
 }
 
+pub struct ExpiredTrashedMedium {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+}
+
+pub struct UnhashedMedium {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+}
+
+pub struct GeotaggingCandidate {
+    pub medium_id: MediumId,
+    pub taken_at: DateTime<Utc>,
+}
+
 #[async_trait]
 pub trait FileStorage: Send + Sync {
     async fn store_file(&self, location: &FileLocation, content: Vec<u8>) -> DomainResult<()>;
@@ -49,24 +162,134 @@ pub trait FileStorage: Send + Sync {
         stream: Box<dyn AsyncRead + Send + Unpin>,
     ) -> DomainResult<()>;
 
+    /// Fails instead of replacing a file already at `dest`
     async fn copy_file(&self, src: &FileLocation, dest: &FileLocation) -> DomainResult<()>;
+    /// Fails instead of replacing a file already at `dest`
     async fn move_file(&self, src: &FileLocation, dest: &FileLocation) -> DomainResult<()>;
+    async fn file_exists(&self, location: &FileLocation) -> DomainResult<bool>;
     async fn retrieve_file(&self, location: &FileLocation) -> DomainResult<Vec<u8>>;
     async fn retrieve_file_stream(
         &self,
         location: &FileLocation,
-    ) -> DomainResult<Box<dyn AsyncRead + Unpin>>;
+    ) -> DomainResult<Box<dyn FileStream>>;
     async fn get_local_path(&self, location: &FileLocation) -> DomainResult<PathBuf>;
     async fn delete_file(&self, location: &FileLocation) -> DomainResult<()>;
     async fn get_file_metadata(&self, location: &FileLocation) -> DomainResult<FileMetadata>;
+    /// Size and modification time of the file, without reading it
+    async fn stat_file(&self, location: &FileLocation) -> DomainResult<FileStat>;
+}
+
+/// Seekable byte stream of a stored file
+pub trait FileStream: AsyncRead + AsyncSeek + Send + Unpin {}
+
+impl<T> FileStream for T where T: AsyncRead + AsyncSeek + Send + Unpin {}
+
+#[async_trait]
+pub trait PreviewRenderer: Send + Sync {
+    /// Whether files of this type can be decoded
+    fn supports(&self, mime: &Mime) -> bool;
+
+    /// Decode the file at `location` once and render it at each of the given
+    /// sizes, upright according to its EXIF orientation
+    async fn render(
+        &self,
+        location: &FileLocation,
+        sizes: &[PreviewSize],
+    ) -> DomainResult<Vec<RenderedPreview>>;
+}
+
+/// An encoded JPEG preview
+pub struct RenderedPreview {
+    pub size: PreviewSize,
+    pub dimensions: Dimensions,
+    pub content: Vec<u8>,
+}
+
+/// Extracts the JPEG cameras embed into their RAW files, see UC-M6
+#[async_trait]
+pub trait EmbeddedPreviewExtractor: Send + Sync {
+    /// The largest embedded JPEG of the file at `location`, upright according
+    /// to the orientation of the RAW file. `None` if the file has none.
+    async fn extract(&self, location: &FileLocation) -> DomainResult<Option<SourcePreview>>;
+}
+
+/// Grabs a representative frame of a video
+#[async_trait]
+pub trait PosterFrameGenerator: Send + Sync {
+    /// A JPEG of a frame of the video at `location`, upright according to
+    /// its rotation. `None` if no frame can be produced.
+    async fn generate(&self, location: &FileLocation) -> DomainResult<Option<SourcePreview>>;
+}
+
+/// Computes the hash near-duplicates are detected by
+#[async_trait]
+pub trait PerceptualHasher: Send + Sync {
+    /// [`PerceptualHash`] of the image at `location`
+    async fn hash(&self, location: &FileLocation) -> DomainResult<PerceptualHash>;
+}
+
+/// A full size JPEG extracted from a RAW file or video, the standard
+/// previews are rendered from it
+pub struct SourcePreview {
+    pub dimensions: Dimensions,
+    pub content: Vec<u8>,
+}
+
+/// Ports the previews of a medium are produced with
+#[derive(Clone)]
+pub struct PreviewServices {
+    pub renderer: Arc<dyn PreviewRenderer>,
+    pub embedded_extractor: Arc<dyn EmbeddedPreviewExtractor>,
+    pub poster_frame_generator: Arc<dyn PosterFrameGenerator>,
+    pub perceptual_hasher: Arc<dyn PerceptualHasher>,
 }
 
 pub trait PublishMediumEvent:
-    PublishEvent<MediumCreatedEvent> + PublishEvent<MediumUpdatedEvent>
+    PublishEvent<MediumCreatedEvent>
+    + PublishEvent<MediumItemCreatedEvent>
+    + PublishEvent<MediumItemLocationAddedEvent>
+    + PublishEvent<MediumItemLocationRemovedEvent>
+    + PublishEvent<MediumUpdatedEvent>
+    + PublishEvent<MediumDeletedEvent>
+    + PublishEvent<MediumRestoredEvent>
+    + PublishEvent<MediumPurgedEvent>
+    + PublishEvent<PreviewGenerationStartedEvent>
+    + PublishEvent<PreviewGenerationCompletedEvent>
+    + PublishEvent<PreviewGenerationFailedEvent>
+    + PublishEvent<LivePhotoPairedEvent>
+    + PublishEvent<MediumMergedEvent>
+    + PublishEvent<MediaStackedEvent>
+    + PublishEvent<MediumSplitEvent>
+    + PublishEvent<MediumSplitOffEvent>
+    + PublishEvent<PerceptualHashComputedEvent>
+    + PublishEvent<MediumTagsAddedEvent>
+    + PublishEvent<MediumTagsRemovedEvent>
+    + PublishEvent<MediumCurationChangedEvent>
+    + PublishCleanupEvent
 {
 }
 
 impl<T> PublishMediumEvent for T where
-    T: PublishEvent<MediumCreatedEvent> + PublishEvent<MediumUpdatedEvent>
+    T: PublishEvent<MediumCreatedEvent>
+        + PublishEvent<MediumItemCreatedEvent>
+        + PublishEvent<MediumItemLocationAddedEvent>
+        + PublishEvent<MediumItemLocationRemovedEvent>
+        + PublishEvent<MediumUpdatedEvent>
+        + PublishEvent<MediumDeletedEvent>
+        + PublishEvent<MediumRestoredEvent>
+        + PublishEvent<MediumPurgedEvent>
+        + PublishEvent<PreviewGenerationStartedEvent>
+        + PublishEvent<PreviewGenerationCompletedEvent>
+        + PublishEvent<PreviewGenerationFailedEvent>
+        + PublishEvent<LivePhotoPairedEvent>
+        + PublishEvent<MediumMergedEvent>
+        + PublishEvent<MediaStackedEvent>
+        + PublishEvent<MediumSplitEvent>
+        + PublishEvent<MediumSplitOffEvent>
+        + PublishEvent<PerceptualHashComputedEvent>
+        + PublishEvent<MediumTagsAddedEvent>
+        + PublishEvent<MediumTagsRemovedEvent>
+        + PublishEvent<MediumCurationChangedEvent>
+        + PublishCleanupEvent
 {
 }
@@ -27,7 +27,8 @@ impl FindAllMediaHandler {
         has_cursor = query.filter.cursor.is_some(),
         has_date_filter = query.filter.start_date.is_some() || query.filter.end_date.is_some(),
         has_album_filter = query.filter.album_id.is_some(),
-        has_tags = !query.filter.tags.is_empty()
+        has_tags = !query.filter.tags.is_empty(),
+        has_search = query.filter.search.is_some()
     ))]
     pub async fn handle(&self, query: FindAllMediaQuery) -> ApplicationResult<Vec<MediumListItem>> {
         info!("Finding all media for user");
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    medium::{cluster_duplicates, DuplicateCluster},
+    user::UserId,
+};
+use tracing::{debug, info, instrument};
+
+use crate::{config::DuplicateConfig, error::ApplicationResult, medium::ports::MediumRepository};
+
+#[derive(Debug)]
+pub struct FindDuplicatesQuery {
+    pub user_id: UserId,
+}
+
+#[derive(new)]
+pub struct FindDuplicatesHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    config: Arc<DuplicateConfig>,
+}
+
+impl FindDuplicatesHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id))]
+    pub async fn handle(
+        &self,
+        query: FindDuplicatesQuery,
+    ) -> ApplicationResult<Vec<DuplicateCluster>> {
+        info!("Finding duplicate media for user");
+
+        let exact = self
+            .medium_repository
+            .find_checksum_duplicates(query.user_id)
+            .await?;
+        let hashes = self
+            .medium_repository
+            .find_perceptual_hashes(query.user_id)
+            .await?;
+
+        let clusters = cluster_duplicates(&exact, &hashes, self.config.similarity_threshold);
+
+        debug!(
+            clusters = clusters.len(),
+            hashed = hashes.len(),
+            "Duplicate clusters found"
+        );
+
+        Ok(clusters)
+    }
+}
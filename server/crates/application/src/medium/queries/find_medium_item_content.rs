@@ -0,0 +1,139 @@
+use std::{io::SeekFrom, sync::Arc};
+
+use derive_new::new;
+use domain::{
+    error::{DomainError, EntityNotFoundSnafu},
+    medium::{FileLocation, FileStat, MediumId, MediumItem, MediumItemId, MediumItemType},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tokio::io::AsyncSeekExt;
+use tracing::{debug, error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{FileStorage, FileStream, MediumRepository},
+};
+
+#[derive(Debug)]
+pub struct FindMediumItemContentQuery {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+    pub item_id: MediumItemId,
+}
+
+/// Everything needed to serve the stored file of a medium item
+#[derive(Debug)]
+pub struct MediumItemContent {
+    pub item: MediumItem,
+    pub location: FileLocation,
+    pub file: FileStat,
+}
+
+impl MediumItemContent {
+    /// Identifies this version of the file without reading it. Sidecars are
+    /// rewritten in place by the XMP write-back, so they and items without a
+    /// checksum are identified by size and modification time.
+    pub fn validator(&self) -> String {
+        match self.item.checksum {
+            Some(checksum) if self.item.medium_item_type != MediumItemType::Sidecar => {
+                checksum.to_hex()
+            }
+            _ => format!(
+                "{:x}-{:x}",
+                self.file.size_bytes,
+                self.file.modified_at.timestamp_micros()
+            ),
+        }
+    }
+}
+
+#[derive(new)]
+pub struct FindMediumItemContentHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    file_storage: Arc<dyn FileStorage>,
+}
+
+impl FindMediumItemContentHandler {
+    #[instrument(skip(self), fields(
+        user_id = %query.user_id,
+        medium_id = %query.medium_id,
+        item_id = %query.item_id,
+    ))]
+    pub async fn handle(
+        &self,
+        query: FindMediumItemContentQuery,
+    ) -> ApplicationResult<MediumItemContent> {
+        info!("Finding medium item content");
+
+        let medium = self
+            .medium_repository
+            .find_by_id(query.medium_id, query.user_id)
+            .await
+            .and_then(|m| {
+                m.context(EntityNotFoundSnafu {
+                    entity: "Medium",
+                    id: query.medium_id,
+                })
+            })
+            .map_err(|e| {
+                error!(error = ?e, "Failed to find medium");
+                e
+            })?;
+
+        let item = medium
+            .find_item(query.item_id)
+            .context(EntityNotFoundSnafu {
+                entity: "MediumItem",
+                id: query.item_id,
+            })?
+            .clone();
+
+        let location = item
+            .fastest_location()
+            .context(EntityNotFoundSnafu {
+                entity: "FileLocation",
+                id: query.item_id,
+            })?
+            .clone();
+
+        let file = self.file_storage.stat_file(&location).await.map_err(|e| {
+            error!(error = ?e, storage_tier = ?location.storage_tier, "Failed to stat file");
+            e
+        })?;
+
+        debug!(
+            storage_tier = ?location.storage_tier,
+            size_bytes = file.size_bytes,
+            "Medium item content resolved"
+        );
+
+        Ok(MediumItemContent {
+            item,
+            location,
+            file,
+        })
+    }
+
+    /// Opens the stored file positioned at `offset` bytes
+    #[instrument(skip(self), fields(
+        storage_tier = ?location.storage_tier,
+        path = ?location.relative_path,
+    ))]
+    pub async fn open(
+        &self,
+        location: &FileLocation,
+        offset: u64,
+    ) -> ApplicationResult<Box<dyn FileStream>> {
+        let mut stream = self.file_storage.retrieve_file_stream(location).await?;
+
+        if offset > 0 {
+            stream.seek(SeekFrom::Start(offset)).await.map_err(|e| {
+                error!(error = ?e, offset, "Failed to seek file stream");
+                DomainError::from(e)
+            })?;
+        }
+
+        Ok(stream)
+    }
+}
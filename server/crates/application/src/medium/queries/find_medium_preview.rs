@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::{MediumId, PreviewSize},
+    user::UserId,
+};
+use snafu::{ensure, OptionExt};
+use tracing::{debug, error, info, instrument, warn};
+
+use crate::{
+    error::ApplicationResult,
+    medium::{
+        commands::GeneratePreviewsHandler,
+        ports::{FileStorage, MediumRepository},
+        queries::MediumItemContent,
+    },
+};
+
+#[derive(Debug)]
+pub struct FindMediumPreviewQuery {
+    pub user_id: UserId,
+    pub medium_id: MediumId,
+    pub size: PreviewSize,
+}
+
+/// Resolves the preview of the requested size, rendering and caching it
+/// first if it has not been generated yet
+#[derive(new)]
+pub struct FindMediumPreviewHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    file_storage: Arc<dyn FileStorage>,
+    generate_previews: Arc<GeneratePreviewsHandler>,
+}
+
+impl FindMediumPreviewHandler {
+    #[instrument(skip(self), fields(
+        user_id = %query.user_id,
+        medium_id = %query.medium_id,
+        size = %query.size,
+    ))]
+    pub async fn handle(
+        &self,
+        query: FindMediumPreviewQuery,
+    ) -> ApplicationResult<MediumItemContent> {
+        info!("Finding medium preview");
+
+        let mut medium = self
+            .medium_repository
+            .find_by_id(query.medium_id, query.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: query.medium_id,
+            })?;
+
+        let item = match medium.find_preview(query.size) {
+            Some(item) => item.clone(),
+            None => {
+                ensure!(
+                    !medium.is_deleted(),
+                    EntityNotFoundSnafu {
+                        entity: "Preview",
+                        id: query.medium_id,
+                    }
+                );
+                debug!("Preview not generated yet");
+                self.generate_previews
+                    .render_missing(&mut medium, query.size)
+                    .await?
+            }
+        };
+
+        let location = item
+            .fastest_location()
+            .context(EntityNotFoundSnafu {
+                entity: "FileLocation",
+                id: item.id,
+            })?
+            .clone();
+
+        let file = match self.file_storage.stat_file(&location).await {
+            Ok(file) => file,
+            Err(e) => {
+                warn!(error = %e, "Cached preview is missing, rendering it again");
+                self.generate_previews
+                    .restore(&mut medium, query.size, &location)
+                    .await?;
+                self.file_storage
+                    .stat_file(&location)
+                    .await
+                    .inspect_err(|e| error!(error = %e, "Failed to read restored preview"))?
+            }
+        };
+
+        debug!(size_bytes = file.size_bytes, "Medium preview resolved");
+
+        Ok(MediumItemContent {
+            item,
+            location,
+            file,
+        })
+    }
+}
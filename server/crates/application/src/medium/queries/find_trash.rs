@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{medium::MediumListItem, user::UserId};
+use tracing::{debug, error, info, instrument};
+
+use crate::{error::ApplicationResult, medium::ports::MediumRepository};
+
+#[derive(Debug)]
+pub struct FindTrashQuery {
+    pub user_id: UserId,
+}
+
+#[derive(new)]
+pub struct FindTrashHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+}
+
+impl FindTrashHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id))]
+    pub async fn handle(&self, query: FindTrashQuery) -> ApplicationResult<Vec<MediumListItem>> {
+        info!("Finding trashed media for user");
+
+        let media = self
+            .medium_repository
+            .find_trash(query.user_id)
+            .await
+            .map_err(|e| {
+                error!(error = ?e, "Failed to find trashed media");
+                e
+            })?;
+
+        debug!(count = media.len(), "Trashed media retrieved successfully");
+
+        Ok(media)
+    }
+}
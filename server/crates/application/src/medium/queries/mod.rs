@@ -1,5 +1,15 @@
 mod find_all_media;
+mod find_duplicates;
 mod find_medium;
+mod find_medium_item_content;
+mod find_medium_preview;
+mod find_trash;
 
 pub use find_all_media::{FindAllMediaHandler, FindAllMediaQuery};
+pub use find_duplicates::{FindDuplicatesHandler, FindDuplicatesQuery};
 pub use find_medium::{FindMediumHandler, FindMediumQuery};
+pub use find_medium_item_content::{
+    FindMediumItemContentHandler, FindMediumItemContentQuery, MediumItemContent,
+};
+pub use find_medium_preview::{FindMediumPreviewHandler, FindMediumPreviewQuery};
+pub use find_trash::{FindTrashHandler, FindTrashQuery};
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{medium::MediumId, user::UserId};
+use tracing::{debug, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::MediumRepository,
+    metadata::ports::{MetadataExtractor, MetadataRepository, PublishMetadataEvent},
+};
+
+pub struct ApplySidecarCommand {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+}
+
+/// Merges the XMP sidecar of a medium into its metadata. Runs when a sidecar
+/// is added and when the metadata is extracted, whichever comes last applies it.
+#[derive(new)]
+pub struct ApplySidecarHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    metadata_extractor: Arc<dyn MetadataExtractor>,
+    metadata_repository: Arc<dyn MetadataRepository>,
+    event_publisher: Arc<dyn PublishMetadataEvent>,
+}
+
+impl ApplySidecarHandler {
+    #[instrument(skip(self, command), fields(
+        medium_id = %command.medium_id,
+        user_id = %command.owner_id,
+    ))]
+    pub async fn handle(&self, command: ApplySidecarCommand) -> ApplicationResult<()> {
+        let Some(medium) = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.owner_id)
+            .await?
+        else {
+            debug!("Medium not found, skipping sidecar");
+            return Ok(());
+        };
+        let Some((sidecar_item_id, location)) = medium
+            .find_sidecar()
+            .and_then(|item| Some((item.id, item.fastest_location()?.clone())))
+        else {
+            debug!("Medium has no sidecar");
+            return Ok(());
+        };
+
+        let Some(mut metadata) = self
+            .metadata_repository
+            .find_by_medium_id(command.medium_id)
+            .await?
+        else {
+            debug!("No metadata yet, the sidecar is applied once extracted");
+            return Ok(());
+        };
+
+        let sidecar = self.metadata_extractor.extract_sidecar(&location).await?;
+
+        let Some(event) = metadata.apply_sidecar(sidecar, sidecar_item_id, command.owner_id)?
+        else {
+            debug!(%sidecar_item_id, "Sidecar unchanged");
+            return Ok(());
+        };
+
+        self.metadata_repository.save(&metadata).await?;
+        self.event_publisher.publish(event).await?;
+
+        info!(%sidecar_item_id, "Sidecar applied");
+
+        Ok(())
+    }
+}
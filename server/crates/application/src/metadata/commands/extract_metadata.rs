@@ -3,14 +3,17 @@ use std::sync::Arc;
 use derive_new::new;
 use domain::{
     medium::{FileLocation, MediumId, MediumItemId},
-    metadata::Metadata,
+    metadata::{Metadata, TimezoneSource},
     user::UserId,
 };
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 use crate::{
     error::ApplicationResult,
-    metadata::ports::{MetadataExtractor, MetadataRepository, PublishMetadataEvent},
+    metadata::ports::{
+        MetadataExtractor, MetadataRepository, PublishMetadataEvent, TimezoneResolver,
+    },
+    user::UserRepository,
 };
 
 pub struct ExtractMetadataCommand {
@@ -24,6 +27,8 @@ pub struct ExtractMetadataCommand {
 pub struct ExtractMetadataHandler {
     metadata_extractor: Arc<dyn MetadataExtractor>,
     metadata_repository: Arc<dyn MetadataRepository>,
+    timezone_resolver: Arc<dyn TimezoneResolver>,
+    user_repository: Arc<dyn UserRepository>,
     event_publisher: Arc<dyn PublishMetadataEvent>,
 }
 
@@ -79,13 +84,66 @@ impl ExtractMetadataHandler {
         &self,
         command: &ExtractMetadataCommand,
     ) -> ApplicationResult<Metadata> {
-        let metadata = self
+        let mut metadata = self
             .metadata_extractor
             .extract(&command.file_location, command.medium_id)
             .await?;
 
+        // The owner's corrections and the sidecar survive extracting the file again
+        if let Some(existing) = self
+            .metadata_repository
+            .find_by_medium_id(command.medium_id)
+            .await?
+        {
+            metadata.id = existing.id;
+            metadata.sidecar = existing.sidecar;
+            metadata.overrides = existing.overrides;
+        }
+
+        self.infer_capture_timezone(&mut metadata, command.user_id)
+            .await?;
+
         self.metadata_repository.save(&metadata).await?;
 
         Ok(metadata)
     }
+
+    /// Many cameras record the capture time without its UTC offset. The time
+    /// zone is then taken from the GPS position, or else the owner's default.
+    async fn infer_capture_timezone(
+        &self,
+        metadata: &mut Metadata,
+        user_id: UserId,
+    ) -> ApplicationResult<()> {
+        let Some(local) = metadata.unresolved_capture_date() else {
+            return Ok(());
+        };
+
+        let gps_offset = metadata
+            .gps_coordinates()
+            .and_then(|(latitude, longitude, _)| {
+                self.timezone_resolver.zone_at(latitude, longitude)
+            })
+            .and_then(|zone| self.timezone_resolver.offset_at(&zone, local));
+        if let Some(offset) = gps_offset {
+            metadata.localize_capture_date(offset, TimezoneSource::Gps);
+            return Ok(());
+        }
+
+        let user_offset = self
+            .user_repository
+            .find_by_id(user_id)
+            .await?
+            .and_then(|user| user.timezone)
+            .and_then(|zone| self.timezone_resolver.offset_at(&zone, local));
+        match user_offset {
+            Some(offset) => metadata.localize_capture_date(offset, TimezoneSource::UserDefault),
+            None => debug!(
+                medium_id = %metadata.medium_id,
+                "Time zone of the capture date is unknown"
+            ),
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_new::new;
+use domain::{
+    error::ValidationSnafu,
+    medium::MediumId,
+    metadata::{GpsTrack, LocationInfo, MetadataOverridePatch},
+    user::UserId,
+};
+use snafu::ensure;
+use tracing::{debug, info, instrument, warn};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::MediumRepository,
+    metadata::{commands::OverrideMetadataHandler, ports::MetadataRepository},
+};
+
+pub struct GeotagFromTrackCommand {
+    pub user_id: UserId,
+    pub track: GpsTrack,
+    /// How far the capture time may be from the nearest track point
+    pub tolerance: TimeDelta,
+    /// Only propose the locations without overriding them
+    pub dry_run: bool,
+}
+
+/// The location a medium was taken at according to the track
+#[derive(Debug, Clone)]
+pub struct GeotagAssignment {
+    pub medium_id: MediumId,
+    pub taken_at: DateTime<Utc>,
+    pub location: LocationInfo,
+}
+
+/// Locates media taken without GPS by matching their capture time against a
+/// track recorded alongside, e.g. by a phone
+#[derive(new)]
+pub struct GeotagFromTrackHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    metadata_repository: Arc<dyn MetadataRepository>,
+    override_metadata: Arc<OverrideMetadataHandler>,
+}
+
+impl GeotagFromTrackHandler {
+    /// Returns the assignments, when not a dry run only those that were applied
+    #[instrument(skip(self, command), fields(
+        user_id = %command.user_id,
+        points = command.track.points().len(),
+        dry_run = command.dry_run,
+    ))]
+    pub async fn handle(
+        &self,
+        command: GeotagFromTrackCommand,
+    ) -> ApplicationResult<Vec<GeotagAssignment>> {
+        ensure!(
+            command.tolerance >= TimeDelta::zero(),
+            ValidationSnafu {
+                message: "The tolerance must not be negative"
+            }
+        );
+
+        let candidates = self
+            .medium_repository
+            .find_geotagging_candidates(
+                command.user_id,
+                command.track.start() - command.tolerance,
+                command.track.end() + command.tolerance,
+            )
+            .await?;
+
+        let assignments: Vec<GeotagAssignment> = candidates
+            .into_iter()
+            .filter_map(|candidate| {
+                let location = command
+                    .track
+                    .position_at(candidate.taken_at, command.tolerance)?;
+                Some(GeotagAssignment {
+                    medium_id: candidate.medium_id,
+                    taken_at: candidate.taken_at,
+                    location,
+                })
+            })
+            .collect();
+
+        if command.dry_run {
+            info!(
+                proposed = assignments.len(),
+                "Proposed locations from the track"
+            );
+            return Ok(assignments);
+        }
+
+        let mut applied = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            match self.apply(command.user_id, &assignment).await {
+                Ok(true) => applied.push(assignment),
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(
+                        medium_id = %assignment.medium_id,
+                        error = %e,
+                        "Failed to geotag medium, skipping"
+                    );
+                }
+            }
+        }
+
+        info!(applied = applied.len(), "Geotagged media from the track");
+
+        Ok(applied)
+    }
+
+    /// Overrides the location of the medium, `false` if nothing changed
+    async fn apply(
+        &self,
+        user_id: UserId,
+        assignment: &GeotagAssignment,
+    ) -> ApplicationResult<bool> {
+        let Some(mut metadata) = self
+            .metadata_repository
+            .find_by_medium_id(assignment.medium_id)
+            .await?
+        else {
+            debug!(medium_id = %assignment.medium_id, "No metadata, skipping");
+            return Ok(false);
+        };
+
+        let patch = MetadataOverridePatch {
+            location: Some(Some(assignment.location.clone())),
+            ..Default::default()
+        };
+
+        if !self
+            .override_metadata
+            .apply_patch(&mut metadata, patch, user_id)
+            .await?
+        {
+            return Ok(false);
+        }
+
+        debug!(medium_id = %assignment.medium_id, "Medium geotagged");
+
+        Ok(true)
+    }
+}
@@ -1,3 +1,19 @@
+pub mod apply_sidecar;
 pub mod extract_metadata;
+pub mod geotag_from_track;
+pub mod override_metadata;
+pub mod reextract_metadata;
+pub mod reextract_outdated_metadata;
+pub mod resolve_location;
+pub mod shift_capture_dates;
+pub mod write_sidecar;
 
+pub use apply_sidecar::*;
 pub use extract_metadata::*;
+pub use geotag_from_track::*;
+pub use override_metadata::*;
+pub use reextract_metadata::*;
+pub use reextract_outdated_metadata::*;
+pub use resolve_location::*;
+pub use shift_capture_dates::*;
+pub use write_sidecar::*;
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::MediumId,
+    metadata::{Metadata, MetadataOverridePatch},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, info};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::MediumRepository,
+    metadata::ports::{MetadataRepository, PublishMetadataEvent},
+};
+
+pub struct OverrideMetadataCommand {
+    pub medium_id: MediumId,
+    pub user_id: UserId,
+    pub patch: MetadataOverridePatch,
+}
+
+/// Lets the owner correct the capture date, camera or location of a medium
+#[derive(new)]
+pub struct OverrideMetadataHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    metadata_repository: Arc<dyn MetadataRepository>,
+    event_publisher: Arc<dyn PublishMetadataEvent>,
+}
+
+impl OverrideMetadataHandler {
+    /// Returns the metadata with the overrides applied
+    pub async fn handle(&self, command: OverrideMetadataCommand) -> ApplicationResult<Metadata> {
+        // Only the owner may change the metadata of a medium
+        self.medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        let mut metadata = self
+            .metadata_repository
+            .find_by_medium_id(command.medium_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "Metadata",
+                id: command.medium_id,
+            })?;
+
+        if !self
+            .apply_patch(&mut metadata, command.patch, command.user_id)
+            .await?
+        {
+            debug!(medium_id = %command.medium_id, "Metadata overrides unchanged");
+            return Ok(metadata);
+        }
+
+        info!(medium_id = %command.medium_id, "Metadata overridden");
+
+        Ok(metadata)
+    }
+
+    /// Applies the patch to loaded metadata, `false` if nothing changed. The
+    /// event is published before the read model is saved, so the projection
+    /// never holds overrides the event store has not recorded.
+    pub async fn apply_patch(
+        &self,
+        metadata: &mut Metadata,
+        patch: MetadataOverridePatch,
+        user_id: UserId,
+    ) -> ApplicationResult<bool> {
+        let Some(event) = metadata.override_fields(patch, user_id)? else {
+            return Ok(false);
+        };
+
+        self.event_publisher.publish(event).await?;
+        self.metadata_repository.save(metadata).await?;
+
+        Ok(true)
+    }
+}
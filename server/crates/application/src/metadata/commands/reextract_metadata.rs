@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::{EntityNotFoundSnafu, ValidationSnafu},
+    medium::{Medium, MediumId},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::MediumRepository,
+    metadata::commands::{ExtractMetadataCommand, ExtractMetadataHandler},
+};
+
+pub struct ReextractMetadataCommand {
+    pub medium_id: MediumId,
+    pub user_id: UserId,
+}
+
+/// Reads the metadata of a medium from its leading item again, e.g. after
+/// the extractor learned to read more of it. Overrides and the sidecar are
+/// kept.
+#[derive(new)]
+pub struct ReextractMetadataHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    extract_metadata: Arc<ExtractMetadataHandler>,
+}
+
+impl ReextractMetadataHandler {
+    #[instrument(skip(self, command), fields(
+        medium_id = %command.medium_id,
+        user_id = %command.user_id,
+    ))]
+    pub async fn handle(&self, command: ReextractMetadataCommand) -> ApplicationResult<()> {
+        let medium = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.user_id)
+            .await?
+            .filter(|medium| !medium.is_deleted())
+            .context(EntityNotFoundSnafu {
+                entity: "Medium",
+                id: command.medium_id,
+            })?;
+
+        self.extract_metadata
+            .handle(extraction_of(&medium)?)
+            .await?;
+
+        info!("Metadata extracted again");
+
+        Ok(())
+    }
+}
+
+/// The extraction of the metadata of a medium from the fastest location of
+/// its leading item
+pub(crate) fn extraction_of(medium: &Medium) -> ApplicationResult<ExtractMetadataCommand> {
+    let location = medium
+        .find_item(medium.leading_item_id)
+        .and_then(|item| item.fastest_location())
+        .context(ValidationSnafu {
+            message: format!("Medium {} has no file to extract metadata from", medium.id),
+        })?;
+
+    Ok(ExtractMetadataCommand {
+        medium_id: medium.id,
+        leading_item_id: medium.leading_item_id,
+        user_id: medium.owner_id,
+        file_location: location.clone(),
+    })
+}
@@ -0,0 +1,471 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use derive_new::new;
+use domain::{
+    medium::MediumId,
+    metadata::events::{
+        MetadataReextractionCompletedEvent, MetadataReextractionFailedEvent,
+        MetadataReextractionProgressedEvent, MetadataReextractionStartedEvent,
+    },
+    user::UserId,
+};
+use snafu::ensure;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+use crate::{
+    error::{format_error_with_backtrace, ApplicationResult, ConflictSnafu},
+    medium::ports::MediumRepository,
+    metadata::{
+        commands::{reextract_metadata::extraction_of, ExtractMetadataHandler},
+        ports::{MetadataExtractor, MetadataRepository, PublishMetadataEvent},
+    },
+};
+
+/// Media processed between two progress reports
+const PROGRESS_INTERVAL: usize = 25;
+
+/// Pause after each medium, so a library-wide run leaves room for uploads
+const PAUSE_BETWEEN_MEDIA: Duration = Duration::from_millis(100);
+
+pub struct ReextractOutdatedMetadataCommand {
+    /// Identifies the task tracking the re-extraction
+    pub reextraction_id: Uuid,
+    /// The administrator starting the re-extraction
+    pub user_id: UserId,
+}
+
+/// Extracts the metadata of all media read by an older version of the
+/// extractor again, one medium at a time. Only one re-extraction runs at once.
+#[derive(new)]
+pub struct ReextractOutdatedMetadataHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    metadata_repository: Arc<dyn MetadataRepository>,
+    metadata_extractor: Arc<dyn MetadataExtractor>,
+    extract_metadata: Arc<ExtractMetadataHandler>,
+    event_publisher: Arc<dyn PublishMetadataEvent>,
+    #[new(default)]
+    running: AtomicBool,
+}
+
+impl ReextractOutdatedMetadataHandler {
+    /// Claims the single slot for a re-extraction, before it is started in
+    /// the background. The claim is released once `handle` finishes.
+    pub fn reserve(&self) -> ApplicationResult<()> {
+        ensure!(
+            self.running
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok(),
+            ConflictSnafu {
+                message: "A metadata re-extraction is already running"
+            }
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self, command), fields(
+        reextraction_id = %command.reextraction_id,
+        user_id = %command.user_id,
+    ))]
+    pub async fn handle(&self, command: ReextractOutdatedMetadataCommand) -> ApplicationResult<()> {
+        let result = self.run(&command).await;
+        self.running.store(false, Ordering::Release);
+        result
+    }
+
+    async fn run(&self, command: &ReextractOutdatedMetadataCommand) -> ApplicationResult<()> {
+        let extractor_version = self.metadata_extractor.version();
+        info!(extractor_version, "Starting metadata re-extraction");
+
+        self.event_publisher
+            .publish(MetadataReextractionStartedEvent::new(
+                command.reextraction_id,
+                command.user_id,
+            ))
+            .await?;
+
+        match self.reextract_outdated(command, extractor_version).await {
+            Ok(media_reextracted) => {
+                info!(media_reextracted, "Metadata re-extraction completed");
+                self.event_publisher
+                    .publish(MetadataReextractionCompletedEvent::new(
+                        command.reextraction_id,
+                        command.user_id,
+                        media_reextracted,
+                    ))
+                    .await?;
+                Ok(())
+            }
+            Err(e) => {
+                error!(error = %format_error_with_backtrace(&e), "Metadata re-extraction failed");
+                self.event_publisher
+                    .publish(MetadataReextractionFailedEvent::new(
+                        command.reextraction_id,
+                        command.user_id,
+                        e.to_string(),
+                    ))
+                    .await?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn reextract_outdated(
+        &self,
+        command: &ReextractOutdatedMetadataCommand,
+        extractor_version: u32,
+    ) -> ApplicationResult<usize> {
+        let outdated = self
+            .metadata_repository
+            .find_outdated(extractor_version)
+            .await?;
+        let total = outdated.len();
+
+        self.report_progress(command, 0, total).await?;
+
+        let mut reextracted = 0;
+
+        for (index, (medium_id, owner_id)) in outdated.into_iter().enumerate() {
+            match self
+                .reextract_medium(medium_id, owner_id, extractor_version)
+                .await
+            {
+                Ok(true) => reextracted += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(
+                        medium_id = %medium_id,
+                        error = %e,
+                        "Failed to extract metadata again, skipping until the extractor changes"
+                    );
+                    self.metadata_repository
+                        .mark_reextraction_failed(medium_id, extractor_version)
+                        .await?;
+                }
+            }
+
+            let processed = index + 1;
+            if processed % PROGRESS_INTERVAL == 0 || processed == total {
+                self.report_progress(command, processed, total).await?;
+            }
+
+            tokio::time::sleep(PAUSE_BETWEEN_MEDIA).await;
+        }
+
+        Ok(reextracted)
+    }
+
+    /// Extracts the metadata of the medium again, `false` if it is gone
+    async fn reextract_medium(
+        &self,
+        medium_id: MediumId,
+        owner_id: UserId,
+        extractor_version: u32,
+    ) -> ApplicationResult<bool> {
+        let Some(medium) = self
+            .medium_repository
+            .find_by_id(medium_id, owner_id)
+            .await?
+            .filter(|medium| !medium.is_deleted())
+        else {
+            debug!(medium_id = %medium_id, "Medium gone, skipping");
+            return Ok(false);
+        };
+
+        self.extract_metadata
+            .handle(extraction_of(&medium)?)
+            .await?;
+
+        // A fallback extractor reads the files the primary one fails on, the
+        // metadata then carries the older version of the fallback
+        let read_with = self
+            .metadata_repository
+            .find_by_medium_id(medium_id)
+            .await?
+            .map(|metadata| metadata.extractor_version);
+        if let Some(read_with) = read_with.filter(|version| *version < extractor_version) {
+            warn!(
+                medium_id = %medium_id,
+                read_with,
+                "Metadata read by an older extractor again, skipping until the extractor changes"
+            );
+            self.metadata_repository
+                .mark_reextraction_failed(medium_id, extractor_version)
+                .await?;
+        }
+
+        Ok(true)
+    }
+
+    async fn report_progress(
+        &self,
+        command: &ReextractOutdatedMetadataCommand,
+        processed: usize,
+        total: usize,
+    ) -> ApplicationResult<()> {
+        self.event_publisher
+            .publish(MetadataReextractionProgressedEvent::new(
+                command.reextraction_id,
+                command.user_id,
+                processed,
+                total,
+            ))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use byte_unit::Byte;
+    use domain::{
+        error::ValidationSnafu,
+        medium::{FileLocation, Filename, Medium, MediumItem, MediumItemType, Priority},
+        metadata::Metadata,
+    };
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::{
+        error::ApplicationError,
+        event_bus::testing::RecordingPublisher,
+        medium::ports::MockMediumRepository,
+        metadata::ports::{MockMetadataExtractor, MockMetadataRepository, MockTimezoneResolver},
+        user::ports::MockUserRepository,
+    };
+
+    const EXTRACTOR_VERSION: u32 = 3;
+
+    fn make_handler(
+        medium_repository: MockMediumRepository,
+        metadata_repository: MockMetadataRepository,
+        publisher: Arc<RecordingPublisher>,
+    ) -> ReextractOutdatedMetadataHandler {
+        make_handler_with_extractor(
+            medium_repository,
+            metadata_repository,
+            MockMetadataExtractor::new(),
+            publisher,
+        )
+    }
+
+    fn make_handler_with_extractor(
+        medium_repository: MockMediumRepository,
+        metadata_repository: MockMetadataRepository,
+        mut extractor: MockMetadataExtractor,
+        publisher: Arc<RecordingPublisher>,
+    ) -> ReextractOutdatedMetadataHandler {
+        let metadata_repository = Arc::new(metadata_repository);
+        extractor.expect_version().return_const(EXTRACTOR_VERSION);
+        let extractor = Arc::new(extractor);
+        let extract_metadata = Arc::new(ExtractMetadataHandler::new(
+            extractor.clone(),
+            metadata_repository.clone(),
+            Arc::new(MockTimezoneResolver::new()),
+            Arc::new(MockUserRepository::new()),
+            publisher.clone(),
+        ));
+
+        ReextractOutdatedMetadataHandler::new(
+            Arc::new(medium_repository),
+            metadata_repository,
+            extractor,
+            extract_metadata,
+            publisher,
+        )
+    }
+
+    fn make_medium_with_file(id: MediumId, owner_id: UserId) -> Medium {
+        let item = MediumItem {
+            id: Uuid::new_v4(),
+            medium_id: id,
+            medium_item_type: MediumItemType::Original,
+            mime: mime::IMAGE_JPEG,
+            filename: Filename::new("IMG_0001.JPG").unwrap(),
+            filesize: Byte::from_u64(1000),
+            priority: Priority::normal(),
+            dimensions: None,
+            locations: vec![FileLocation::permanent("IMG_0001.JPG".into())],
+            checksum: None,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        };
+
+        Medium {
+            id,
+            owner_id,
+            leading_item_id: item.id,
+            items: vec![item],
+            ..Default::default()
+        }
+    }
+
+    fn make_command() -> ReextractOutdatedMetadataCommand {
+        ReextractOutdatedMetadataCommand {
+            reextraction_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+        }
+    }
+
+    #[test]
+    fn test_reserve_rejects_a_second_run() {
+        let handler = make_handler(
+            MockMediumRepository::new(),
+            MockMetadataRepository::new(),
+            Arc::default(),
+        );
+
+        handler.reserve().unwrap();
+
+        assert!(matches!(
+            handler.reserve(),
+            Err(ApplicationError::Conflict { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_run_releases_the_slot() {
+        let mut metadata_repository = MockMetadataRepository::new();
+        metadata_repository
+            .expect_find_outdated()
+            .returning(|_| ValidationSnafu { message: "broken" }.fail());
+        let publisher = Arc::new(RecordingPublisher::default());
+        let handler = make_handler(
+            MockMediumRepository::new(),
+            metadata_repository,
+            publisher.clone(),
+        );
+
+        handler.reserve().unwrap();
+        let result = handler.handle(make_command()).await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            publisher.events::<MetadataReextractionFailedEvent>().len(),
+            1
+        );
+        handler.reserve().unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_progress_is_reported_every_interval() {
+        let total = 2 * PROGRESS_INTERVAL + 5;
+        let mut metadata_repository = MockMetadataRepository::new();
+        metadata_repository
+            .expect_find_outdated()
+            .with(eq(EXTRACTOR_VERSION))
+            .returning(move |_| {
+                Ok((0..total)
+                    .map(|_| (Uuid::new_v4(), Uuid::new_v4()))
+                    .collect())
+            });
+        // Media deleted in the meantime are skipped without extracting them
+        let mut medium_repository = MockMediumRepository::new();
+        medium_repository
+            .expect_find_by_id()
+            .times(total)
+            .returning(|_, _| Ok(None));
+        let publisher = Arc::new(RecordingPublisher::default());
+        let handler = make_handler(medium_repository, metadata_repository, publisher.clone());
+
+        handler.handle(make_command()).await.unwrap();
+
+        let progress: Vec<_> = publisher
+            .events::<MetadataReextractionProgressedEvent>()
+            .into_iter()
+            .map(|event| (event.processed, event.total))
+            .collect();
+        assert_eq!(
+            progress,
+            vec![
+                (0, total),
+                (PROGRESS_INTERVAL, total),
+                (2 * PROGRESS_INTERVAL, total),
+                (total, total),
+            ]
+        );
+        assert_eq!(
+            publisher
+                .events::<MetadataReextractionCompletedEvent>()
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_media_that_cannot_be_extracted_are_recorded() {
+        let medium_id = Uuid::new_v4();
+        let owner_id = Uuid::new_v4();
+        let mut metadata_repository = MockMetadataRepository::new();
+        metadata_repository
+            .expect_find_outdated()
+            .returning(move |_| Ok(vec![(medium_id, owner_id)]));
+        metadata_repository
+            .expect_mark_reextraction_failed()
+            .with(eq(medium_id), eq(EXTRACTOR_VERSION))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        // Without items there is no file to extract the metadata from
+        let mut medium_repository = MockMediumRepository::new();
+        medium_repository
+            .expect_find_by_id()
+            .returning(move |id, owner_id| {
+                Ok(Some(Medium {
+                    id,
+                    owner_id,
+                    ..Default::default()
+                }))
+            });
+        let handler = make_handler(medium_repository, metadata_repository, Arc::default());
+
+        handler.handle(make_command()).await.unwrap();
+    }
+    #[tokio::test(start_paused = true)]
+    async fn test_media_read_by_an_older_fallback_are_recorded() {
+        let medium_id = Uuid::new_v4();
+        let owner_id = Uuid::new_v4();
+        let read_by_fallback = Metadata {
+            medium_id,
+            extractor_version: EXTRACTOR_VERSION - 1,
+            ..Default::default()
+        };
+        let mut metadata_repository = MockMetadataRepository::new();
+        metadata_repository
+            .expect_find_outdated()
+            .returning(move |_| Ok(vec![(medium_id, owner_id)]));
+        metadata_repository
+            .expect_find_by_medium_id()
+            .returning(move |_| Ok(Some(read_by_fallback.clone())));
+        metadata_repository.expect_save().returning(|_| Ok(()));
+        metadata_repository
+            .expect_mark_reextraction_failed()
+            .with(eq(medium_id), eq(EXTRACTOR_VERSION))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut medium_repository = MockMediumRepository::new();
+        medium_repository
+            .expect_find_by_id()
+            .returning(|id, owner_id| Ok(Some(make_medium_with_file(id, owner_id))));
+        let mut extractor = MockMetadataExtractor::new();
+        extractor.expect_extract().returning(move |_, medium_id| {
+            Ok(Metadata {
+                medium_id,
+                extractor_version: EXTRACTOR_VERSION - 1,
+                ..Default::default()
+            })
+        });
+        let handler = make_handler_with_extractor(
+            medium_repository,
+            metadata_repository,
+            extractor,
+            Arc::default(),
+        );
+
+        handler.handle(make_command()).await.unwrap();
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{medium::MediumId, metadata::Metadata, user::UserId};
+use tracing::{debug, info};
+
+use crate::{
+    error::ApplicationResult,
+    metadata::ports::{PublishMetadataEvent, ReverseGeocoder},
+};
+
+pub struct ResolveLocationCommand {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Resolves the GPS coordinates of a medium into a country, region and city
+#[derive(new)]
+pub struct ResolveLocationHandler {
+    reverse_geocoder: Arc<dyn ReverseGeocoder>,
+    event_publisher: Arc<dyn PublishMetadataEvent>,
+}
+
+impl ResolveLocationHandler {
+    pub async fn handle(&self, command: ResolveLocationCommand) -> ApplicationResult<()> {
+        let Some(place) = self
+            .reverse_geocoder
+            .resolve(command.latitude, command.longitude)
+            .await?
+        else {
+            debug!(
+                medium_id = %command.medium_id,
+                "No place found near the coordinates"
+            );
+            return Ok(());
+        };
+
+        info!(
+            medium_id = %command.medium_id,
+            country = %place.country,
+            city = ?place.city,
+            "Location resolved"
+        );
+
+        self.event_publisher
+            .publish(Metadata::location_resolved(
+                command.medium_id,
+                command.owner_id,
+                place,
+            ))
+            .await?;
+
+        Ok(())
+    }
+}
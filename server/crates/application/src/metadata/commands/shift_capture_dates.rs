@@ -0,0 +1,386 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, FixedOffset, TimeDelta, TimeZone};
+use derive_new::new;
+use domain::{
+    error::ValidationSnafu,
+    medium::MediumId,
+    metadata::{
+        events::{
+            CaptureDateShiftCompletedEvent, CaptureDateShiftFailedEvent,
+            CaptureDateShiftProgressedEvent, CaptureDateShiftStartedEvent,
+        },
+        MetadataOverridePatch,
+    },
+    user::UserId,
+};
+use snafu::ensure;
+use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
+
+use crate::{
+    error::{format_error_with_backtrace, ApplicationResult},
+    medium::ports::{CaptureDateShiftCriteria, MediumRepository},
+    metadata::{
+        commands::OverrideMetadataHandler,
+        ports::{MetadataRepository, PublishMetadataEvent, TimezoneResolver},
+    },
+};
+
+/// Media processed between two progress reports
+const PROGRESS_INTERVAL: usize = 25;
+
+/// How the capture dates are corrected
+#[derive(Debug, Clone)]
+pub enum CaptureDateShift {
+    /// Moves the capture dates, e.g. for a camera clock that was off by an hour
+    Offset(TimeDelta),
+    /// Keeps the local times and reads them in the time zone, e.g. for a
+    /// camera still set to the zone of home while travelling
+    Timezone(String),
+}
+
+impl CaptureDateShift {
+    /// The corrected capture date, `None` if it is out of range or the time
+    /// zone is unknown
+    fn apply(
+        &self,
+        date: DateTime<FixedOffset>,
+        timezones: &dyn TimezoneResolver,
+    ) -> Option<DateTime<FixedOffset>> {
+        match self {
+            CaptureDateShift::Offset(delta) => date.checked_add_signed(*delta),
+            CaptureDateShift::Timezone(zone) => {
+                let local = date.naive_local();
+                let offset = timezones.offset_at(zone, local)?;
+                offset.from_local_datetime(&local).single()
+            }
+        }
+    }
+}
+
+pub struct ShiftCaptureDatesCommand {
+    /// Identifies the task tracking the shift
+    pub shift_id: Uuid,
+    pub user_id: UserId,
+    pub criteria: CaptureDateShiftCriteria,
+    pub shift: CaptureDateShift,
+}
+
+impl ShiftCaptureDatesCommand {
+    fn validate(&self, timezones: &dyn TimezoneResolver) -> ApplicationResult<()> {
+        ensure!(
+            !self.criteria.is_empty(),
+            ValidationSnafu {
+                message: "At least one criterion is required to select the media to shift"
+            }
+        );
+
+        if let (Some(start), Some(end)) = (self.criteria.start_date, self.criteria.end_date) {
+            ensure!(
+                start <= end,
+                ValidationSnafu {
+                    message: "start_date must be before or equal to end_date"
+                }
+            );
+        }
+
+        match &self.shift {
+            CaptureDateShift::Offset(delta) => ensure!(
+                !delta.is_zero(),
+                ValidationSnafu {
+                    message: "The offset must not be zero"
+                }
+            ),
+            CaptureDateShift::Timezone(zone) => ensure!(
+                timezones
+                    .offset_at(zone, DateTime::UNIX_EPOCH.naive_utc())
+                    .is_some(),
+                ValidationSnafu {
+                    message: format!("Unknown time zone {}", zone)
+                }
+            ),
+        }
+
+        Ok(())
+    }
+}
+
+/// Corrects the capture dates of many media at once by overriding them.
+/// Files stored under a date are moved along once the media are updated.
+#[derive(new)]
+pub struct ShiftCaptureDatesHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    metadata_repository: Arc<dyn MetadataRepository>,
+    timezone_resolver: Arc<dyn TimezoneResolver>,
+    event_publisher: Arc<dyn PublishMetadataEvent>,
+    override_metadata: Arc<OverrideMetadataHandler>,
+}
+
+impl ShiftCaptureDatesHandler {
+    /// Rejects a shift that cannot run, before it is started in the background
+    pub fn validate(&self, command: &ShiftCaptureDatesCommand) -> ApplicationResult<()> {
+        command.validate(self.timezone_resolver.as_ref())
+    }
+
+    #[instrument(skip(self, command), fields(
+        shift_id = %command.shift_id,
+        user_id = %command.user_id,
+    ))]
+    pub async fn handle(&self, command: ShiftCaptureDatesCommand) -> ApplicationResult<()> {
+        self.validate(&command)?;
+
+        info!(criteria = ?command.criteria, shift = ?command.shift, "Starting capture date shift");
+
+        self.event_publisher
+            .publish(CaptureDateShiftStartedEvent::new(
+                command.shift_id,
+                command.user_id,
+            ))
+            .await?;
+
+        match self.execute_shift(&command).await {
+            Ok(media_shifted) => {
+                info!(media_shifted, "Capture date shift completed");
+                self.event_publisher
+                    .publish(CaptureDateShiftCompletedEvent::new(
+                        command.shift_id,
+                        command.user_id,
+                        media_shifted,
+                    ))
+                    .await?;
+                Ok(())
+            }
+            Err(e) => {
+                error!(error = %format_error_with_backtrace(&e), "Capture date shift failed");
+                self.event_publisher
+                    .publish(CaptureDateShiftFailedEvent::new(
+                        command.shift_id,
+                        command.user_id,
+                        e.to_string(),
+                    ))
+                    .await?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn execute_shift(&self, command: &ShiftCaptureDatesCommand) -> ApplicationResult<usize> {
+        let medium_ids = self
+            .medium_repository
+            .find_capture_date_shift_candidates(&command.criteria, command.user_id)
+            .await?;
+        let total = medium_ids.len();
+
+        self.report_progress(command, 0, total).await?;
+
+        let mut shifted = 0;
+
+        for (index, medium_id) in medium_ids.into_iter().enumerate() {
+            match self.shift_medium(command, medium_id).await {
+                Ok(true) => shifted += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    warn!(
+                        medium_id = %medium_id,
+                        error = %e,
+                        "Failed to shift capture date, skipping"
+                    );
+                }
+            }
+
+            let processed = index + 1;
+            if processed % PROGRESS_INTERVAL == 0 || processed == total {
+                self.report_progress(command, processed, total).await?;
+            }
+        }
+
+        Ok(shifted)
+    }
+
+    /// Overrides the capture date of the medium, `false` if it has none
+    async fn shift_medium(
+        &self,
+        command: &ShiftCaptureDatesCommand,
+        medium_id: MediumId,
+    ) -> ApplicationResult<bool> {
+        let Some(mut metadata) = self
+            .metadata_repository
+            .find_by_medium_id(medium_id)
+            .await?
+        else {
+            debug!(medium_id = %medium_id, "No metadata, skipping");
+            return Ok(false);
+        };
+
+        let Some(shifted) = metadata
+            .capture_date()
+            .and_then(|date| command.shift.apply(date, self.timezone_resolver.as_ref()))
+        else {
+            debug!(medium_id = %medium_id, "No capture date to shift, skipping");
+            return Ok(false);
+        };
+
+        let patch = MetadataOverridePatch {
+            capture_date: Some(Some(shifted)),
+            ..Default::default()
+        };
+
+        if !self
+            .override_metadata
+            .apply_patch(&mut metadata, patch, command.user_id)
+            .await?
+        {
+            return Ok(false);
+        }
+
+        debug!(medium_id = %medium_id, taken_at = %shifted, "Capture date shifted");
+
+        Ok(true)
+    }
+
+    async fn report_progress(
+        &self,
+        command: &ShiftCaptureDatesCommand,
+        processed: usize,
+        total: usize,
+    ) -> ApplicationResult<()> {
+        self.event_publisher
+            .publish(CaptureDateShiftProgressedEvent::new(
+                command.shift_id,
+                command.user_id,
+                processed,
+                total,
+            ))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDateTime, TimeZone, Utc};
+
+    use super::*;
+
+    /// Lisbon around the end of summer time in 2024 and Tokyo, reading
+    /// the repeated hour with the earlier offset like the real resolver
+    struct TestTimezones;
+
+    impl TimezoneResolver for TestTimezones {
+        fn zone_at(&self, _latitude: f64, _longitude: f64) -> Option<String> {
+            None
+        }
+
+        fn offset_at(&self, zone: &str, local: NaiveDateTime) -> Option<FixedOffset> {
+            let summer_time_end =
+                NaiveDateTime::parse_from_str("2024-10-27 02:00", "%Y-%m-%d %H:%M").ok()?;
+            match zone {
+                "Europe/Lisbon" if local < summer_time_end => FixedOffset::east_opt(3600),
+                "Europe/Lisbon" => FixedOffset::east_opt(0),
+                "Asia/Tokyo" => FixedOffset::east_opt(9 * 3600),
+                _ => None,
+            }
+        }
+    }
+
+    fn date(value: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(value).unwrap()
+    }
+
+    fn command(
+        criteria: CaptureDateShiftCriteria,
+        shift: CaptureDateShift,
+    ) -> ShiftCaptureDatesCommand {
+        ShiftCaptureDatesCommand {
+            shift_id: Uuid::new_v4(),
+            user_id: Default::default(),
+            criteria,
+            shift,
+        }
+    }
+
+    fn camera_criteria() -> CaptureDateShiftCriteria {
+        CaptureDateShiftCriteria {
+            camera_make: Some("FUJIFILM".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let one_hour = CaptureDateShift::Offset(TimeDelta::hours(1));
+
+        assert!(command(camera_criteria(), one_hour.clone())
+            .validate(&TestTimezones)
+            .is_ok());
+        assert!(command(
+            camera_criteria(),
+            CaptureDateShift::Timezone("Asia/Tokyo".to_string())
+        )
+        .validate(&TestTimezones)
+        .is_ok());
+        assert!(command(Default::default(), one_hour.clone())
+            .validate(&TestTimezones)
+            .is_err());
+        let reversed = CaptureDateShiftCriteria {
+            start_date: Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).single(),
+            end_date: Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).single(),
+            ..Default::default()
+        };
+        assert!(command(reversed, one_hour)
+            .validate(&TestTimezones)
+            .is_err());
+        assert!(command(
+            camera_criteria(),
+            CaptureDateShift::Offset(TimeDelta::zero())
+        )
+        .validate(&TestTimezones)
+        .is_err());
+        assert!(command(
+            camera_criteria(),
+            CaptureDateShift::Timezone("Mars/Olympus".to_string())
+        )
+        .validate(&TestTimezones)
+        .is_err());
+    }
+
+    #[test]
+    fn test_offset_shift_moves_the_instant() {
+        let shift = CaptureDateShift::Offset(TimeDelta::minutes(-90));
+
+        assert_eq!(
+            shift.apply(date("2024-06-20T14:30:00+02:00"), &TestTimezones),
+            Some(date("2024-06-20T13:00:00+02:00"))
+        );
+    }
+
+    #[test]
+    fn test_timezone_shift_keeps_the_local_time() {
+        let shift = CaptureDateShift::Timezone("Asia/Tokyo".to_string());
+
+        assert_eq!(
+            shift.apply(date("2024-06-20T14:30:00+01:00"), &TestTimezones),
+            Some(date("2024-06-20T14:30:00+09:00"))
+        );
+        assert_eq!(
+            CaptureDateShift::Timezone("Mars/Olympus".to_string())
+                .apply(date("2024-06-20T14:30:00+01:00"), &TestTimezones),
+            None
+        );
+    }
+
+    #[test]
+    fn test_timezone_shift_of_a_repeated_local_time() {
+        // 01:30 happens twice in Lisbon when summer time ends
+        let shift = CaptureDateShift::Timezone("Europe/Lisbon".to_string());
+
+        assert_eq!(
+            shift.apply(date("2024-10-27T01:30:00+09:00"), &TestTimezones),
+            Some(date("2024-10-27T01:30:00+01:00"))
+        );
+        assert_eq!(
+            shift.apply(date("2024-10-27T02:30:00+09:00"), &TestTimezones),
+            Some(date("2024-10-27T02:30:00+00:00"))
+        );
+    }
+}
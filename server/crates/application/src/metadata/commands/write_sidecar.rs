@@ -0,0 +1,125 @@
+use std::{io::Cursor, path::PathBuf, sync::Arc};
+
+use byte_unit::Byte;
+use derive_new::new;
+use domain::{
+    error::format_error_with_backtrace as format_domain_error,
+    medium::{FileLocation, MediumId, MediumItemType, StorageTier},
+    user::UserId,
+};
+use tracing::{debug, info, instrument, warn};
+use uuid::Uuid;
+
+use crate::{
+    error::ApplicationResult,
+    medium::{
+        commands::{AddMediumItemCommand, AddMediumItemHandler},
+        ports::{FileStorage, MediumRepository},
+    },
+    metadata::ports::{MetadataRepository, SidecarWriter},
+    user::UserRepository,
+};
+
+pub struct WriteSidecarCommand {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+}
+
+/// Writes the tags, curation and metadata of a medium back into its XMP
+/// sidecar if the owner enabled it. An existing sidecar is updated in place, otherwise a new one
+/// is added as a `Sidecar` item, which the `MoveToPermanentStorageListener`
+/// then stores next to the original.
+#[derive(new)]
+pub struct WriteSidecarHandler {
+    medium_repository: Arc<dyn MediumRepository>,
+    metadata_repository: Arc<dyn MetadataRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    sidecar_writer: Arc<dyn SidecarWriter>,
+    file_storage: Arc<dyn FileStorage>,
+    add_medium_item: Arc<AddMediumItemHandler>,
+}
+
+impl WriteSidecarHandler {
+    #[instrument(skip(self, command), fields(
+        medium_id = %command.medium_id,
+        user_id = %command.owner_id,
+    ))]
+    pub async fn handle(&self, command: WriteSidecarCommand) -> ApplicationResult<()> {
+        let enabled = self
+            .user_repository
+            .find_by_id(command.owner_id)
+            .await?
+            .is_some_and(|user| user.settings.xmp_write_back);
+        if !enabled {
+            debug!("XMP write-back disabled");
+            return Ok(());
+        }
+
+        let Some(medium) = self
+            .medium_repository
+            .find_by_id(command.medium_id, command.owner_id)
+            .await?
+            .filter(|medium| !medium.is_deleted())
+        else {
+            debug!("Medium not found or in the trash, skipping write-back");
+            return Ok(());
+        };
+        let Some(metadata) = self
+            .metadata_repository
+            .find_by_medium_id(command.medium_id)
+            .await?
+        else {
+            debug!("No metadata yet, nothing to write back");
+            return Ok(());
+        };
+
+        if let Some(sidecar) = medium.find_sidecar() {
+            for location in sidecar
+                .locations
+                .iter()
+                .filter(|l| l.storage_tier != StorageTier::Cache)
+            {
+                self.sidecar_writer
+                    .write_sidecar(location, &medium, &metadata)
+                    .await?;
+            }
+            info!(item_id = %sidecar.id, "Sidecar updated");
+            return Ok(());
+        }
+
+        let Some(leading) = medium.find_item(medium.leading_item_id) else {
+            debug!("Medium has no leading item");
+            return Ok(());
+        };
+        let filename = format!("{}.xmp", leading.filename.stem());
+
+        // The writer works on files, the new sidecar is composed in a scratch file
+        let scratch = FileLocation::temporary(PathBuf::from(format!("{}.xmp", Uuid::new_v4())));
+        self.sidecar_writer
+            .write_sidecar(&scratch, &medium, &metadata)
+            .await?;
+        let content = self.file_storage.retrieve_file(&scratch).await;
+        if let Err(e) = self.file_storage.delete_file(&scratch).await {
+            warn!(error = %format_domain_error(&e), "Failed to delete scratch sidecar");
+        }
+        let content = content?;
+
+        let item_id = self
+            .add_medium_item
+            .handle(AddMediumItemCommand {
+                user_id: command.owner_id,
+                medium_id: command.medium_id,
+                medium_item_type: MediumItemType::Sidecar,
+                file_size: Byte::from_u64(content.len() as u64),
+                stream: Box::new(Cursor::new(content)),
+                mime_type: "application/rdf+xml".parse().unwrap(),
+                filename,
+                priority: None,
+            })
+            .await?;
+
+        info!(%item_id, "Sidecar created");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::metadata::events::{
+    MetadataExtractedEvent, MetadataOverriddenEvent, MetadataSidecarAppliedEvent,
+};
+use tracing::instrument;
+
+use crate::{
+    error::ApplicationResult,
+    event_bus::EventProcessor,
+    metadata::commands::{ResolveLocationCommand, ResolveLocationHandler},
+};
+
+#[derive(new)]
+pub struct LocationResolutionListener {
+    handler: Arc<ResolveLocationHandler>,
+}
+
+#[async_trait]
+impl EventProcessor<MetadataExtractedEvent> for LocationResolutionListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "LocationResolutionListener::MetadataExtractedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataExtractedEvent) -> ApplicationResult<()> {
+        let Some(location) = event.metadata.effective_location() else {
+            return Ok(());
+        };
+
+        self.handler
+            .handle(ResolveLocationCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+                latitude: location.latitude,
+                longitude: location.longitude,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataOverriddenEvent> for LocationResolutionListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "LocationResolutionListener::MetadataOverriddenEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataOverriddenEvent) -> ApplicationResult<()> {
+        let Some(location) = event.changes.location.as_ref().and_then(|c| c.new.as_ref()) else {
+            return Ok(());
+        };
+
+        self.handler
+            .handle(ResolveLocationCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+                latitude: location.latitude,
+                longitude: location.longitude,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataSidecarAppliedEvent> for LocationResolutionListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "LocationResolutionListener::MetadataSidecarAppliedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataSidecarAppliedEvent) -> ApplicationResult<()> {
+        let Some(location) = event.location.as_ref().and_then(|c| c.new.as_ref()) else {
+            return Ok(());
+        };
+
+        self.handler
+            .handle(ResolveLocationCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+                latitude: location.latitude,
+                longitude: location.longitude,
+            })
+            .await
+    }
+}
@@ -1,3 +1,9 @@
+mod location_resolution_listener;
 mod metadata_extraction_listener;
+mod sidecar_listener;
+mod sidecar_write_back_listener;
 
+pub use location_resolution_listener::LocationResolutionListener;
 pub use metadata_extraction_listener::MetadataExtractionListeners;
+pub use sidecar_listener::SidecarListener;
+pub use sidecar_write_back_listener::SidecarWriteBackListener;
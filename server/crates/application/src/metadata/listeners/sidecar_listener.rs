@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    medium::{events::MediumItemCreatedEvent, MediumItemType},
+    metadata::events::MetadataExtractedEvent,
+};
+use tracing::instrument;
+
+use crate::{
+    error::ApplicationResult,
+    event_bus::EventProcessor,
+    metadata::commands::{ApplySidecarCommand, ApplySidecarHandler},
+};
+
+#[derive(new)]
+pub struct SidecarListener {
+    handler: Arc<ApplySidecarHandler>,
+}
+
+#[async_trait]
+impl EventProcessor<MediumItemCreatedEvent> for SidecarListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(name = "SidecarListener::MediumItemCreatedEvent", skip(self, event))]
+    async fn process(&self, event: &MediumItemCreatedEvent) -> ApplicationResult<()> {
+        if event.item_type != MediumItemType::Sidecar {
+            return Ok(());
+        }
+
+        self.handler
+            .handle(ApplySidecarCommand {
+                medium_id: event.medium_id,
+                owner_id: event.user_id,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataExtractedEvent> for SidecarListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(name = "SidecarListener::MetadataExtractedEvent", skip(self, event))]
+    async fn process(&self, event: &MetadataExtractedEvent) -> ApplicationResult<()> {
+        // The sidecar may have been added while the metadata was extracted
+        self.handler
+            .handle(ApplySidecarCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+            })
+            .await
+    }
+}
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    medium::events::{MediumCurationChangedEvent, MediumTagsAddedEvent, MediumTagsRemovedEvent},
+    metadata::events::MetadataOverriddenEvent,
+};
+use tracing::{debug, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    event_bus::EventProcessor,
+    metadata::commands::{WriteSidecarCommand, WriteSidecarHandler},
+};
+
+#[derive(new)]
+pub struct SidecarWriteBackListener {
+    handler: Arc<WriteSidecarHandler>,
+}
+
+#[async_trait]
+impl EventProcessor<MetadataOverriddenEvent> for SidecarWriteBackListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "SidecarWriteBackListener::MetadataOverriddenEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MetadataOverriddenEvent) -> ApplicationResult<()> {
+        self.handler
+            .handle(WriteSidecarCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MediumCurationChangedEvent> for SidecarWriteBackListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "SidecarWriteBackListener::MediumCurationChangedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediumCurationChangedEvent) -> ApplicationResult<()> {
+        if event.embedded {
+            debug!("Curation was read from the file or its sidecar, nothing to write back");
+            return Ok(());
+        }
+
+        self.handler
+            .handle(WriteSidecarCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MediumTagsAddedEvent> for SidecarWriteBackListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "SidecarWriteBackListener::MediumTagsAddedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediumTagsAddedEvent) -> ApplicationResult<()> {
+        self.handler
+            .handle(WriteSidecarCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MediumTagsRemovedEvent> for SidecarWriteBackListener {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "SidecarWriteBackListener::MediumTagsRemovedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &MediumTagsRemovedEvent) -> ApplicationResult<()> {
+        self.handler
+            .handle(WriteSidecarCommand {
+                medium_id: event.medium_id,
+                owner_id: event.owner_id,
+            })
+            .await
+    }
+}
@@ -1,9 +1,20 @@
 use std::sync::Arc;
 
-use crate::metadata::{
-    commands::ExtractMetadataHandler,
-    ports::{MetadataExtractor, MetadataRepository, PublishMetadataEvent},
-    queries::FindMetadataByMediumIdHandler,
+use crate::{
+    medium::{
+        commands::AddMediumItemHandler,
+        ports::{FileStorage, MediumRepository},
+    },
+    metadata::{
+        commands::{
+            ApplySidecarHandler, ExtractMetadataHandler, GeotagFromTrackHandler,
+            OverrideMetadataHandler, ReextractMetadataHandler, ReextractOutdatedMetadataHandler,
+            ResolveLocationHandler, ShiftCaptureDatesHandler, WriteSidecarHandler,
+        },
+        ports::{MetadataRepository, MetadataServices, PublishMetadataEvent},
+        queries::FindMetadataByMediumIdHandler,
+    },
+    user::UserRepository,
 };
 
 pub mod commands;
@@ -13,21 +24,84 @@ pub mod queries;
 
 pub struct MetadataApplicationHandlers {
     pub extract_metadata_handler: Arc<ExtractMetadataHandler>,
+    pub reextract_metadata: Arc<ReextractMetadataHandler>,
+    pub reextract_outdated_metadata: Arc<ReextractOutdatedMetadataHandler>,
+    pub resolve_location: Arc<ResolveLocationHandler>,
+    pub override_metadata: Arc<OverrideMetadataHandler>,
+    pub shift_capture_dates: Arc<ShiftCaptureDatesHandler>,
+    pub geotag_from_track: Arc<GeotagFromTrackHandler>,
+    pub apply_sidecar: Arc<ApplySidecarHandler>,
+    pub write_sidecar: Arc<WriteSidecarHandler>,
     pub find_metadata_by_medium_id: Arc<FindMetadataByMediumIdHandler>,
 }
 
 impl MetadataApplicationHandlers {
     pub fn new(
-        metadata_extractor: Arc<dyn MetadataExtractor>,
+        services: MetadataServices,
         metadata_repository: Arc<dyn MetadataRepository>,
+        medium_repository: Arc<dyn MediumRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        file_storage: Arc<dyn FileStorage>,
+        add_medium_item: Arc<AddMediumItemHandler>,
         event_bus: Arc<dyn PublishMetadataEvent>,
     ) -> Self {
+        let extract_metadata_handler = Arc::new(ExtractMetadataHandler::new(
+            services.extractor.clone(),
+            metadata_repository.clone(),
+            services.timezone_resolver.clone(),
+            user_repository.clone(),
+            event_bus.clone(),
+        ));
+        let override_metadata = Arc::new(OverrideMetadataHandler::new(
+            medium_repository.clone(),
+            metadata_repository.clone(),
+            event_bus.clone(),
+        ));
+
         Self {
-            extract_metadata_handler: Arc::new(ExtractMetadataHandler::new(
-                metadata_extractor,
+            reextract_metadata: Arc::new(ReextractMetadataHandler::new(
+                medium_repository.clone(),
+                extract_metadata_handler.clone(),
+            )),
+            reextract_outdated_metadata: Arc::new(ReextractOutdatedMetadataHandler::new(
+                medium_repository.clone(),
+                metadata_repository.clone(),
+                services.extractor.clone(),
+                extract_metadata_handler.clone(),
+                event_bus.clone(),
+            )),
+            extract_metadata_handler,
+            resolve_location: Arc::new(ResolveLocationHandler::new(
+                services.reverse_geocoder,
+                event_bus.clone(),
+            )),
+            shift_capture_dates: Arc::new(ShiftCaptureDatesHandler::new(
+                medium_repository.clone(),
+                metadata_repository.clone(),
+                services.timezone_resolver,
+                event_bus.clone(),
+                override_metadata.clone(),
+            )),
+            geotag_from_track: Arc::new(GeotagFromTrackHandler::new(
+                medium_repository.clone(),
+                metadata_repository.clone(),
+                override_metadata.clone(),
+            )),
+            override_metadata,
+            apply_sidecar: Arc::new(ApplySidecarHandler::new(
+                medium_repository.clone(),
+                services.extractor,
                 metadata_repository.clone(),
                 event_bus,
             )),
+            write_sidecar: Arc::new(WriteSidecarHandler::new(
+                medium_repository,
+                metadata_repository.clone(),
+                user_repository,
+                services.sidecar_writer,
+                file_storage,
+                add_medium_item,
+            )),
             find_metadata_by_medium_id: Arc::new(FindMetadataByMediumIdHandler::new(
                 metadata_repository,
             )),
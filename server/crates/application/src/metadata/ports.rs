@@ -1,36 +1,121 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use chrono::{FixedOffset, NaiveDateTime};
 use domain::{
     error::DomainResult,
-    medium::{FileLocation, MediumId},
+    medium::{FileLocation, Medium, MediumId},
     metadata::{
         events::{
+            CaptureDateShiftCompletedEvent, CaptureDateShiftFailedEvent,
+            CaptureDateShiftProgressedEvent, CaptureDateShiftStartedEvent, LocationResolvedEvent,
             MetadataExtractedEvent, MetadataExtractionFailedEvent, MetadataExtractionStartedEvent,
+            MetadataOverriddenEvent, MetadataReextractionCompletedEvent,
+            MetadataReextractionFailedEvent, MetadataReextractionProgressedEvent,
+            MetadataReextractionStartedEvent, MetadataSidecarAppliedEvent,
         },
-        Metadata, MetadataId,
+        Metadata, MetadataId, Place, SidecarMetadata,
     },
+    user::UserId,
 };
 
 use crate::event_bus::PublishEvent;
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait MetadataRepository: Send + Sync {
     async fn find_by_id(&self, id: MetadataId) -> DomainResult<Option<Metadata>>;
     async fn find_by_medium_id(&self, medium_id: MediumId) -> DomainResult<Option<Metadata>>;
     async fn save(&self, metadata: &Metadata) -> DomainResult<()>;
     async fn delete(&self, id: MetadataId) -> DomainResult<()>;
+
+    /// Media that are not deleted and whose metadata was read by an extractor
+    /// older than `extractor_version`, with their owners. Media extracting
+    /// again failed for at that version are left out.
+    async fn find_outdated(&self, extractor_version: u32) -> DomainResult<Vec<(MediumId, UserId)>>;
+
+    /// Records that extracting the metadata of the medium again failed at
+    /// `extractor_version`, so it is only tried again with a newer extractor
+    async fn mark_reextraction_failed(
+        &self,
+        medium_id: MediumId,
+        extractor_version: u32,
+    ) -> DomainResult<()>;
 }
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait MetadataExtractor: Send + Sync {
+    /// Version of the extraction, raised whenever it reads more or different
+    /// values from the same file
+    fn version(&self) -> u32;
+
     /// Extract metadata from a file at the given location
     async fn extract(&self, location: &FileLocation, medium_id: MediumId)
         -> DomainResult<Metadata>;
+
+    /// Read the values of the XMP sidecar at the given location
+    async fn extract_sidecar(&self, location: &FileLocation) -> DomainResult<SidecarMetadata>;
+}
+
+/// Writes the curation done in Photonic into XMP sidecars, so desktop tools
+/// working on the permanent storage see it
+#[async_trait]
+pub trait SidecarWriter: Send + Sync {
+    /// Write the tags and curation of `medium` and the title, description,
+    /// capture date, camera and location of `metadata` into the XMP sidecar
+    /// at `location`. A missing sidecar is created, other values of an
+    /// existing one are kept.
+    async fn write_sidecar(
+        &self,
+        location: &FileLocation,
+        medium: &Medium,
+        metadata: &Metadata,
+    ) -> DomainResult<()>;
+}
+
+#[async_trait]
+pub trait ReverseGeocoder: Send + Sync {
+    /// The place nearest to the coordinates, `None` when there is no known
+    /// place close enough, e.g. at sea
+    async fn resolve(&self, latitude: f64, longitude: f64) -> DomainResult<Option<Place>>;
+}
+
+/// Looks up time zones of the IANA time zone database
+#[cfg_attr(test, mockall::automock)]
+pub trait TimezoneResolver: Send + Sync {
+    /// Name of the time zone at the coordinates, e.g. `Europe/Lisbon`
+    fn zone_at(&self, latitude: f64, longitude: f64) -> Option<String>;
+
+    /// UTC offset of the named time zone at the local time, `None` if the
+    /// zone is unknown
+    fn offset_at(&self, zone: &str, local: NaiveDateTime) -> Option<FixedOffset>;
+}
+
+/// Ports the metadata of a medium is read, enriched and written back with
+#[derive(Clone)]
+pub struct MetadataServices {
+    pub extractor: Arc<dyn MetadataExtractor>,
+    pub reverse_geocoder: Arc<dyn ReverseGeocoder>,
+    pub timezone_resolver: Arc<dyn TimezoneResolver>,
+    pub sidecar_writer: Arc<dyn SidecarWriter>,
 }
 
 pub trait PublishMetadataEvent:
     PublishEvent<MetadataExtractionStartedEvent>
     + PublishEvent<MetadataExtractedEvent>
     + PublishEvent<MetadataExtractionFailedEvent>
+    + PublishEvent<LocationResolvedEvent>
+    + PublishEvent<MetadataOverriddenEvent>
+    + PublishEvent<MetadataSidecarAppliedEvent>
+    + PublishEvent<CaptureDateShiftStartedEvent>
+    + PublishEvent<CaptureDateShiftProgressedEvent>
+    + PublishEvent<CaptureDateShiftCompletedEvent>
+    + PublishEvent<CaptureDateShiftFailedEvent>
+    + PublishEvent<MetadataReextractionStartedEvent>
+    + PublishEvent<MetadataReextractionProgressedEvent>
+    + PublishEvent<MetadataReextractionCompletedEvent>
+    + PublishEvent<MetadataReextractionFailedEvent>
 {
 }
 
@@ -38,5 +123,16 @@ impl<T> PublishMetadataEvent for T where
     T: PublishEvent<MetadataExtractionStartedEvent>
         + PublishEvent<MetadataExtractedEvent>
         + PublishEvent<MetadataExtractionFailedEvent>
+        + PublishEvent<LocationResolvedEvent>
+        + PublishEvent<MetadataOverriddenEvent>
+        + PublishEvent<MetadataSidecarAppliedEvent>
+        + PublishEvent<CaptureDateShiftStartedEvent>
+        + PublishEvent<CaptureDateShiftProgressedEvent>
+        + PublishEvent<CaptureDateShiftCompletedEvent>
+        + PublishEvent<CaptureDateShiftFailedEvent>
+        + PublishEvent<MetadataReextractionStartedEvent>
+        + PublishEvent<MetadataReextractionProgressedEvent>
+        + PublishEvent<MetadataReextractionCompletedEvent>
+        + PublishEvent<MetadataReextractionFailedEvent>
 {
 }
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{medium::FilterExpression, smart_album::SmartAlbum, user::UserId};
+use tracing::{info, instrument};
+
+use crate::{error::ApplicationResult, smart_album::ports::PublishSmartAlbumEvent};
+
+#[derive(Debug)]
+pub struct CreateSmartAlbumCommand {
+    pub user_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+    pub filter: FilterExpression,
+}
+
+#[derive(new)]
+pub struct CreateSmartAlbumHandler {
+    event_bus: Arc<dyn PublishSmartAlbumEvent>,
+}
+
+impl CreateSmartAlbumHandler {
+    #[instrument(skip(self, command), fields(user_id = %command.user_id))]
+    pub async fn handle(&self, command: CreateSmartAlbumCommand) -> ApplicationResult<SmartAlbum> {
+        let (smart_album, event) = SmartAlbum::new(
+            command.user_id,
+            command.title,
+            command.description,
+            command.filter,
+        )?;
+
+        self.event_bus.publish(event).await?;
+
+        info!(smart_album_id = %smart_album.id, "Smart album created");
+
+        Ok(smart_album)
+    }
+}
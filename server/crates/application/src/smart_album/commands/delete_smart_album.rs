@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::EntityNotFoundSnafu, smart_album::SmartAlbumId, user::UserId};
+use snafu::OptionExt;
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    smart_album::ports::{PublishSmartAlbumEvent, SmartAlbumRepository},
+};
+
+#[derive(Debug)]
+pub struct DeleteSmartAlbumCommand {
+    pub user_id: UserId,
+    pub smart_album_id: SmartAlbumId,
+}
+
+#[derive(new)]
+pub struct DeleteSmartAlbumHandler {
+    smart_album_repository: Arc<dyn SmartAlbumRepository>,
+    event_bus: Arc<dyn PublishSmartAlbumEvent>,
+}
+
+impl DeleteSmartAlbumHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, smart_album_id = %command.smart_album_id))]
+    pub async fn handle(&self, command: DeleteSmartAlbumCommand) -> ApplicationResult<()> {
+        let mut smart_album = self
+            .smart_album_repository
+            .find_by_id(command.smart_album_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "SmartAlbum",
+                id: command.smart_album_id,
+            })?;
+
+        let event = smart_album.delete()?;
+
+        self.event_bus.publish(event).await.map_err(|e| {
+            error!(error = %e, "Failed to publish SmartAlbumDeletedEvent");
+            e
+        })?;
+
+        info!("Smart album deleted");
+
+        Ok(())
+    }
+}
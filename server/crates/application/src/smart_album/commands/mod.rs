@@ -0,0 +1,7 @@
+pub mod create_smart_album;
+pub mod delete_smart_album;
+pub mod update_smart_album;
+
+pub use create_smart_album::*;
+pub use delete_smart_album::*;
+pub use update_smart_album::*;
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::FilterExpression,
+    smart_album::{SmartAlbum, SmartAlbumId},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    smart_album::ports::{PublishSmartAlbumEvent, SmartAlbumRepository},
+};
+
+/// Values left out stay as they are, an empty description removes it
+#[derive(Debug)]
+pub struct UpdateSmartAlbumCommand {
+    pub user_id: UserId,
+    pub smart_album_id: SmartAlbumId,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub filter: Option<FilterExpression>,
+}
+
+#[derive(new)]
+pub struct UpdateSmartAlbumHandler {
+    smart_album_repository: Arc<dyn SmartAlbumRepository>,
+    event_bus: Arc<dyn PublishSmartAlbumEvent>,
+}
+
+impl UpdateSmartAlbumHandler {
+    #[instrument(skip(self), fields(user_id = %command.user_id, smart_album_id = %command.smart_album_id))]
+    pub async fn handle(&self, command: UpdateSmartAlbumCommand) -> ApplicationResult<SmartAlbum> {
+        let mut smart_album = self
+            .smart_album_repository
+            .find_by_id(command.smart_album_id, command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "SmartAlbum",
+                id: command.smart_album_id,
+            })?;
+
+        let title = command.title.unwrap_or_else(|| smart_album.title.clone());
+        let description = command
+            .description
+            .or_else(|| smart_album.description.clone());
+        let filter = command.filter.unwrap_or_else(|| smart_album.filter.clone());
+        match smart_album.update(title, description, filter)? {
+            Some(event) => {
+                self.event_bus.publish(event).await?;
+                info!("Smart album updated");
+            }
+            None => debug!("Smart album unchanged"),
+        }
+
+        Ok(smart_album)
+    }
+}
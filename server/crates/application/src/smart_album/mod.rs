@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::{medium::ports::MediumRepository, smart_album::ports::PublishSmartAlbumEvent};
+
+pub mod commands;
+pub mod ports;
+pub mod queries;
+
+pub use ports::SmartAlbumRepository;
+
+pub struct SmartAlbumApplicationHandlers {
+    pub create_smart_album: Arc<commands::CreateSmartAlbumHandler>,
+    pub update_smart_album: Arc<commands::UpdateSmartAlbumHandler>,
+    pub delete_smart_album: Arc<commands::DeleteSmartAlbumHandler>,
+    pub find_smart_album: Arc<queries::FindSmartAlbumHandler>,
+    pub find_all_smart_albums: Arc<queries::FindAllSmartAlbumsHandler>,
+    pub find_smart_album_media: Arc<queries::FindSmartAlbumMediaHandler>,
+}
+
+impl SmartAlbumApplicationHandlers {
+    pub fn new(
+        smart_album_repository: Arc<dyn SmartAlbumRepository>,
+        medium_repository: Arc<dyn MediumRepository>,
+        event_bus: Arc<dyn PublishSmartAlbumEvent>,
+    ) -> Self {
+        Self {
+            create_smart_album: Arc::new(commands::CreateSmartAlbumHandler::new(event_bus.clone())),
+            update_smart_album: Arc::new(commands::UpdateSmartAlbumHandler::new(
+                smart_album_repository.clone(),
+                event_bus.clone(),
+            )),
+            delete_smart_album: Arc::new(commands::DeleteSmartAlbumHandler::new(
+                smart_album_repository.clone(),
+                event_bus,
+            )),
+            find_smart_album: Arc::new(queries::FindSmartAlbumHandler::new(
+                smart_album_repository.clone(),
+            )),
+            find_all_smart_albums: Arc::new(queries::FindAllSmartAlbumsHandler::new(
+                smart_album_repository.clone(),
+            )),
+            find_smart_album_media: Arc::new(queries::FindSmartAlbumMediaHandler::new(
+                smart_album_repository,
+                medium_repository,
+            )),
+        }
+    }
+}
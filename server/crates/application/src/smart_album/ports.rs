@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use domain::{
+    error::DomainResult,
+    smart_album::{
+        events::{SmartAlbumCreatedEvent, SmartAlbumDeletedEvent, SmartAlbumUpdatedEvent},
+        SmartAlbum, SmartAlbumId,
+    },
+    user::UserId,
+};
+
+use crate::event_bus::PublishEvent;
+
+#[async_trait]
+pub trait SmartAlbumRepository: Send + Sync {
+    /// The smart album of the user, `None` if it is deleted
+    async fn find_by_id(
+        &self,
+        id: SmartAlbumId,
+        user_id: UserId,
+    ) -> DomainResult<Option<SmartAlbum>>;
+    /// Smart albums of the user that are not deleted, newest first
+    async fn find_all(&self, user_id: UserId) -> DomainResult<Vec<SmartAlbum>>;
+}
+
+pub trait PublishSmartAlbumEvent:
+    PublishEvent<SmartAlbumCreatedEvent>
+    + PublishEvent<SmartAlbumUpdatedEvent>
+    + PublishEvent<SmartAlbumDeletedEvent>
+{
+}
+
+impl<T> PublishSmartAlbumEvent for T where
+    T: PublishEvent<SmartAlbumCreatedEvent>
+        + PublishEvent<SmartAlbumUpdatedEvent>
+        + PublishEvent<SmartAlbumDeletedEvent>
+{
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{smart_album::SmartAlbum, user::UserId};
+use tracing::{info, instrument};
+
+use crate::{error::ApplicationResult, smart_album::ports::SmartAlbumRepository};
+
+#[derive(Debug)]
+pub struct FindAllSmartAlbumsQuery {
+    pub user_id: UserId,
+}
+
+#[derive(new)]
+pub struct FindAllSmartAlbumsHandler {
+    smart_album_repository: Arc<dyn SmartAlbumRepository>,
+}
+
+impl FindAllSmartAlbumsHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id))]
+    pub async fn handle(
+        &self,
+        query: FindAllSmartAlbumsQuery,
+    ) -> ApplicationResult<Vec<SmartAlbum>> {
+        let smart_albums = self.smart_album_repository.find_all(query.user_id).await?;
+
+        info!(count = smart_albums.len(), "Smart albums retrieved");
+
+        Ok(smart_albums)
+    }
+}
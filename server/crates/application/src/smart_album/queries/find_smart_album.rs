@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    smart_album::{SmartAlbum, SmartAlbumId},
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, instrument};
+
+use crate::{error::ApplicationResult, smart_album::ports::SmartAlbumRepository};
+
+#[derive(Debug)]
+pub struct FindSmartAlbumQuery {
+    pub user_id: UserId,
+    pub smart_album_id: SmartAlbumId,
+}
+
+#[derive(new)]
+pub struct FindSmartAlbumHandler {
+    smart_album_repository: Arc<dyn SmartAlbumRepository>,
+}
+
+impl FindSmartAlbumHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id, smart_album_id = %query.smart_album_id))]
+    pub async fn handle(&self, query: FindSmartAlbumQuery) -> ApplicationResult<SmartAlbum> {
+        let smart_album = self
+            .smart_album_repository
+            .find_by_id(query.smart_album_id, query.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "SmartAlbum",
+                id: query.smart_album_id,
+            })?;
+
+        debug!("Smart album retrieved successfully");
+
+        Ok(smart_album)
+    }
+}
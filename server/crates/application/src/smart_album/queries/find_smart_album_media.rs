@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    medium::{MediumFilter, MediumId, MediumListItem},
+    shared::{KeysetCursor, SortDirection},
+    smart_album::SmartAlbumId,
+    user::UserId,
+};
+use snafu::OptionExt;
+use tracing::{debug, instrument};
+
+use crate::{
+    error::ApplicationResult, medium::ports::MediumRepository,
+    smart_album::ports::SmartAlbumRepository,
+};
+
+/// A page of the media matching a smart album
+#[derive(Debug)]
+pub struct FindSmartAlbumMediaQuery {
+    pub user_id: UserId,
+    pub smart_album_id: SmartAlbumId,
+    pub per_page: Option<u64>,
+    pub cursor: Option<KeysetCursor<MediumId>>,
+    pub direction: Option<SortDirection>,
+}
+
+#[derive(new)]
+pub struct FindSmartAlbumMediaHandler {
+    smart_album_repository: Arc<dyn SmartAlbumRepository>,
+    medium_repository: Arc<dyn MediumRepository>,
+}
+
+impl FindSmartAlbumMediaHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id, smart_album_id = %query.smart_album_id))]
+    pub async fn handle(
+        &self,
+        query: FindSmartAlbumMediaQuery,
+    ) -> ApplicationResult<Vec<MediumListItem>> {
+        let smart_album = self
+            .smart_album_repository
+            .find_by_id(query.smart_album_id, query.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "SmartAlbum",
+                id: query.smart_album_id,
+            })?;
+
+        let filter = MediumFilter::new(
+            None,
+            None,
+            query.per_page,
+            query.cursor,
+            vec![],
+            None,
+            query.direction,
+            false,
+        )?
+        .with_expression(smart_album.filter)?;
+
+        let media = self
+            .medium_repository
+            .find_all(filter, query.user_id)
+            .await?;
+
+        debug!(count = media.len(), "Smart album media retrieved");
+
+        Ok(media)
+    }
+}
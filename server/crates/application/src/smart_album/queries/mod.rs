@@ -0,0 +1,7 @@
+mod find_all_smart_albums;
+mod find_smart_album;
+mod find_smart_album_media;
+
+pub use find_all_smart_albums::{FindAllSmartAlbumsHandler, FindAllSmartAlbumsQuery};
+pub use find_smart_album::{FindSmartAlbumHandler, FindSmartAlbumQuery};
+pub use find_smart_album_media::{FindSmartAlbumMediaHandler, FindSmartAlbumMediaQuery};
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::ValidationSnafu, user::UserId};
+use snafu::ensure;
+use tracing::{info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    tag::commands::{RenameTagCommand, RenameTagHandler},
+};
+
+#[derive(Debug)]
+pub struct MergeTagsCommand {
+    pub user_id: UserId,
+    /// Tags replaced by the target, together with the tags below them
+    pub sources: Vec<String>,
+    pub target: String,
+}
+
+/// Merges tags that mean the same into one, e.g. `Seaside` into `Beach`
+#[derive(new)]
+pub struct MergeTagsHandler {
+    rename_tag: Arc<RenameTagHandler>,
+}
+
+impl MergeTagsHandler {
+    /// Returns the number of media whose tags changed
+    #[instrument(skip(self), fields(user_id = %command.user_id))]
+    pub async fn handle(&self, command: MergeTagsCommand) -> ApplicationResult<u64> {
+        ensure!(
+            !command.sources.is_empty(),
+            ValidationSnafu {
+                message: "At least one tag has to be merged"
+            }
+        );
+
+        let mut changed = 0;
+        for source in command.sources {
+            changed += self
+                .rename_tag
+                .handle(RenameTagCommand {
+                    user_id: command.user_id,
+                    from: source,
+                    to: command.target.clone(),
+                })
+                .await?;
+        }
+
+        info!(target = %command.target, changed, "Tags merged");
+
+        Ok(changed)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod merge_tags;
+pub mod rename_tag;
+
+pub use merge_tags::*;
+pub use rename_tag::*;
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{medium::Tag, user::UserId};
+use tracing::{error, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    medium::ports::{MediumRepository, PublishMediumEvent},
+    tag::ports::TagRepository,
+};
+
+#[derive(Debug)]
+pub struct RenameTagCommand {
+    pub user_id: UserId,
+    pub from: String,
+    pub to: String,
+}
+
+/// Renames a tag on all media of the user. The tags below it move along,
+/// renaming `Travel` to `Trips` turns `Travel/Lisbon` into `Trips/Lisbon`.
+#[derive(new)]
+pub struct RenameTagHandler {
+    tag_repository: Arc<dyn TagRepository>,
+    medium_repository: Arc<dyn MediumRepository>,
+    event_bus: Arc<dyn PublishMediumEvent>,
+}
+
+impl RenameTagHandler {
+    /// Returns the number of media whose tags changed
+    #[instrument(skip(self), fields(user_id = %command.user_id))]
+    pub async fn handle(&self, command: RenameTagCommand) -> ApplicationResult<u64> {
+        let from = Tag::new(&command.from)?;
+        let to = Tag::new(&command.to)?;
+        if from == to {
+            return Ok(0);
+        }
+
+        let medium_ids = self
+            .tag_repository
+            .find_medium_ids(command.user_id, &from)
+            .await?;
+
+        let mut changed = 0;
+        for medium_id in medium_ids {
+            let Some(mut medium) = self
+                .medium_repository
+                .find_by_id(medium_id, command.user_id)
+                .await?
+            else {
+                continue;
+            };
+
+            let (removed, added) = medium.rename_tag(&from, &to)?;
+            if removed.is_none() && added.is_none() {
+                continue;
+            }
+            if let Some(event) = removed {
+                self.event_bus.publish(event).await.map_err(|e| {
+                    error!(error = %e, %medium_id, "Failed to publish MediumTagsRemovedEvent");
+                    e
+                })?;
+            }
+            if let Some(event) = added {
+                self.event_bus.publish(event).await.map_err(|e| {
+                    error!(error = %e, %medium_id, "Failed to publish MediumTagsAddedEvent");
+                    e
+                })?;
+            }
+            changed += 1;
+        }
+
+        info!(%from, %to, changed, "Tag renamed");
+
+        Ok(changed)
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::medium::ports::{MediumRepository, PublishMediumEvent};
+
+pub mod commands;
+pub mod ports;
+pub mod queries;
+
+pub use ports::TagRepository;
+
+pub struct TagApplicationHandlers {
+    pub rename_tag: Arc<commands::RenameTagHandler>,
+    pub merge_tags: Arc<commands::MergeTagsHandler>,
+    pub find_tags: Arc<queries::FindTagsHandler>,
+}
+
+impl TagApplicationHandlers {
+    pub fn new(
+        tag_repository: Arc<dyn TagRepository>,
+        medium_repository: Arc<dyn MediumRepository>,
+        event_bus: Arc<dyn PublishMediumEvent>,
+    ) -> Self {
+        let rename_tag = Arc::new(commands::RenameTagHandler::new(
+            tag_repository.clone(),
+            medium_repository,
+            event_bus,
+        ));
+
+        Self {
+            merge_tags: Arc::new(commands::MergeTagsHandler::new(rename_tag.clone())),
+            rename_tag,
+            find_tags: Arc::new(queries::FindTagsHandler::new(tag_repository)),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use domain::{
+    error::DomainResult,
+    medium::{MediumId, Tag},
+    user::UserId,
+};
+
+/// A tag with the number of media tagged with it or a tag below it
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagUsage {
+    pub tag: Tag,
+    pub count: u64,
+}
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    /// Tags of the user's media outside the trash, including the tags above
+    /// the ones in use, most used first. With a prefix only tags whose path
+    /// or last segment start with it, ignoring case.
+    async fn find_usage(
+        &self,
+        user_id: UserId,
+        prefix: Option<&str>,
+        limit: u64,
+    ) -> DomainResult<Vec<TagUsage>>;
+    /// Media of the user tagged with the tag or a tag below it, trashed ones
+    /// included
+    async fn find_medium_ids(&self, user_id: UserId, tag: &Tag) -> DomainResult<Vec<MediumId>>;
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::ValidationSnafu, user::UserId};
+use snafu::ensure;
+use tracing::{info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    tag::ports::{TagRepository, TagUsage},
+};
+
+#[derive(Debug)]
+pub struct FindTagsQuery {
+    pub user_id: UserId,
+    /// Only tags starting with it, for autocompletion
+    pub prefix: Option<String>,
+    pub limit: Option<u64>,
+}
+
+#[derive(new)]
+pub struct FindTagsHandler {
+    tag_repository: Arc<dyn TagRepository>,
+}
+
+impl FindTagsHandler {
+    const DEFAULT_LIMIT: u64 = 20;
+    const MAX_LIMIT: u64 = 100;
+
+    #[instrument(skip(self), fields(user_id = %query.user_id))]
+    pub async fn handle(&self, query: FindTagsQuery) -> ApplicationResult<Vec<TagUsage>> {
+        let limit = query.limit.unwrap_or(Self::DEFAULT_LIMIT);
+        ensure!(
+            (1..=Self::MAX_LIMIT).contains(&limit),
+            ValidationSnafu {
+                message: format!(
+                    "limit must be between 1 and {}, got {limit}",
+                    Self::MAX_LIMIT
+                ),
+            }
+        );
+        let prefix = query
+            .prefix
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty());
+
+        let tags = self
+            .tag_repository
+            .find_usage(query.user_id, prefix, limit)
+            .await?;
+
+        info!(count = tags.len(), "Tags retrieved");
+
+        Ok(tags)
+    }
+}
@@ -0,0 +1,3 @@
+mod find_tags;
+
+pub use find_tags::{FindTagsHandler, FindTagsQuery};
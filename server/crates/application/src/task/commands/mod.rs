@@ -1,9 +1,11 @@
 mod complete_task;
 mod create_task;
 mod fail_task;
+mod report_task_progress;
 mod start_task;
 
 pub use complete_task::{CompleteTaskCommand, CompleteTaskHandler};
 pub use create_task::{CreateTaskCommand, CreateTaskHandler};
 pub use fail_task::{FailTaskCommand, FailTaskHandler};
+pub use report_task_progress::{ReportTaskProgressCommand, ReportTaskProgressHandler};
 pub use start_task::{StartTaskCommand, StartTaskHandler};
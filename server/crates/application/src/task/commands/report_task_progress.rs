@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{error::EntityNotFoundSnafu, task::TaskType, user::UserId};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApplicationError, ApplicationResult},
+    task::ports::TaskRepository,
+};
+
+pub struct ReportTaskProgressCommand {
+    pub reference_id: Uuid,
+    pub user_id: UserId,
+    pub task_type: TaskType,
+    pub processed: usize,
+    pub total: usize,
+}
+
+#[derive(new)]
+pub struct ReportTaskProgressHandler {
+    repository: Arc<dyn TaskRepository>,
+}
+
+impl ReportTaskProgressHandler {
+    pub async fn handle(&self, command: ReportTaskProgressCommand) -> ApplicationResult<()> {
+        let mut task = self
+            .repository
+            .find_by_reference_id(command.reference_id, command.task_type, command.user_id)
+            .await?
+            .ok_or_else(|| ApplicationError::Domain {
+                source: EntityNotFoundSnafu {
+                    entity: "TaskReference",
+                    id: command.reference_id,
+                }
+                .build(),
+            })?;
+
+        task.report_progress(command.processed, command.total)?;
+
+        self.repository.save(&task).await?;
+
+        debug!(
+            "Task {} progressed (type={:?}, reference_id={}, {}/{})",
+            task.id, task.task_type, task.reference_id, command.processed, command.total
+        );
+
+        Ok(())
+    }
+}
@@ -27,6 +27,9 @@ impl StartTaskHandler {
             .repository
             .find_by_reference_id(command.reference_id, command.task_type, command.user_id)
             .await?
+            // A finished task is run again as a new one, e.g. when the
+            // metadata of a medium is extracted again
+            .filter(|task| !task.is_terminal())
             .unwrap_or_else(|| {
                 let (task, _event) =
                     Task::new(command.task_type, command.reference_id, command.user_id);
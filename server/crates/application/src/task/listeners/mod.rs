@@ -1,9 +1,11 @@
 mod task_completed_listeners;
 mod task_creation_listeners;
 mod task_failed_listeners;
+mod task_progress_listeners;
 mod task_started_listeners;
 
 pub use task_completed_listeners::*;
 pub use task_creation_listeners::*;
 pub use task_failed_listeners::*;
+pub use task_progress_listeners::*;
 pub use task_started_listeners::*;
@@ -3,7 +3,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use derive_new::new;
 use domain::{
-    medium::events::TempCleanupCompletedEvent, metadata::events::MetadataExtractedEvent,
+    medium::events::{PreviewGenerationCompletedEvent, TempCleanupCompletedEvent},
+    metadata::events::{
+        CaptureDateShiftCompletedEvent, MetadataExtractedEvent, MetadataReextractionCompletedEvent,
+    },
     task::TaskType,
 };
 use tracing::{debug, info, instrument};
@@ -84,3 +87,86 @@ impl EventProcessor<TempCleanupCompletedEvent> for TaskCompletedListeners {
         Ok(())
     }
 }
+
+#[async_trait]
+impl EventProcessor<PreviewGenerationCompletedEvent> for TaskCompletedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskCompletedListeners::PreviewGenerationCompletedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &PreviewGenerationCompletedEvent) -> ApplicationResult<()> {
+        info!(
+            "Completing preview generation task for medium_id={} (source_item_id={}, previews_generated={})",
+            event.medium_id, event.source_item_id, event.previews_generated,
+        );
+
+        self.complete_task_handler
+            .handle(CompleteTaskCommand {
+                reference_id: event.source_item_id,
+                user_id: event.owner_id,
+                task_type: TaskType::PreviewGeneration,
+            })
+            .await?;
+
+        debug!(
+            "Completed PreviewGeneration task for medium_id={}",
+            event.medium_id
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventProcessor<CaptureDateShiftCompletedEvent> for TaskCompletedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskCompletedListeners::CaptureDateShiftCompletedEvent",
+        skip(self, event),
+        fields(shift_id = %event.shift_id)
+    )]
+    async fn process(&self, event: &CaptureDateShiftCompletedEvent) -> ApplicationResult<()> {
+        info!(shift_id = %event.shift_id, "Completing capture date shift task");
+
+        self.complete_task_handler
+            .handle(CompleteTaskCommand {
+                reference_id: event.shift_id,
+                user_id: event.owner_id,
+                task_type: TaskType::CaptureDateShift,
+            })
+            .await?;
+
+        debug!(shift_id = %event.shift_id, "Completed capture date shift task");
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataReextractionCompletedEvent> for TaskCompletedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskCompletedListeners::MetadataReextractionCompletedEvent",
+        skip(self, event),
+        fields(reextraction_id = %event.reextraction_id)
+    )]
+    async fn process(&self, event: &MetadataReextractionCompletedEvent) -> ApplicationResult<()> {
+        info!(reextraction_id = %event.reextraction_id, "Completing metadata re-extraction task");
+
+        self.complete_task_handler
+            .handle(CompleteTaskCommand {
+                reference_id: event.reextraction_id,
+                user_id: event.owner_id,
+                task_type: TaskType::MetadataReextraction,
+            })
+            .await?;
+
+        debug!(reextraction_id = %event.reextraction_id, "Completed metadata re-extraction task");
+
+        Ok(())
+    }
+}
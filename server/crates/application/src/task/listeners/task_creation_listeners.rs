@@ -46,6 +46,24 @@ impl EventProcessor<MediumCreatedEvent> for TaskCreationListeners {
             event.medium_id
         );
 
+        self.create_task_handler
+            .handle(CreateTaskCommand {
+                reference_id: item.id,
+                user_id: event.user_id,
+                task_type: TaskType::PreviewGeneration,
+                file_location: item
+                    .locations
+                    .first()
+                    .expect("Item must have a location")
+                    .clone(),
+            })
+            .await?;
+
+        debug!(
+            "Created PreviewGeneration task for medium_id={}",
+            event.medium_id
+        );
+
         Ok(())
     }
 }
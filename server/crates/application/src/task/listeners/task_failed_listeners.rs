@@ -3,7 +3,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use derive_new::new;
 use domain::{
-    medium::events::TempCleanupFailedEvent, metadata::events::MetadataExtractionFailedEvent,
+    medium::events::{PreviewGenerationFailedEvent, TempCleanupFailedEvent},
+    metadata::events::{
+        CaptureDateShiftFailedEvent, MetadataExtractionFailedEvent, MetadataReextractionFailedEvent,
+    },
     task::TaskType,
 };
 use tracing::{debug, info, instrument};
@@ -85,3 +88,89 @@ impl EventProcessor<TempCleanupFailedEvent> for TaskFailedListeners {
         Ok(())
     }
 }
+
+#[async_trait]
+impl EventProcessor<PreviewGenerationFailedEvent> for TaskFailedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskFailedListeners::PreviewGenerationFailedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &PreviewGenerationFailedEvent) -> ApplicationResult<()> {
+        info!(
+            "Failed preview generation task for medium_id={} (source_item_id={})",
+            event.medium_id, event.source_item_id,
+        );
+
+        self.fail_task_handler
+            .handle(FailTaskCommand {
+                reference_id: event.source_item_id,
+                user_id: event.owner_id,
+                task_type: TaskType::PreviewGeneration,
+                error: event.error.clone(),
+            })
+            .await?;
+
+        debug!(
+            "Failed PreviewGeneration task for medium_id={}",
+            event.medium_id
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventProcessor<CaptureDateShiftFailedEvent> for TaskFailedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskFailedListeners::CaptureDateShiftFailedEvent",
+        skip(self, event),
+        fields(shift_id = %event.shift_id)
+    )]
+    async fn process(&self, event: &CaptureDateShiftFailedEvent) -> ApplicationResult<()> {
+        info!(shift_id = %event.shift_id, "Failing capture date shift task");
+
+        self.fail_task_handler
+            .handle(FailTaskCommand {
+                reference_id: event.shift_id,
+                user_id: event.owner_id,
+                task_type: TaskType::CaptureDateShift,
+                error: event.error.clone(),
+            })
+            .await?;
+
+        debug!(shift_id = %event.shift_id, "Failed capture date shift task");
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataReextractionFailedEvent> for TaskFailedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskFailedListeners::MetadataReextractionFailedEvent",
+        skip(self, event),
+        fields(reextraction_id = %event.reextraction_id)
+    )]
+    async fn process(&self, event: &MetadataReextractionFailedEvent) -> ApplicationResult<()> {
+        info!(reextraction_id = %event.reextraction_id, "Failing metadata re-extraction task");
+
+        self.fail_task_handler
+            .handle(FailTaskCommand {
+                reference_id: event.reextraction_id,
+                user_id: event.owner_id,
+                task_type: TaskType::MetadataReextraction,
+                error: event.error.clone(),
+            })
+            .await?;
+
+        debug!(reextraction_id = %event.reextraction_id, "Failed metadata re-extraction task");
+
+        Ok(())
+    }
+}
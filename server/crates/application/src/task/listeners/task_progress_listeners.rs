@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    metadata::events::{CaptureDateShiftProgressedEvent, MetadataReextractionProgressedEvent},
+    task::TaskType,
+};
+use tracing::{debug, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    event_bus::EventProcessor,
+    task::commands::{ReportTaskProgressCommand, ReportTaskProgressHandler},
+};
+
+#[derive(new)]
+pub struct TaskProgressListeners {
+    report_task_progress_handler: Arc<ReportTaskProgressHandler>,
+}
+
+#[async_trait]
+impl EventProcessor<CaptureDateShiftProgressedEvent> for TaskProgressListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskProgressListeners::CaptureDateShiftProgressedEvent",
+        skip(self, event),
+        fields(shift_id = %event.shift_id)
+    )]
+    async fn process(&self, event: &CaptureDateShiftProgressedEvent) -> ApplicationResult<()> {
+        self.report_task_progress_handler
+            .handle(ReportTaskProgressCommand {
+                reference_id: event.shift_id,
+                user_id: event.owner_id,
+                task_type: TaskType::CaptureDateShift,
+                processed: event.processed,
+                total: event.total,
+            })
+            .await?;
+
+        debug!(
+            shift_id = %event.shift_id,
+            "Capture date shift task at {}/{}", event.processed, event.total
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataReextractionProgressedEvent> for TaskProgressListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskProgressListeners::MetadataReextractionProgressedEvent",
+        skip(self, event),
+        fields(reextraction_id = %event.reextraction_id)
+    )]
+    async fn process(&self, event: &MetadataReextractionProgressedEvent) -> ApplicationResult<()> {
+        self.report_task_progress_handler
+            .handle(ReportTaskProgressCommand {
+                reference_id: event.reextraction_id,
+                user_id: event.owner_id,
+                task_type: TaskType::MetadataReextraction,
+                processed: event.processed,
+                total: event.total,
+            })
+            .await?;
+
+        debug!(
+            reextraction_id = %event.reextraction_id,
+            "Metadata re-extraction task at {}/{}", event.processed, event.total
+        );
+
+        Ok(())
+    }
+}
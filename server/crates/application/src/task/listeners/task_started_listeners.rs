@@ -3,7 +3,11 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use derive_new::new;
 use domain::{
-    medium::events::TempCleanupStartedEvent, metadata::events::MetadataExtractionStartedEvent,
+    medium::events::{PreviewGenerationStartedEvent, TempCleanupStartedEvent},
+    metadata::events::{
+        CaptureDateShiftStartedEvent, MetadataExtractionStartedEvent,
+        MetadataReextractionStartedEvent,
+    },
     task::TaskType,
 };
 use tracing::{debug, info, instrument};
@@ -79,3 +83,86 @@ impl EventProcessor<TempCleanupStartedEvent> for TaskStartedListeners {
         Ok(())
     }
 }
+
+#[async_trait]
+impl EventProcessor<PreviewGenerationStartedEvent> for TaskStartedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskStartedListeners::PreviewGenerationStartedEvent",
+        skip(self, event)
+    )]
+    async fn process(&self, event: &PreviewGenerationStartedEvent) -> ApplicationResult<()> {
+        info!(
+            "Starting preview generation task for medium_id={} (source_item_id={})",
+            event.medium_id, event.source_item_id,
+        );
+
+        self.start_task_handler
+            .handle(StartTaskCommand {
+                reference_id: event.source_item_id,
+                user_id: event.owner_id,
+                task_type: TaskType::PreviewGeneration,
+            })
+            .await?;
+
+        debug!(
+            "Started PreviewGeneration task for medium_id={}",
+            event.medium_id
+        );
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventProcessor<CaptureDateShiftStartedEvent> for TaskStartedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskStartedListeners::CaptureDateShiftStartedEvent",
+        skip(self, event),
+        fields(shift_id = %event.shift_id)
+    )]
+    async fn process(&self, event: &CaptureDateShiftStartedEvent) -> ApplicationResult<()> {
+        info!(shift_id = %event.shift_id, "Starting capture date shift task");
+
+        self.start_task_handler
+            .handle(StartTaskCommand {
+                reference_id: event.shift_id,
+                user_id: event.owner_id,
+                task_type: TaskType::CaptureDateShift,
+            })
+            .await?;
+
+        debug!(shift_id = %event.shift_id, "Started capture date shift task");
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventProcessor<MetadataReextractionStartedEvent> for TaskStartedListeners {
+    type Error = crate::error::ApplicationError;
+
+    #[instrument(
+        name = "TaskStartedListeners::MetadataReextractionStartedEvent",
+        skip(self, event),
+        fields(reextraction_id = %event.reextraction_id)
+    )]
+    async fn process(&self, event: &MetadataReextractionStartedEvent) -> ApplicationResult<()> {
+        info!(reextraction_id = %event.reextraction_id, "Starting metadata re-extraction task");
+
+        self.start_task_handler
+            .handle(StartTaskCommand {
+                reference_id: event.reextraction_id,
+                user_id: event.owner_id,
+                task_type: TaskType::MetadataReextraction,
+            })
+            .await?;
+
+        debug!(reextraction_id = %event.reextraction_id, "Started metadata re-extraction task");
+
+        Ok(())
+    }
+}
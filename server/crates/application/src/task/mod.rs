@@ -12,6 +12,7 @@ pub struct ProcessingApplicationHandlers {
     pub start_task: Arc<commands::StartTaskHandler>,
     pub complete_task: Arc<commands::CompleteTaskHandler>,
     pub fail_task: Arc<commands::FailTaskHandler>,
+    pub report_task_progress: Arc<commands::ReportTaskProgressHandler>,
 }
 
 impl ProcessingApplicationHandlers {
@@ -20,7 +21,8 @@ impl ProcessingApplicationHandlers {
             create_task: Arc::new(commands::CreateTaskHandler::new(repository.clone())),
             start_task: Arc::new(commands::StartTaskHandler::new(repository.clone())),
             complete_task: Arc::new(commands::CompleteTaskHandler::new(repository.clone())),
-            fail_task: Arc::new(commands::FailTaskHandler::new(repository)),
+            fail_task: Arc::new(commands::FailTaskHandler::new(repository.clone())),
+            report_task_progress: Arc::new(commands::ReportTaskProgressHandler::new(repository)),
         }
     }
 }
@@ -1,3 +1,5 @@
+pub mod update_user_settings;
 pub mod user_exists;
 
+pub use update_user_settings::*;
 pub use user_exists::*;
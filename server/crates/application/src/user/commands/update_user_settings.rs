@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    user::{UserId, UserSettings},
+};
+use snafu::OptionExt;
+use tracing::{debug, info, instrument};
+
+use crate::{
+    error::ApplicationResult,
+    user::ports::{PublishUserEvent, UserRepository},
+};
+
+pub struct UpdateUserSettingsCommand {
+    pub user_id: UserId,
+    pub settings: UserSettings,
+}
+
+#[derive(new)]
+pub struct UpdateUserSettingsHandler {
+    user_repository: Arc<dyn UserRepository>,
+    event_bus: Arc<dyn PublishUserEvent>,
+}
+
+impl UpdateUserSettingsHandler {
+    #[instrument(skip(self, command), fields(user_id = %command.user_id))]
+    pub async fn handle(
+        &self,
+        command: UpdateUserSettingsCommand,
+    ) -> ApplicationResult<UserSettings> {
+        let mut user = self
+            .user_repository
+            .find_by_id(command.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "User",
+                id: command.user_id,
+            })?;
+
+        let Some(event) = user.update_settings(command.settings) else {
+            debug!("Settings unchanged");
+            return Ok(user.settings);
+        };
+
+        self.user_repository.update(&user).await?;
+        self.event_bus.publish(event).await?;
+
+        info!(settings = ?user.settings, "User settings updated");
+
+        Ok(user.settings)
+    }
+}
@@ -15,6 +15,8 @@ pub struct EnsureUserExistsCommand {
     pub user_id: UserId,
     pub username: String,
     pub email: Option<String>,
+    /// IANA time zone of the user, if the identity provider knows it
+    pub timezone: Option<String>,
     pub quota: Option<Byte>,
 }
 
@@ -48,6 +50,7 @@ impl EnsureUserExistsHandler {
                 let update_request = UserUpdateRequestBuilder::default()
                     .username(command.username.clone())
                     .email(command.email.clone())
+                    .timezone(command.timezone.clone())
                     .quota(quota_limit)
                     .build()
                     .unwrap();
@@ -85,6 +88,7 @@ impl EnsureUserExistsHandler {
                     sub: command.user_id,
                     username: command.username.clone(),
                     email: command.email.clone(),
+                    timezone: command.timezone.clone(),
                     quota: quota_limit,
                 };
 
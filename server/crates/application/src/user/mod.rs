@@ -4,6 +4,7 @@ use crate::{config::QuotaConfig, user::ports::PublishUserEvent};
 
 pub mod commands;
 pub mod ports;
+pub mod queries;
 pub mod quota_manager;
 
 pub use ports::UserRepository;
@@ -11,6 +12,8 @@ pub use quota_manager::QuotaManager;
 
 pub struct UserApplicationHandlers {
     pub user_exists: Arc<commands::EnsureUserExistsHandler>,
+    pub update_user_settings: Arc<commands::UpdateUserSettingsHandler>,
+    pub find_user_settings: Arc<queries::FindUserSettingsHandler>,
 }
 
 impl UserApplicationHandlers {
@@ -21,10 +24,15 @@ impl UserApplicationHandlers {
     ) -> Self {
         Self {
             user_exists: Arc::new(commands::EnsureUserExistsHandler::new(
-                user_repository,
-                event_bus,
+                user_repository.clone(),
+                event_bus.clone(),
                 quota_config,
             )),
+            update_user_settings: Arc::new(commands::UpdateUserSettingsHandler::new(
+                user_repository.clone(),
+                event_bus,
+            )),
+            find_user_settings: Arc::new(queries::FindUserSettingsHandler::new(user_repository)),
         }
     }
 }
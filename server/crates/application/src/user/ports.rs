@@ -4,7 +4,7 @@ use domain::{
     user::{
         events::{
             QuotaCommittedEvent, QuotaReleasedEvent, QuotaReservedEvent, UserCreatedEvent,
-            UserUpdatedEvent,
+            UserSettingsUpdatedEvent, UserUpdatedEvent,
         },
         User, UserId,
     },
@@ -12,6 +12,7 @@ use domain::{
 
 use crate::event_bus::PublishEvent;
 
+#[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: UserId) -> DomainResult<Option<User>>;
@@ -22,6 +23,7 @@ pub trait UserRepository: Send + Sync {
 pub trait PublishUserEvent:
     PublishEvent<UserCreatedEvent>
     + PublishEvent<UserUpdatedEvent>
+    + PublishEvent<UserSettingsUpdatedEvent>
     + PublishEvent<QuotaReservedEvent>
     + PublishEvent<QuotaCommittedEvent>
     + PublishEvent<QuotaReleasedEvent>
@@ -31,6 +33,7 @@ pub trait PublishUserEvent:
 impl<T> PublishUserEvent for T where
     T: PublishEvent<UserCreatedEvent>
         + PublishEvent<UserUpdatedEvent>
+        + PublishEvent<UserSettingsUpdatedEvent>
         + PublishEvent<QuotaReservedEvent>
         + PublishEvent<QuotaCommittedEvent>
         + PublishEvent<QuotaReleasedEvent>
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_new::new;
+use domain::{
+    error::EntityNotFoundSnafu,
+    user::{UserId, UserSettings},
+};
+use snafu::OptionExt;
+use tracing::instrument;
+
+use crate::{error::ApplicationResult, user::ports::UserRepository};
+
+#[derive(Debug)]
+pub struct FindUserSettingsQuery {
+    pub user_id: UserId,
+}
+
+#[derive(new)]
+pub struct FindUserSettingsHandler {
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl FindUserSettingsHandler {
+    #[instrument(skip(self), fields(user_id = %query.user_id))]
+    pub async fn handle(&self, query: FindUserSettingsQuery) -> ApplicationResult<UserSettings> {
+        let user = self
+            .user_repository
+            .find_by_id(query.user_id)
+            .await?
+            .context(EntityNotFoundSnafu {
+                entity: "User",
+                id: query.user_id,
+            })?;
+
+        Ok(user.settings)
+    }
+}
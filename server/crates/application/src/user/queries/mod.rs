@@ -0,0 +1,3 @@
+mod find_user_settings;
+
+pub use find_user_settings::{FindUserSettingsHandler, FindUserSettingsQuery};
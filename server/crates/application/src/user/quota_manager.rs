@@ -9,6 +9,7 @@ use domain::{
 use snafu::OptionExt;
 use tokio::time::sleep;
 use tracing::{debug, error, info, instrument, warn};
+use uuid::Uuid;
 
 use crate::{
     error::{ApplicationError, ApplicationResult},
@@ -161,6 +162,20 @@ impl QuotaManager {
     ) -> ApplicationResult<User> {
         debug!("Releasing quota reservation");
 
+        self.release(user_id, reserved.bytes, reserved.metadata.event_id)
+            .await
+    }
+
+    /// Returns committed quota to the user, e.g. after the files of a medium were purged
+    #[instrument(skip(self), fields(user_id = %user_id, bytes = %bytes.as_u64()))]
+    pub async fn release(
+        &self,
+        user_id: UserId,
+        bytes: Byte,
+        cause_event_id: Uuid,
+    ) -> ApplicationResult<User> {
+        debug!("Releasing quota");
+
         let mut last_version = 0;
         let retries = 5;
 
@@ -177,7 +192,7 @@ impl QuotaManager {
                 .map_err(|e| ApplicationError::Domain { source: e })?;
             last_version = user.version;
 
-            let event = user.release_quota(&reserved);
+            let event = user.free_quota(bytes, cause_event_id);
 
             match self.user_repository.update(&user).await {
                 Ok(_) => {
@@ -189,7 +204,7 @@ impl QuotaManager {
                         );
                     }
                     info!(
-                        bytes_released = %reserved.bytes.as_u64(),
+                        bytes_released = %bytes.as_u64(),
                         "Quota released successfully"
                     );
                     return Ok(user);
@@ -0,0 +1,397 @@
+use chrono::{DateTime, Utc};
+use event_sourcing::aggregate::traits::{Aggregate, ApplyEvent};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+use uuid::Uuid;
+
+use super::events::{
+    AlbumCoverChangedEvent, AlbumCreatedEvent, AlbumDeletedEvent, AlbumMediumAddedEvent,
+    AlbumMediumRemovedEvent, AlbumRenamedEvent,
+};
+use crate::{
+    aggregate::{AggregateRoot, AggregateVersion},
+    error::{DomainResult, ValidationSnafu},
+    medium::MediumId,
+    user::UserId,
+};
+
+pub type AlbumId = Uuid;
+
+/// A collection of media curated by their owner. A medium belongs to at most
+/// one album.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Album {
+    pub id: AlbumId,
+    pub owner_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+    /// Medium shown for the album, defaults to the first medium put into it
+    pub cover_medium_id: Option<MediumId>,
+    /// The media of the album
+    pub medium_ids: Vec<MediumId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub version: AggregateVersion,
+}
+
+impl Default for Album {
+    fn default() -> Self {
+        Self {
+            id: Uuid::nil(),
+            owner_id: Uuid::nil(),
+            title: String::new(),
+            description: None,
+            cover_medium_id: None,
+            medium_ids: Vec::new(),
+            created_at: DateTime::default(),
+            updated_at: DateTime::default(),
+            deleted_at: None,
+            version: 0,
+        }
+    }
+}
+
+impl AggregateRoot for Album {
+    fn aggregate_type() -> &'static str {
+        "Album"
+    }
+
+    fn version(&self) -> AggregateVersion {
+        self.version
+    }
+}
+
+impl Aggregate for Album {
+    type Id = Uuid;
+
+    fn aggregate_type() -> &'static str {
+        "Album"
+    }
+}
+
+impl ApplyEvent<AlbumCreatedEvent> for Album {
+    fn apply(&mut self, e: &AlbumCreatedEvent) {
+        self.id = e.album_id;
+        self.owner_id = e.owner_id;
+        self.title = e.title.clone();
+        self.description = e.description.clone();
+        self.created_at = e.created_at;
+        self.updated_at = e.created_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<AlbumRenamedEvent> for Album {
+    fn apply(&mut self, e: &AlbumRenamedEvent) {
+        self.title = e.title.clone();
+        self.description = e.description.clone();
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<AlbumCoverChangedEvent> for Album {
+    fn apply(&mut self, e: &AlbumCoverChangedEvent) {
+        self.cover_medium_id = Some(e.cover_medium_id);
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<AlbumMediumAddedEvent> for Album {
+    fn apply(&mut self, e: &AlbumMediumAddedEvent) {
+        self.medium_ids.push(e.medium_id);
+        self.cover_medium_id.get_or_insert(e.medium_id);
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<AlbumMediumRemovedEvent> for Album {
+    fn apply(&mut self, e: &AlbumMediumRemovedEvent) {
+        self.medium_ids.retain(|id| *id != e.medium_id);
+        if self.cover_medium_id == Some(e.medium_id) {
+            self.cover_medium_id = None;
+        }
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<AlbumDeletedEvent> for Album {
+    fn apply(&mut self, e: &AlbumDeletedEvent) {
+        self.medium_ids.clear();
+        self.cover_medium_id = None;
+        self.deleted_at = Some(e.deleted_at);
+        self.updated_at = e.deleted_at;
+        self.version += 1;
+    }
+}
+
+impl Album {
+    const MAX_TITLE_LENGTH: usize = 255;
+
+    pub fn new(
+        owner_id: UserId,
+        title: String,
+        description: Option<String>,
+    ) -> DomainResult<(Self, AlbumCreatedEvent)> {
+        let title = Self::validate_title(title)?;
+        let description = description.filter(|d| !d.trim().is_empty());
+        let now = Utc::now();
+
+        let album = Self {
+            id: Uuid::new_v4(),
+            owner_id,
+            title,
+            description,
+            created_at: now,
+            updated_at: now,
+            version: 1,
+            ..Default::default()
+        };
+
+        let event = AlbumCreatedEvent::new(
+            album.id,
+            owner_id,
+            album.title.clone(),
+            album.description.clone(),
+            now,
+        );
+        Ok((album, event))
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    pub fn contains(&self, medium_id: MediumId) -> bool {
+        self.medium_ids.contains(&medium_id)
+    }
+
+    /// Change the title and description, `None` if both are unchanged
+    pub fn rename(
+        &mut self,
+        title: String,
+        description: Option<String>,
+    ) -> DomainResult<Option<AlbumRenamedEvent>> {
+        self.ensure_not_deleted()?;
+        let title = Self::validate_title(title)?;
+        let description = description.filter(|d| !d.trim().is_empty());
+        if self.title == title && self.description == description {
+            return Ok(None);
+        }
+
+        let mut event =
+            AlbumRenamedEvent::new(self.id, self.owner_id, title.clone(), description.clone());
+        event.metadata.expected_version = self.version;
+        self.title = title;
+        self.description = description;
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Ok(Some(event))
+    }
+
+    /// Show another medium of the album as its cover, `None` if it already is
+    pub fn change_cover(
+        &mut self,
+        medium_id: MediumId,
+    ) -> DomainResult<Option<AlbumCoverChangedEvent>> {
+        self.ensure_not_deleted()?;
+        ensure!(
+            self.contains(medium_id),
+            ValidationSnafu {
+                message: format!("Medium {medium_id} is not in the album"),
+            }
+        );
+        if self.cover_medium_id == Some(medium_id) {
+            return Ok(None);
+        }
+
+        let mut event = AlbumCoverChangedEvent::new(self.id, self.owner_id, medium_id);
+        event.metadata.expected_version = self.version;
+        self.cover_medium_id = Some(medium_id);
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Ok(Some(event))
+    }
+
+    /// Put a medium into the album, `None` if it is in the album already
+    pub fn add_medium(
+        &mut self,
+        medium_id: MediumId,
+    ) -> DomainResult<Option<AlbumMediumAddedEvent>> {
+        self.ensure_not_deleted()?;
+        if self.contains(medium_id) {
+            return Ok(None);
+        }
+
+        let mut event = AlbumMediumAddedEvent::new(self.id, self.owner_id, medium_id);
+        event.metadata.expected_version = self.version;
+        self.medium_ids.push(medium_id);
+        self.cover_medium_id.get_or_insert(medium_id);
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Ok(Some(event))
+    }
+
+    /// Take a medium out of the album, `None` if it is not in the album
+    pub fn remove_medium(
+        &mut self,
+        medium_id: MediumId,
+    ) -> DomainResult<Option<AlbumMediumRemovedEvent>> {
+        self.ensure_not_deleted()?;
+        if !self.contains(medium_id) {
+            return Ok(None);
+        }
+
+        let mut event = AlbumMediumRemovedEvent::new(self.id, self.owner_id, medium_id);
+        event.metadata.expected_version = self.version;
+        self.medium_ids.retain(|id| *id != medium_id);
+        if self.cover_medium_id == Some(medium_id) {
+            self.cover_medium_id = None;
+        }
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Ok(Some(event))
+    }
+
+    /// Delete the album, its media are kept
+    pub fn delete(&mut self) -> DomainResult<AlbumDeletedEvent> {
+        self.ensure_not_deleted()?;
+
+        let now = Utc::now();
+        let mut event = AlbumDeletedEvent::new(self.id, self.owner_id, now);
+        event.metadata.expected_version = self.version;
+        self.medium_ids.clear();
+        self.cover_medium_id = None;
+        self.deleted_at = Some(now);
+        self.updated_at = now;
+        self.version += 1;
+        Ok(event)
+    }
+
+    fn ensure_not_deleted(&self) -> DomainResult<()> {
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Album is deleted"
+            }
+        );
+        Ok(())
+    }
+
+    fn validate_title(title: String) -> DomainResult<String> {
+        let title = title.trim().to_string();
+        ensure!(
+            !title.is_empty(),
+            ValidationSnafu {
+                message: "Album title must not be empty"
+            }
+        );
+        ensure!(
+            title.chars().count() <= Self::MAX_TITLE_LENGTH,
+            ValidationSnafu {
+                message: format!(
+                    "Album title cannot exceed {} characters",
+                    Self::MAX_TITLE_LENGTH
+                ),
+            }
+        );
+        Ok(title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_album() -> Album {
+        Album::new(Uuid::new_v4(), "Holidays".to_string(), None)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_album_title_is_trimmed_and_required() {
+        let (album, event) =
+            Album::new(Uuid::new_v4(), "  Lisbon 2024 ".to_string(), None).unwrap();
+        assert_eq!(album.title, "Lisbon 2024");
+        assert_eq!(event.title, "Lisbon 2024");
+
+        assert!(Album::new(Uuid::new_v4(), "   ".to_string(), None).is_err());
+        assert!(Album::new(Uuid::new_v4(), "x".repeat(256), None).is_err());
+    }
+
+    #[test]
+    fn test_first_medium_becomes_cover() {
+        let mut album = create_test_album();
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+
+        album.add_medium(first).unwrap().unwrap();
+        album.add_medium(second).unwrap().unwrap();
+
+        assert_eq!(album.cover_medium_id, Some(first));
+        assert_eq!(album.medium_ids, vec![first, second]);
+        assert!(album.add_medium(first).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_removing_cover_clears_it() {
+        let mut album = create_test_album();
+        let medium_id = Uuid::new_v4();
+        album.add_medium(medium_id).unwrap();
+
+        let event = album.remove_medium(medium_id).unwrap().unwrap();
+
+        assert_eq!(event.medium_id, medium_id);
+        assert_eq!(album.cover_medium_id, None);
+        assert!(album.remove_medium(medium_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cover_must_be_in_album() {
+        let mut album = create_test_album();
+        let medium_id = Uuid::new_v4();
+
+        assert!(album.change_cover(medium_id).is_err());
+
+        album.add_medium(Uuid::new_v4()).unwrap();
+        album.add_medium(medium_id).unwrap();
+        let event = album.change_cover(medium_id).unwrap().unwrap();
+        assert_eq!(event.cover_medium_id, medium_id);
+        assert!(album.change_cover(medium_id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_deleted_album_cannot_be_changed() {
+        let mut album = create_test_album();
+        album.add_medium(Uuid::new_v4()).unwrap();
+
+        album.delete().unwrap();
+
+        assert!(album.medium_ids.is_empty());
+        assert!(album.delete().is_err());
+        assert!(album.add_medium(Uuid::new_v4()).is_err());
+        assert!(album.rename("Other".to_string(), None).is_err());
+    }
+
+    #[test]
+    fn test_applying_events_rebuilds_album() {
+        let mut album = create_test_album();
+        let medium_id = Uuid::new_v4();
+        let (_, created) = Album::new(album.owner_id, "Holidays".to_string(), None).unwrap();
+        let added = album.add_medium(medium_id).unwrap().unwrap();
+        let renamed = album
+            .rename("Summer".to_string(), Some("At the coast".to_string()))
+            .unwrap()
+            .unwrap();
+
+        let mut rebuilt = Album::default();
+        rebuilt.apply(&created);
+        rebuilt.apply(&added);
+        rebuilt.apply(&renamed);
+
+        assert_eq!(rebuilt.title, "Summer");
+        assert_eq!(rebuilt.description.as_deref(), Some("At the coast"));
+        assert_eq!(rebuilt.cover_medium_id, Some(medium_id));
+        assert_eq!(rebuilt.version, 3);
+    }
+}
@@ -0,0 +1,26 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    album::AlbumId,
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    user::UserId,
+};
+
+/// Event emitted when the owner picks another medium as the cover of an album.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct AlbumCoverChangedEvent {
+    pub album_id: AlbumId,
+    pub owner_id: UserId,
+    pub cover_medium_id: MediumId,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for AlbumCoverChangedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
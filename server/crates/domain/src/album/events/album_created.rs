@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    album::AlbumId,
+    event::{DomainEvent, EventMetadata},
+    user::UserId,
+};
+
+/// Event emitted when a user creates an album.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct AlbumCreatedEvent {
+    pub album_id: AlbumId,
+    pub owner_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for AlbumCreatedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    album::AlbumId,
+    event::{DomainEvent, EventMetadata},
+    user::UserId,
+};
+
+/// Event emitted when a user deletes an album. Its media are kept and no
+/// longer belong to any album.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct AlbumDeletedEvent {
+    pub album_id: AlbumId,
+    pub owner_id: UserId,
+    pub deleted_at: DateTime<Utc>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for AlbumDeletedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
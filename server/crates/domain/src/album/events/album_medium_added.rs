@@ -0,0 +1,27 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    album::AlbumId,
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    user::UserId,
+};
+
+/// Event emitted when a medium is put into an album. The first medium of an
+/// album without a cover becomes its cover.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct AlbumMediumAddedEvent {
+    pub album_id: AlbumId,
+    pub owner_id: UserId,
+    pub medium_id: MediumId,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for AlbumMediumAddedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,28 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    album::AlbumId,
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    user::UserId,
+};
+
+/// Event emitted when a medium is taken out of an album, either explicitly
+/// or because it was moved into another album. Removing the cover leaves the
+/// album without one.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct AlbumMediumRemovedEvent {
+    pub album_id: AlbumId,
+    pub owner_id: UserId,
+    pub medium_id: MediumId,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for AlbumMediumRemovedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,26 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    album::AlbumId,
+    event::{DomainEvent, EventMetadata},
+    user::UserId,
+};
+
+/// Event emitted when the title or description of an album changed.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct AlbumRenamedEvent {
+    pub album_id: AlbumId,
+    pub owner_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for AlbumRenamedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
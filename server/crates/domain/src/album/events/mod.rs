@@ -0,0 +1,13 @@
+mod album_cover_changed;
+mod album_created;
+mod album_deleted;
+mod album_medium_added;
+mod album_medium_removed;
+mod album_renamed;
+
+pub use album_cover_changed::AlbumCoverChangedEvent;
+pub use album_created::AlbumCreatedEvent;
+pub use album_deleted::AlbumDeletedEvent;
+pub use album_medium_added::AlbumMediumAddedEvent;
+pub use album_medium_removed::AlbumMediumRemovedEvent;
+pub use album_renamed::AlbumRenamedEvent;
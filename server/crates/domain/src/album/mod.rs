@@ -0,0 +1,5 @@
+mod album;
+pub mod events;
+
+pub use album::*;
+pub use events::*;
@@ -51,6 +51,9 @@ pub enum DomainError {
     #[snafu(display("The path {path:?} does not exist"))]
     FileNotExists { path: PathBuf, backtrace: Backtrace },
 
+    #[snafu(display("The path {path:?} already exists"))]
+    FileExists { path: PathBuf, backtrace: Backtrace },
+
     #[snafu(display("The path {path:?} is not valid"))]
     InvalidPath { path: PathBuf, backtrace: Backtrace },
 
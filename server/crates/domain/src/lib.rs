@@ -1,11 +1,12 @@
 pub mod aggregate;
-// pub mod album;
+pub mod album;
 pub mod error;
 pub mod event;
 pub mod medium;
 pub mod metadata;
 pub mod serde_helpers;
 pub mod shared;
+pub mod smart_album;
 pub mod task;
 pub mod user;
 
@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::error::{DomainResult, ValidationSnafu};
+
+/// Color labels as Lightroom offers them
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorLabel {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+impl ColorLabel {
+    /// The color of an `xmp:Label` value, `None` for other labels
+    pub fn from_xmp_label(label: &str) -> Option<Self> {
+        match label.trim().to_lowercase().as_str() {
+            "red" => Some(ColorLabel::Red),
+            "yellow" => Some(ColorLabel::Yellow),
+            "green" => Some(ColorLabel::Green),
+            "blue" => Some(ColorLabel::Blue),
+            "purple" => Some(ColorLabel::Purple),
+            _ => None,
+        }
+    }
+
+    /// The `xmp:Label` value Lightroom writes for the color
+    pub fn xmp_label(&self) -> &'static str {
+        match self {
+            ColorLabel::Red => "Red",
+            ColorLabel::Yellow => "Yellow",
+            ColorLabel::Green => "Green",
+            ColorLabel::Blue => "Blue",
+            ColorLabel::Purple => "Purple",
+        }
+    }
+}
+
+/// How the owner culled a medium
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Curation {
+    pub favorite: bool,
+    /// 0 for unrated and up to 5 stars
+    pub rating: u8,
+    pub color_label: Option<ColorLabel>,
+    pub rejected: bool,
+}
+
+impl Curation {
+    pub const MAX_RATING: u8 = 5;
+
+    /// The curation of an `xmp:Rating` and `xmp:Label`, where a rating of
+    /// -1 marks a rejected medium. Values out of range are ignored.
+    pub fn from_xmp(rating: Option<i8>, label: Option<&str>) -> Self {
+        Self {
+            favorite: false,
+            rating: rating
+                .and_then(|r| u8::try_from(r).ok())
+                .filter(|r| *r <= Self::MAX_RATING)
+                .unwrap_or(0),
+            color_label: label.and_then(ColorLabel::from_xmp_label),
+            rejected: rating == Some(-1),
+        }
+    }
+
+    /// The `xmp:Rating` of the curation, -1 for a rejected medium
+    pub fn xmp_rating(&self) -> i8 {
+        if self.rejected {
+            -1
+        } else {
+            self.rating as i8
+        }
+    }
+}
+
+/// Changes of a [`Curation`], a missing value is left as it is
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CurationPatch {
+    pub favorite: Option<bool>,
+    pub rating: Option<u8>,
+    /// `Some(None)` removes the color label
+    pub color_label: Option<Option<ColorLabel>>,
+    pub rejected: Option<bool>,
+}
+
+impl CurationPatch {
+    pub fn is_empty(&self) -> bool {
+        self.favorite.is_none()
+            && self.rating.is_none()
+            && self.color_label.is_none()
+            && self.rejected.is_none()
+    }
+
+    pub fn validate(&self) -> DomainResult<()> {
+        if let Some(rating) = self.rating {
+            ensure!(
+                rating <= Curation::MAX_RATING,
+                ValidationSnafu {
+                    message: format!(
+                        "Rating must be between 0 and {}, got {}",
+                        Curation::MAX_RATING,
+                        rating
+                    ),
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// The curation with the changes applied
+    pub fn apply_to(&self, curation: Curation) -> Curation {
+        Curation {
+            favorite: self.favorite.unwrap_or(curation.favorite),
+            rating: self.rating.unwrap_or(curation.rating),
+            color_label: self.color_label.unwrap_or(curation.color_label),
+            rejected: self.rejected.unwrap_or(curation.rejected),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curation_from_xmp() {
+        let curation = Curation::from_xmp(Some(4), Some(" green "));
+        assert_eq!(curation.rating, 4);
+        assert_eq!(curation.color_label, Some(ColorLabel::Green));
+        assert!(!curation.rejected);
+
+        let rejected = Curation::from_xmp(Some(-1), Some("To Review"));
+        assert_eq!(rejected.rating, 0);
+        assert_eq!(rejected.color_label, None);
+        assert!(rejected.rejected);
+
+        assert_eq!(Curation::from_xmp(Some(7), None), Curation::default());
+    }
+
+    #[test]
+    fn test_curation_to_xmp() {
+        let curation = Curation::from_xmp(Some(4), Some("Purple"));
+        assert_eq!(curation.xmp_rating(), 4);
+        assert_eq!(curation.color_label.map(|l| l.xmp_label()), Some("Purple"));
+
+        let rejected = Curation::from_xmp(Some(-1), None);
+        assert_eq!(rejected.xmp_rating(), -1);
+    }
+
+    #[test]
+    fn test_patch_keeps_missing_values() {
+        let curation = Curation {
+            favorite: true,
+            rating: 3,
+            color_label: Some(ColorLabel::Red),
+            rejected: false,
+        };
+        let patch = CurationPatch {
+            rating: Some(5),
+            color_label: Some(None),
+            ..Default::default()
+        };
+
+        let patched = patch.apply_to(curation);
+
+        assert!(patched.favorite);
+        assert_eq!(patched.rating, 5);
+        assert_eq!(patched.color_label, None);
+        assert!(CurationPatch {
+            rating: Some(6),
+            ..Default::default()
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::MediumId;
+
+/// 64 bit difference hash (dHash) of an image. Visually similar images have
+/// hashes that differ in few bits, see [`PerceptualHash::distance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PerceptualHash(u64);
+
+impl PerceptualHash {
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Hash of a 9x8 grayscale image in row-major order, each bit records
+    /// whether a pixel is brighter than its right neighbour
+    pub fn from_gradient(pixels: &[u8; 72]) -> Self {
+        let mut value = 0u64;
+        for row in pixels.chunks_exact(9) {
+            for pair in row.windows(2) {
+                value = (value << 1) | u64::from(pair[0] > pair[1]);
+            }
+        }
+        Self(value)
+    }
+
+    /// Number of differing bits, 0 for identical images and up to 64
+    pub fn distance(&self, other: &PerceptualHash) -> u32 {
+        (self.0 ^ other.0).count_ones()
+    }
+}
+
+/// How the media of a [`DuplicateCluster`] resemble each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DuplicateKind {
+    /// The originals are byte for byte identical
+    Exact,
+    /// The images look alike, e.g. a re-encoded or resized copy
+    Similar,
+}
+
+/// Media that are copies of each other, oldest first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateCluster {
+    pub kind: DuplicateKind,
+    pub medium_ids: Vec<MediumId>,
+}
+
+/// Group media into clusters of duplicates. `exact` holds groups of media
+/// sharing a checksum, `hashes` the perceptual hash of each medium. Media
+/// whose hashes are at most `threshold` bits apart end up in one cluster,
+/// which is [`DuplicateKind::Exact`] if it consists of a single exact group.
+/// Members keep the order they first appear in within the inputs.
+pub fn cluster_duplicates(
+    exact: &[Vec<MediumId>],
+    hashes: &[(MediumId, PerceptualHash)],
+    threshold: u32,
+) -> Vec<DuplicateCluster> {
+    let mut clusters = UnionFind::default();
+    for group in exact {
+        for pair in group.windows(2) {
+            clusters.union(pair[0], pair[1]);
+        }
+    }
+    for (i, (a, hash_a)) in hashes.iter().enumerate() {
+        for (b, hash_b) in &hashes[i + 1..] {
+            if hash_a.distance(hash_b) <= threshold {
+                clusters.union(*a, *b);
+            }
+        }
+    }
+
+    let mut members: Vec<(MediumId, Vec<MediumId>)> = Vec::new();
+    for id in clusters.order.clone() {
+        let root = clusters.find(id);
+        match members.iter_mut().find(|(r, _)| *r == root) {
+            Some((_, ids)) => ids.push(id),
+            None => members.push((root, vec![id])),
+        }
+    }
+
+    members
+        .into_iter()
+        .filter(|(_, ids)| ids.len() > 1)
+        .map(|(_, medium_ids)| {
+            let kind = if exact
+                .iter()
+                .any(|group| medium_ids.iter().all(|id| group.contains(id)))
+            {
+                DuplicateKind::Exact
+            } else {
+                DuplicateKind::Similar
+            };
+            DuplicateCluster { kind, medium_ids }
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct UnionFind {
+    parents: HashMap<MediumId, MediumId>,
+    /// Media in the order they were first seen
+    order: Vec<MediumId>,
+}
+
+impl UnionFind {
+    fn find(&mut self, id: MediumId) -> MediumId {
+        let parent = *self.parents.entry(id).or_insert_with(|| {
+            self.order.push(id);
+            id
+        });
+        if parent == id {
+            return id;
+        }
+        let root = self.find(parent);
+        self.parents.insert(id, root);
+        root
+    }
+
+    fn union(&mut self, a: MediumId, b: MediumId) {
+        let (a, b) = (self.find(a), self.find(b));
+        if a != b {
+            self.parents.insert(b, a);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn test_from_gradient_compares_horizontal_neighbours() {
+        let mut pixels = [0u8; 72];
+        // Only the first pixel is brighter than its neighbour
+        pixels[0] = 255;
+
+        let hash = PerceptualHash::from_gradient(&pixels);
+
+        assert_eq!(hash.value(), 1 << 63);
+        assert_eq!(hash.distance(&PerceptualHash::new(0)), 1);
+    }
+
+    #[test]
+    fn test_cluster_duplicates_joins_exact_and_similar_media() {
+        let ids: Vec<MediumId> = (0..5).map(|_| Uuid::new_v4()).collect();
+        let exact = vec![vec![ids[0], ids[1]], vec![ids[3], ids[4]]];
+        let hashes = vec![
+            (ids[0], PerceptualHash::new(0b1111)),
+            (ids[1], PerceptualHash::new(0b1111)),
+            (ids[2], PerceptualHash::new(0b0111)),
+            (ids[3], PerceptualHash::new(u64::MAX)),
+        ];
+
+        let clusters = cluster_duplicates(&exact, &hashes, 2);
+
+        assert_eq!(
+            clusters,
+            vec![
+                DuplicateCluster {
+                    kind: DuplicateKind::Similar,
+                    medium_ids: vec![ids[0], ids[1], ids[2]],
+                },
+                DuplicateCluster {
+                    kind: DuplicateKind::Exact,
+                    medium_ids: vec![ids[3], ids[4]],
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{MediumId, MediumItem},
+    user::UserId,
+};
+
+/// Event emitted when the still of a Live Photo absorbs its separately
+/// uploaded video. The items are moved as they are, their files stay in place.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct LivePhotoPairedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub video_medium_id: MediumId,
+    pub items: Vec<MediumItem>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for LivePhotoPairedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,32 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{MediumId, MediumItem, MediumItemId, MediumType},
+    user::UserId,
+};
+
+/// Event emitted when the items of another medium were stacked onto this
+/// one, e.g. the RAW and JPEG of a single shot or the frames of a burst.
+/// The items are moved as they are, their files stay in place.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediaStackedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub source_medium_id: MediumId,
+    pub medium_type: MediumType,
+    pub leading_item_id: MediumItemId,
+    pub items: Vec<MediumItem>,
+    /// Generated previews of this medium that no longer match the leading item
+    pub dropped_item_ids: Vec<MediumItemId>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediaStackedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, FixedOffset};
 use derive_new::new;
 use serde::{Deserialize, Serialize};
 
@@ -15,6 +16,12 @@ pub struct MediumCreatedEvent {
     pub user_id: UserId,
     pub medium_type: MediumType,
     pub initial_item: MediumItem,
+    #[serde(default)]
+    pub taken_at: Option<DateTime<FixedOffset>>,
+    #[serde(default)]
+    pub camera_make: Option<String>,
+    #[serde(default)]
+    pub camera_model: Option<String>,
     #[new(default)]
     pub metadata: EventMetadata,
 }
@@ -0,0 +1,29 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{Curation, MediumId},
+    user::UserId,
+};
+
+/// Event emitted when the favorite flag, rating, color label or reject flag
+/// of a medium changed. Carries the whole curation afterwards.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumCurationChangedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub curation: Curation,
+    /// Taken over from the rating and label embedded in the file or its
+    /// sidecar rather than set by the owner
+    pub embedded: bool,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumCurationChangedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
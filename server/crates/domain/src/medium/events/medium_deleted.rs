@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    user::UserId,
+};
+
+/// Event emitted when a medium is moved to its owner's trash.
+/// The files stay in place until the medium is purged.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumDeletedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub deleted_at: DateTime<Utc>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumDeletedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -1,5 +1,4 @@
 use byte_unit::Byte;
-use derive_new::new;
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 
@@ -8,18 +7,22 @@ use crate::{
     medium::{
         file::{Dimensions, Filename, Priority},
         storage::FileLocation,
-        MediumId, MediumItemId, MediumItemType,
+        MediumId, MediumItem, MediumItemId, MediumItemType,
     },
+    shared::crypto::Sha256,
     user::UserId,
 };
 
-#[derive(new, Debug, Clone, Serialize, Deserialize)]
-#[new(visibility = "pub(crate)")]
+/// Event emitted when an item is added to an existing medium. Carries the
+/// complete item; its creation time is the time the event occurred.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MediumItemCreatedEvent {
     pub user_id: UserId,
     pub medium_id: MediumId,
     pub item_id: MediumItemId,
     pub item_type: MediumItemType,
+    /// The single location the item starts out with, further copies
+    /// follow as `MediumItemLocationAddedEvent`s
     pub file_location: FileLocation,
     #[serde(with = "crate::serde_helpers::mime_serde")]
     pub mime_type: Mime,
@@ -27,10 +30,31 @@ pub struct MediumItemCreatedEvent {
     pub filesize: Byte,
     pub priority: Priority,
     pub dimensions: Option<Dimensions>,
-    #[new(default)]
+    #[serde(default)]
+    pub checksum: Option<Sha256>,
     pub metadata: EventMetadata,
 }
 
+impl MediumItemCreatedEvent {
+    /// Records the item with the location it starts out with
+    pub(crate) fn new(user_id: UserId, item: &MediumItem, file_location: FileLocation) -> Self {
+        Self {
+            user_id,
+            medium_id: item.medium_id,
+            item_id: item.id,
+            item_type: item.medium_item_type,
+            file_location,
+            mime_type: item.mime.clone(),
+            filename: item.filename.clone(),
+            filesize: item.filesize,
+            priority: item.priority,
+            dimensions: item.dimensions,
+            checksum: item.checksum,
+            metadata: EventMetadata::default(),
+        }
+    }
+}
+
 impl DomainEvent for MediumItemCreatedEvent {
     fn metadata(&self) -> &EventMetadata {
         &self.metadata
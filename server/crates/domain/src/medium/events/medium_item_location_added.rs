@@ -0,0 +1,27 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{storage::FileLocation, MediumId, MediumItemId},
+    user::UserId,
+};
+
+/// Event emitted when a copy of a medium item is stored in another location,
+/// e.g. after it was moved from temporary to permanent storage.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumItemLocationAddedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub item_id: MediumItemId,
+    pub location: FileLocation,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumItemLocationAddedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,27 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{storage::StorageTier, MediumId, MediumItemId},
+    user::UserId,
+};
+
+/// Event emitted when the copy of a medium item on a storage tier is gone,
+/// e.g. after the temporary upload was cleaned up.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumItemLocationRemovedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub item_id: MediumItemId,
+    pub storage_tier: StorageTier,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumItemLocationRemovedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
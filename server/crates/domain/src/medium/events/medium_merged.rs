@@ -0,0 +1,26 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    user::UserId,
+};
+
+/// Event emitted when a medium was merged into another one, e.g. the video of
+/// a Live Photo. Its items now belong to `into_medium_id`.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumMergedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub into_medium_id: MediumId,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumMergedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
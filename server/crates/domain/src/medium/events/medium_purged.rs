@@ -0,0 +1,27 @@
+use byte_unit::Byte;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    user::UserId,
+};
+
+/// Event emitted when a trashed medium is removed for good.
+/// Carries the number of bytes that are given back to the owner's quota.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumPurgedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub released_bytes: Byte,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumPurgedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,24 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    user::UserId,
+};
+
+/// Event emitted when a medium is taken back out of the trash.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumRestoredEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumRestoredEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, FixedOffset};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{camera::GpsCoordinates, MediumId, MediumItem, MediumItemId, MediumType},
+    user::UserId,
+};
+
+/// Event emitted when items were taken out of a stack. Their new medium
+/// records a [`MediumSplitOffEvent`].
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumSplitEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub new_medium_id: MediumId,
+    pub item_ids: Vec<MediumItemId>,
+    pub medium_type: MediumType,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumSplitEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
+
+/// Event emitted when a medium is created from items split off a stack.
+/// The items are moved as they are, their files stay in place.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediumSplitOffEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub split_from_medium_id: MediumId,
+    pub medium_type: MediumType,
+    pub leading_item_id: MediumItemId,
+    pub items: Vec<MediumItem>,
+    pub taken_at: Option<DateTime<FixedOffset>>,
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    pub gps_coordinates: Option<GpsCoordinates>,
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumSplitOffEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
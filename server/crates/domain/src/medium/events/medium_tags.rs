@@ -0,0 +1,42 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{MediumId, Tag},
+    user::UserId,
+};
+
+/// Event emitted when tags are put on a medium.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumTagsAddedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub tags: Vec<Tag>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumTagsAddedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
+
+/// Event emitted when tags are taken off a medium.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MediumTagsRemovedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub tags: Vec<Tag>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for MediumTagsRemovedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
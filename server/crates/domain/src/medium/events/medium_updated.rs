@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     event::{DomainEvent, EventMetadata},
     medium::{camera::GpsCoordinates, MediumId},
+    metadata::TimezoneSource,
     user::UserId,
 };
 
@@ -16,6 +17,9 @@ pub struct MediumUpdatedEvent {
     pub medium_id: MediumId,
     pub owner_id: UserId,
     pub taken_at: Option<DateTime<FixedOffset>>,
+    /// How the offset of `taken_at` was determined, `None` when it is unknown
+    #[serde(default)]
+    pub taken_at_timezone_source: Option<TimezoneSource>,
     pub camera_make: Option<String>,
     pub camera_model: Option<String>,
     pub gps_coordinates: Option<GpsCoordinates>,
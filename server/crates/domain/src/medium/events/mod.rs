@@ -1,11 +1,39 @@
+mod live_photo_paired;
+mod media_stacked;
 mod medium_created;
+mod medium_curation_changed;
+mod medium_deleted;
 mod medium_item_created;
+mod medium_item_location_added;
+mod medium_item_location_removed;
+mod medium_merged;
+mod medium_purged;
+mod medium_restored;
+mod medium_split;
+mod medium_tags;
 mod medium_updated;
+mod perceptual_hash_computed;
+mod preview_generation;
 mod temp_cleanup;
 
+pub use live_photo_paired::LivePhotoPairedEvent;
+pub use media_stacked::MediaStackedEvent;
 pub use medium_created::MediumCreatedEvent;
+pub use medium_curation_changed::MediumCurationChangedEvent;
+pub use medium_deleted::MediumDeletedEvent;
 pub use medium_item_created::MediumItemCreatedEvent;
+pub use medium_item_location_added::MediumItemLocationAddedEvent;
+pub use medium_item_location_removed::MediumItemLocationRemovedEvent;
+pub use medium_merged::MediumMergedEvent;
+pub use medium_purged::MediumPurgedEvent;
+pub use medium_restored::MediumRestoredEvent;
+pub use medium_split::{MediumSplitEvent, MediumSplitOffEvent};
+pub use medium_tags::{MediumTagsAddedEvent, MediumTagsRemovedEvent};
 pub use medium_updated::MediumUpdatedEvent;
+pub use perceptual_hash_computed::PerceptualHashComputedEvent;
+pub use preview_generation::{
+    PreviewGenerationCompletedEvent, PreviewGenerationFailedEvent, PreviewGenerationStartedEvent,
+};
 pub use temp_cleanup::{
     TempCleanupCompletedEvent, TempCleanupFailedEvent, TempCleanupStartedEvent,
 };
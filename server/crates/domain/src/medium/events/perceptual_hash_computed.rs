@@ -0,0 +1,26 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{MediumId, PerceptualHash},
+    user::UserId,
+};
+
+/// Event emitted when the perceptual hash near-duplicates are detected by
+/// was computed from the thumbnail of a medium.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct PerceptualHashComputedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub perceptual_hash: PerceptualHash,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for PerceptualHashComputedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{MediumId, MediumItemId},
+    user::UserId,
+};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewGenerationStartedEvent {
+    pub medium_id: MediumId,
+    pub source_item_id: MediumItemId,
+    pub owner_id: UserId,
+    pub metadata: EventMetadata,
+}
+
+impl PreviewGenerationStartedEvent {
+    pub fn new(medium_id: MediumId, source_item_id: MediumItemId, owner_id: UserId) -> Self {
+        Self {
+            medium_id,
+            source_item_id,
+            owner_id,
+            metadata: EventMetadata::default(),
+        }
+    }
+}
+
+impl DomainEvent for PreviewGenerationStartedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewGenerationCompletedEvent {
+    pub medium_id: MediumId,
+    pub source_item_id: MediumItemId,
+    pub owner_id: UserId,
+    pub previews_generated: usize,
+    pub metadata: EventMetadata,
+}
+
+impl PreviewGenerationCompletedEvent {
+    pub fn new(
+        medium_id: MediumId,
+        source_item_id: MediumItemId,
+        owner_id: UserId,
+        previews_generated: usize,
+    ) -> Self {
+        Self {
+            medium_id,
+            source_item_id,
+            owner_id,
+            previews_generated,
+            metadata: EventMetadata::default(),
+        }
+    }
+}
+
+impl DomainEvent for PreviewGenerationCompletedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewGenerationFailedEvent {
+    pub medium_id: MediumId,
+    pub source_item_id: MediumItemId,
+    pub owner_id: UserId,
+    pub error: String,
+    pub metadata: EventMetadata,
+}
+
+impl PreviewGenerationFailedEvent {
+    pub fn new(
+        medium_id: MediumId,
+        source_item_id: MediumItemId,
+        owner_id: UserId,
+        error: String,
+    ) -> Self {
+        Self {
+            medium_id,
+            source_item_id,
+            owner_id,
+            error,
+            metadata: EventMetadata::default(),
+        }
+    }
+}
+
+impl DomainEvent for PreviewGenerationFailedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -1,13 +1,169 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use snafu::ensure;
 use uuid::Uuid;
 
-use super::MediumId;
+use super::{ColorLabel, Curation, MediumId, MediumType, SearchQuery, Tag};
 use crate::{
     error::{DomainResult, ValidationSnafu},
     shared::{KeysetCursor, SortDirection},
 };
 
+/// How the tags of a [`MediumFilter`] are combined
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum TagMatch {
+    /// Media with at least one of the tags
+    #[default]
+    Any,
+    /// Media with every one of the tags
+    All,
+}
+
+/// Restricts a [`MediumFilter`] by how media were curated, a missing value
+/// matches all media
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CurationFilter {
+    pub favorite: Option<bool>,
+    /// Matches media rated with at least this many stars
+    pub min_rating: Option<u8>,
+    pub color_label: Option<ColorLabel>,
+    pub rejected: Option<bool>,
+}
+
+/// A stored query over the media of a user, e.g. `camera = X100V AND
+/// rating >= 4 AND taken in 2024`. A condition on a missing value, like the
+/// lens of a medium without metadata, does not match, so its `Not` does.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterExpression {
+    /// Matches if every expression matches
+    And(Vec<FilterExpression>),
+    /// Matches if at least one expression matches
+    Or(Vec<FilterExpression>),
+    Not(Box<FilterExpression>),
+    /// Taken within the range, a missing bound leaves the range open
+    Taken {
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    },
+    /// Taken with the camera, make and model are compared ignoring case
+    Camera {
+        make: Option<String>,
+        model: Option<String>,
+    },
+    /// Taken with the lens model, compared ignoring case
+    Lens(String),
+    /// Tagged with the tag or a tag below it
+    Tag(Tag),
+    /// Rated with at least this many stars
+    MinRating(u8),
+    MediumType(MediumType),
+    /// Taken in the country, region or city
+    Place(String),
+    /// Whether the medium has GPS coordinates
+    HasGps(bool),
+}
+
+impl FilterExpression {
+    const MAX_DEPTH: usize = 8;
+    const MAX_CONDITIONS: usize = 50;
+
+    /// Checks every condition and keeps expressions small enough to query
+    pub fn validate(&self) -> DomainResult<()> {
+        let conditions = self.validate_at(1)?;
+        ensure!(
+            conditions <= Self::MAX_CONDITIONS,
+            ValidationSnafu {
+                message: format!(
+                    "Filter cannot have more than {} conditions, got {}",
+                    Self::MAX_CONDITIONS,
+                    conditions
+                ),
+            }
+        );
+        Ok(())
+    }
+
+    /// Validates the expression at the given depth, returns its number of
+    /// conditions
+    fn validate_at(&self, depth: usize) -> DomainResult<usize> {
+        ensure!(
+            depth <= Self::MAX_DEPTH,
+            ValidationSnafu {
+                message: format!("Filter cannot be nested deeper than {}", Self::MAX_DEPTH),
+            }
+        );
+        match self {
+            FilterExpression::And(expressions) | FilterExpression::Or(expressions) => {
+                ensure!(
+                    !expressions.is_empty(),
+                    ValidationSnafu {
+                        message: "Filter combinations must not be empty",
+                    }
+                );
+                expressions
+                    .iter()
+                    .map(|e| e.validate_at(depth + 1))
+                    .sum::<DomainResult<usize>>()
+            }
+            FilterExpression::Not(expression) => expression.validate_at(depth + 1),
+            FilterExpression::Taken { start, end } => {
+                ensure!(
+                    start.is_some() || end.is_some(),
+                    ValidationSnafu {
+                        message: "Date filter needs a start or an end",
+                    }
+                );
+                if let (Some(start), Some(end)) = (start, end) {
+                    ensure!(
+                        start <= end,
+                        ValidationSnafu {
+                            message: "Date filter must start before or when it ends",
+                        }
+                    );
+                }
+                Ok(1)
+            }
+            FilterExpression::Camera { make, model } => {
+                let given =
+                    |value: &Option<String>| value.as_ref().is_some_and(|v| !v.trim().is_empty());
+                ensure!(
+                    given(make) || given(model),
+                    ValidationSnafu {
+                        message: "Camera filter needs a make or a model",
+                    }
+                );
+                Ok(1)
+            }
+            FilterExpression::Lens(value) | FilterExpression::Place(value) => {
+                ensure!(
+                    !value.trim().is_empty(),
+                    ValidationSnafu {
+                        message: "Lens and place filters must not be empty",
+                    }
+                );
+                Ok(1)
+            }
+            // Tags are not validated when deserialized
+            FilterExpression::Tag(tag) => Tag::new(tag.as_str()).map(|_| 1),
+            FilterExpression::MinRating(min_rating) => {
+                ensure!(
+                    *min_rating <= Curation::MAX_RATING,
+                    ValidationSnafu {
+                        message: format!(
+                            "min_rating cannot exceed {}, got {}",
+                            Curation::MAX_RATING,
+                            min_rating
+                        ),
+                    }
+                );
+                Ok(1)
+            }
+            FilterExpression::MediumType(_) | FilterExpression::HasGps(_) => Ok(1),
+        }
+    }
+}
+
 /// Filter for querying media
 /// Encapsulates all query criteria and pagination settings
 #[derive(Debug, Clone, PartialEq)]
@@ -16,10 +172,22 @@ pub struct MediumFilter {
     pub end_date: Option<DateTime<Utc>>,
     pub per_page: u64,
     pub cursor: Option<KeysetCursor<MediumId>>,
-    pub tags: Vec<String>,
+    /// Matches media tagged with the tags or a tag below them
+    pub tags: Vec<Tag>,
+    /// Whether any or all of the tags have to match
+    pub tag_match: TagMatch,
+    /// Matches the media of the album
     pub album_id: Option<Uuid>,
     pub direction: SortDirection,
+    /// Also matches media in no album when filtering by album
     pub include_no_album: bool,
+    /// Matches the country, region or city the media were taken in
+    pub place: Option<String>,
+    pub curation: CurationFilter,
+    /// Matches the media of a smart album
+    pub expression: Option<FilterExpression>,
+    /// Matches the media found by a search
+    pub search: Option<SearchQuery>,
 }
 
 impl MediumFilter {
@@ -72,18 +240,68 @@ impl MediumFilter {
             );
         }
 
+        let tags = Tag::parse_all(&tags)?;
+
         Ok(Self {
             start_date,
             end_date,
             per_page,
             cursor,
             tags,
+            tag_match: TagMatch::default(),
             album_id,
             direction: direction.unwrap_or_default(),
             include_no_album,
+            place: None,
+            curation: CurationFilter::default(),
+            expression: None,
+            search: None,
         })
     }
 
+    /// Restricts the filter to media taken in the country, region or city
+    pub fn with_place(mut self, place: Option<String>) -> Self {
+        self.place = place.filter(|p| !p.trim().is_empty());
+        self
+    }
+
+    /// Sets whether any or all of the tags have to match
+    pub fn with_tag_match(mut self, tag_match: TagMatch) -> Self {
+        self.tag_match = tag_match;
+        self
+    }
+
+    /// Restricts the filter to media curated as given
+    pub fn with_curation(mut self, curation: CurationFilter) -> DomainResult<Self> {
+        if let Some(min_rating) = curation.min_rating {
+            ensure!(
+                min_rating <= Curation::MAX_RATING,
+                ValidationSnafu {
+                    message: format!(
+                        "min_rating cannot exceed {}, got {}",
+                        Curation::MAX_RATING,
+                        min_rating
+                    ),
+                }
+            );
+        }
+        self.curation = curation;
+        Ok(self)
+    }
+
+    /// Restricts the filter to media matching the expression
+    pub fn with_expression(mut self, expression: FilterExpression) -> DomainResult<Self> {
+        expression.validate()?;
+        self.expression = Some(expression);
+        Ok(self)
+    }
+
+    /// Restricts the filter to media found by the search
+    pub fn with_search(mut self, search: SearchQuery) -> Self {
+        self.search = Some(search);
+        self
+    }
+
     /// Create a default filter with no criteria
     pub fn default_filter() -> Self {
         Self {
@@ -92,9 +310,94 @@ impl MediumFilter {
             per_page: Self::DEFAULT_PER_PAGE,
             cursor: None,
             tags: vec![],
+            tag_match: TagMatch::default(),
             album_id: None,
             direction: SortDirection::default(),
             include_no_album: false,
+            place: None,
+            curation: CurationFilter::default(),
+            expression: None,
+            search: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn year(year: i32) -> FilterExpression {
+        FilterExpression::Taken {
+            start: Some(Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap()),
+            end: Some(Utc.with_ymd_and_hms(year, 12, 31, 23, 59, 59).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_expression_round_trips_through_json() {
+        let expression = FilterExpression::And(vec![
+            FilterExpression::Camera {
+                make: None,
+                model: Some("X100V".to_string()),
+            },
+            FilterExpression::MinRating(4),
+            year(2024),
+            FilterExpression::Not(Box::new(FilterExpression::Tag(
+                Tag::new("Travel/Lisbon").unwrap(),
+            ))),
+        ]);
+
+        let json = serde_json::to_value(&expression).unwrap();
+
+        assert_eq!(json["and"][1], serde_json::json!({ "min_rating": 4 }));
+        assert_eq!(json["and"][3]["not"]["tag"], "Travel/Lisbon");
+        assert_eq!(
+            serde_json::from_value::<FilterExpression>(json).unwrap(),
+            expression
+        );
+    }
+
+    #[test]
+    fn test_expression_validation() {
+        assert!(year(2024).validate().is_ok());
+        assert!(FilterExpression::Or(vec![]).validate().is_err());
+        assert!(FilterExpression::MinRating(6).validate().is_err());
+        assert!(FilterExpression::Lens(" ".to_string()).validate().is_err());
+        assert!(FilterExpression::Camera {
+            make: Some(String::new()),
+            model: None
+        }
+        .validate()
+        .is_err());
+        assert!(FilterExpression::Taken {
+            start: None,
+            end: None
         }
+        .validate()
+        .is_err());
+        let backwards = FilterExpression::Taken {
+            start: Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+            end: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+        };
+        assert!(backwards.validate().is_err());
+
+        let invalid_tag: FilterExpression = serde_json::from_str(r#"{"tag": "Travel//"}"#).unwrap();
+        assert!(invalid_tag.validate().is_err());
+    }
+
+    #[test]
+    fn test_expression_size_is_limited() {
+        let deep = (0..8).fold(FilterExpression::HasGps(true), |e, _| {
+            FilterExpression::Not(Box::new(e))
+        });
+        assert!(deep.validate().is_err());
+
+        let wide = FilterExpression::Or((0..51).map(|_| FilterExpression::HasGps(true)).collect());
+        assert!(wide.validate().is_err());
+        assert!(MediumFilter::default_filter()
+            .with_expression(wide)
+            .is_err());
     }
 }
@@ -3,18 +3,31 @@ use chrono::{DateTime, FixedOffset, Utc};
 use event_sourcing::aggregate::traits::{Aggregate, ApplyEvent};
 use mime::Mime;
 use serde::{Deserialize, Serialize};
-use snafu::ensure;
+use snafu::{ensure, OptionExt};
 use uuid::Uuid;
 
 use super::{
     camera::GpsCoordinates,
+    curation::{Curation, CurationPatch},
+    duplicate::PerceptualHash,
     file::{Dimensions, Filename, Priority},
+    preview::{PreviewSize, EMBEDDED_PREVIEW_FILENAME, POSTER_FRAME_FILENAME},
     storage::{FileLocation, StorageTier},
+    tag::Tag,
 };
 use crate::{
     aggregate::{AggregateRoot, AggregateVersion},
-    error::{DomainResult, ValidationSnafu},
-    medium::events::{MediumCreatedEvent, MediumItemCreatedEvent, MediumUpdatedEvent},
+    error::{DomainResult, EntityNotFoundSnafu, ValidationSnafu},
+    event::EventMetadata,
+    medium::events::{
+        LivePhotoPairedEvent, MediaStackedEvent, MediumCreatedEvent, MediumCurationChangedEvent,
+        MediumDeletedEvent, MediumItemCreatedEvent, MediumItemLocationAddedEvent,
+        MediumItemLocationRemovedEvent, MediumMergedEvent, MediumPurgedEvent, MediumRestoredEvent,
+        MediumSplitEvent, MediumSplitOffEvent, MediumTagsAddedEvent, MediumTagsRemovedEvent,
+        MediumUpdatedEvent, PerceptualHashComputedEvent,
+    },
+    metadata::{Place, TimezoneSource},
+    shared::crypto::Sha256,
     user::UserId,
 };
 
@@ -29,6 +42,7 @@ pub enum MediumType {
     Vector,
     Sequence,
     Gif,
+    Raw,
     Other,
 }
 
@@ -40,8 +54,94 @@ pub enum MediumItemType {
     Sidecar,
 }
 
+impl MediumItemType {
+    /// Whether a file with the given MIME type and name can be stored as this kind of item
+    pub fn accepts(&self, mime: &Mime, filename: &Filename) -> bool {
+        match self {
+            MediumItemType::Original => true,
+            MediumItemType::Edit => mime.type_() == mime::IMAGE,
+            MediumItemType::Preview => mime.type_() == mime::IMAGE || mime.type_() == mime::VIDEO,
+            MediumItemType::Sidecar => {
+                filename.extension().eq_ignore_ascii_case("xmp")
+                    && matches!(
+                        mime.essence_str(),
+                        "application/xml"
+                            | "application/rdf+xml"
+                            | "application/octet-stream"
+                            | "text/xml"
+                    )
+            }
+        }
+    }
+}
+
+/// MIME types camera vendors and tools use for RAW files
+const RAW_MIME_TYPES: [&str; 16] = [
+    "image/x-adobe-dng",
+    "image/dng",
+    "image/x-canon-cr2",
+    "image/x-canon-cr3",
+    "image/x-canon-crw",
+    "image/x-nikon-nef",
+    "image/x-nikon-nrw",
+    "image/x-sony-arw",
+    "image/x-sony-sr2",
+    "image/x-fuji-raf",
+    "image/x-olympus-orf",
+    "image/x-panasonic-rw2",
+    "image/x-pentax-pef",
+    "image/x-samsung-srw",
+    "image/x-sigma-x3f",
+    "image/x-hasselblad-3fr",
+];
+
+/// Extensions of RAW files, for uploads that arrive as `application/octet-stream`
+const RAW_EXTENSIONS: [&str; 16] = [
+    "dng", "cr2", "cr3", "crw", "nef", "nrw", "arw", "sr2", "raf", "orf", "rw2", "pef", "srw",
+    "x3f", "3fr", "iiq",
+];
+
+impl MediumType {
+    /// Detects the type from the MIME type, falling back to the file extension
+    /// for RAW files uploaded without a vendor specific MIME type
+    pub fn detect(mime: &Mime, filename: &Filename) -> Self {
+        match MediumType::from(mime.clone()) {
+            MediumType::Photo | MediumType::Other
+                if RAW_EXTENSIONS
+                    .iter()
+                    .any(|ext| filename.extension().eq_ignore_ascii_case(ext)) =>
+            {
+                MediumType::Raw
+            }
+            medium_type => medium_type,
+        }
+    }
+
+    /// Whether media of this type hold the files of a single capture and can
+    /// be stacked, see [`Medium::stack`]
+    pub fn is_stackable(&self) -> bool {
+        matches!(
+            self,
+            MediumType::Photo | MediumType::Raw | MediumType::Sequence
+        )
+    }
+
+    /// Filename of the full size JPEG the standard sizes are rendered from,
+    /// for types whose original cannot be decoded as an image
+    pub fn source_preview_filename(&self) -> Option<&'static str> {
+        match self {
+            MediumType::Raw => Some(EMBEDDED_PREVIEW_FILENAME),
+            MediumType::Video => Some(POSTER_FRAME_FILENAME),
+            _ => None,
+        }
+    }
+}
+
 impl From<Mime> for MediumType {
     fn from(value: Mime) -> Self {
+        if RAW_MIME_TYPES.contains(&value.essence_str()) {
+            return MediumType::Raw;
+        }
         match (value.type_(), value.subtype()) {
             (mime::IMAGE, mime::SVG) => MediumType::Vector,
             (mime::IMAGE, mime::GIF) => MediumType::Gif,
@@ -64,6 +164,22 @@ pub struct Medium {
     pub gps_coordinates: Option<GpsCoordinates>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set while the medium is in its owner's trash
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Hash of the thumbnail near-duplicates are detected by, computed in
+    /// the background once the previews exist
+    #[serde(default)]
+    pub perceptual_hash: Option<PerceptualHash>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    /// Favorite flag, rating, color label and reject flag
+    #[serde(default)]
+    pub curation: Curation,
+    /// Set once the owner curated the medium, the ratings embedded in its
+    /// files are no longer taken over after that
+    #[serde(default)]
+    pub curated: bool,
     pub items: Vec<MediumItem>,
     pub version: AggregateVersion,
 }
@@ -81,6 +197,11 @@ impl Default for Medium {
             gps_coordinates: None,
             created_at: DateTime::default(),
             updated_at: DateTime::default(),
+            deleted_at: None,
+            perceptual_hash: None,
+            tags: Vec::new(),
+            curation: Curation::default(),
+            curated: false,
             items: Vec::new(),
             version: 0,
         }
@@ -111,17 +232,56 @@ impl ApplyEvent<MediumCreatedEvent> for Medium {
         self.owner_id = e.user_id;
         self.medium_type = e.medium_type;
         self.leading_item_id = e.initial_item.id;
+        self.taken_at = e.taken_at;
+        self.camera_make = e.camera_make.clone();
+        self.camera_model = e.camera_model.clone();
+        self.created_at = e.initial_item.created_at;
+        self.updated_at = e.initial_item.created_at;
         self.items = vec![e.initial_item.clone()];
         self.version += 1;
     }
 }
 
 impl ApplyEvent<MediumItemCreatedEvent> for Medium {
-    fn apply(&mut self, _e: &MediumItemCreatedEvent) {
-        // Item data is added via add_item(); during replay this is a no-op
-        // because the item is already constructed from the event data.
-        // Full reconstruction from events will be implemented when
-        // MediumItemCreatedEvent is enriched with all item fields.
+    fn apply(&mut self, e: &MediumItemCreatedEvent) {
+        let created_at = e.metadata.occurred_at;
+        self.items.push(MediumItem {
+            id: e.item_id,
+            medium_id: e.medium_id,
+            medium_item_type: e.item_type,
+            mime: e.mime_type.clone(),
+            filename: e.filename.clone(),
+            filesize: e.filesize,
+            priority: e.priority,
+            dimensions: e.dimensions,
+            locations: vec![e.file_location.clone()],
+            checksum: e.checksum,
+            created_at,
+            updated_at: created_at,
+        });
+        self.updated_at = created_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumItemLocationAddedEvent> for Medium {
+    fn apply(&mut self, e: &MediumItemLocationAddedEvent) {
+        if let Some(item) = self.find_item_mut(e.item_id) {
+            item.locations
+                .retain(|l| l.storage_tier != e.location.storage_tier);
+            item.locations.push(e.location.clone());
+            item.updated_at = e.metadata.occurred_at;
+        }
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumItemLocationRemovedEvent> for Medium {
+    fn apply(&mut self, e: &MediumItemLocationRemovedEvent) {
+        if let Some(item) = self.find_item_mut(e.item_id) {
+            item.locations.retain(|l| l.storage_tier != e.storage_tier);
+            item.updated_at = e.metadata.occurred_at;
+        }
         self.version += 1;
     }
 }
@@ -132,6 +292,128 @@ impl ApplyEvent<MediumUpdatedEvent> for Medium {
         self.camera_make = e.camera_make.clone();
         self.camera_model = e.camera_model.clone();
         self.gps_coordinates = e.gps_coordinates;
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumDeletedEvent> for Medium {
+    fn apply(&mut self, e: &MediumDeletedEvent) {
+        self.deleted_at = Some(e.deleted_at);
+        self.updated_at = e.deleted_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumRestoredEvent> for Medium {
+    fn apply(&mut self, e: &MediumRestoredEvent) {
+        self.deleted_at = None;
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumPurgedEvent> for Medium {
+    fn apply(&mut self, _e: &MediumPurgedEvent) {
+        // The files are gone, only the stream remains
+        self.items.clear();
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<LivePhotoPairedEvent> for Medium {
+    fn apply(&mut self, e: &LivePhotoPairedEvent) {
+        self.medium_type = MediumType::LivePhoto;
+        self.items.extend(e.items.iter().cloned().map(|mut item| {
+            item.medium_id = e.medium_id;
+            item
+        }));
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumMergedEvent> for Medium {
+    fn apply(&mut self, _e: &MediumMergedEvent) {
+        // The items live on in the other medium, only the stream remains
+        self.items.clear();
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediaStackedEvent> for Medium {
+    fn apply(&mut self, e: &MediaStackedEvent) {
+        self.items
+            .retain(|item| !e.dropped_item_ids.contains(&item.id));
+        self.items.extend(e.items.iter().cloned().map(|mut item| {
+            item.medium_id = e.medium_id;
+            item
+        }));
+        if self.leading_item_id != e.leading_item_id {
+            self.perceptual_hash = None;
+        }
+        self.medium_type = e.medium_type;
+        self.leading_item_id = e.leading_item_id;
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<PerceptualHashComputedEvent> for Medium {
+    fn apply(&mut self, e: &PerceptualHashComputedEvent) {
+        self.perceptual_hash = Some(e.perceptual_hash);
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumTagsAddedEvent> for Medium {
+    fn apply(&mut self, e: &MediumTagsAddedEvent) {
+        self.tags.extend(e.tags.iter().cloned());
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumTagsRemovedEvent> for Medium {
+    fn apply(&mut self, e: &MediumTagsRemovedEvent) {
+        self.tags
+            .retain(|tag| !e.tags.iter().any(|t| t.matches(tag)));
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumCurationChangedEvent> for Medium {
+    fn apply(&mut self, e: &MediumCurationChangedEvent) {
+        self.curation = e.curation;
+        self.curated |= !e.embedded;
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumSplitEvent> for Medium {
+    fn apply(&mut self, e: &MediumSplitEvent) {
+        self.items.retain(|item| !e.item_ids.contains(&item.id));
+        self.medium_type = e.medium_type;
+        self.updated_at = e.metadata.occurred_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MediumSplitOffEvent> for Medium {
+    fn apply(&mut self, e: &MediumSplitOffEvent) {
+        self.id = e.medium_id;
+        self.owner_id = e.owner_id;
+        self.medium_type = e.medium_type;
+        self.leading_item_id = e.leading_item_id;
+        self.taken_at = e.taken_at;
+        self.camera_make = e.camera_make.clone();
+        self.camera_model = e.camera_model.clone();
+        self.gps_coordinates = e.gps_coordinates;
+        self.created_at = e.metadata.occurred_at;
+        self.updated_at = e.metadata.occurred_at;
+        self.items = e.items.clone();
         self.version += 1;
     }
 }
@@ -143,12 +425,18 @@ pub struct MediumListItem {
     pub owner_id: UserId,
     pub medium_type: MediumType,
     pub leading_item_id: MediumItemId,
+    /// The album the medium is in
+    pub album_id: Option<Uuid>,
     pub taken_at: Option<DateTime<FixedOffset>>,
     pub camera_make: Option<String>,
     pub camera_model: Option<String>,
     pub gps_coordinates: Option<GpsCoordinates>,
+    pub curation: Curation,
+    /// Where the medium was taken, once its coordinates were resolved
+    pub place: Option<Place>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
     pub items: Vec<MediumItem>,
 }
 
@@ -176,12 +464,24 @@ impl Medium {
             gps_coordinates: None,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
+            perceptual_hash: None,
+            tags: Vec::new(),
+            curation: Curation::default(),
+            curated: false,
             items: vec![item.clone()],
             version: 0,
         };
 
-        let mut event =
-            MediumCreatedEvent::new(medium.id, medium.owner_id, medium.medium_type, item);
+        let mut event = MediumCreatedEvent::new(
+            medium.id,
+            medium.owner_id,
+            medium.medium_type,
+            item,
+            medium.taken_at,
+            medium.camera_make.clone(),
+            medium.camera_model.clone(),
+        );
         event.metadata.expected_version = 0;
         medium.version = 1;
 
@@ -193,30 +493,110 @@ impl Medium {
         request: MediumItemCreateRequest,
     ) -> DomainResult<MediumItemCreatedEvent> {
         ensure!(
-            !request.locations.is_empty(),
+            request.locations.len() == 1,
             ValidationSnafu {
-                message: "MediumItem locations must not be empty"
+                message: "A new MediumItem must have exactly one location"
             }
         );
-        let owner_id = request.owner_id;
-        let item = MediumItem::new(self.id, request);
-        let event = MediumItemCreatedEvent::new(
-            owner_id,
-            self.id,
-            item.id,
-            item.medium_item_type,
-            item.locations.first().unwrap().clone(),
-            item.mime.clone(),
-            item.filename.clone(),
-            item.filesize,
-            item.priority,
-            item.dimensions,
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Cannot add items to a medium in the trash"
+            }
+        );
+        ensure!(
+            request
+                .medium_item_type
+                .accepts(&request.mime, &request.filename),
+            ValidationSnafu {
+                message: format!(
+                    "{} ({}) is not a valid {:?} item",
+                    request.filename, request.mime, request.medium_item_type
+                )
+            }
+        );
+        ensure!(
+            !self.items.iter().any(|i| i.filename == request.filename),
+            ValidationSnafu {
+                message: format!("Medium already has an item named {}", request.filename)
+            }
         );
+        let owner_id = request.owner_id;
+        let mut item = MediumItem::new(self.id, request);
+        let mut event =
+            MediumItemCreatedEvent::new(owner_id, &item, item.locations.first().unwrap().clone());
+        event.metadata.expected_version = self.version;
+        item.created_at = event.metadata.occurred_at;
+        item.updated_at = event.metadata.occurred_at;
         self.items.push(item);
-        self.updated_at = Utc::now();
+        self.updated_at = event.metadata.occurred_at;
+        self.version += 1;
+        Ok(event)
+    }
+
+    /// Record that a copy of the item is stored at `location`. An existing
+    /// location on the same storage tier is replaced.
+    pub fn add_item_location(
+        &mut self,
+        item_id: MediumItemId,
+        location: FileLocation,
+    ) -> DomainResult<MediumItemLocationAddedEvent> {
+        let (id, owner_id, version) = (self.id, self.owner_id, self.version);
+        let item = self.find_item_mut(item_id).context(EntityNotFoundSnafu {
+            entity: "MediumItem",
+            id: item_id,
+        })?;
+
+        let mut event = MediumItemLocationAddedEvent::new(id, owner_id, item_id, location.clone());
+        event.metadata.expected_version = version;
+        item.remove_location(location.storage_tier.clone());
+        item.add_location(location);
+        self.updated_at = event.metadata.occurred_at;
+        self.version += 1;
         Ok(event)
     }
 
+    /// Record that the item's copy on `storage_tier` is gone. The last
+    /// location of an item cannot be removed.
+    pub fn remove_item_location(
+        &mut self,
+        item_id: MediumItemId,
+        storage_tier: StorageTier,
+    ) -> DomainResult<MediumItemLocationRemovedEvent> {
+        let (id, owner_id, version) = (self.id, self.owner_id, self.version);
+        let item = self.find_item_mut(item_id).context(EntityNotFoundSnafu {
+            entity: "MediumItem",
+            id: item_id,
+        })?;
+
+        ensure!(
+            item.locations
+                .iter()
+                .any(|l| l.storage_tier == storage_tier),
+            ValidationSnafu {
+                message: format!("MediumItem has no {} location", storage_tier)
+            }
+        );
+        ensure!(
+            item.locations.len() > 1,
+            ValidationSnafu {
+                message: "Cannot remove the last location of a MediumItem"
+            }
+        );
+
+        let mut event =
+            MediumItemLocationRemovedEvent::new(id, owner_id, item_id, storage_tier.clone());
+        event.metadata.expected_version = version;
+        item.remove_location(storage_tier);
+        self.updated_at = event.metadata.occurred_at;
+        self.version += 1;
+        Ok(event)
+    }
+
+    pub fn find_item(&self, item_id: MediumItemId) -> Option<&MediumItem> {
+        self.items.iter().find(|i| i.id == item_id)
+    }
+
     pub fn find_item_mut(&mut self, item_id: MediumItemId) -> Option<&mut MediumItem> {
         self.items.iter_mut().find(|i| i.id == item_id)
     }
@@ -226,6 +606,7 @@ impl Medium {
     pub fn update_basic_metadata(
         &mut self,
         taken_at: Option<DateTime<FixedOffset>>,
+        taken_at_timezone_source: Option<TimezoneSource>,
         camera_make: Option<String>,
         camera_model: Option<String>,
         gps_coordinates: Option<GpsCoordinates>,
@@ -234,6 +615,7 @@ impl Medium {
             self.id,
             self.owner_id,
             taken_at.clone(),
+            taken_at_timezone_source,
             camera_make.clone(),
             camera_model.clone(),
             gps_coordinates,
@@ -247,6 +629,568 @@ impl Medium {
         self.version += 1;
         event
     }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Record the hash computed from the thumbnail of the medium
+    pub fn record_perceptual_hash(
+        &mut self,
+        perceptual_hash: PerceptualHash,
+    ) -> PerceptualHashComputedEvent {
+        let mut event = PerceptualHashComputedEvent::new(self.id, self.owner_id, perceptual_hash);
+        event.metadata.expected_version = self.version;
+        self.perceptual_hash = Some(perceptual_hash);
+        self.version += 1;
+        event
+    }
+
+    /// Whether the medium has the tag, ignoring case
+    pub fn has_tag(&self, tag: &Tag) -> bool {
+        self.tags.iter().any(|t| t.matches(tag))
+    }
+
+    /// Put tags on the medium, `None` if it has all of them already
+    pub fn add_tags(&mut self, tags: Vec<Tag>) -> DomainResult<Option<MediumTagsAddedEvent>> {
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Cannot tag a medium in the trash"
+            }
+        );
+        Ok(self.tag(tags))
+    }
+
+    /// Take tags off the medium, `None` if it has none of them
+    pub fn remove_tags(&mut self, tags: &[Tag]) -> DomainResult<Option<MediumTagsRemovedEvent>> {
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Cannot untag a medium in the trash"
+            }
+        );
+        Ok(self.untag(tags))
+    }
+
+    /// Replace `from` and the tags below it with `to`, keeping the hierarchy
+    /// below. Applies to trashed media as well so a restored medium does not
+    /// bring the old tag back.
+    pub fn rename_tag(
+        &mut self,
+        from: &Tag,
+        to: &Tag,
+    ) -> DomainResult<(Option<MediumTagsRemovedEvent>, Option<MediumTagsAddedEvent>)> {
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        for tag in &self.tags {
+            if let Some(renamed) = tag.rebase(from, to)? {
+                removed.push(tag.clone());
+                added.push(renamed);
+            }
+        }
+
+        let removed = self.untag(&removed);
+        let added = self.tag(added);
+        Ok((removed, added))
+    }
+
+    fn tag(&mut self, tags: Vec<Tag>) -> Option<MediumTagsAddedEvent> {
+        let mut new_tags: Vec<Tag> = Vec::new();
+        for tag in tags {
+            if !self.has_tag(&tag) && !new_tags.iter().any(|t| t.matches(&tag)) {
+                new_tags.push(tag);
+            }
+        }
+        if new_tags.is_empty() {
+            return None;
+        }
+
+        let mut event = MediumTagsAddedEvent::new(self.id, self.owner_id, new_tags.clone());
+        event.metadata.expected_version = self.version;
+        self.tags.extend(new_tags);
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Some(event)
+    }
+
+    fn untag(&mut self, tags: &[Tag]) -> Option<MediumTagsRemovedEvent> {
+        let removed: Vec<Tag> = self
+            .tags
+            .iter()
+            .filter(|tag| tags.iter().any(|t| t.matches(tag)))
+            .cloned()
+            .collect();
+        if removed.is_empty() {
+            return None;
+        }
+
+        let mut event = MediumTagsRemovedEvent::new(self.id, self.owner_id, removed);
+        event.metadata.expected_version = self.version;
+        self.tags.retain(|tag| !tags.iter().any(|t| t.matches(tag)));
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Some(event)
+    }
+
+    /// Change the curation, `None` if nothing changed
+    pub fn curate(
+        &mut self,
+        patch: &CurationPatch,
+    ) -> DomainResult<Option<MediumCurationChangedEvent>> {
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Cannot curate a medium in the trash"
+            }
+        );
+        patch.validate()?;
+
+        let curation = patch.apply_to(self.curation);
+        if curation == self.curation {
+            return Ok(None);
+        }
+        Ok(Some(self.change_curation(curation, false)))
+    }
+
+    /// Take over the `xmp:Rating` and `xmp:Label` embedded in the files of
+    /// the medium, `None` if there are none, nothing changed or the owner
+    /// curated the medium already
+    pub fn adopt_embedded_curation(
+        &mut self,
+        rating: Option<i8>,
+        label: Option<&str>,
+    ) -> Option<MediumCurationChangedEvent> {
+        if self.curated || (rating.is_none() && label.is_none()) {
+            return None;
+        }
+
+        let curation = Curation {
+            favorite: self.curation.favorite,
+            ..Curation::from_xmp(rating, label)
+        };
+        if curation == self.curation {
+            return None;
+        }
+        Some(self.change_curation(curation, true))
+    }
+
+    fn change_curation(
+        &mut self,
+        curation: Curation,
+        embedded: bool,
+    ) -> MediumCurationChangedEvent {
+        let mut event = MediumCurationChangedEvent::new(self.id, self.owner_id, curation, embedded);
+        event.metadata.expected_version = self.version;
+        self.curation = curation;
+        self.curated |= !embedded;
+        self.updated_at = Utc::now();
+        self.version += 1;
+        event
+    }
+
+    /// Combined size of all items, i.e. what the medium counts against the owner's quota.
+    /// Items that only live on the cache tier are derived and not counted.
+    pub fn total_size(&self) -> Byte {
+        Byte::from_u64(
+            self.items
+                .iter()
+                .filter(|i| !i.is_cache_only())
+                .map(|i| i.filesize.as_u64())
+                .sum(),
+        )
+    }
+
+    /// The generated preview of the given size, if it has been rendered yet
+    pub fn find_preview(&self, size: PreviewSize) -> Option<&MediumItem> {
+        self.find_generated_preview(size.filename().as_str())
+    }
+
+    /// The JPEG extracted from a RAW or video leading item, if it has been
+    /// extracted yet, see [`MediumType::source_preview_filename`]
+    pub fn find_source_preview(&self) -> Option<&MediumItem> {
+        self.medium_type
+            .source_preview_filename()
+            .and_then(|filename| self.find_generated_preview(filename))
+    }
+
+    /// The XMP sidecar of the leading item. Lightroom names it after the
+    /// stem (`IMG_0001.xmp`), darktable after the whole filename
+    /// (`IMG_0001.CR3.xmp`). Without such a sidecar the latest one is taken.
+    pub fn find_sidecar(&self) -> Option<&MediumItem> {
+        let sidecars = self
+            .items
+            .iter()
+            .filter(|i| i.medium_item_type == MediumItemType::Sidecar);
+        let leading = self.find_item(self.leading_item_id)?;
+
+        sidecars
+            .clone()
+            .find(|i| {
+                let stem = i.filename.stem();
+                stem.eq_ignore_ascii_case(leading.filename.stem())
+                    || stem.eq_ignore_ascii_case(leading.filename.as_str())
+            })
+            .or_else(|| sidecars.max_by_key(|i| i.created_at))
+    }
+
+    fn find_generated_preview(&self, filename: &str) -> Option<&MediumItem> {
+        self.items.iter().find(|i| {
+            i.medium_item_type == MediumItemType::Preview
+                && i.filename.as_str() == filename
+                && i.is_cache_only()
+        })
+    }
+
+    /// Move the medium to the trash
+    pub fn delete(&mut self) -> DomainResult<MediumDeletedEvent> {
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Medium is already in the trash"
+            }
+        );
+
+        let now = Utc::now();
+        let mut event = MediumDeletedEvent::new(self.id, self.owner_id, now);
+        event.metadata.expected_version = self.version;
+        self.deleted_at = Some(now);
+        self.updated_at = now;
+        self.version += 1;
+        Ok(event)
+    }
+
+    /// Take the medium back out of the trash
+    pub fn restore(&mut self) -> DomainResult<MediumRestoredEvent> {
+        ensure!(
+            self.is_deleted(),
+            ValidationSnafu {
+                message: "Medium is not in the trash"
+            }
+        );
+
+        let mut event = MediumRestoredEvent::new(self.id, self.owner_id);
+        event.metadata.expected_version = self.version;
+        self.deleted_at = None;
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Ok(event)
+    }
+
+    /// Remove a trashed medium for good. The caller is responsible for
+    /// deleting the files before publishing the event.
+    pub fn purge(&mut self) -> DomainResult<MediumPurgedEvent> {
+        ensure!(
+            self.is_deleted(),
+            ValidationSnafu {
+                message: "Only media in the trash can be purged"
+            }
+        );
+
+        let mut event = MediumPurgedEvent::new(self.id, self.owner_id, self.total_size());
+        event.metadata.expected_version = self.version;
+        self.items.clear();
+        self.version += 1;
+        Ok(event)
+    }
+
+    /// Turn this still into a Live Photo by moving the items of its separately
+    /// uploaded `video` over. Generated previews of the video are dropped, the
+    /// caller is responsible for deleting their files.
+    pub fn pair_live_photo(
+        &mut self,
+        video: &mut Medium,
+    ) -> DomainResult<(LivePhotoPairedEvent, MediumMergedEvent)> {
+        ensure!(
+            self.id != video.id && self.owner_id == video.owner_id,
+            ValidationSnafu {
+                message: "A Live Photo can only be paired from two media of the same owner"
+            }
+        );
+        ensure!(
+            self.medium_type == MediumType::Photo && video.medium_type == MediumType::Video,
+            ValidationSnafu {
+                message: format!(
+                    "Cannot pair a {:?} with a {:?} as a Live Photo",
+                    self.medium_type, video.medium_type
+                )
+            }
+        );
+        ensure!(
+            !self.is_deleted() && !video.is_deleted(),
+            ValidationSnafu {
+                message: "Cannot pair media in the trash"
+            }
+        );
+
+        let items: Vec<MediumItem> = video
+            .items
+            .iter()
+            .filter(|i| !i.is_cache_only())
+            .cloned()
+            .collect();
+        ensure!(
+            !items
+                .iter()
+                .any(|i| self.items.iter().any(|own| own.filename == i.filename)),
+            ValidationSnafu {
+                message: "Medium already has an item named like one of the video"
+            }
+        );
+
+        let mut merged = MediumMergedEvent::new(video.id, video.owner_id, self.id);
+        merged.metadata.expected_version = video.version;
+        video.items.clear();
+        video.version += 1;
+
+        let mut paired = LivePhotoPairedEvent::new(self.id, self.owner_id, video.id, items);
+        paired.metadata.expected_version = self.version;
+        self.medium_type = MediumType::LivePhoto;
+        self.items
+            .extend(paired.items.iter().cloned().map(|mut item| {
+                item.medium_id = self.id;
+                item
+            }));
+        self.updated_at = paired.metadata.occurred_at;
+        self.version += 1;
+
+        Ok((paired, merged))
+    }
+
+    /// Stack the separately uploaded files of `other` onto this medium, e.g.
+    /// the RAW and JPEG of a single shot or the frames of a `burst`. The
+    /// original with the highest priority becomes the leading item, generated
+    /// previews of a leading item that lost its place are dropped. The caller
+    /// is responsible for deleting their files.
+    pub fn stack(
+        &mut self,
+        other: &mut Medium,
+        burst: bool,
+    ) -> DomainResult<(MediaStackedEvent, MediumMergedEvent)> {
+        ensure!(
+            self.id != other.id && self.owner_id == other.owner_id,
+            ValidationSnafu {
+                message: "Only two media of the same owner can be stacked"
+            }
+        );
+        ensure!(
+            self.medium_type.is_stackable() && other.medium_type.is_stackable(),
+            ValidationSnafu {
+                message: format!(
+                    "Cannot stack a {:?} with a {:?}",
+                    self.medium_type, other.medium_type
+                )
+            }
+        );
+        ensure!(
+            !self.is_deleted() && !other.is_deleted(),
+            ValidationSnafu {
+                message: "Cannot stack media in the trash"
+            }
+        );
+
+        let leading = leading_item(self.items.iter().chain(other.items.iter())).context(
+            EntityNotFoundSnafu {
+                entity: "MediumItem",
+                id: self.id,
+            },
+        )?;
+        let leading_item_id = leading.id;
+        let medium_type = if burst
+            || self.medium_type == MediumType::Sequence
+            || other.medium_type == MediumType::Sequence
+        {
+            MediumType::Sequence
+        } else {
+            MediumType::detect(&leading.mime, &leading.filename)
+        };
+
+        let dropped_item_ids: Vec<MediumItemId> = if leading_item_id == self.leading_item_id {
+            Vec::new()
+        } else {
+            self.items
+                .iter()
+                .filter(|i| i.is_cache_only())
+                .map(|i| i.id)
+                .collect()
+        };
+        let items: Vec<MediumItem> = other
+            .items
+            .iter()
+            .filter(|i| !i.is_cache_only() || leading_item_id == other.leading_item_id)
+            .cloned()
+            .collect();
+        ensure!(
+            !items.iter().any(|i| self
+                .items
+                .iter()
+                .any(|own| own.filename == i.filename && !dropped_item_ids.contains(&own.id))),
+            ValidationSnafu {
+                message: "Medium already has an item named like one of the stacked medium"
+            }
+        );
+
+        let mut merged = MediumMergedEvent::new(other.id, other.owner_id, self.id);
+        merged.metadata.expected_version = other.version;
+        other.items.clear();
+        other.version += 1;
+
+        let mut stacked = MediaStackedEvent::new(
+            self.id,
+            self.owner_id,
+            other.id,
+            medium_type,
+            leading_item_id,
+            items,
+            dropped_item_ids,
+        );
+        stacked.metadata.expected_version = self.version;
+        self.items
+            .retain(|item| !stacked.dropped_item_ids.contains(&item.id));
+        self.items
+            .extend(stacked.items.iter().cloned().map(|mut item| {
+                item.medium_id = self.id;
+                item
+            }));
+        if self.leading_item_id != leading_item_id {
+            self.perceptual_hash = None;
+        }
+        self.medium_type = medium_type;
+        self.leading_item_id = leading_item_id;
+        self.updated_at = stacked.metadata.occurred_at;
+        self.version += 1;
+
+        Ok((stacked, merged))
+    }
+
+    /// Take the given items out of this stack into a new medium of their own.
+    /// The leading item and the generated previews stay with this medium.
+    pub fn split(
+        &mut self,
+        item_ids: &[MediumItemId],
+    ) -> DomainResult<(Medium, MediumSplitOffEvent, MediumSplitEvent)> {
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Cannot split a medium in the trash"
+            }
+        );
+        ensure!(
+            !item_ids.is_empty(),
+            ValidationSnafu {
+                message: "No items to split off"
+            }
+        );
+        for item_id in item_ids {
+            let item = self.find_item(*item_id).context(EntityNotFoundSnafu {
+                entity: "MediumItem",
+                id: *item_id,
+            })?;
+            ensure!(
+                item.id != self.leading_item_id && !item.is_cache_only(),
+                ValidationSnafu {
+                    message: format!("Item {} cannot be split off", item.id)
+                }
+            );
+        }
+
+        let new_id = MediumId::new_v4();
+        let (split_items, remaining): (Vec<MediumItem>, Vec<MediumItem>) = self
+            .items
+            .iter()
+            .cloned()
+            .partition(|item| item_ids.contains(&item.id));
+        let split_items: Vec<MediumItem> = split_items
+            .into_iter()
+            .map(|mut item| {
+                item.medium_id = new_id;
+                item
+            })
+            .collect();
+
+        let leading = leading_item(split_items.iter()).context(ValidationSnafu {
+            message: "The items split off must include an original",
+        })?;
+        let new_type = stack_type(self.medium_type, leading, &split_items);
+        let leading_item_id = leading.id;
+        let remaining_type = self
+            .find_item(self.leading_item_id)
+            .map(|leading| stack_type(self.medium_type, leading, &remaining))
+            .unwrap_or(self.medium_type);
+
+        let split_off = MediumSplitOffEvent {
+            medium_id: new_id,
+            owner_id: self.owner_id,
+            split_from_medium_id: self.id,
+            medium_type: new_type,
+            leading_item_id,
+            items: split_items,
+            taken_at: self.taken_at,
+            camera_make: self.camera_make.clone(),
+            camera_model: self.camera_model.clone(),
+            gps_coordinates: self.gps_coordinates,
+            metadata: EventMetadata::default(),
+        };
+        let new_medium = Medium {
+            id: new_id,
+            owner_id: self.owner_id,
+            medium_type: new_type,
+            leading_item_id,
+            taken_at: self.taken_at,
+            camera_make: self.camera_make.clone(),
+            camera_model: self.camera_model.clone(),
+            gps_coordinates: self.gps_coordinates,
+            created_at: split_off.metadata.occurred_at,
+            updated_at: split_off.metadata.occurred_at,
+            deleted_at: None,
+            perceptual_hash: None,
+            tags: Vec::new(),
+            curation: Curation::default(),
+            curated: false,
+            items: split_off.items.clone(),
+            version: 1,
+        };
+
+        let mut split = MediumSplitEvent::new(
+            self.id,
+            self.owner_id,
+            new_id,
+            item_ids.to_vec(),
+            remaining_type,
+        );
+        split.metadata.expected_version = self.version;
+        self.items = remaining;
+        self.medium_type = remaining_type;
+        self.updated_at = split.metadata.occurred_at;
+        self.version += 1;
+
+        Ok((new_medium, split_off, split))
+    }
+}
+
+/// The original a medium holding these items is shown by. That is the one
+/// with the highest [`Priority`], preferring decodable files over RAW ones.
+fn leading_item<'a>(items: impl Iterator<Item = &'a MediumItem>) -> Option<&'a MediumItem> {
+    items
+        .filter(|item| item.medium_item_type == MediumItemType::Original)
+        .min_by_key(|item| {
+            let is_raw = MediumType::detect(&item.mime, &item.filename) == MediumType::Raw;
+            (item.priority, is_raw, item.created_at)
+        })
+}
+
+/// Type of a stack with the given leading item, a burst stays a sequence as
+/// long as it holds more than one original
+fn stack_type(current: MediumType, leading: &MediumItem, items: &[MediumItem]) -> MediumType {
+    let originals = items
+        .iter()
+        .filter(|item| item.medium_item_type == MediumItemType::Original)
+        .count();
+    if current == MediumType::Sequence && originals > 1 {
+        MediumType::Sequence
+    } else {
+        MediumType::detect(&leading.mime, &leading.filename)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -261,6 +1205,9 @@ pub struct MediumItem {
     pub priority: Priority,
     pub dimensions: Option<Dimensions>,
     pub locations: Vec<FileLocation>,
+    /// SHA-256 of an uploaded original, exact duplicates are detected by it
+    #[serde(default)]
+    pub checksum: Option<Sha256>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -276,6 +1223,22 @@ impl MediumItem {
         self.updated_at = Utc::now();
     }
 
+    /// Whether the item is derived data that only lives on the cache tier
+    pub fn is_cache_only(&self) -> bool {
+        !self.locations.is_empty()
+            && self
+                .locations
+                .iter()
+                .all(|l| l.storage_tier == StorageTier::Cache)
+    }
+
+    /// Location on the fastest available storage tier, see [`StorageTier::speed`]
+    pub fn fastest_location(&self) -> Option<&FileLocation> {
+        self.locations
+            .iter()
+            .min_by_key(|location| location.storage_tier.speed())
+    }
+
     fn new(medium_id: MediumId, request: MediumItemCreateRequest) -> Self {
         Self {
             id: MediumItemId::new_v4(),
@@ -287,6 +1250,7 @@ impl MediumItem {
             priority: request.priority,
             dimensions: request.dimensions,
             locations: request.locations,
+            checksum: request.checksum,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -313,4 +1277,635 @@ pub struct MediumItemCreateRequest {
     pub priority: Priority,
     pub dimensions: Option<Dimensions>,
     pub locations: Vec<FileLocation>,
+    pub checksum: Option<Sha256>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::medium::ColorLabel;
+
+    fn create_test_medium() -> Medium {
+        let owner_id = Uuid::new_v4();
+        let request = MediumCreateRequest {
+            owner_id,
+            medium_type: MediumType::Photo,
+            taken_at: None,
+            camera_make: None,
+            camera_model: None,
+            medium_item: MediumItemCreateRequest {
+                owner_id,
+                medium_item_type: MediumItemType::Original,
+                mime: mime::IMAGE_JPEG,
+                filename: Filename::new("test.jpg").unwrap(),
+                filesize: Byte::from_u64(1024),
+                priority: Priority::default(),
+                dimensions: None,
+                locations: vec![FileLocation::temporary(PathBuf::from("test.jpg"))],
+                checksum: None,
+            },
+        };
+        Medium::new(request).unwrap().0
+    }
+
+    #[test]
+    fn test_detect_raw_medium_type() {
+        let cr3 = Filename::new("IMG_0001.CR3").unwrap();
+        let jpg = Filename::new("IMG_0001.jpg").unwrap();
+
+        assert_eq!(
+            MediumType::from("image/x-nikon-nef".parse::<Mime>().unwrap()),
+            MediumType::Raw
+        );
+        assert_eq!(
+            MediumType::detect(&mime::APPLICATION_OCTET_STREAM, &cr3),
+            MediumType::Raw
+        );
+        assert_eq!(
+            MediumType::detect(&mime::IMAGE_JPEG, &jpg),
+            MediumType::Photo
+        );
+        assert_eq!(
+            MediumType::detect(&mime::APPLICATION_OCTET_STREAM, &jpg),
+            MediumType::Other
+        );
+    }
+
+    #[test]
+    fn test_delete_moves_medium_to_trash() {
+        let mut medium = create_test_medium();
+
+        let event = medium.delete().unwrap();
+
+        assert!(medium.is_deleted());
+        assert_eq!(medium.deleted_at, Some(event.deleted_at));
+        assert_eq!(event.metadata.expected_version, 1);
+        assert_eq!(medium.version, 2);
+    }
+
+    #[test]
+    fn test_delete_twice_fails() {
+        let mut medium = create_test_medium();
+        medium.delete().unwrap();
+
+        assert!(medium.delete().is_err());
+    }
+
+    #[test]
+    fn test_restore_takes_medium_out_of_trash() {
+        let mut medium = create_test_medium();
+        assert!(medium.restore().is_err());
+
+        medium.delete().unwrap();
+        medium.restore().unwrap();
+
+        assert!(!medium.is_deleted());
+    }
+
+    #[test]
+    fn test_purge_requires_trashed_medium() {
+        let mut medium = create_test_medium();
+        assert!(medium.purge().is_err());
+
+        medium.delete().unwrap();
+        let event = medium.purge().unwrap();
+
+        assert_eq!(event.released_bytes, Byte::from_u64(1024));
+        assert!(medium.items.is_empty());
+    }
+
+    #[test]
+    fn test_apply_deleted_and_restored_events() {
+        let mut source = create_test_medium();
+        let deleted = source.delete().unwrap();
+        let restored = source.restore().unwrap();
+
+        let mut medium = Medium::default();
+        medium.apply(&deleted);
+        assert_eq!(medium.deleted_at, Some(deleted.deleted_at));
+
+        medium.apply(&restored);
+        assert!(!medium.is_deleted());
+        assert_eq!(medium.version, 2);
+    }
+
+    fn tags(values: &[&str]) -> Vec<Tag> {
+        Tag::parse_all(values).unwrap()
+    }
+
+    #[test]
+    fn test_add_tags_skips_existing_tags() {
+        let mut medium = create_test_medium();
+
+        let event = medium
+            .add_tags(tags(&["Beach", "Sunset"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.tags, tags(&["Beach", "Sunset"]));
+
+        let event = medium
+            .add_tags(tags(&["beach", "Lisbon"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.tags, tags(&["Lisbon"]));
+        assert!(medium.add_tags(tags(&["SUNSET"])).unwrap().is_none());
+        assert_eq!(medium.tags.len(), 3);
+        assert_eq!(medium.version, 3);
+    }
+
+    #[test]
+    fn test_remove_tags_ignores_missing_tags() {
+        let mut medium = create_test_medium();
+        medium.add_tags(tags(&["Beach", "Sunset"])).unwrap();
+
+        let event = medium
+            .remove_tags(&tags(&["beach", "Lisbon"]))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.tags, tags(&["Beach"]));
+        assert_eq!(medium.tags, tags(&["Sunset"]));
+        assert!(medium.remove_tags(&tags(&["Lisbon"])).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_trashed_medium_cannot_be_tagged() {
+        let mut medium = create_test_medium();
+        medium.delete().unwrap();
+
+        assert!(medium.add_tags(tags(&["Beach"])).is_err());
+        assert!(medium.remove_tags(&tags(&["Beach"])).is_err());
+    }
+
+    #[test]
+    fn test_rename_tag_rewrites_descendants() {
+        let mut medium = create_test_medium();
+        medium
+            .add_tags(tags(&[
+                "Travel/Portugal",
+                "Travel/Portugal/Lisbon",
+                "Trips",
+            ]))
+            .unwrap();
+
+        let (removed, added) = medium
+            .rename_tag(&Tag::new("travel").unwrap(), &Tag::new("Trips").unwrap())
+            .unwrap();
+
+        assert_eq!(
+            removed.unwrap().tags,
+            tags(&["Travel/Portugal", "Travel/Portugal/Lisbon"])
+        );
+        assert_eq!(
+            added.unwrap().tags,
+            tags(&["Trips/Portugal", "Trips/Portugal/Lisbon"])
+        );
+        assert_eq!(
+            medium.tags,
+            tags(&["Trips", "Trips/Portugal", "Trips/Portugal/Lisbon"])
+        );
+    }
+
+    #[test]
+    fn test_merging_into_existing_tag_only_removes() {
+        let mut medium = create_test_medium();
+        medium.add_tags(tags(&["Beach", "Seaside"])).unwrap();
+
+        let (removed, added) = medium
+            .rename_tag(&Tag::new("Seaside").unwrap(), &Tag::new("Beach").unwrap())
+            .unwrap();
+
+        assert_eq!(removed.unwrap().tags, tags(&["Seaside"]));
+        assert!(added.is_none());
+        assert_eq!(medium.tags, tags(&["Beach"]));
+    }
+
+    #[test]
+    fn test_apply_tag_events() {
+        let mut source = create_test_medium();
+        let added = source
+            .add_tags(tags(&["Beach", "Sunset"]))
+            .unwrap()
+            .unwrap();
+        let removed = source.remove_tags(&tags(&["beach"])).unwrap().unwrap();
+
+        let mut medium = Medium::default();
+        medium.apply(&added);
+        medium.apply(&removed);
+
+        assert_eq!(medium.tags, tags(&["Sunset"]));
+        assert_eq!(medium.version, 2);
+    }
+
+    #[test]
+    fn test_curate_changes_only_given_values() {
+        let mut medium = create_test_medium();
+        let rate = CurationPatch {
+            rating: Some(4),
+            ..Default::default()
+        };
+
+        let event = medium.curate(&rate).unwrap().unwrap();
+        medium
+            .curate(&CurationPatch {
+                favorite: Some(true),
+                ..Default::default()
+            })
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.curation.rating, 4);
+        assert!(!event.embedded);
+        assert_eq!(medium.curation.rating, 4);
+        assert!(medium.curation.favorite);
+        assert!(medium.curated);
+        assert!(medium.curate(&rate).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_trashed_medium_cannot_be_curated() {
+        let mut medium = create_test_medium();
+        medium.delete().unwrap();
+
+        let patch = CurationPatch {
+            rejected: Some(true),
+            ..Default::default()
+        };
+        assert!(medium.curate(&patch).is_err());
+    }
+
+    #[test]
+    fn test_embedded_curation_is_adopted_until_owner_curates() {
+        let mut medium = create_test_medium();
+
+        let event = medium
+            .adopt_embedded_curation(Some(-1), Some("Red"))
+            .unwrap();
+        assert!(event.embedded);
+        assert!(medium.curation.rejected);
+        assert_eq!(medium.curation.color_label, Some(ColorLabel::Red));
+        assert!(medium.adopt_embedded_curation(None, None).is_none());
+
+        medium
+            .curate(&CurationPatch {
+                rejected: Some(false),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(medium.adopt_embedded_curation(Some(5), None).is_none());
+        assert!(!medium.curation.rejected);
+    }
+
+    #[test]
+    fn test_apply_curation_events() {
+        let mut source = create_test_medium();
+        let embedded = source.adopt_embedded_curation(Some(3), None).unwrap();
+        let curated = source
+            .curate(&CurationPatch {
+                color_label: Some(Some(ColorLabel::Blue)),
+                ..Default::default()
+            })
+            .unwrap()
+            .unwrap();
+
+        let mut medium = Medium::default();
+        medium.apply(&embedded);
+        assert!(!medium.curated);
+        medium.apply(&curated);
+
+        assert_eq!(medium.curation, source.curation);
+        assert!(medium.curated);
+        assert_eq!(medium.version, 2);
+    }
+
+    fn item_request(
+        owner_id: UserId,
+        medium_item_type: MediumItemType,
+        mime: Mime,
+        filename: &str,
+    ) -> MediumItemCreateRequest {
+        MediumItemCreateRequest {
+            owner_id,
+            medium_item_type,
+            mime,
+            filename: Filename::new(filename).unwrap(),
+            filesize: Byte::from_u64(512),
+            priority: Priority::default(),
+            dimensions: None,
+            locations: vec![FileLocation::temporary(PathBuf::from(filename))],
+            checksum: None,
+        }
+    }
+
+    #[test]
+    fn test_stack_leads_with_jpeg_over_raw() {
+        let mut raw = create_test_medium();
+        raw.medium_type = MediumType::Raw;
+        raw.items[0].mime = "image/x-canon-cr3".parse().unwrap();
+        raw.items[0].filename = Filename::new("IMG_0001.CR3").unwrap();
+        let mut preview = item_request(
+            raw.owner_id,
+            MediumItemType::Preview,
+            mime::IMAGE_JPEG,
+            "embedded_preview.jpg",
+        );
+        preview.locations = vec![FileLocation::cache(PathBuf::from("embedded_preview.jpg"))];
+        let preview_id = raw.add_item(preview).unwrap().item_id;
+        let mut jpeg = create_test_medium();
+        jpeg.owner_id = raw.owner_id;
+        let jpeg_item_id = jpeg.leading_item_id;
+
+        let (stacked, merged) = raw.stack(&mut jpeg, false).unwrap();
+
+        assert_eq!(raw.medium_type, MediumType::Photo);
+        assert_eq!(raw.leading_item_id, jpeg_item_id);
+        assert_eq!(stacked.dropped_item_ids, vec![preview_id]);
+        assert!(raw.find_item(preview_id).is_none());
+        assert_eq!(raw.find_item(jpeg_item_id).unwrap().medium_id, raw.id);
+        assert_eq!(merged.into_medium_id, raw.id);
+        assert!(jpeg.items.is_empty());
+    }
+
+    #[test]
+    fn test_stack_rejects_videos() {
+        let mut still = create_test_medium();
+        let mut video = create_test_medium();
+        video.owner_id = still.owner_id;
+        video.medium_type = MediumType::Video;
+
+        assert!(still.stack(&mut video, false).is_err());
+    }
+
+    #[test]
+    fn test_split_moves_items_into_new_medium() {
+        let mut burst = create_test_medium();
+        let mut other = create_test_medium();
+        other.owner_id = burst.owner_id;
+        other.items[0].filename = Filename::new("test_2.jpg").unwrap();
+        let frame_id = other.leading_item_id;
+        burst.stack(&mut other, true).unwrap();
+        assert_eq!(burst.medium_type, MediumType::Sequence);
+
+        assert!(burst.split(&[burst.leading_item_id]).is_err());
+        let (split_off, off_event, split) = burst.split(&[frame_id]).unwrap();
+
+        assert_eq!(burst.items.len(), 1);
+        assert_eq!(burst.medium_type, MediumType::Photo);
+        assert_eq!(split.medium_type, MediumType::Photo);
+        assert_eq!(split_off.leading_item_id, frame_id);
+        assert_eq!(split_off.items[0].medium_id, split_off.id);
+        assert_eq!(off_event.split_from_medium_id, burst.id);
+        assert_eq!(split.new_medium_id, split_off.id);
+    }
+
+    #[test]
+    fn test_pair_live_photo_moves_video_items() {
+        let mut still = create_test_medium();
+        let mut video = create_test_medium();
+        video.owner_id = still.owner_id;
+        video.medium_type = MediumType::Video;
+        video.items[0].filename = Filename::new("test.mov").unwrap();
+        let mut preview = item_request(
+            still.owner_id,
+            MediumItemType::Preview,
+            mime::IMAGE_JPEG,
+            "poster_frame.jpg",
+        );
+        preview.locations = vec![FileLocation::cache(PathBuf::from("poster_frame.jpg"))];
+        video.add_item(preview).unwrap();
+        let video_item_id = video.leading_item_id;
+
+        let (paired, merged) = still.pair_live_photo(&mut video).unwrap();
+
+        assert_eq!(still.medium_type, MediumType::LivePhoto);
+        assert_eq!(paired.items.len(), 1);
+        assert_eq!(still.items.len(), 2);
+        assert_eq!(still.find_item(video_item_id).unwrap().medium_id, still.id);
+        assert_eq!(still.total_size(), Byte::from_u64(2048));
+        assert_eq!(merged.into_medium_id, still.id);
+        assert!(video.items.is_empty());
+        assert!(still.pair_live_photo(&mut video).is_err());
+    }
+
+    #[test]
+    fn test_add_item_accepts_edit_and_sidecar() {
+        let mut medium = create_test_medium();
+        let owner_id = medium.owner_id;
+
+        let edit = medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Edit,
+                mime::IMAGE_JPEG,
+                "test_edit.jpg",
+            ))
+            .unwrap();
+        let sidecar = medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Sidecar,
+                "application/rdf+xml".parse().unwrap(),
+                "test.xmp",
+            ))
+            .unwrap();
+
+        assert_eq!(medium.items.len(), 3);
+        assert_eq!(edit.metadata.expected_version, 1);
+        assert_eq!(sidecar.metadata.expected_version, 2);
+        assert_eq!(medium.version, 3);
+    }
+
+    #[test]
+    fn test_sidecar_is_paired_by_stem() {
+        let mut medium = create_test_medium();
+        let owner_id = medium.owner_id;
+        let xmp: Mime = "application/rdf+xml".parse().unwrap();
+
+        medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Sidecar,
+                xmp.clone(),
+                "other.xmp",
+            ))
+            .unwrap();
+        assert_eq!(
+            medium.find_sidecar().unwrap().filename.as_str(),
+            "other.xmp"
+        );
+
+        medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Sidecar,
+                xmp.clone(),
+                "test.jpg.xmp",
+            ))
+            .unwrap();
+        medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Sidecar,
+                xmp,
+                "later.xmp",
+            ))
+            .unwrap();
+        assert_eq!(
+            medium.find_sidecar().unwrap().filename.as_str(),
+            "test.jpg.xmp"
+        );
+    }
+
+    #[test]
+    fn test_generated_previews_are_found_and_not_counted() {
+        let mut medium = create_test_medium();
+        let owner_id = medium.owner_id;
+        let size = PreviewSize::Thumbnail;
+
+        let mut request = item_request(
+            owner_id,
+            MediumItemType::Preview,
+            mime::IMAGE_JPEG,
+            size.filename().as_str(),
+        );
+        request.locations = vec![FileLocation::cache(PathBuf::from("preview_200.jpg"))];
+        medium.add_item(request).unwrap();
+
+        assert!(medium.find_preview(size).is_some());
+        assert!(medium.find_preview(PreviewSize::Large).is_none());
+        assert_eq!(medium.total_size(), Byte::from_u64(1024));
+    }
+
+    #[test]
+    fn test_add_item_rejects_mime_not_matching_item_type() {
+        let mut medium = create_test_medium();
+        let owner_id = medium.owner_id;
+
+        assert!(medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Edit,
+                mime::TEXT_PLAIN,
+                "notes.txt",
+            ))
+            .is_err());
+        assert!(medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Sidecar,
+                mime::IMAGE_JPEG,
+                "test.jpg.xmp",
+            ))
+            .is_err());
+        assert!(medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Sidecar,
+                mime::APPLICATION_OCTET_STREAM,
+                "test.xml",
+            ))
+            .is_err());
+        assert_eq!(medium.items.len(), 1);
+    }
+
+    #[test]
+    fn test_add_item_rejects_duplicate_filename_and_trashed_medium() {
+        let mut medium = create_test_medium();
+        let owner_id = medium.owner_id;
+
+        assert!(medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Edit,
+                mime::IMAGE_JPEG,
+                "test.jpg",
+            ))
+            .is_err());
+
+        medium.delete().unwrap();
+        assert!(medium
+            .add_item(item_request(
+                owner_id,
+                MediumItemType::Edit,
+                mime::IMAGE_JPEG,
+                "test_edit.jpg",
+            ))
+            .is_err());
+    }
+
+    #[test]
+    fn test_remove_item_location_keeps_last_location() {
+        let mut medium = create_test_medium();
+        let item_id = medium.leading_item_id;
+
+        assert!(medium
+            .remove_item_location(item_id, StorageTier::Temporary)
+            .is_err());
+        assert!(medium
+            .remove_item_location(item_id, StorageTier::Permanent)
+            .is_err());
+        assert!(medium
+            .remove_item_location(Uuid::new_v4(), StorageTier::Temporary)
+            .is_err());
+    }
+
+    #[test]
+    fn test_replaying_events_rebuilds_medium() {
+        let mut source = create_test_medium();
+        let created = MediumCreatedEvent::new(
+            source.id,
+            source.owner_id,
+            source.medium_type,
+            source.items[0].clone(),
+            None,
+            None,
+            None,
+        );
+        let leading_item_id = source.leading_item_id;
+
+        let item_created = source
+            .add_item(item_request(
+                source.owner_id,
+                MediumItemType::Sidecar,
+                "application/rdf+xml".parse().unwrap(),
+                "test.xmp",
+            ))
+            .unwrap();
+        let sidecar_id = item_created.item_id;
+        let location_added = source
+            .add_item_location(
+                leading_item_id,
+                FileLocation::permanent(PathBuf::from("2024/test.jpg")),
+            )
+            .unwrap();
+        let location_removed = source
+            .remove_item_location(leading_item_id, StorageTier::Temporary)
+            .unwrap();
+        let updated = source.update_basic_metadata(None, None, Some("Canon".into()), None, None);
+        let deleted = source.delete().unwrap();
+
+        let mut medium = Medium::default();
+        medium.apply(&created);
+        medium.apply(&item_created);
+        medium.apply(&location_added);
+        medium.apply(&location_removed);
+        medium.apply(&updated);
+        medium.apply(&deleted);
+
+        assert_eq!(medium.version, source.version);
+        assert_eq!(medium.camera_make.as_deref(), Some("Canon"));
+        assert!(medium.is_deleted());
+        assert_eq!(medium.items.len(), 2);
+
+        let leading = medium.find_item(leading_item_id).unwrap();
+        assert_eq!(leading.locations.len(), 1);
+        assert_eq!(leading.locations[0].storage_tier, StorageTier::Permanent);
+
+        let sidecar = medium.find_item(sidecar_id).unwrap();
+        assert_eq!(sidecar.medium_item_type, MediumItemType::Sidecar);
+        assert_eq!(sidecar.filename.as_str(), "test.xmp");
+        assert_eq!(sidecar.locations[0].storage_tier, StorageTier::Temporary);
+    }
 }
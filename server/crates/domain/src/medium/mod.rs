@@ -1,14 +1,24 @@
 pub mod camera;
+pub mod curation;
+pub mod duplicate;
 pub mod events;
 pub mod file;
 pub mod filter;
 pub mod medium;
 pub mod path_service;
+pub mod preview;
+pub mod search;
 pub mod storage;
+pub mod tag;
 
 pub use camera::*;
+pub use curation::*;
+pub use duplicate::*;
 pub use file::*;
 pub use filter::*;
 pub use medium::*;
 pub use path_service::*;
+pub use preview::*;
+pub use search::*;
 pub use storage::*;
+pub use tag::*;
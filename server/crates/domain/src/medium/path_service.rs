@@ -1,14 +1,14 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use chrono::{Datelike, Utc};
+use chrono::Datelike;
 
-use super::{Medium, MediumItem};
+use super::{Medium, MediumId, MediumItem, PreviewSize};
 
 /// Domain service that determines where media files should be stored
 /// based on configurable patterns using `<token>` syntax from StorageConfig.
 ///
 /// Supported tokens:
-/// - `<year>`: Year from taken_at (falls back to created_at)
+/// - `<year>`: Year from taken_at in the local time it was taken at (falls back to created_at)
 /// - `<month>`: Two-digit month
 /// - `<day>`: Two-digit day
 /// - `<camera_make>`: Camera manufacturer
@@ -32,8 +32,8 @@ impl StoragePathService {
     pub fn generate_permanent_path(&self, medium: &Medium, item: &MediumItem) -> PathBuf {
         let date = medium
             .taken_at
-            .map(|d| d.with_timezone(&Utc))
-            .unwrap_or(medium.created_at);
+            .map(|d| d.date_naive())
+            .unwrap_or(medium.created_at.date_naive());
 
         let path = self
             .pattern
@@ -59,6 +59,33 @@ impl StoragePathService {
         Self::sanitize_path(PathBuf::from(path))
     }
 
+    /// Generates the path for an item whose permanent path is already taken
+    /// by another file, by appending the item id to the file name
+    pub fn generate_deduplicated_path(&self, path: &Path, item: &MediumItem) -> PathBuf {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let filename = match path.extension() {
+            Some(extension) => format!("{stem}_{}.{}", item.id, extension.to_string_lossy()),
+            None => format!("{stem}_{}", item.id),
+        };
+
+        path.with_file_name(filename)
+    }
+
+    /// Generates the relative cache path of a rendered preview
+    pub fn generate_preview_path(&self, medium_id: MediumId, size: PreviewSize) -> PathBuf {
+        PathBuf::from("previews")
+            .join(medium_id.to_string())
+            .join(size.filename().as_str())
+    }
+
+    /// Generates the relative cache path of the JPEG the previews of a RAW
+    /// or video medium are rendered from
+    pub fn generate_source_preview_path(&self, medium_id: MediumId, filename: &str) -> PathBuf {
+        PathBuf::from("previews")
+            .join(medium_id.to_string())
+            .join(filename)
+    }
+
     fn sanitize_path(path: PathBuf) -> PathBuf {
         path.components()
             .filter(|c| {
@@ -74,7 +101,7 @@ impl StoragePathService {
 #[cfg(test)]
 mod tests {
     use byte_unit::Byte;
-    use chrono::DateTime;
+    use chrono::{DateTime, Utc};
     use uuid::Uuid;
 
     use super::*;
@@ -102,6 +129,11 @@ mod tests {
                 .unwrap()
                 .with_timezone(&Utc),
             updated_at: Utc::now(),
+            deleted_at: None,
+            perceptual_hash: None,
+            tags: vec![],
+            curation: Default::default(),
+            curated: false,
             items: vec![],
             version: 0,
         }
@@ -118,6 +150,7 @@ mod tests {
             priority: Priority::normal(),
             dimensions: None,
             locations: vec![FileLocation::temporary("test.heic".into())],
+            checksum: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -139,6 +172,20 @@ mod tests {
         assert_eq!(path_str, "2024/0620/Apple_iPhone 15 Pro/IMG_4598.HEIC");
     }
 
+    #[test]
+    fn test_generate_permanent_path_uses_local_date() {
+        let service = StoragePathService::new("<year>/<month><day>/<filename>".to_string());
+
+        // Still the 20th in UTC
+        let taken_at = DateTime::parse_from_rfc3339("2024-06-21T01:30:00+09:00").ok();
+        let medium = make_medium(taken_at, None, None);
+        let item = make_item("IMG_4598.HEIC");
+
+        let path = service.generate_permanent_path(&medium, &item);
+
+        assert_eq!(path.to_string_lossy(), "2024/0621/IMG_4598");
+    }
+
     #[test]
     fn test_generate_permanent_path_with_defaults() {
         let service = StoragePathService::new(
@@ -155,6 +202,21 @@ mod tests {
         assert_eq!(path_str, "2024/0315/unknown_unknown/photo.jpg");
     }
 
+    #[test]
+    fn test_generate_deduplicated_path() {
+        let service = StoragePathService::new("<year>/<filename>.<extension>".to_string());
+        let item = make_item("IMG_4598.HEIC");
+
+        assert_eq!(
+            service.generate_deduplicated_path(Path::new("2024/IMG_4598.HEIC"), &item),
+            PathBuf::from(format!("2024/IMG_4598_{}.HEIC", item.id))
+        );
+        assert_eq!(
+            service.generate_deduplicated_path(Path::new("2024/IMG_4598"), &item),
+            PathBuf::from(format!("2024/IMG_4598_{}", item.id))
+        );
+    }
+
     #[test]
     fn test_sanitize_removes_root_and_parent() {
         let service = StoragePathService::new("/<year>/../<filename>.<extension>".to_string());
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+use super::file::Filename;
+
+/// Filename of the full size JPEG extracted from a RAW file, see UC-M6
+pub const EMBEDDED_PREVIEW_FILENAME: &str = "preview_embedded.jpg";
+
+/// Filename of the frame grabbed from a video
+pub const POSTER_FRAME_FILENAME: &str = "poster_frame.jpg";
+
+/// Standard sizes rendered as `Preview` items on the cache tier
+#[derive(Display, EnumIter, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[strum(serialize_all = "snake_case")]
+pub enum PreviewSize {
+    /// Grid thumbnail, see UC-M4
+    Thumbnail,
+    /// Detail view, see UC-M5
+    Medium,
+    /// Full screen on high density displays
+    Large,
+}
+
+impl PreviewSize {
+    /// Maximum length of the longer side in pixels
+    pub fn longest_side(&self) -> u32 {
+        match self {
+            PreviewSize::Thumbnail => 200,
+            PreviewSize::Medium => 1024,
+            PreviewSize::Large => 2048,
+        }
+    }
+
+    /// JPEG quality the size is encoded with
+    pub fn quality(&self) -> u8 {
+        match self {
+            PreviewSize::Thumbnail => 85,
+            PreviewSize::Medium | PreviewSize::Large => 90,
+        }
+    }
+
+    /// Filename of the generated item, unique per size within a medium
+    pub fn filename(&self) -> Filename {
+        Filename::new(format!("preview_{}.jpg", self.longest_side()))
+            .expect("Preview filenames are valid")
+    }
+
+    /// All sizes, smallest first
+    pub fn all() -> Vec<PreviewSize> {
+        PreviewSize::iter().collect()
+    }
+
+    /// Smallest size that covers the requested box. Without any bound the
+    /// thumbnail is used, requests larger than every size get the largest.
+    pub fn best_fit(width: Option<u32>, height: Option<u32>) -> PreviewSize {
+        let requested = width.into_iter().chain(height).max().unwrap_or(0);
+        PreviewSize::iter()
+            .find(|size| size.longest_side() >= requested)
+            .unwrap_or(PreviewSize::Large)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_best_fit_picks_smallest_covering_size() {
+        assert_eq!(PreviewSize::best_fit(None, None), PreviewSize::Thumbnail);
+        assert_eq!(
+            PreviewSize::best_fit(Some(200), None),
+            PreviewSize::Thumbnail
+        );
+        assert_eq!(PreviewSize::best_fit(Some(201), None), PreviewSize::Medium);
+        assert_eq!(
+            PreviewSize::best_fit(Some(300), Some(1500)),
+            PreviewSize::Large
+        );
+        assert_eq!(PreviewSize::best_fit(Some(8000), None), PreviewSize::Large);
+    }
+
+    #[test]
+    fn test_filenames_are_distinct() {
+        let names: Vec<_> = PreviewSize::all().iter().map(|s| s.filename()).collect();
+        assert_eq!(names.len(), 3);
+        assert!(names.windows(2).all(|w| w[0] != w[1]));
+    }
+}
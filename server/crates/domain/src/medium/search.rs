@@ -0,0 +1,360 @@
+use chrono::{DateTime, Days, Months, NaiveDate, NaiveTime, Utc};
+use snafu::{ensure, OptionExt};
+
+use super::{Curation, MediumType, Tag};
+use crate::error::{DomainResult, ValidationSnafu};
+
+/// How a number of a [`SearchTerm`] is compared, e.g. the `>` of `iso:>3200`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+/// A number and how the values of media are compared to it
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NumberMatch<T> {
+    pub comparison: Comparison,
+    pub value: T,
+}
+
+/// A single condition of a [`SearchQuery`], a condition on a missing value
+/// does not match
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchTerm {
+    /// A word or quoted phrase found in the filenames, title, description,
+    /// tags or place names of a medium
+    Text(String),
+    /// Part of the camera make or model, ignoring case
+    Camera(String),
+    /// Part of the lens model, ignoring case
+    Lens(String),
+    /// Tagged with the tag or a tag below it
+    Tag(Tag),
+    /// Taken in the country, region or city
+    Place(String),
+    Iso(NumberMatch<u32>),
+    Rating(NumberMatch<u8>),
+    MediumType(MediumType),
+    /// Taken at or after `start` and before `end`
+    Taken {
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    },
+}
+
+/// A search over the media of a user, parsed from text like
+/// `camera:fuji lens:23mm iso:>3200 tag:beach place:lisbon "sunset"`.
+/// Every term has to match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchQuery {
+    pub terms: Vec<SearchTerm>,
+}
+
+/// A `field:value` pair, or a word or phrase without a field
+struct Token {
+    field: Option<String>,
+    value: String,
+}
+
+impl SearchQuery {
+    const MAX_LENGTH: usize = 500;
+    const MAX_TERMS: usize = 20;
+    const FIELDS: &'static str = "camera, lens, tag, place, iso, rating, type or taken";
+
+    /// Parses a query made of words, `"quoted phrases"` and `field:value`
+    /// pairs separated by whitespace, a value with spaces can be quoted as in
+    /// `place:"New York"`
+    pub fn parse(input: &str) -> DomainResult<Self> {
+        ensure!(
+            input.chars().count() <= Self::MAX_LENGTH,
+            ValidationSnafu {
+                message: format!("Search query cannot exceed {} characters", Self::MAX_LENGTH),
+            }
+        );
+
+        let terms = Self::tokenize(input)?
+            .into_iter()
+            .map(Self::term)
+            .collect::<DomainResult<Vec<_>>>()?;
+
+        ensure!(
+            !terms.is_empty(),
+            ValidationSnafu {
+                message: "Search query must not be empty"
+            }
+        );
+        ensure!(
+            terms.len() <= Self::MAX_TERMS,
+            ValidationSnafu {
+                message: format!(
+                    "Search query cannot have more than {} terms, got {}",
+                    Self::MAX_TERMS,
+                    terms.len()
+                ),
+            }
+        );
+
+        Ok(Self { terms })
+    }
+
+    /// The words and phrases of the query
+    pub fn texts(&self) -> impl Iterator<Item = &str> {
+        self.terms.iter().filter_map(|term| match term {
+            SearchTerm::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    fn tokenize(input: &str) -> DomainResult<Vec<Token>> {
+        let mut tokens = Vec::new();
+        let mut chars = input.chars().peekable();
+
+        loop {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if chars.peek().is_none() {
+                break;
+            }
+
+            let mut field = None;
+            let mut value = String::new();
+            let mut quoted = false;
+            let mut in_quotes = false;
+            while let Some(c) = chars.next_if(|c| in_quotes || !c.is_whitespace()) {
+                match c {
+                    '"' => {
+                        quoted = true;
+                        in_quotes = !in_quotes;
+                    }
+                    // Only a plain word in front of the first colon names a
+                    // field, so `"12:30"` and `10:30` stay text
+                    ':' if field.is_none()
+                        && !quoted
+                        && !value.is_empty()
+                        && value.chars().all(|c| c.is_ascii_alphabetic()) =>
+                    {
+                        field = Some(value.to_lowercase());
+                        value.clear();
+                    }
+                    c => value.push(c),
+                }
+            }
+            ensure!(
+                !in_quotes,
+                ValidationSnafu {
+                    message: "Search query has an unterminated quote"
+                }
+            );
+
+            let value = value.trim().to_string();
+            if field.is_some() || !value.is_empty() {
+                tokens.push(Token { field, value });
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    fn term(token: Token) -> DomainResult<SearchTerm> {
+        let Some(field) = token.field else {
+            return Ok(SearchTerm::Text(token.value));
+        };
+        let value = token.value;
+        ensure!(
+            !value.is_empty(),
+            ValidationSnafu {
+                message: format!("Search field '{field}' needs a value"),
+            }
+        );
+
+        match field.as_str() {
+            "camera" => Ok(SearchTerm::Camera(value)),
+            "lens" => Ok(SearchTerm::Lens(value)),
+            "tag" => Ok(SearchTerm::Tag(Tag::new(&value)?)),
+            "place" => Ok(SearchTerm::Place(value)),
+            "iso" => Ok(SearchTerm::Iso(Self::number(&field, &value)?)),
+            "rating" => {
+                let rating = Self::number(&field, &value)?;
+                ensure!(
+                    rating.value <= Curation::MAX_RATING,
+                    ValidationSnafu {
+                        message: format!(
+                            "rating cannot exceed {}, got {}",
+                            Curation::MAX_RATING,
+                            rating.value
+                        ),
+                    }
+                );
+                Ok(SearchTerm::Rating(rating))
+            }
+            "type" => Self::medium_type(&value).map(SearchTerm::MediumType),
+            "taken" => Self::taken(&value),
+            _ => ValidationSnafu {
+                message: format!(
+                    "Unknown search field '{field}', expected one of {}",
+                    Self::FIELDS
+                ),
+            }
+            .fail(),
+        }
+    }
+
+    /// Parses numbers like `3200`, `>3200` or `<=800`
+    fn number<T: std::str::FromStr>(field: &str, value: &str) -> DomainResult<NumberMatch<T>> {
+        let (comparison, number) = [
+            (">=", Comparison::GreaterOrEqual),
+            ("<=", Comparison::LessOrEqual),
+            (">", Comparison::Greater),
+            ("<", Comparison::Less),
+            ("=", Comparison::Equal),
+        ]
+        .into_iter()
+        .find_map(|(prefix, comparison)| value.strip_prefix(prefix).map(|n| (comparison, n)))
+        .unwrap_or((Comparison::Equal, value));
+
+        let value = number.parse().ok().context(ValidationSnafu {
+            message: format!(
+                "Search field '{field}' needs a number like 800 or >=800, got '{value}'"
+            ),
+        })?;
+        Ok(NumberMatch { comparison, value })
+    }
+
+    fn medium_type(value: &str) -> DomainResult<MediumType> {
+        match value.to_lowercase().as_str() {
+            "photo" => Ok(MediumType::Photo),
+            "video" => Ok(MediumType::Video),
+            "live" | "live_photo" | "livephoto" => Ok(MediumType::LivePhoto),
+            "vector" => Ok(MediumType::Vector),
+            "sequence" => Ok(MediumType::Sequence),
+            "gif" => Ok(MediumType::Gif),
+            "raw" => Ok(MediumType::Raw),
+            "other" => Ok(MediumType::Other),
+            _ => ValidationSnafu {
+                message: format!("Unknown medium type '{value}'"),
+            }
+            .fail(),
+        }
+    }
+
+    /// Parses a year, month or day like `2024`, `2024-06` or `2024-06-15`
+    /// into the range of that period
+    fn taken(value: &str) -> DomainResult<SearchTerm> {
+        let parts = value
+            .split('-')
+            .map(|part| part.parse::<u32>().ok())
+            .collect::<Option<Vec<_>>>()
+            .filter(|_| value.find('-').unwrap_or(value.len()) == 4);
+
+        let range = match parts.as_deref() {
+            Some(&[year]) => NaiveDate::from_ymd_opt(year as i32, 1, 1)
+                .and_then(|start| Some((start, start.checked_add_months(Months::new(12))?))),
+            Some(&[year, month]) => NaiveDate::from_ymd_opt(year as i32, month, 1)
+                .and_then(|start| Some((start, start.checked_add_months(Months::new(1))?))),
+            Some(&[year, month, day]) => NaiveDate::from_ymd_opt(year as i32, month, day)
+                .and_then(|start| Some((start, start.checked_add_days(Days::new(1))?))),
+            _ => None,
+        };
+        let (start, end) = range.context(ValidationSnafu {
+            message: format!(
+                "Search field 'taken' needs a year, month or day like 2024, 2024-06 or 2024-06-15, got '{value}'"
+            ),
+        })?;
+
+        Ok(SearchTerm::Taken {
+            start: start.and_time(NaiveTime::MIN).and_utc(),
+            end: end.and_time(NaiveTime::MIN).and_utc(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_fields_and_text() {
+        let query = SearchQuery::parse(
+            r#"camera:fuji lens:23mm iso:>3200 tag:Beach place:"New York" "golden hour" sunset"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::Camera("fuji".to_string()),
+                SearchTerm::Lens("23mm".to_string()),
+                SearchTerm::Iso(NumberMatch {
+                    comparison: Comparison::Greater,
+                    value: 3200
+                }),
+                SearchTerm::Tag(Tag::new("Beach").unwrap()),
+                SearchTerm::Place("New York".to_string()),
+                SearchTerm::Text("golden hour".to_string()),
+                SearchTerm::Text("sunset".to_string()),
+            ]
+        );
+        assert_eq!(
+            query.texts().collect::<Vec<_>>(),
+            vec!["golden hour", "sunset"]
+        );
+    }
+
+    #[test]
+    fn test_parse_numbers_types_and_dates() {
+        let query = SearchQuery::parse("RATING:<=2 type:Video taken:2024-02 10:30").unwrap();
+
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::Rating(NumberMatch {
+                    comparison: Comparison::LessOrEqual,
+                    value: 2
+                }),
+                SearchTerm::MediumType(MediumType::Video),
+                SearchTerm::Taken {
+                    start: Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap(),
+                    end: Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+                },
+                SearchTerm::Text("10:30".to_string()),
+            ]
+        );
+        assert_eq!(
+            SearchQuery::parse("taken:2024-12-31").unwrap().terms,
+            vec![SearchTerm::Taken {
+                start: Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap(),
+                end: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_collapses_whitespace_between_terms() {
+        assert_eq!(
+            SearchQuery::parse("  camera:fuji \t  sunset\n").unwrap(),
+            SearchQuery::parse("camera:fuji sunset").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_queries() {
+        assert!(SearchQuery::parse("").is_err());
+        assert!(SearchQuery::parse(" \t ").is_err());
+        assert!(SearchQuery::parse(r#""""#).is_err());
+        assert!(SearchQuery::parse(r#""sunset"#).is_err());
+        assert!(SearchQuery::parse("camera:").is_err());
+        assert!(SearchQuery::parse("colour:red").is_err());
+        assert!(SearchQuery::parse("iso:high").is_err());
+        assert!(SearchQuery::parse("rating:6").is_err());
+        assert!(SearchQuery::parse("type:painting").is_err());
+        assert!(SearchQuery::parse("taken:24").is_err());
+        assert!(SearchQuery::parse("taken:2024-13").is_err());
+        assert!(SearchQuery::parse("tag:Travel//").is_err());
+        assert!(SearchQuery::parse(&"sunset ".repeat(21)).is_err());
+    }
+}
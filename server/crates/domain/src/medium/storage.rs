@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use chrono::{DateTime, Utc};
 use derive_new::new;
 use mime::Mime;
 use serde::{Deserialize, Serialize};
@@ -53,3 +54,10 @@ pub struct FileMetadata {
     pub mime_type: Mime,
     pub checksum: Sha256,
 }
+
+/// Size and modification time of a stored file, read without its content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileStat {
+    pub size_bytes: u64,
+    pub modified_at: DateTime<Utc>,
+}
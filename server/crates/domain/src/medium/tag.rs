@@ -0,0 +1,174 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::error::{DomainResult, ValidationSnafu};
+
+/// A tag of a medium. Tags form a hierarchy through their `/` separated
+/// segments, `Travel/Portugal/Lisbon` is below `Travel/Portugal` and `Travel`.
+/// Tags are compared case-insensitively when matching, but keep the spelling
+/// they were given.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Tag(String);
+
+impl Tag {
+    pub const SEPARATOR: char = '/';
+    const MAX_LENGTH: usize = 100;
+
+    /// Surrounding whitespace of every segment is dropped, `Travel / Lisbon`
+    /// becomes `Travel/Lisbon`
+    pub fn new(value: &str) -> DomainResult<Self> {
+        let segments: Vec<&str> = value.split(Self::SEPARATOR).map(str::trim).collect();
+
+        ensure!(
+            segments.iter().all(|s| !s.is_empty()),
+            ValidationSnafu {
+                message: format!("Tag '{value}' must not be empty or contain empty segments"),
+            }
+        );
+        ensure!(
+            !value.chars().any(char::is_control),
+            ValidationSnafu {
+                message: "Tag cannot contain control characters",
+            }
+        );
+
+        let tag = segments.join("/");
+        ensure!(
+            tag.chars().count() <= Self::MAX_LENGTH,
+            ValidationSnafu {
+                message: format!("Tag cannot exceed {} characters", Self::MAX_LENGTH),
+            }
+        );
+        Ok(Self(tag))
+    }
+
+    /// Parse a list of tags, dropping duplicates that only differ in case
+    pub fn parse_all<S: AsRef<str>>(values: &[S]) -> DomainResult<Vec<Self>> {
+        let mut tags: Vec<Self> = Vec::with_capacity(values.len());
+        for value in values {
+            let tag = Self::new(value.as_ref())?;
+            if !tags.iter().any(|t| t.matches(&tag)) {
+                tags.push(tag);
+            }
+        }
+        Ok(tags)
+    }
+
+    /// Restore a tag that was validated before, e.g. from the read model
+    pub fn from_trusted(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The last segment, `Lisbon` for `Travel/Portugal/Lisbon`
+    pub fn name(&self) -> &str {
+        self.0
+            .rsplit_once(Self::SEPARATOR)
+            .map_or(self.0.as_str(), |(_, name)| name)
+    }
+
+    /// The tags above this one, closest first
+    pub fn ancestors(&self) -> impl Iterator<Item = Tag> + '_ {
+        self.0
+            .rmatch_indices(Self::SEPARATOR)
+            .map(|(index, _)| Self(self.0[..index].to_string()))
+    }
+
+    /// Same tag, ignoring case
+    pub fn matches(&self, other: &Tag) -> bool {
+        self.0.to_lowercase() == other.0.to_lowercase()
+    }
+
+    /// Whether this is `other` or a tag below it, ignoring case
+    pub fn is_within(&self, other: &Tag) -> bool {
+        let tag = self.0.to_lowercase();
+        let other = other.0.to_lowercase();
+        tag == other
+            || tag
+                .strip_prefix(&other)
+                .is_some_and(|rest| rest.starts_with(Self::SEPARATOR))
+    }
+
+    /// Move this tag from below `from` to below `to`, `None` if it is not
+    /// within `from`. Renaming `Travel` to `Trips` turns
+    /// `Travel/Portugal` into `Trips/Portugal`.
+    pub fn rebase(&self, from: &Tag, to: &Tag) -> DomainResult<Option<Tag>> {
+        if !self.is_within(from) {
+            return Ok(None);
+        }
+        // The matched prefix only differs from `from` in case
+        let rest: String = self.0.chars().skip(from.0.chars().count()).collect();
+        Self::new(&format!("{}{rest}", to.0)).map(Some)
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Tag {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(value: &str) -> Tag {
+        Tag::new(value).unwrap()
+    }
+
+    #[test]
+    fn test_tag_segments_are_trimmed() {
+        assert_eq!(tag(" Travel / Portugal ").as_str(), "Travel/Portugal");
+        assert!(Tag::new("").is_err());
+        assert!(Tag::new("Travel//Lisbon").is_err());
+        assert!(Tag::new("Travel/").is_err());
+        assert!(Tag::new(&"x".repeat(101)).is_err());
+    }
+
+    #[test]
+    fn test_tag_hierarchy() {
+        let lisbon = tag("Travel/Portugal/Lisbon");
+
+        assert_eq!(lisbon.name(), "Lisbon");
+        assert_eq!(
+            lisbon.ancestors().collect::<Vec<_>>(),
+            vec![tag("Travel/Portugal"), tag("Travel")]
+        );
+        assert!(lisbon.is_within(&tag("travel/portugal")));
+        assert!(lisbon.is_within(&lisbon));
+        assert!(!lisbon.is_within(&tag("Travel/Port")));
+        assert!(!tag("Travel").is_within(&lisbon));
+    }
+
+    #[test]
+    fn test_rebase_moves_descendants() {
+        let from = tag("travel");
+        let to = tag("Trips");
+
+        assert_eq!(
+            tag("Travel/Portugal").rebase(&from, &to).unwrap(),
+            Some(tag("Trips/Portugal"))
+        );
+        assert_eq!(tag("Travel").rebase(&from, &to).unwrap(), Some(to.clone()));
+        assert_eq!(tag("Traveling").rebase(&from, &to).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_all_drops_duplicates() {
+        let tags = Tag::parse_all(&["Beach", "beach", "Sunset"]).unwrap();
+
+        assert_eq!(tags, vec![tag("Beach"), tag("Sunset")]);
+    }
+}
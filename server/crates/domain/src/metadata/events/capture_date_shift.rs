@@ -0,0 +1,70 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    user::UserId,
+};
+
+/// Event emitted when a batch shift of capture dates started
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureDateShiftStartedEvent {
+    pub shift_id: Uuid,
+    pub owner_id: UserId,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for CaptureDateShiftStartedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
+
+/// Event emitted while a batch shift of capture dates works through the media
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureDateShiftProgressedEvent {
+    pub shift_id: Uuid,
+    pub owner_id: UserId,
+    pub processed: usize,
+    pub total: usize,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for CaptureDateShiftProgressedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
+
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureDateShiftCompletedEvent {
+    pub shift_id: Uuid,
+    pub owner_id: UserId,
+    pub media_shifted: usize,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for CaptureDateShiftCompletedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
+
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct CaptureDateShiftFailedEvent {
+    pub shift_id: Uuid,
+    pub owner_id: UserId,
+    pub error: String,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for CaptureDateShiftFailedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
@@ -0,0 +1,27 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    metadata::metadata::Place,
+    user::UserId,
+};
+
+/// Event emitted when the GPS coordinates of a medium were resolved into
+/// a place name
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct LocationResolvedEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub place: Place,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for LocationResolvedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, FixedOffset};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::MediumId,
+    metadata::metadata::{LocationInfo, Metadata},
+    user::UserId,
+};
+
+/// Effective value of a field before and after its override changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange<T> {
+    pub original: Option<T>,
+    pub new: Option<T>,
+}
+
+/// The fields whose override changed, untouched fields are `None`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetadataChanges {
+    pub capture_date: Option<FieldChange<DateTime<FixedOffset>>>,
+    pub camera_make: Option<FieldChange<String>>,
+    pub camera_model: Option<FieldChange<String>>,
+    pub location: Option<FieldChange<LocationInfo>>,
+}
+
+impl MetadataChanges {
+    pub fn is_empty(&self) -> bool {
+        self.capture_date.is_none()
+            && self.camera_make.is_none()
+            && self.camera_model.is_none()
+            && self.location.is_none()
+    }
+}
+
+/// Event emitted when the owner overrode extracted metadata of a medium
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MetadataOverriddenEvent {
+    pub medium_id: MediumId,
+    pub owner_id: UserId,
+    pub changes: MetadataChanges,
+    /// The metadata with the overrides applied
+    pub metadata: Metadata,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for MetadataOverriddenEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
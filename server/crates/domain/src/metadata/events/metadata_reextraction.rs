@@ -0,0 +1,72 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    user::UserId,
+};
+
+/// Event emitted when the metadata of media read by an outdated extractor
+/// started to be extracted again
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataReextractionStartedEvent {
+    pub reextraction_id: Uuid,
+    /// The administrator who started the re-extraction
+    pub owner_id: UserId,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for MetadataReextractionStartedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
+
+/// Event emitted while a re-extraction works through the outdated media
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataReextractionProgressedEvent {
+    pub reextraction_id: Uuid,
+    pub owner_id: UserId,
+    pub processed: usize,
+    pub total: usize,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for MetadataReextractionProgressedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
+
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataReextractionCompletedEvent {
+    pub reextraction_id: Uuid,
+    pub owner_id: UserId,
+    pub media_reextracted: usize,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for MetadataReextractionCompletedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
+
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataReextractionFailedEvent {
+    pub reextraction_id: Uuid,
+    pub owner_id: UserId,
+    pub error: String,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for MetadataReextractionFailedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
@@ -0,0 +1,36 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::{MediumId, MediumItemId},
+    metadata::{
+        events::FieldChange,
+        metadata::{LocationInfo, Metadata},
+    },
+    user::UserId,
+};
+
+/// Event emitted when the values of an XMP sidecar were merged into the
+/// metadata of a medium
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct MetadataSidecarAppliedEvent {
+    pub medium_id: MediumId,
+    pub sidecar_item_id: MediumItemId,
+    pub owner_id: UserId,
+    /// Keywords of the previous sidecar the new one no longer has
+    pub removed_keywords: Vec<String>,
+    /// Effective location before and after, if it changed
+    pub location: Option<FieldChange<LocationInfo>>,
+    /// The metadata with the sidecar applied
+    pub metadata: Metadata,
+    #[new(default)]
+    pub event_metadata: EventMetadata,
+}
+
+impl DomainEvent for MetadataSidecarAppliedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.event_metadata
+    }
+}
@@ -1,7 +1,23 @@
+mod capture_date_shift;
+mod location_resolved;
 mod metadata_extracted;
 mod metadata_extraction_failed;
 mod metadata_extraction_started;
+mod metadata_overridden;
+mod metadata_reextraction;
+mod metadata_sidecar_applied;
 
+pub use capture_date_shift::{
+    CaptureDateShiftCompletedEvent, CaptureDateShiftFailedEvent, CaptureDateShiftProgressedEvent,
+    CaptureDateShiftStartedEvent,
+};
+pub use location_resolved::LocationResolvedEvent;
 pub use metadata_extracted::MetadataExtractedEvent;
 pub use metadata_extraction_failed::MetadataExtractionFailedEvent;
 pub use metadata_extraction_started::MetadataExtractionStartedEvent;
+pub use metadata_overridden::{FieldChange, MetadataChanges, MetadataOverriddenEvent};
+pub use metadata_reextraction::{
+    MetadataReextractionCompletedEvent, MetadataReextractionFailedEvent,
+    MetadataReextractionProgressedEvent, MetadataReextractionStartedEvent,
+};
+pub use metadata_sidecar_applied::MetadataSidecarAppliedEvent;
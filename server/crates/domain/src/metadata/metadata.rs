@@ -1,16 +1,24 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, FixedOffset, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
 use event_sourcing::aggregate::traits::{Aggregate, ApplyEvent};
 use mime::Mime;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use snafu::ensure;
+
 use crate::{
     aggregate::{AggregateRoot, AggregateVersion},
-    medium::{MediumId, MediumItemId},
-    metadata::events::{
-        MetadataExtractedEvent, MetadataExtractionFailedEvent, MetadataExtractionStartedEvent,
+    error::{DomainResult, ValidationSnafu},
+    medium::{GpsCoordinates, MediumId, MediumItemId},
+    metadata::{
+        events::{
+            FieldChange, LocationResolvedEvent, MetadataChanges, MetadataExtractedEvent,
+            MetadataExtractionFailedEvent, MetadataExtractionStartedEvent, MetadataOverriddenEvent,
+            MetadataSidecarAppliedEvent,
+        },
+        SidecarMetadata,
     },
     user::UserId,
 };
@@ -23,11 +31,38 @@ pub struct Metadata {
     pub id: MetadataId,
     pub medium_id: MediumId,
     pub extracted_at: DateTime<Utc>,
+    /// Version of the extractor the values were read with. Media read by an
+    /// older version are extracted again to pick up its improvements.
+    #[serde(default)]
+    pub extractor_version: u32,
     pub file_info: FileInfo,
     pub camera_info: Option<CameraInfo>,
     pub location: Option<LocationInfo>,
+    /// Place name of `location`, resolved after the extraction
+    #[serde(default)]
+    pub place: Option<Place>,
     pub technical: TechnicalInfo,
+    #[serde(default)]
+    pub descriptive: DescriptiveInfo,
+    #[serde(default)]
+    pub video: Option<VideoInfo>,
+    /// Apple `ContentIdentifier` shared by the still and the video of a Live Photo
+    #[serde(default)]
+    pub content_identifier: Option<String>,
+    /// Serial number of the camera body, tells apart shots of identical cameras
+    #[serde(default)]
+    pub camera_serial_number: Option<String>,
+    /// Identifier the camera writes into every frame of a burst
+    #[serde(default)]
+    pub burst_id: Option<String>,
     pub additional: HashMap<String, String>,
+    /// Values of the XMP sidecar of the medium, they take precedence over
+    /// the extracted ones
+    #[serde(default)]
+    pub sidecar: Option<SidecarMetadata>,
+    /// Values the owner set in place of the extracted ones
+    #[serde(default)]
+    pub overrides: MetadataOverrides,
     pub version: AggregateVersion,
 }
 
@@ -37,6 +72,7 @@ impl Default for Metadata {
             id: Uuid::nil(),
             medium_id: Uuid::nil(),
             extracted_at: DateTime::default(),
+            extractor_version: 0,
             file_info: FileInfo {
                 mime_type: mime::APPLICATION_OCTET_STREAM,
                 file_size: 0,
@@ -44,12 +80,21 @@ impl Default for Metadata {
             },
             camera_info: None,
             location: None,
+            place: None,
             technical: TechnicalInfo {
                 width: None,
                 height: None,
                 orientation: None,
+                software: None,
             },
+            descriptive: DescriptiveInfo::default(),
+            video: None,
+            content_identifier: None,
+            camera_serial_number: None,
+            burst_id: None,
             additional: HashMap::new(),
+            sidecar: None,
+            overrides: MetadataOverrides::default(),
             version: 0,
         }
     }
@@ -85,11 +130,47 @@ impl ApplyEvent<MetadataExtractedEvent> for Metadata {
         self.id = e.metadata.id;
         self.medium_id = e.metadata.medium_id;
         self.extracted_at = e.metadata.extracted_at;
+        self.extractor_version = e.metadata.extractor_version;
         self.file_info = e.metadata.file_info.clone();
         self.camera_info = e.metadata.camera_info.clone();
         self.location = e.metadata.location.clone();
+        self.place = e.metadata.place.clone();
         self.technical = e.metadata.technical.clone();
+        self.descriptive = e.metadata.descriptive.clone();
+        self.video = e.metadata.video.clone();
+        self.content_identifier = e.metadata.content_identifier.clone();
+        self.camera_serial_number = e.metadata.camera_serial_number.clone();
+        self.burst_id = e.metadata.burst_id.clone();
         self.additional = e.metadata.additional.clone();
+        self.sidecar = e.metadata.sidecar.clone();
+        self.overrides = e.metadata.overrides.clone();
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MetadataOverriddenEvent> for Metadata {
+    fn apply(&mut self, e: &MetadataOverriddenEvent) {
+        self.overrides = e.metadata.overrides.clone();
+        if e.changes.location.is_some() {
+            self.place = None;
+        }
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<MetadataSidecarAppliedEvent> for Metadata {
+    fn apply(&mut self, e: &MetadataSidecarAppliedEvent) {
+        self.sidecar = e.metadata.sidecar.clone();
+        if e.location.is_some() {
+            self.place = None;
+        }
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<LocationResolvedEvent> for Metadata {
+    fn apply(&mut self, e: &LocationResolvedEvent) {
+        self.place = Some(e.place.clone());
         self.version += 1;
     }
 }
@@ -114,6 +195,10 @@ pub struct CameraInfo {
     pub make: Option<String>,
     pub model: Option<String>,
     pub capture_date: Option<DateTime<FixedOffset>>,
+    /// How the UTC offset of `capture_date` was determined. `None` while it
+    /// is the local time of the camera labelled as UTC.
+    #[serde(default)]
+    pub capture_timezone_source: Option<TimezoneSource>,
     pub modified_date: Option<DateTime<FixedOffset>>,
     pub lens_make: Option<String>,
     pub lens_model: Option<String>,
@@ -122,9 +207,25 @@ pub struct CameraInfo {
     pub iso: Option<u16>,
     pub focal_length: Option<f64>,
     pub flash: Option<bool>,
+    #[serde(default)]
+    pub lens_serial_number: Option<String>,
+    #[serde(default)]
+    pub exposure_program: Option<ExposureProgram>,
+    #[serde(default)]
+    pub exposure_mode: Option<ExposureMode>,
+    #[serde(default)]
+    pub metering_mode: Option<MeteringMode>,
+    #[serde(default)]
+    pub white_balance: Option<WhiteBalance>,
+    /// Exposure compensation in EV
+    #[serde(default)]
+    pub exposure_bias: Option<f64>,
+    /// Focal length giving the same field of view on a 35mm film camera
+    #[serde(default)]
+    pub focal_length_35mm: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LocationInfo {
     pub latitude: f64,
     pub longitude: f64,
@@ -133,11 +234,92 @@ pub struct LocationInfo {
     pub horizontal_position_error: Option<f64>,
 }
 
+/// Values the owner set in place of the extracted ones. They are kept when
+/// the metadata is extracted again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetadataOverrides {
+    pub capture_date: Option<DateTime<FixedOffset>>,
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    pub location: Option<LocationInfo>,
+}
+
+impl MetadataOverrides {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Changes to the overrides of a metadata. `None` leaves the override of a
+/// field as it is, `Some(None)` falls back to the extracted value.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataOverridePatch {
+    pub capture_date: Option<Option<DateTime<FixedOffset>>>,
+    pub camera_make: Option<Option<String>>,
+    pub camera_model: Option<Option<String>>,
+    pub location: Option<Option<LocationInfo>>,
+}
+
+/// Where the UTC offset of a capture time was taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimezoneSource {
+    /// The camera recorded the offset, e.g. in `OffsetTimeOriginal`
+    Exif,
+    /// The time zone at the GPS coordinates of the medium
+    Gps,
+    /// The default time zone of the owner
+    UserDefault,
+    /// The owner set the capture date along with its offset
+    Manual,
+}
+
+/// Named place resolved from GPS coordinates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Place {
+    /// ISO 3166-1 alpha-2 code
+    pub country_code: String,
+    pub country: String,
+    /// First-level administrative division, e.g. a state or province
+    pub region: Option<String>,
+    pub city: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TechnicalInfo {
     pub width: Option<u32>,
     pub height: Option<u32>,
     pub orientation: Option<Orientation>,
+    /// Firmware or application that wrote the file
+    #[serde(default)]
+    pub software: Option<String>,
+}
+
+/// Descriptive values embedded in the file by the camera or an editor, as
+/// opposed to those of its XMP sidecar
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DescriptiveInfo {
+    /// -1 for rejected, 0 for unrated and up to 5 stars
+    pub rating: Option<i8>,
+    pub keywords: Vec<String>,
+    pub caption: Option<String>,
+    pub creator: Option<String>,
+    pub copyright: Option<String>,
+}
+
+/// Container and stream properties of a video
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VideoInfo {
+    /// Duration in seconds
+    pub duration: Option<f64>,
+    /// Frames per second
+    pub frame_rate: Option<f64>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    /// Average bitrate in bits per second
+    pub bitrate: Option<u64>,
+    /// Clockwise rotation in degrees players apply on playback
+    pub rotation: Option<u16>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -153,6 +335,8 @@ pub enum Orientation {
 }
 
 impl Metadata {
+    const MAX_CAMERA_NAME_LENGTH: usize = 100;
+
     pub fn extraction_started(
         medium_id: MediumId,
         leading_item_id: MediumItemId,
@@ -178,6 +362,140 @@ impl Metadata {
         MetadataExtractionFailedEvent::new(medium_id, leading_item_id, owner_id, error)
     }
 
+    pub fn location_resolved(
+        medium_id: MediumId,
+        owner_id: UserId,
+        place: Place,
+    ) -> LocationResolvedEvent {
+        LocationResolvedEvent::new(medium_id, owner_id, place)
+    }
+
+    /// Apply the changes of the owner to the overrides. `None` if none of
+    /// the overrides changed.
+    pub fn override_fields(
+        &mut self,
+        patch: MetadataOverridePatch,
+        owner_id: UserId,
+    ) -> DomainResult<Option<MetadataOverriddenEvent>> {
+        for name in [&patch.camera_make, &patch.camera_model]
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            ensure!(
+                name.len() <= Self::MAX_CAMERA_NAME_LENGTH,
+                ValidationSnafu {
+                    message: format!(
+                        "Camera make and model cannot exceed {} characters",
+                        Self::MAX_CAMERA_NAME_LENGTH
+                    ),
+                }
+            );
+        }
+        if let Some(Some(location)) = &patch.location {
+            GpsCoordinates::new(location.latitude, location.longitude, location.altitude)?;
+        }
+
+        let before = self.clone();
+        if let Some(capture_date) = patch.capture_date {
+            self.overrides.capture_date = capture_date;
+        }
+        if let Some(camera_make) = patch.camera_make {
+            self.overrides.camera_make = camera_make;
+        }
+        if let Some(camera_model) = patch.camera_model {
+            self.overrides.camera_model = camera_model;
+        }
+        if let Some(location) = patch.location {
+            self.overrides.location = location;
+        }
+
+        let changes = MetadataChanges {
+            capture_date: field_change(
+                &before.overrides.capture_date,
+                &self.overrides.capture_date,
+                before.capture_date(),
+                self.capture_date(),
+            ),
+            camera_make: field_change(
+                &before.overrides.camera_make,
+                &self.overrides.camera_make,
+                before.camera_make().map(String::from),
+                self.camera_make().map(String::from),
+            ),
+            camera_model: field_change(
+                &before.overrides.camera_model,
+                &self.overrides.camera_model,
+                before.camera_model().map(String::from),
+                self.camera_model().map(String::from),
+            ),
+            location: field_change(
+                &before.overrides.location,
+                &self.overrides.location,
+                before.effective_location().cloned(),
+                self.effective_location().cloned(),
+            ),
+        };
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        // Resolved again from the new location
+        if changes.location.is_some() {
+            self.place = None;
+        }
+        self.version += 1;
+
+        Ok(Some(MetadataOverriddenEvent::new(
+            self.medium_id,
+            owner_id,
+            changes,
+            self.clone(),
+        )))
+    }
+
+    /// Merge the values of an XMP sidecar. `None` if the sidecar did not change.
+    pub fn apply_sidecar(
+        &mut self,
+        sidecar: SidecarMetadata,
+        sidecar_item_id: MediumItemId,
+        owner_id: UserId,
+    ) -> DomainResult<Option<MetadataSidecarAppliedEvent>> {
+        sidecar.validate()?;
+        if self.sidecar.as_ref() == Some(&sidecar) {
+            return Ok(None);
+        }
+
+        let location_before = self.effective_location().cloned();
+        let removed_keywords = self
+            .sidecar
+            .iter()
+            .flat_map(|previous| previous.keywords.iter())
+            .filter(|keyword| !sidecar.keywords.contains(keyword))
+            .cloned()
+            .collect();
+        self.sidecar = Some(sidecar);
+
+        let location_after = self.effective_location().cloned();
+        let location = (location_before != location_after).then_some(FieldChange {
+            original: location_before,
+            new: location_after,
+        });
+        if location.is_some() {
+            self.place = None;
+        }
+        self.version += 1;
+
+        Ok(Some(MetadataSidecarAppliedEvent::new(
+            self.medium_id,
+            sidecar_item_id,
+            owner_id,
+            removed_keywords,
+            location,
+            self.clone(),
+        )))
+    }
+
     pub fn is_video(&self) -> bool {
         self.file_info.mime_type.type_().eq(&mime::VIDEO)
     }
@@ -186,18 +504,202 @@ impl Metadata {
         self.file_info.mime_type.type_().eq(&mime::IMAGE)
     }
 
+    /// The capture date of the owner, or else the extracted one
     pub fn capture_date(&self) -> Option<DateTime<FixedOffset>> {
-        self.camera_info.as_ref().and_then(|c| c.capture_date)
+        self.overrides
+            .capture_date
+            .or_else(|| self.camera_info.as_ref().and_then(|c| c.capture_date))
     }
 
-    pub fn gps_coordinates(&self) -> Option<(f64, f64, Option<f64>)> {
-        self.location
+    /// How the UTC offset of [`Self::capture_date`] was determined
+    pub fn capture_timezone_source(&self) -> Option<TimezoneSource> {
+        if self.overrides.capture_date.is_some() {
+            return Some(TimezoneSource::Manual);
+        }
+        self.camera_info
+            .as_ref()
+            .and_then(|c| c.capture_timezone_source)
+    }
+
+    pub fn camera_make(&self) -> Option<&str> {
+        self.overrides
+            .camera_make
+            .as_deref()
+            .or_else(|| self.camera_info.as_ref().and_then(|c| c.make.as_deref()))
+    }
+
+    pub fn camera_model(&self) -> Option<&str> {
+        self.overrides
+            .camera_model
+            .as_deref()
+            .or_else(|| self.camera_info.as_ref().and_then(|c| c.model.as_deref()))
+    }
+
+    /// The rating of the sidecar, or else the one embedded in the file
+    pub fn rating(&self) -> Option<i8> {
+        self.sidecar
+            .as_ref()
+            .and_then(|s| s.rating)
+            .or(self.descriptive.rating)
+    }
+
+    /// The label of the sidecar, e.g. the name of a color label
+    pub fn label(&self) -> Option<&str> {
+        self.sidecar.as_ref().and_then(|s| s.label.as_deref())
+    }
+
+    /// The location of the owner, or else the one of the sidecar, or else
+    /// the extracted one
+    pub fn effective_location(&self) -> Option<&LocationInfo> {
+        self.overrides
+            .location
+            .as_ref()
+            .or_else(|| self.sidecar.as_ref().and_then(|s| s.location.as_ref()))
+            .or(self.location.as_ref())
+    }
+
+    /// The capture date as the local time of the camera, as long as its
+    /// UTC offset is unknown
+    pub fn unresolved_capture_date(&self) -> Option<NaiveDateTime> {
+        self.camera_info
             .as_ref()
+            .filter(|c| c.capture_timezone_source.is_none())
+            .and_then(|c| c.capture_date)
+            .map(|d| d.naive_local())
+    }
+
+    /// Attach the offset of the time zone the camera was in to the local
+    /// capture date
+    pub fn localize_capture_date(&mut self, offset: FixedOffset, source: TimezoneSource) {
+        let Some(camera) = self.camera_info.as_mut() else {
+            return;
+        };
+        if let Some(date) = camera
+            .capture_date
+            .and_then(|d| offset.from_local_datetime(&d.naive_local()).single())
+        {
+            camera.capture_date = Some(date);
+            camera.capture_timezone_source = Some(source);
+        }
+    }
+
+    pub fn gps_coordinates(&self) -> Option<(f64, f64, Option<f64>)> {
+        self.effective_location()
             .map(|loc| (loc.latitude, loc.longitude, loc.altitude))
     }
 
     pub fn has_gps(&self) -> bool {
-        self.location.is_some()
+        self.effective_location().is_some()
+    }
+}
+
+/// The change of a field, if its override changed
+fn field_change<T: PartialEq, V>(
+    override_before: &Option<T>,
+    override_after: &Option<T>,
+    original: Option<V>,
+    new: Option<V>,
+) -> Option<FieldChange<V>> {
+    (override_before != override_after).then_some(FieldChange { original, new })
+}
+
+/// EXIF `ExposureProgram`, the mode dial of the camera
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExposureProgram {
+    Manual,
+    Program,
+    AperturePriority,
+    ShutterPriority,
+    Creative,
+    Action,
+    Portrait,
+    Landscape,
+    Bulb,
+}
+
+impl ExposureProgram {
+    /// `None` for "not defined" and unknown values
+    pub fn from_exif(value: u64) -> Option<Self> {
+        match value {
+            1 => Some(Self::Manual),
+            2 => Some(Self::Program),
+            3 => Some(Self::AperturePriority),
+            4 => Some(Self::ShutterPriority),
+            5 => Some(Self::Creative),
+            6 => Some(Self::Action),
+            7 => Some(Self::Portrait),
+            8 => Some(Self::Landscape),
+            9 => Some(Self::Bulb),
+            _ => None,
+        }
+    }
+}
+
+/// EXIF `ExposureMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExposureMode {
+    Auto,
+    Manual,
+    AutoBracket,
+}
+
+impl ExposureMode {
+    pub fn from_exif(value: u64) -> Option<Self> {
+        match value {
+            0 => Some(Self::Auto),
+            1 => Some(Self::Manual),
+            2 => Some(Self::AutoBracket),
+            _ => None,
+        }
+    }
+}
+
+/// EXIF `MeteringMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeteringMode {
+    Average,
+    CenterWeightedAverage,
+    Spot,
+    MultiSpot,
+    MultiSegment,
+    Partial,
+    Other,
+}
+
+impl MeteringMode {
+    /// `None` for "unknown" and undefined values
+    pub fn from_exif(value: u64) -> Option<Self> {
+        match value {
+            1 => Some(Self::Average),
+            2 => Some(Self::CenterWeightedAverage),
+            3 => Some(Self::Spot),
+            4 => Some(Self::MultiSpot),
+            5 => Some(Self::MultiSegment),
+            6 => Some(Self::Partial),
+            255 => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+/// EXIF `WhiteBalance`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WhiteBalance {
+    Auto,
+    Manual,
+}
+
+impl WhiteBalance {
+    pub fn from_exif(value: u64) -> Option<Self> {
+        match value {
+            0 => Some(Self::Auto),
+            1 => Some(Self::Manual),
+            _ => None,
+        }
     }
 }
 
@@ -216,3 +718,253 @@ impl From<u8> for Orientation {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_capture_date(date: &str, source: Option<TimezoneSource>) -> Metadata {
+        Metadata {
+            camera_info: Some(CameraInfo {
+                make: None,
+                model: None,
+                capture_date: DateTime::parse_from_rfc3339(date).ok(),
+                capture_timezone_source: source,
+                modified_date: None,
+                lens_make: None,
+                lens_model: None,
+                exposure_time: None,
+                f_number: None,
+                iso: None,
+                focal_length: None,
+                flash: None,
+                lens_serial_number: None,
+                exposure_program: None,
+                exposure_mode: None,
+                metering_mode: None,
+                white_balance: None,
+                exposure_bias: None,
+                focal_length_35mm: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_localize_capture_date_keeps_local_time() {
+        let mut metadata = with_capture_date("2024-06-20T23:30:00+00:00", None);
+        let local = metadata.unresolved_capture_date().unwrap();
+
+        metadata.localize_capture_date(
+            FixedOffset::east_opt(9 * 3600).unwrap(),
+            TimezoneSource::Gps,
+        );
+
+        let camera = metadata.camera_info.as_ref().unwrap();
+        assert_eq!(camera.capture_date.unwrap().naive_local(), local);
+        assert_eq!(
+            camera.capture_date,
+            DateTime::parse_from_rfc3339("2024-06-20T23:30:00+09:00").ok()
+        );
+        assert_eq!(camera.capture_timezone_source, Some(TimezoneSource::Gps));
+        assert_eq!(metadata.unresolved_capture_date(), None);
+    }
+
+    #[test]
+    fn test_override_records_original_and_new_value() {
+        let mut metadata = with_capture_date("2024-06-20T23:30:00+00:00", None);
+        let corrected = DateTime::parse_from_rfc3339("2024-06-21T08:00:00+09:00").unwrap();
+
+        let event = metadata
+            .override_fields(
+                MetadataOverridePatch {
+                    capture_date: Some(Some(corrected)),
+                    camera_make: Some(Some("Leica".into())),
+                    ..Default::default()
+                },
+                Uuid::new_v4(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            event.changes.capture_date,
+            Some(FieldChange {
+                original: DateTime::parse_from_rfc3339("2024-06-20T23:30:00+00:00").ok(),
+                new: Some(corrected),
+            })
+        );
+        assert_eq!(
+            event.changes.camera_make,
+            Some(FieldChange {
+                original: None,
+                new: Some("Leica".to_string()),
+            })
+        );
+        assert_eq!(event.changes.location, None);
+        assert_eq!(metadata.capture_date(), Some(corrected));
+        assert_eq!(
+            metadata.capture_timezone_source(),
+            Some(TimezoneSource::Manual)
+        );
+
+        // Removing the override falls back to the extracted value
+        let event = metadata
+            .override_fields(
+                MetadataOverridePatch {
+                    capture_date: Some(None),
+                    ..Default::default()
+                },
+                Uuid::new_v4(),
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            event.changes.capture_date.unwrap().new,
+            DateTime::parse_from_rfc3339("2024-06-20T23:30:00+00:00").ok()
+        );
+        assert_eq!(metadata.camera_make(), Some("Leica"));
+    }
+
+    #[test]
+    fn test_unchanged_override_emits_no_event() {
+        let mut metadata = with_capture_date("2024-06-20T23:30:00+00:00", None);
+        let patch = MetadataOverridePatch {
+            camera_model: Some(Some("Q3".into())),
+            ..Default::default()
+        };
+
+        assert!(metadata
+            .override_fields(patch.clone(), Uuid::new_v4())
+            .unwrap()
+            .is_some());
+        assert!(metadata
+            .override_fields(patch, Uuid::new_v4())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_override_rejects_invalid_location() {
+        let mut metadata = Metadata::default();
+
+        let result = metadata.override_fields(
+            MetadataOverridePatch {
+                location: Some(Some(LocationInfo {
+                    latitude: 91.0,
+                    longitude: 0.0,
+                    altitude: None,
+                    direction: None,
+                    horizontal_position_error: None,
+                })),
+                ..Default::default()
+            },
+            Uuid::new_v4(),
+        );
+
+        assert!(result.is_err());
+        assert!(metadata.overrides.is_empty());
+    }
+
+    fn location(latitude: f64, longitude: f64) -> LocationInfo {
+        LocationInfo {
+            latitude,
+            longitude,
+            altitude: None,
+            direction: None,
+            horizontal_position_error: None,
+        }
+    }
+
+    #[test]
+    fn test_sidecar_location_precedes_extracted_but_not_override() {
+        let mut metadata = Metadata {
+            location: Some(location(38.7, -9.1)),
+            ..Default::default()
+        };
+        let sidecar = SidecarMetadata {
+            keywords: vec!["beach".into(), "family".into()],
+            rating: Some(4),
+            location: Some(location(41.1, -8.6)),
+            ..Default::default()
+        };
+
+        let event = metadata
+            .apply_sidecar(sidecar, Uuid::new_v4(), Uuid::new_v4())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.location.unwrap().new.map(|l| l.latitude), Some(41.1));
+        assert_eq!(metadata.effective_location().unwrap().latitude, 41.1);
+
+        metadata
+            .override_fields(
+                MetadataOverridePatch {
+                    location: Some(Some(location(52.5, 13.4))),
+                    ..Default::default()
+                },
+                Uuid::new_v4(),
+            )
+            .unwrap();
+        assert_eq!(metadata.effective_location().unwrap().latitude, 52.5);
+
+        // Behind the override a new sidecar location changes nothing visible
+        let event = metadata
+            .apply_sidecar(
+                SidecarMetadata {
+                    keywords: vec!["beach".into()],
+                    location: Some(location(40.0, -8.0)),
+                    ..Default::default()
+                },
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.location, None);
+        assert_eq!(event.removed_keywords, vec!["family".to_string()]);
+    }
+
+    #[test]
+    fn test_unchanged_sidecar_emits_no_event() {
+        let mut metadata = Metadata::default();
+        let sidecar = SidecarMetadata {
+            title: Some("Sunset".into()),
+            ..Default::default()
+        };
+
+        assert!(metadata
+            .apply_sidecar(sidecar.clone(), Uuid::new_v4(), Uuid::new_v4())
+            .unwrap()
+            .is_some());
+        assert!(metadata
+            .apply_sidecar(sidecar, Uuid::new_v4(), Uuid::new_v4())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_sidecar_rejects_invalid_rating() {
+        let mut metadata = Metadata::default();
+
+        let result = metadata.apply_sidecar(
+            SidecarMetadata {
+                rating: Some(7),
+                ..Default::default()
+            },
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+
+        assert!(result.is_err());
+        assert_eq!(metadata.sidecar, None);
+    }
+
+    #[test]
+    fn test_recorded_offset_is_not_unresolved() {
+        let metadata = with_capture_date("2024-06-20T23:30:00+02:00", Some(TimezoneSource::Exif));
+
+        assert_eq!(metadata.unresolved_capture_date(), None);
+    }
+}
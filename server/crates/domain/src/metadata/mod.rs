@@ -1,4 +1,8 @@
 pub mod events;
 mod metadata;
+mod sidecar;
+mod track;
 
 pub use metadata::*;
+pub use sidecar::*;
+pub use track::*;
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+use crate::{
+    error::{DomainResult, ValidationSnafu},
+    medium::GpsCoordinates,
+    metadata::LocationInfo,
+};
+
+/// Values an editor like Lightroom or darktable keeps in an XMP sidecar.
+/// They take precedence over the values embedded in the file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SidecarMetadata {
+    /// `dc:subject`
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// `xmp:Rating`, -1 for rejected, 0 for unrated and up to 5 stars
+    pub rating: Option<i8>,
+    /// `xmp:Label`, e.g. the name of a color label
+    pub label: Option<String>,
+    /// `dc:title`
+    pub title: Option<String>,
+    /// `dc:description`
+    pub description: Option<String>,
+    pub crop: Option<CropInfo>,
+    pub location: Option<LocationInfo>,
+}
+
+/// Crop of the developed image, the edges as fractions of the width and
+/// height of the original
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CropInfo {
+    pub top: f64,
+    pub left: f64,
+    pub bottom: f64,
+    pub right: f64,
+    /// Rotation in degrees
+    pub angle: f64,
+}
+
+impl SidecarMetadata {
+    /// Tags are stored with at most 100 characters
+    pub const MAX_KEYWORD_LENGTH: usize = 100;
+
+    pub fn validate(&self) -> DomainResult<()> {
+        ensure!(
+            self.keywords
+                .iter()
+                .all(|k| !k.trim().is_empty() && k.chars().count() <= Self::MAX_KEYWORD_LENGTH),
+            ValidationSnafu {
+                message: format!(
+                    "Keywords must not be empty or exceed {} characters",
+                    Self::MAX_KEYWORD_LENGTH
+                ),
+            }
+        );
+        if let Some(rating) = self.rating {
+            ensure!(
+                (-1..=5).contains(&rating),
+                ValidationSnafu {
+                    message: format!("Invalid rating {}", rating),
+                }
+            );
+        }
+        if let Some(crop) = &self.crop {
+            let within = |edge: f64| (0.0..=1.0).contains(&edge);
+            ensure!(
+                within(crop.top)
+                    && within(crop.left)
+                    && within(crop.bottom)
+                    && within(crop.right)
+                    && crop.top < crop.bottom
+                    && crop.left < crop.right,
+                ValidationSnafu {
+                    message: "Invalid crop",
+                }
+            );
+        }
+        if let Some(location) = &self.location {
+            GpsCoordinates::new(location.latitude, location.longitude, location.altitude)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use snafu::ensure;
+
+use crate::{
+    error::{DomainResult, ValidationSnafu},
+    medium::GpsCoordinates,
+    metadata::LocationInfo,
+};
+
+/// A position recorded by a GPS logger or phone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackPoint {
+    pub time: DateTime<Utc>,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: Option<f64>,
+}
+
+/// The points of a recorded track, in the order they were recorded
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpsTrack {
+    points: Vec<TrackPoint>,
+}
+
+impl GpsTrack {
+    pub fn new(mut points: Vec<TrackPoint>) -> DomainResult<Self> {
+        ensure!(
+            !points.is_empty(),
+            ValidationSnafu {
+                message: "The track has no points with a time",
+            }
+        );
+        for point in &points {
+            GpsCoordinates::new(point.latitude, point.longitude, point.altitude)?;
+        }
+
+        points.sort_by_key(|p| p.time);
+        Ok(Self { points })
+    }
+
+    pub fn points(&self) -> &[TrackPoint] {
+        &self.points
+    }
+
+    pub fn start(&self) -> DateTime<Utc> {
+        self.points[0].time
+    }
+
+    pub fn end(&self) -> DateTime<Utc> {
+        self.points[self.points.len() - 1].time
+    }
+
+    /// Where the track was at the time. Between two points within the
+    /// tolerance the position is interpolated, otherwise the nearest point
+    /// within the tolerance is taken.
+    pub fn position_at(&self, time: DateTime<Utc>, tolerance: TimeDelta) -> Option<LocationInfo> {
+        let index = self.points.partition_point(|p| p.time <= time);
+        let before = index.checked_sub(1).map(|i| &self.points[i]);
+        let after = self.points.get(index);
+
+        let within = |point: &&TrackPoint| (point.time - time).abs() <= tolerance;
+        let point = match (before.filter(within), after.filter(within)) {
+            (Some(before), Some(after)) => Self::interpolate(before, after, time),
+            (Some(point), None) | (None, Some(point)) => *point,
+            (None, None) => return None,
+        };
+
+        Some(LocationInfo {
+            latitude: point.latitude,
+            longitude: point.longitude,
+            altitude: point.altitude,
+            direction: None,
+            horizontal_position_error: None,
+        })
+    }
+
+    fn interpolate(before: &TrackPoint, after: &TrackPoint, time: DateTime<Utc>) -> TrackPoint {
+        let span = (after.time - before.time).num_milliseconds();
+        if span == 0 {
+            return *before;
+        }
+        let fraction = (time - before.time).num_milliseconds() as f64 / span as f64;
+        let lerp = |from: f64, to: f64| from + (to - from) * fraction;
+
+        // Take the short way across the antimeridian
+        let mut to_longitude = after.longitude;
+        if to_longitude - before.longitude > 180.0 {
+            to_longitude -= 360.0;
+        } else if before.longitude - to_longitude > 180.0 {
+            to_longitude += 360.0;
+        }
+        let mut longitude = lerp(before.longitude, to_longitude);
+        if longitude > 180.0 {
+            longitude -= 360.0;
+        } else if longitude < -180.0 {
+            longitude += 360.0;
+        }
+
+        TrackPoint {
+            time,
+            latitude: lerp(before.latitude, after.latitude),
+            longitude,
+            altitude: Option::zip(before.altitude, after.altitude).map(|(a, b)| lerp(a, b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(time: &str, latitude: f64, longitude: f64) -> TrackPoint {
+        TrackPoint {
+            time: time.parse().unwrap(),
+            latitude,
+            longitude,
+            altitude: None,
+        }
+    }
+
+    fn track() -> GpsTrack {
+        GpsTrack::new(vec![
+            point("2024-07-15T10:10:00Z", 38.72, -9.12),
+            point("2024-07-15T10:00:00Z", 38.70, -9.14),
+            point("2024-07-15T12:00:00Z", 38.80, -9.00),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_interpolates_between_points_within_tolerance() {
+        let location = track()
+            .position_at(
+                "2024-07-15T10:05:00Z".parse().unwrap(),
+                TimeDelta::minutes(5),
+            )
+            .unwrap();
+
+        assert!((location.latitude - 38.71).abs() < 1e-9);
+        assert!((location.longitude - -9.13).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_takes_nearest_point_across_a_gap() {
+        let track = track();
+        let tolerance = TimeDelta::minutes(15);
+
+        let location = track
+            .position_at("2024-07-15T10:20:00Z".parse().unwrap(), tolerance)
+            .unwrap();
+        assert_eq!((location.latitude, location.longitude), (38.72, -9.12));
+
+        let location = track
+            .position_at("2024-07-15T11:50:00Z".parse().unwrap(), tolerance)
+            .unwrap();
+        assert_eq!((location.latitude, location.longitude), (38.80, -9.00));
+    }
+
+    #[test]
+    fn test_no_position_outside_tolerance() {
+        let track = track();
+        let tolerance = TimeDelta::minutes(15);
+
+        assert!(track
+            .position_at("2024-07-15T11:00:00Z".parse().unwrap(), tolerance)
+            .is_none());
+        assert!(track
+            .position_at("2024-07-15T09:30:00Z".parse().unwrap(), tolerance)
+            .is_none());
+    }
+
+    #[test]
+    fn test_interpolates_across_antimeridian() {
+        let track = GpsTrack::new(vec![
+            point("2024-07-15T10:00:00Z", -17.0, 179.0),
+            point("2024-07-15T10:10:00Z", -17.0, -179.0),
+        ])
+        .unwrap();
+
+        let location = track
+            .position_at(
+                "2024-07-15T10:05:00Z".parse().unwrap(),
+                TimeDelta::minutes(5),
+            )
+            .unwrap();
+
+        assert!((location.longitude.abs() - 180.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,7 @@
+mod smart_album_created;
+mod smart_album_deleted;
+mod smart_album_updated;
+
+pub use smart_album_created::SmartAlbumCreatedEvent;
+pub use smart_album_deleted::SmartAlbumDeletedEvent;
+pub use smart_album_updated::SmartAlbumUpdatedEvent;
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::FilterExpression,
+    smart_album::SmartAlbumId,
+    user::UserId,
+};
+
+/// Event emitted when a user saves a filter as a smart album.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct SmartAlbumCreatedEvent {
+    pub smart_album_id: SmartAlbumId,
+    pub owner_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+    pub filter: FilterExpression,
+    pub created_at: DateTime<Utc>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for SmartAlbumCreatedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
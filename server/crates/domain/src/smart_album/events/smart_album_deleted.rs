@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    smart_album::SmartAlbumId,
+    user::UserId,
+};
+
+/// Event emitted when a user deletes a smart album. The media it matched are
+/// not touched.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct SmartAlbumDeletedEvent {
+    pub smart_album_id: SmartAlbumId,
+    pub owner_id: UserId,
+    pub deleted_at: DateTime<Utc>,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for SmartAlbumDeletedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,29 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    medium::FilterExpression,
+    smart_album::SmartAlbumId,
+    user::UserId,
+};
+
+/// Event emitted when the title, description or filter of a smart album
+/// changed.
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct SmartAlbumUpdatedEvent {
+    pub smart_album_id: SmartAlbumId,
+    pub owner_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+    pub filter: FilterExpression,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for SmartAlbumUpdatedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -0,0 +1,5 @@
+pub mod events;
+mod smart_album;
+
+pub use events::*;
+pub use smart_album::*;
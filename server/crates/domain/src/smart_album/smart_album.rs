@@ -0,0 +1,308 @@
+use chrono::{DateTime, Utc};
+use event_sourcing::aggregate::traits::{Aggregate, ApplyEvent};
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+use uuid::Uuid;
+
+use super::events::{SmartAlbumCreatedEvent, SmartAlbumDeletedEvent, SmartAlbumUpdatedEvent};
+use crate::{
+    aggregate::{AggregateRoot, AggregateVersion},
+    error::{DomainResult, ValidationSnafu},
+    medium::FilterExpression,
+    user::UserId,
+};
+
+pub type SmartAlbumId = Uuid;
+
+/// An album whose media are the media of the owner matching a saved filter.
+/// Unlike an [`Album`](crate::album::Album) it has no members of its own, a
+/// medium can show up in any number of smart albums.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmartAlbum {
+    pub id: SmartAlbumId,
+    pub owner_id: UserId,
+    pub title: String,
+    pub description: Option<String>,
+    pub filter: FilterExpression,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub version: AggregateVersion,
+}
+
+impl Default for SmartAlbum {
+    fn default() -> Self {
+        Self {
+            id: Uuid::nil(),
+            owner_id: Uuid::nil(),
+            title: String::new(),
+            description: None,
+            filter: FilterExpression::And(Vec::new()),
+            created_at: DateTime::default(),
+            updated_at: DateTime::default(),
+            deleted_at: None,
+            version: 0,
+        }
+    }
+}
+
+impl AggregateRoot for SmartAlbum {
+    fn aggregate_type() -> &'static str {
+        "SmartAlbum"
+    }
+
+    fn version(&self) -> AggregateVersion {
+        self.version
+    }
+}
+
+impl Aggregate for SmartAlbum {
+    type Id = Uuid;
+
+    fn aggregate_type() -> &'static str {
+        "SmartAlbum"
+    }
+}
+
+impl ApplyEvent<SmartAlbumCreatedEvent> for SmartAlbum {
+    fn apply(&mut self, e: &SmartAlbumCreatedEvent) {
+        self.id = e.smart_album_id;
+        self.owner_id = e.owner_id;
+        self.title = e.title.clone();
+        self.description = e.description.clone();
+        self.filter = e.filter.clone();
+        self.created_at = e.created_at;
+        self.updated_at = e.created_at;
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<SmartAlbumUpdatedEvent> for SmartAlbum {
+    fn apply(&mut self, e: &SmartAlbumUpdatedEvent) {
+        self.title = e.title.clone();
+        self.description = e.description.clone();
+        self.filter = e.filter.clone();
+        self.version += 1;
+    }
+}
+
+impl ApplyEvent<SmartAlbumDeletedEvent> for SmartAlbum {
+    fn apply(&mut self, e: &SmartAlbumDeletedEvent) {
+        self.deleted_at = Some(e.deleted_at);
+        self.updated_at = e.deleted_at;
+        self.version += 1;
+    }
+}
+
+impl SmartAlbum {
+    const MAX_TITLE_LENGTH: usize = 255;
+
+    pub fn new(
+        owner_id: UserId,
+        title: String,
+        description: Option<String>,
+        filter: FilterExpression,
+    ) -> DomainResult<(Self, SmartAlbumCreatedEvent)> {
+        let title = Self::validate_title(title)?;
+        let description = description.filter(|d| !d.trim().is_empty());
+        filter.validate()?;
+        let now = Utc::now();
+
+        let smart_album = Self {
+            id: Uuid::new_v4(),
+            owner_id,
+            title,
+            description,
+            filter,
+            created_at: now,
+            updated_at: now,
+            version: 1,
+            ..Default::default()
+        };
+
+        let event = SmartAlbumCreatedEvent::new(
+            smart_album.id,
+            owner_id,
+            smart_album.title.clone(),
+            smart_album.description.clone(),
+            smart_album.filter.clone(),
+            now,
+        );
+        Ok((smart_album, event))
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Change the title, description and filter, `None` if all are unchanged
+    pub fn update(
+        &mut self,
+        title: String,
+        description: Option<String>,
+        filter: FilterExpression,
+    ) -> DomainResult<Option<SmartAlbumUpdatedEvent>> {
+        self.ensure_not_deleted()?;
+        let title = Self::validate_title(title)?;
+        let description = description.filter(|d| !d.trim().is_empty());
+        filter.validate()?;
+        if self.title == title && self.description == description && self.filter == filter {
+            return Ok(None);
+        }
+
+        let mut event = SmartAlbumUpdatedEvent::new(
+            self.id,
+            self.owner_id,
+            title.clone(),
+            description.clone(),
+            filter.clone(),
+        );
+        event.metadata.expected_version = self.version;
+        self.title = title;
+        self.description = description;
+        self.filter = filter;
+        self.updated_at = Utc::now();
+        self.version += 1;
+        Ok(Some(event))
+    }
+
+    /// Delete the smart album, the media it matched are kept
+    pub fn delete(&mut self) -> DomainResult<SmartAlbumDeletedEvent> {
+        self.ensure_not_deleted()?;
+
+        let now = Utc::now();
+        let mut event = SmartAlbumDeletedEvent::new(self.id, self.owner_id, now);
+        event.metadata.expected_version = self.version;
+        self.deleted_at = Some(now);
+        self.updated_at = now;
+        self.version += 1;
+        Ok(event)
+    }
+
+    fn ensure_not_deleted(&self) -> DomainResult<()> {
+        ensure!(
+            !self.is_deleted(),
+            ValidationSnafu {
+                message: "Smart album is deleted"
+            }
+        );
+        Ok(())
+    }
+
+    fn validate_title(title: String) -> DomainResult<String> {
+        let title = title.trim().to_string();
+        ensure!(
+            !title.is_empty(),
+            ValidationSnafu {
+                message: "Smart album title must not be empty"
+            }
+        );
+        ensure!(
+            title.chars().count() <= Self::MAX_TITLE_LENGTH,
+            ValidationSnafu {
+                message: format!(
+                    "Smart album title cannot exceed {} characters",
+                    Self::MAX_TITLE_LENGTH
+                ),
+            }
+        );
+        Ok(title)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn favorites() -> FilterExpression {
+        FilterExpression::MinRating(4)
+    }
+
+    fn create_test_smart_album() -> SmartAlbum {
+        SmartAlbum::new(Uuid::new_v4(), "Best of".to_string(), None, favorites())
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_smart_album_needs_title_and_valid_filter() {
+        let (smart_album, event) = SmartAlbum::new(
+            Uuid::new_v4(),
+            " Best of ".to_string(),
+            Some(" ".to_string()),
+            favorites(),
+        )
+        .unwrap();
+        assert_eq!(smart_album.title, "Best of");
+        assert_eq!(smart_album.description, None);
+        assert_eq!(event.filter, favorites());
+
+        assert!(SmartAlbum::new(Uuid::new_v4(), " ".to_string(), None, favorites()).is_err());
+        assert!(SmartAlbum::new(
+            Uuid::new_v4(),
+            "Best of".to_string(),
+            None,
+            FilterExpression::And(vec![])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_update_changes_filter() {
+        let mut smart_album = create_test_smart_album();
+        let filter = FilterExpression::Not(Box::new(FilterExpression::HasGps(true)));
+
+        assert!(smart_album
+            .update("Best of".to_string(), None, favorites())
+            .unwrap()
+            .is_none());
+
+        let event = smart_album
+            .update("Best of".to_string(), None, filter.clone())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.filter, filter);
+        assert_eq!(event.metadata.expected_version, 1);
+        assert_eq!(smart_album.filter, filter);
+        assert_eq!(smart_album.version, 2);
+        assert!(smart_album
+            .update("Best of".to_string(), None, FilterExpression::MinRating(9))
+            .is_err());
+    }
+
+    #[test]
+    fn test_deleted_smart_album_cannot_be_changed() {
+        let mut smart_album = create_test_smart_album();
+
+        smart_album.delete().unwrap();
+
+        assert!(smart_album.delete().is_err());
+        assert!(smart_album
+            .update("Other".to_string(), None, favorites())
+            .is_err());
+    }
+
+    #[test]
+    fn test_applying_events_rebuilds_smart_album() {
+        let (mut smart_album, created) =
+            SmartAlbum::new(Uuid::new_v4(), "Best of".to_string(), None, favorites()).unwrap();
+        let updated = smart_album
+            .update(
+                "Top rated".to_string(),
+                Some("Four stars and up".to_string()),
+                favorites(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let mut rebuilt = SmartAlbum::default();
+        rebuilt.apply(&created);
+        rebuilt.apply(&updated);
+
+        assert_eq!(rebuilt.id, smart_album.id);
+        assert_eq!(rebuilt.title, "Top rated");
+        assert_eq!(rebuilt.filter, favorites());
+        assert_eq!(rebuilt.version, 2);
+    }
+}
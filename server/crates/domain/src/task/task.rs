@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use event_sourcing::aggregate::traits::{Aggregate, ApplyEvent};
 use serde::{Deserialize, Serialize};
-use snafu::OptionExt;
+use snafu::{ensure, OptionExt};
 use uuid::Uuid;
 
 use super::status::TaskStatus;
@@ -27,9 +27,17 @@ pub struct Task {
     pub created_at: DateTime<Utc>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
+    pub progress: Option<TaskProgress>,
     pub version: AggregateVersion,
 }
 
+/// How far a task working through a batch of items got
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskProgress {
+    pub processed: usize,
+    pub total: usize,
+}
+
 impl Default for Task {
     fn default() -> Self {
         Self {
@@ -41,6 +49,7 @@ impl Default for Task {
             created_at: DateTime::default(),
             started_at: None,
             completed_at: None,
+            progress: None,
             version: 0,
         }
     }
@@ -129,6 +138,7 @@ impl Task {
             created_at: Utc::now(),
             started_at: None,
             completed_at: None,
+            progress: None,
             version: 0,
         };
 
@@ -176,6 +186,25 @@ impl Task {
         Ok(TaskFailedEvent::new(self.id, error))
     }
 
+    /// Record how many of the items of a batch were processed
+    /// Business rule: Only in-progress tasks make progress
+    pub fn report_progress(&mut self, processed: usize, total: usize) -> DomainResult<()> {
+        ensure!(
+            self.status == TaskStatus::InProgress,
+            InvariantViolationSnafu {
+                message: format!("Cannot report progress of task in {:?} status", self.status),
+            }
+        );
+        ensure!(
+            processed <= total,
+            InvariantViolationSnafu {
+                message: format!("Processed {} of only {} items", processed, total),
+            }
+        );
+        self.progress = Some(TaskProgress { processed, total });
+        Ok(())
+    }
+
     pub fn is_retriable(&self) -> bool {
         matches!(self.status, TaskStatus::Failed(_))
     }
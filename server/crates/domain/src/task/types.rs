@@ -4,4 +4,7 @@ use serde::{Deserialize, Serialize};
 pub enum TaskType {
     MetadataExtraction,
     TempCleanup,
+    PreviewGeneration,
+    CaptureDateShift,
+    MetadataReextraction,
 }
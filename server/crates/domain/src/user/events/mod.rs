@@ -2,11 +2,13 @@ mod quota_committed;
 mod quota_released;
 mod quota_reserved;
 mod user_created;
+mod user_settings_updated;
 mod user_updated;
 
 pub use quota_committed::QuotaCommittedEvent;
 pub use quota_released::QuotaReleasedEvent;
 pub use quota_reserved::QuotaReservedEvent;
 pub use user_created::UserCreatedEvent;
+pub use user_settings_updated::UserSettingsUpdatedEvent;
 pub use user_updated::UserUpdatedEvent;
 pub(super) use user_updated::UserUpdatedEventBuilder;
@@ -14,6 +14,7 @@ pub struct QuotaReleasedEvent {
     pub user_id: UserId,
     pub bytes: Byte,
     pub quota_used_after: Byte,
+    /// The reservation that was rolled back, or the event that freed the space
     pub reserved_event_id: Uuid,
     #[new(default)]
     pub metadata: EventMetadata,
@@ -13,6 +13,8 @@ pub struct UserCreatedEvent {
     pub user_id: UserId,
     pub username: String,
     pub email: Option<String>,
+    #[serde(default)]
+    pub timezone: Option<String>,
     pub quota: Byte,
     #[new(default)]
     pub metadata: EventMetadata,
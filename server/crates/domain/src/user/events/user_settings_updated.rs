@@ -0,0 +1,23 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event::{DomainEvent, EventMetadata},
+    user::{UserId, UserSettings},
+};
+
+/// Event published when the user changed their settings
+#[derive(new, Debug, Clone, Serialize, Deserialize)]
+#[new(visibility = "pub(crate)")]
+pub struct UserSettingsUpdatedEvent {
+    pub user_id: UserId,
+    pub settings: UserSettings,
+    #[new(default)]
+    pub metadata: EventMetadata,
+}
+
+impl DomainEvent for UserSettingsUpdatedEvent {
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
     user::UserId,
 };
 
-/// Event published when user profile is updated (username, email, or quota limit)
+/// Event published when user profile is updated (username, email, time zone, or quota limit)
 #[derive(Debug, Clone, Builder, Serialize, Deserialize)]
 #[builder(setter(into, strip_option))]
 pub struct UserUpdatedEvent {
@@ -21,6 +21,12 @@ pub struct UserUpdatedEvent {
     #[builder(default)]
     pub new_email: Option<String>,
     #[builder(default)]
+    #[serde(default)]
+    pub old_timezone: Option<String>,
+    #[builder(default)]
+    #[serde(default)]
+    pub new_timezone: Option<String>,
+    #[builder(default)]
     pub old_quota: Option<Byte>,
     #[builder(default)]
     pub new_quota: Option<Byte>,
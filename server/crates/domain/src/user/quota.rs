@@ -57,7 +57,7 @@ impl QuotaState {
     }
 
     pub fn release_quota(&mut self, released: Byte) {
-        self.used = Byte::from_u64(self.used.as_u64() - released.as_u64());
+        self.used = Byte::from_u64(self.used.as_u64().saturating_sub(released.as_u64()));
     }
 
     pub fn used(&self) -> Byte {
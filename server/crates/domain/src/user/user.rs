@@ -7,7 +7,7 @@ use uuid::Uuid;
 use super::{
     events::{
         QuotaCommittedEvent, QuotaReleasedEvent, QuotaReservedEvent, UserCreatedEvent,
-        UserUpdatedEvent, UserUpdatedEventBuilder,
+        UserSettingsUpdatedEvent, UserUpdatedEvent, UserUpdatedEventBuilder,
     },
     quota::QuotaState,
 };
@@ -24,9 +24,22 @@ pub struct User {
     pub version: i64,
     pub username: String,
     pub email: Option<String>,
+    /// IANA time zone media without a recorded offset are assumed to be taken in
+    #[serde(default)]
+    pub timezone: Option<String>,
+    #[serde(default)]
+    pub settings: UserSettings,
     pub quota: QuotaState,
 }
 
+/// Preferences the user sets in Photonic itself
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UserSettings {
+    /// Keep an XMP sidecar next to each original in permanent storage up to
+    /// date with the curation done in Photonic
+    pub xmp_write_back: bool,
+}
+
 impl Default for User {
     fn default() -> Self {
         Self {
@@ -34,6 +47,8 @@ impl Default for User {
             version: 0,
             username: String::new(),
             email: None,
+            timezone: None,
+            settings: UserSettings::default(),
             quota: QuotaState::new_unchecked(Byte::from_u64(0), Byte::from_u64(0)),
         }
     }
@@ -62,6 +77,7 @@ impl ApplyEvent<UserCreatedEvent> for User {
         self.id = e.user_id;
         self.username = e.username.clone();
         self.email = e.email.clone();
+        self.timezone = e.timezone.clone();
         self.version += 1;
     }
 }
@@ -74,6 +90,9 @@ impl ApplyEvent<UserUpdatedEvent> for User {
         if let Some(ref email) = e.new_email {
             self.email = Some(email.clone());
         }
+        if let Some(ref timezone) = e.new_timezone {
+            self.timezone = Some(timezone.clone());
+        }
         if let Some(new_quota) = e.new_quota {
             self.quota = QuotaState::new_unchecked(self.quota.used(), new_quota);
         }
@@ -81,6 +100,13 @@ impl ApplyEvent<UserUpdatedEvent> for User {
     }
 }
 
+impl ApplyEvent<UserSettingsUpdatedEvent> for User {
+    fn apply(&mut self, e: &UserSettingsUpdatedEvent) {
+        self.settings = e.settings.clone();
+        self.version += 1;
+    }
+}
+
 impl ApplyEvent<QuotaReservedEvent> for User {
     fn apply(&mut self, e: &QuotaReservedEvent) {
         let _ = self.quota.reserve_quota(e.bytes);
@@ -112,11 +138,18 @@ impl User {
             version: 1,
             username: request.username.clone(),
             email: request.email.clone(),
+            timezone: request.timezone.clone(),
+            settings: UserSettings::default(),
             quota: QuotaState::new(Byte::from_u64(0), request.quota, quota_max_limit)?,
         };
 
-        let event =
-            UserCreatedEvent::new(request.sub, request.username, request.email, request.quota);
+        let event = UserCreatedEvent::new(
+            request.sub,
+            request.username,
+            request.email,
+            request.timezone,
+            request.quota,
+        );
 
         Ok((user, event))
     }
@@ -131,13 +164,21 @@ impl User {
     }
 
     pub fn release_quota(&mut self, reserved: &QuotaReservedEvent) -> QuotaReleasedEvent {
-        self.quota.release_quota(reserved.bytes);
-        QuotaReleasedEvent::new(
-            self.id,
-            reserved.bytes,
-            self.quota.used(),
-            reserved.metadata.event_id,
-        )
+        self.free_quota(reserved.bytes, reserved.metadata.event_id)
+    }
+
+    /// Give back committed quota, e.g. once the files of a medium are purged
+    pub fn free_quota(&mut self, bytes: Byte, cause_event_id: Uuid) -> QuotaReleasedEvent {
+        self.quota.release_quota(bytes);
+        QuotaReleasedEvent::new(self.id, bytes, self.quota.used(), cause_event_id)
+    }
+
+    pub fn update_settings(&mut self, settings: UserSettings) -> Option<UserSettingsUpdatedEvent> {
+        if self.settings == settings {
+            return None;
+        }
+        self.settings = settings.clone();
+        Some(UserSettingsUpdatedEvent::new(self.id, settings))
     }
 
     pub fn update(
@@ -170,6 +211,17 @@ impl User {
             }
         }
 
+        if let Some(timezone) = request.timezone {
+            if self.timezone.as_ref() != Some(&timezone) {
+                if let Some(old_timezone) = &self.timezone {
+                    builder.old_timezone(old_timezone.clone());
+                }
+                builder.new_timezone(timezone.clone());
+                self.timezone = Some(timezone);
+                changed = true;
+            }
+        }
+
         if let Some(new_quota) = request.quota {
             if self.quota.limit() != new_quota {
                 builder.old_quota(self.quota.limit());
@@ -194,6 +246,7 @@ pub struct UserCreateRequest {
     pub sub: UserId,
     pub username: String,
     pub email: Option<String>,
+    pub timezone: Option<String>,
     pub quota: Byte,
 }
 
@@ -203,6 +256,8 @@ pub struct UserUpdateRequest {
     pub username: Option<String>,
     #[builder(setter(into), default)]
     pub email: Option<String>,
+    #[builder(setter(into), default)]
+    pub timezone: Option<String>,
     #[builder(setter(into, strip_option), default)]
     pub quota: Option<Byte>,
 }
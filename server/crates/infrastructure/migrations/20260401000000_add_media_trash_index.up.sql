@@ -0,0 +1,3 @@
+-- Speeds up trash listing and retention-based purging
+CREATE INDEX idx_media_owner_deleted_at ON media (owner_id, deleted_at)
+    WHERE deleted_at IS NOT NULL;
@@ -0,0 +1 @@
+ALTER TYPE task_type_enum ADD VALUE IF NOT EXISTS 'preview_generation';
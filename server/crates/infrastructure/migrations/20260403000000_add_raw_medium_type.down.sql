@@ -0,0 +1,7 @@
+-- Postgres cannot drop a single enum value, so the type is rebuilt without it
+UPDATE media SET medium_type = 'photo' WHERE medium_type = 'raw';
+ALTER TYPE medium_type_enum RENAME TO medium_type_enum_old;
+CREATE TYPE medium_type_enum AS ENUM ('photo', 'video', 'live_photo', 'vector', 'sequence', 'gif', 'other');
+ALTER TABLE media
+    ALTER COLUMN medium_type TYPE medium_type_enum USING medium_type::text::medium_type_enum;
+DROP TYPE medium_type_enum_old;
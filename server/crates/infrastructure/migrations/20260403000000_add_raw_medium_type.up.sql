@@ -0,0 +1 @@
+ALTER TYPE medium_type_enum ADD VALUE IF NOT EXISTS 'raw';
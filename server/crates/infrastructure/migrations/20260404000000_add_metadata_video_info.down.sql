@@ -0,0 +1,7 @@
+ALTER TABLE metadata
+    DROP COLUMN video_duration,
+    DROP COLUMN video_frame_rate,
+    DROP COLUMN video_codec,
+    DROP COLUMN audio_codec,
+    DROP COLUMN video_bitrate,
+    DROP COLUMN video_rotation;
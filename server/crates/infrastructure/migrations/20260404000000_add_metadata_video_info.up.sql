@@ -0,0 +1,8 @@
+-- Video info
+ALTER TABLE metadata
+    ADD COLUMN video_duration DOUBLE PRECISION,
+    ADD COLUMN video_frame_rate DOUBLE PRECISION,
+    ADD COLUMN video_codec VARCHAR(100),
+    ADD COLUMN audio_codec VARCHAR(100),
+    ADD COLUMN video_bitrate BIGINT,
+    ADD COLUMN video_rotation SMALLINT;
@@ -0,0 +1,3 @@
+DROP INDEX idx_metadata_content_identifier;
+
+ALTER TABLE metadata DROP COLUMN content_identifier;
@@ -0,0 +1,5 @@
+-- Live Photo pairing, the still and the video share Apple's content identifier
+ALTER TABLE metadata ADD COLUMN content_identifier VARCHAR(100);
+
+CREATE INDEX idx_metadata_content_identifier ON metadata(content_identifier)
+    WHERE content_identifier IS NOT NULL;
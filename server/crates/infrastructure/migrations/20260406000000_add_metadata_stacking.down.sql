@@ -0,0 +1,7 @@
+DROP INDEX idx_metadata_capture_date;
+
+DROP INDEX idx_metadata_burst_id;
+
+ALTER TABLE metadata
+    DROP COLUMN camera_serial_number,
+    DROP COLUMN burst_id;
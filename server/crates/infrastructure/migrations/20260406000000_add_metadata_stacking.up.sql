@@ -0,0 +1,10 @@
+-- Stacking, the RAW and JPEG of a shot and the frames of a burst
+ALTER TABLE metadata
+    ADD COLUMN camera_serial_number VARCHAR(100),
+    ADD COLUMN burst_id VARCHAR(100);
+
+CREATE INDEX idx_metadata_burst_id ON metadata(burst_id)
+    WHERE burst_id IS NOT NULL;
+
+CREATE INDEX idx_metadata_capture_date ON metadata(capture_date)
+    WHERE capture_date IS NOT NULL;
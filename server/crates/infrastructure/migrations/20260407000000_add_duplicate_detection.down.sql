@@ -0,0 +1,7 @@
+ALTER TABLE media
+    DROP COLUMN perceptual_hash;
+
+DROP INDEX idx_medium_items_checksum;
+
+ALTER TABLE medium_items
+    DROP COLUMN checksum;
@@ -0,0 +1,10 @@
+-- Duplicate detection, exact by the checksum of an original, similar by a
+-- perceptual hash of the thumbnail
+ALTER TABLE medium_items
+    ADD COLUMN checksum BYTEA;
+
+CREATE INDEX idx_medium_items_checksum ON medium_items(checksum)
+    WHERE checksum IS NOT NULL;
+
+ALTER TABLE media
+    ADD COLUMN perceptual_hash BIGINT;
@@ -0,0 +1,11 @@
+DROP INDEX idx_metadata_city;
+
+DROP INDEX idx_metadata_region;
+
+DROP INDEX idx_metadata_country;
+
+ALTER TABLE metadata
+    DROP COLUMN country_code,
+    DROP COLUMN country,
+    DROP COLUMN region,
+    DROP COLUMN city;
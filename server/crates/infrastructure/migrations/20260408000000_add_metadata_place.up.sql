@@ -0,0 +1,15 @@
+-- Place names resolved from the GPS coordinates
+ALTER TABLE metadata
+    ADD COLUMN country_code VARCHAR(2),
+    ADD COLUMN country VARCHAR(200),
+    ADD COLUMN region VARCHAR(200),
+    ADD COLUMN city VARCHAR(200);
+
+CREATE INDEX idx_metadata_country ON metadata(LOWER(country))
+    WHERE country IS NOT NULL;
+
+CREATE INDEX idx_metadata_region ON metadata(LOWER(region))
+    WHERE region IS NOT NULL;
+
+CREATE INDEX idx_metadata_city ON metadata(LOWER(city))
+    WHERE city IS NOT NULL;
@@ -0,0 +1,2 @@
+-- Default time zone of media without a recorded UTC offset
+ALTER TABLE users ADD COLUMN timezone VARCHAR(64);
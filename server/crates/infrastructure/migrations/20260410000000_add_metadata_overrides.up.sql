@@ -0,0 +1,3 @@
+-- Values the owner set in place of the extracted ones
+ALTER TABLE metadata
+    ADD COLUMN overrides JSONB NOT NULL DEFAULT '{}';
@@ -0,0 +1,11 @@
+ALTER TABLE tasks
+    DROP COLUMN processed_count,
+    DROP COLUMN total_count;
+
+-- Postgres cannot drop a single enum value, so the type is rebuilt without it
+DELETE FROM tasks WHERE task_type = 'capture_date_shift';
+ALTER TYPE task_type_enum RENAME TO task_type_enum_old;
+CREATE TYPE task_type_enum AS ENUM ('metadata_extraction', 'temp_cleanup', 'preview_generation');
+ALTER TABLE tasks
+    ALTER COLUMN task_type TYPE task_type_enum USING task_type::text::task_type_enum;
+DROP TYPE task_type_enum_old;
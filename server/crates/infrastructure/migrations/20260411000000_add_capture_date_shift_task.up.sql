@@ -0,0 +1,6 @@
+ALTER TYPE task_type_enum ADD VALUE IF NOT EXISTS 'capture_date_shift';
+
+-- How far tasks working through a batch of items got
+ALTER TABLE tasks
+    ADD COLUMN processed_count INTEGER,
+    ADD COLUMN total_count INTEGER;
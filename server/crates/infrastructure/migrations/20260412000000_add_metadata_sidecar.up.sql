@@ -0,0 +1,3 @@
+-- Values of the XMP sidecar, they take precedence over the extracted ones
+ALTER TABLE metadata
+    ADD COLUMN sidecar JSONB;
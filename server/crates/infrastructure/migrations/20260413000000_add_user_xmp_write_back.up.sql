@@ -0,0 +1,2 @@
+-- Whether XMP sidecars are written next to the originals of the user
+ALTER TABLE users ADD COLUMN xmp_write_back BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,10 @@
+DROP INDEX IF EXISTS idx_metadata_extractor_version;
+ALTER TABLE metadata DROP COLUMN extractor_version;
+
+-- Postgres cannot drop a single enum value, so the type is rebuilt without it
+DELETE FROM tasks WHERE task_type = 'metadata_reextraction';
+ALTER TYPE task_type_enum RENAME TO task_type_enum_old;
+CREATE TYPE task_type_enum AS ENUM ('metadata_extraction', 'temp_cleanup', 'preview_generation', 'capture_date_shift');
+ALTER TABLE tasks
+    ALTER COLUMN task_type TYPE task_type_enum USING task_type::text::task_type_enum;
+DROP TYPE task_type_enum_old;
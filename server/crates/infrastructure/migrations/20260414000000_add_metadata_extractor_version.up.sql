@@ -0,0 +1,6 @@
+ALTER TYPE task_type_enum ADD VALUE IF NOT EXISTS 'metadata_reextraction';
+
+-- Version of the extractor the metadata was read with, rows from before
+-- versioning count as outdated
+ALTER TABLE metadata ADD COLUMN extractor_version INTEGER NOT NULL DEFAULT 0;
+CREATE INDEX idx_metadata_extractor_version ON metadata(extractor_version);
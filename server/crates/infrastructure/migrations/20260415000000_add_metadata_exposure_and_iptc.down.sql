@@ -0,0 +1,22 @@
+DROP INDEX IF EXISTS idx_metadata_keywords;
+DROP INDEX IF EXISTS idx_metadata_rating;
+
+ALTER TABLE metadata
+    DROP COLUMN lens_serial_number,
+    DROP COLUMN exposure_program,
+    DROP COLUMN exposure_mode,
+    DROP COLUMN metering_mode,
+    DROP COLUMN white_balance,
+    DROP COLUMN exposure_bias,
+    DROP COLUMN focal_length_35mm,
+    DROP COLUMN software,
+    DROP COLUMN rating,
+    DROP COLUMN keywords,
+    DROP COLUMN caption,
+    DROP COLUMN creator,
+    DROP COLUMN copyright;
+
+DROP TYPE IF EXISTS white_balance_enum;
+DROP TYPE IF EXISTS metering_mode_enum;
+DROP TYPE IF EXISTS exposure_mode_enum;
+DROP TYPE IF EXISTS exposure_program_enum;
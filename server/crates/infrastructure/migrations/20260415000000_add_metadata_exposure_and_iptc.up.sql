@@ -0,0 +1,31 @@
+CREATE TYPE exposure_program_enum AS ENUM (
+    'manual', 'program', 'aperture_priority', 'shutter_priority',
+    'creative', 'action', 'portrait', 'landscape', 'bulb'
+);
+CREATE TYPE exposure_mode_enum AS ENUM ('auto', 'manual', 'auto_bracket');
+CREATE TYPE metering_mode_enum AS ENUM (
+    'average', 'center_weighted_average', 'spot', 'multi_spot',
+    'multi_segment', 'partial', 'other'
+);
+CREATE TYPE white_balance_enum AS ENUM ('auto', 'manual');
+
+ALTER TABLE metadata
+    -- Camera info
+    ADD COLUMN lens_serial_number VARCHAR(100),
+    ADD COLUMN exposure_program exposure_program_enum,
+    ADD COLUMN exposure_mode exposure_mode_enum,
+    ADD COLUMN metering_mode metering_mode_enum,
+    ADD COLUMN white_balance white_balance_enum,
+    ADD COLUMN exposure_bias DOUBLE PRECISION,
+    ADD COLUMN focal_length_35mm DOUBLE PRECISION,
+    -- Technical
+    ADD COLUMN software VARCHAR(255),
+    -- Descriptive values embedded in the file
+    ADD COLUMN rating SMALLINT,
+    ADD COLUMN keywords TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN caption TEXT,
+    ADD COLUMN creator VARCHAR(255),
+    ADD COLUMN copyright TEXT;
+
+CREATE INDEX idx_metadata_rating ON metadata(rating) WHERE rating IS NOT NULL;
+CREATE INDEX idx_metadata_keywords ON metadata USING GIN (keywords);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_media_album_id;
+DROP INDEX IF EXISTS idx_albums_owner_id;
+ALTER TABLE albums DROP COLUMN updated_at, DROP COLUMN cover_medium_id;
@@ -0,0 +1,7 @@
+-- Medium shown for the album, kept by the album projection
+ALTER TABLE albums
+    ADD COLUMN cover_medium_id uuid,
+    ADD COLUMN updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP;
+
+CREATE INDEX idx_albums_owner_id ON albums(owner_id);
+CREATE INDEX idx_media_album_id ON media(album_id) WHERE album_id IS NOT NULL;
@@ -0,0 +1,2 @@
+-- Tags are matched ignoring case, together with the tags below them
+CREATE INDEX idx_media_tags_tag_title ON media_tags (LOWER(tag_title) text_pattern_ops);
@@ -0,0 +1,11 @@
+DROP INDEX IF EXISTS idx_media_owner_favorite;
+DROP INDEX IF EXISTS idx_media_owner_rating;
+
+ALTER TABLE media
+    DROP COLUMN curated,
+    DROP COLUMN rejected,
+    DROP COLUMN color_label,
+    DROP COLUMN rating,
+    DROP COLUMN favorite;
+
+DROP TYPE IF EXISTS color_label_enum;
@@ -0,0 +1,13 @@
+CREATE TYPE color_label_enum AS ENUM ('red', 'yellow', 'green', 'blue', 'purple');
+
+-- Curation of the owner, kept by the medium projection
+ALTER TABLE media
+    ADD COLUMN favorite BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN rating SMALLINT NOT NULL DEFAULT 0 CHECK (rating BETWEEN 0 AND 5),
+    ADD COLUMN color_label color_label_enum,
+    ADD COLUMN rejected BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Set once the owner curated the medium, embedded ratings are no longer taken over
+    ADD COLUMN curated BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_media_owner_rating ON media(owner_id, rating) WHERE deleted_at IS NULL;
+CREATE INDEX idx_media_owner_favorite ON media(owner_id) WHERE favorite AND deleted_at IS NULL;
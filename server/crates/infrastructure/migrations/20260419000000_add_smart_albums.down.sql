@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_smart_albums_owner_id;
+DROP TABLE IF EXISTS smart_albums;
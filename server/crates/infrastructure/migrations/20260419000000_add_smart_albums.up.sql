@@ -0,0 +1,14 @@
+-- Albums whose media are the media of the owner matching a saved filter,
+-- kept by the smart album projection
+CREATE TABLE smart_albums (
+    id uuid PRIMARY KEY,
+    owner_id uuid NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    filter JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP
+);
+
+CREATE INDEX idx_smart_albums_owner_id ON smart_albums(owner_id) WHERE deleted_at IS NULL;
@@ -0,0 +1,3 @@
+-- Version of the extractor extracting the metadata again failed at, the
+-- medium is only tried again once the version rises above it
+ALTER TABLE metadata ADD COLUMN reextraction_failed_version INTEGER;
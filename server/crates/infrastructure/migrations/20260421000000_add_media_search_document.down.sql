@@ -0,0 +1,14 @@
+DROP TRIGGER IF EXISTS media_tags_search_document_refresh ON media_tags;
+DROP TRIGGER IF EXISTS medium_items_search_document_refresh ON medium_items;
+DROP TRIGGER IF EXISTS metadata_search_document_refresh ON metadata;
+DROP TRIGGER IF EXISTS media_search_document_init ON media;
+
+DROP FUNCTION IF EXISTS refresh_media_search_document();
+DROP FUNCTION IF EXISTS init_media_search_document();
+
+DROP INDEX IF EXISTS idx_media_search_document;
+
+ALTER TABLE media
+    DROP COLUMN search_document;
+
+DROP FUNCTION IF EXISTS media_search_document(UUID);
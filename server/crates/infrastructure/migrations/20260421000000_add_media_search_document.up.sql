@@ -0,0 +1,64 @@
+-- The words a search finds a medium by: its title, description, caption and
+-- place names, the filenames of its items and its tags. Filenames are split
+-- into words at `_`, `-` and `.`, tags at `/`.
+CREATE FUNCTION media_search_document(medium UUID) RETURNS TSVECTOR
+    LANGUAGE sql STABLE AS $$
+    SELECT to_tsvector('simple', concat_ws(' ',
+        (SELECT concat_ws(' ', md.sidecar->>'title', md.sidecar->>'description', md.caption,
+                          md.country, md.region, md.city)
+         FROM metadata md WHERE md.medium_id = medium),
+        (SELECT string_agg(translate(fi.filename, '_-.', '   '), ' ') FROM medium_items fi
+         WHERE fi.medium_id = medium AND fi.deleted_at IS NULL),
+        (SELECT string_agg(translate(st.tag_title, '/', ' '), ' ') FROM media_tags st
+         WHERE st.medium_id = medium)))
+$$;
+
+-- Kept up to date by the triggers below, so searches do not build it per row
+ALTER TABLE media
+    ADD COLUMN search_document TSVECTOR NOT NULL DEFAULT ''::TSVECTOR;
+
+UPDATE media SET search_document = media_search_document(id);
+
+CREATE INDEX idx_media_search_document ON media USING GIN (search_document);
+
+CREATE FUNCTION init_media_search_document() RETURNS TRIGGER
+    LANGUAGE plpgsql AS $$
+BEGIN
+    NEW.search_document := media_search_document(NEW.id);
+    RETURN NEW;
+END
+$$;
+
+-- Refreshes the document of the medium a row belongs to, and of the one it
+-- belonged to before when items move between media
+CREATE FUNCTION refresh_media_search_document() RETURNS TRIGGER
+    LANGUAGE plpgsql AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') AND OLD.medium_id IS DISTINCT FROM NEW.medium_id THEN
+        UPDATE media SET search_document = media_search_document(OLD.medium_id)
+        WHERE id = OLD.medium_id;
+    END IF;
+    IF TG_OP <> 'DELETE' THEN
+        UPDATE media SET search_document = media_search_document(NEW.medium_id)
+        WHERE id = NEW.medium_id;
+    END IF;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER media_search_document_init
+    BEFORE INSERT ON media
+    FOR EACH ROW EXECUTE FUNCTION init_media_search_document();
+
+CREATE TRIGGER metadata_search_document_refresh
+    AFTER INSERT OR DELETE
+        OR UPDATE OF medium_id, sidecar, caption, country, region, city ON metadata
+    FOR EACH ROW EXECUTE FUNCTION refresh_media_search_document();
+
+CREATE TRIGGER medium_items_search_document_refresh
+    AFTER INSERT OR DELETE OR UPDATE OF medium_id, filename, deleted_at ON medium_items
+    FOR EACH ROW EXECUTE FUNCTION refresh_media_search_document();
+
+CREATE TRIGGER media_tags_search_document_refresh
+    AFTER INSERT OR DELETE OR UPDATE ON media_tags
+    FOR EACH ROW EXECUTE FUNCTION refresh_media_search_document();
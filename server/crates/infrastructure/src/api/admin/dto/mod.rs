@@ -0,0 +1,3 @@
+mod response;
+
+pub use response::*;
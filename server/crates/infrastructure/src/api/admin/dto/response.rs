@@ -0,0 +1,9 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+/// A metadata re-extraction running in the background, tracked by the task of
+/// the same reference id
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct MetadataReextractionResponse {
+    pub reextraction_id: Uuid,
+}
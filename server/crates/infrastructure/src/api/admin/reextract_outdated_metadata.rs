@@ -0,0 +1,63 @@
+use application::{error::ForbiddenSnafu, metadata::commands::ReextractOutdatedMetadataCommand};
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use snafu::ensure;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::MetadataReextractionResponse;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/metadata/reextract",
+    tag = "admin",
+    responses(
+        (status = 202, content_type = "application/json", description = "Starts extracting the metadata of all outdated media again", body = MetadataReextractionResponse),
+        (status = 403, description = "User is not an administrator"),
+        (status = 409, description = "A re-extraction is already running"),
+    ),
+)]
+pub async fn reextract_outdated_metadata(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<MetadataReextractionResponse>)> {
+    let user_id = claims.user_id();
+
+    ensure!(
+        claims.is_in_group(&state.config.server.admin_group),
+        ForbiddenSnafu {
+            message: "Only administrators can re-extract metadata"
+        }
+    );
+
+    let reextraction_id = Uuid::new_v4();
+    let command = ReextractOutdatedMetadataCommand {
+        reextraction_id,
+        user_id,
+    };
+
+    let handler = state.metadata_handlers.reextract_outdated_metadata.clone();
+    handler.reserve()?;
+
+    // Runs in the background, failures are recorded on the task
+    tokio::spawn(async move {
+        let _ = handler.handle(command).await;
+    });
+
+    info!(
+        user_id = %user_id,
+        reextraction_id = %reextraction_id,
+        "Metadata re-extraction started"
+    );
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(MetadataReextractionResponse { reextraction_id }),
+    ))
+}
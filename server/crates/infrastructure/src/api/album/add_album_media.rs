@@ -0,0 +1,56 @@
+use application::album::commands::AddAlbumMediaCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::{AlbumMediaInput, AlbumResponse};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/{album_id}/media",
+    tag = "album",
+    request_body = AlbumMediaInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Puts the media into the album, moving them out of their previous album", body = AlbumResponse),
+        (status = 404, description = "Album or medium not found"),
+    ),
+    params(
+        ("album_id" = Uuid, Path, description = "The id of the album"),
+    ),
+)]
+pub async fn add_album_media(
+    State(state): State<AppState>,
+    Path(album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<AlbumMediaInput>,
+) -> ApiResult<(StatusCode, Json<AlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = AddAlbumMediaCommand {
+        user_id,
+        album_id,
+        medium_ids: input.medium_ids,
+    };
+
+    let album = state.album_handlers.add_album_media.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        album_id = %album_id,
+        "Media added to album"
+    );
+
+    Ok((StatusCode::OK, Json(album.into())))
+}
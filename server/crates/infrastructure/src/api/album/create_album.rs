@@ -0,0 +1,46 @@
+use application::album::commands::CreateAlbumCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::{AlbumResponse, CreateAlbumInput};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "album",
+    request_body = CreateAlbumInput,
+    responses(
+        (status = 201, content_type = "application/json", description = "The newly created album", body = AlbumResponse),
+        (status = 400, description = "The title is empty or too long"),
+    ),
+)]
+pub async fn create_album(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<CreateAlbumInput>,
+) -> ApiResult<(StatusCode, Json<AlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = CreateAlbumCommand {
+        user_id,
+        title: input.title,
+        description: input.description,
+    };
+
+    let album = state.album_handlers.create_album.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        album_id = %album.id,
+        "Album created"
+    );
+
+    Ok((StatusCode::CREATED, Json(album.into())))
+}
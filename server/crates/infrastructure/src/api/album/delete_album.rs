@@ -0,0 +1,48 @@
+use application::album::commands::DeleteAlbumCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    delete,
+    path = "/{album_id}",
+    tag = "album",
+    responses(
+        (status = 204, description = "Deletes the album, its media are kept"),
+        (status = 404, description = "Album not found"),
+    ),
+    params(
+        ("album_id" = Uuid, Path, description = "The id of the album to delete"),
+    ),
+)]
+pub async fn delete_album(
+    State(state): State<AppState>,
+    Path(album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<StatusCode> {
+    let user_id = claims.user_id();
+
+    let command = DeleteAlbumCommand { user_id, album_id };
+
+    state.album_handlers.delete_album.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        album_id = %album_id,
+        "Album deleted"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,6 @@
+pub mod request;
+pub mod response;
+
+// Re-export commonly used items
+pub use request::*;
+pub use response::*;
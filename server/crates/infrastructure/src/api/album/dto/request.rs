@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateAlbumInput {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// Fields left out stay as they are
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateAlbumInput {
+    pub title: Option<String>,
+    /// An empty description removes it
+    pub description: Option<String>,
+    /// Must be a medium of the album
+    pub cover_medium_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct AlbumMediaInput {
+    pub medium_ids: Vec<Uuid>,
+}
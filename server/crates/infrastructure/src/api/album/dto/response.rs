@@ -0,0 +1,32 @@
+use chrono::{DateTime, FixedOffset};
+use domain::album::Album;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AlbumResponse {
+    pub id: Uuid,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cover_medium_id: Option<Uuid>,
+    pub medium_ids: Vec<Uuid>,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<Album> for AlbumResponse {
+    fn from(album: Album) -> Self {
+        Self {
+            id: album.id,
+            title: album.title,
+            description: album.description,
+            cover_medium_id: album.cover_medium_id,
+            medium_ids: album.medium_ids,
+            created_at: album.created_at.into(),
+            updated_at: album.updated_at.into(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use application::album::queries::FindAlbumQuery;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::AlbumResponse;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "/{album_id}",
+    tag = "album",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets a single album by ID", body = AlbumResponse),
+        (status = 404, description = "Album not found"),
+    ),
+    params(
+        ("album_id" = Uuid, Path, description = "The id of the album"),
+    ),
+)]
+pub async fn get_album(
+    State(state): State<AppState>,
+    Path(album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<AlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let query = FindAlbumQuery { user_id, album_id };
+
+    let album = state.album_handlers.find_album.handle(query).await?;
+
+    info!(
+        user_id = %user_id,
+        album_id = %album_id,
+        "Album retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(album.into())))
+}
@@ -0,0 +1,40 @@
+use application::album::queries::FindAllAlbumsQuery;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::AlbumResponse;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "album",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets all albums of the user, newest first", body = [AlbumResponse]),
+    ),
+)]
+pub async fn get_albums(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<Vec<AlbumResponse>>)> {
+    let user_id = claims.user_id();
+
+    let query = FindAllAlbumsQuery { user_id };
+
+    let albums = state.album_handlers.find_all_albums.handle(query).await?;
+    let responses: Vec<AlbumResponse> = albums.into_iter().map(Into::into).collect();
+
+    info!(
+        user_id = %user_id,
+        count = responses.len(),
+        "Albums retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(responses)))
+}
@@ -0,0 +1,46 @@
+use axum::middleware;
+use jwt_authorizer::layer::AuthorizationLayer;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::{
+    api::state::AppState,
+    auth::{ensure_user_exists, JwtUserClaims},
+};
+
+mod add_album_media;
+mod create_album;
+mod delete_album;
+pub mod dto;
+mod get_album;
+mod get_albums;
+mod remove_album_media;
+mod update_album;
+
+/// Returns routes with OpenAPI metadata. No state or layers needed.
+pub fn routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        // route /
+        .routes(routes!(create_album::create_album, get_albums::get_albums))
+        // route /{album_id}
+        .routes(routes!(
+            get_album::get_album,
+            update_album::update_album,
+            delete_album::delete_album,
+        ))
+        // route /{album_id}/media
+        .routes(routes!(
+            add_album_media::add_album_media,
+            remove_album_media::remove_album_media,
+        ))
+}
+
+/// Full router with authorization layers and state.
+pub fn router(state: AppState, authorization: AuthorizationLayer<JwtUserClaims>) -> OpenApiRouter {
+    routes()
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            ensure_user_exists,
+        ))
+        .layer(authorization)
+        .with_state(state)
+}
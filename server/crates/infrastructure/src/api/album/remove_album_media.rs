@@ -0,0 +1,60 @@
+use application::album::commands::RemoveAlbumMediaCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::{AlbumMediaInput, AlbumResponse};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    delete,
+    path = "/{album_id}/media",
+    tag = "album",
+    request_body = AlbumMediaInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Takes the media out of the album, they stay in the library", body = AlbumResponse),
+        (status = 404, description = "Album not found"),
+    ),
+    params(
+        ("album_id" = Uuid, Path, description = "The id of the album"),
+    ),
+)]
+pub async fn remove_album_media(
+    State(state): State<AppState>,
+    Path(album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<AlbumMediaInput>,
+) -> ApiResult<(StatusCode, Json<AlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = RemoveAlbumMediaCommand {
+        user_id,
+        album_id,
+        medium_ids: input.medium_ids,
+    };
+
+    let album = state
+        .album_handlers
+        .remove_album_media
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        album_id = %album_id,
+        "Media removed from album"
+    );
+
+    Ok((StatusCode::OK, Json(album.into())))
+}
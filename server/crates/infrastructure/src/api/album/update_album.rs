@@ -0,0 +1,59 @@
+use application::album::commands::UpdateAlbumCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::{AlbumResponse, UpdateAlbumInput};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    patch,
+    path = "/{album_id}",
+    tag = "album",
+    request_body = UpdateAlbumInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Changes the title, description or cover of the album", body = AlbumResponse),
+        (status = 400, description = "The title is invalid or the cover is not in the album"),
+        (status = 404, description = "Album not found"),
+    ),
+    params(
+        ("album_id" = Uuid, Path, description = "The id of the album to update"),
+    ),
+)]
+pub async fn update_album(
+    State(state): State<AppState>,
+    Path(album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<UpdateAlbumInput>,
+) -> ApiResult<(StatusCode, Json<AlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = UpdateAlbumCommand {
+        user_id,
+        album_id,
+        title: input.title,
+        description: input.description,
+        cover_medium_id: input.cover_medium_id,
+    };
+
+    let album = state.album_handlers.update_album.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        album_id = %album_id,
+        "Album updated"
+    );
+
+    Ok((StatusCode::OK, Json(album.into())))
+}
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ResolveDuplicatesInput {
+    /// The medium that stays in the library
+    pub keep_medium_id: Uuid,
+    /// The duplicates of it, they are moved to the trash
+    pub medium_ids: Vec<Uuid>,
+}
@@ -0,0 +1,39 @@
+use domain::medium::{DuplicateCluster, DuplicateKind};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicateKindDto {
+    /// The originals are byte for byte identical
+    Exact,
+    /// The images look alike
+    Similar,
+}
+
+/// Media that are copies of each other
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DuplicateClusterResponse {
+    pub kind: DuplicateKindDto,
+    /// Oldest first
+    pub medium_ids: Vec<Uuid>,
+}
+
+impl From<DuplicateKind> for DuplicateKindDto {
+    fn from(kind: DuplicateKind) -> Self {
+        match kind {
+            DuplicateKind::Exact => DuplicateKindDto::Exact,
+            DuplicateKind::Similar => DuplicateKindDto::Similar,
+        }
+    }
+}
+
+impl From<DuplicateCluster> for DuplicateClusterResponse {
+    fn from(cluster: DuplicateCluster) -> Self {
+        Self {
+            kind: cluster.kind.into(),
+            medium_ids: cluster.medium_ids,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use application::medium::queries::FindDuplicatesQuery;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::DuplicateClusterResponse;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "duplicate",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets the clusters of exact and near-duplicate media", body = [DuplicateClusterResponse]),
+    ),
+)]
+pub async fn get_duplicates(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<Vec<DuplicateClusterResponse>>)> {
+    let user_id = claims.user_id();
+
+    let query = FindDuplicatesQuery { user_id };
+
+    let clusters = state.medium_handlers.find_duplicates.handle(query).await?;
+
+    let responses: Vec<DuplicateClusterResponse> = clusters.into_iter().map(Into::into).collect();
+
+    info!(
+        user_id = %user_id,
+        count = responses.len(),
+        "Duplicates retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(responses)))
+}
@@ -0,0 +1,51 @@
+use application::medium::commands::ResolveDuplicatesCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::ResolveDuplicatesInput;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/resolve",
+    tag = "duplicate",
+    request_body = ResolveDuplicatesInput,
+    responses(
+        (status = 204, description = "Keeps one medium and moves its duplicates to the trash"),
+        (status = 400, description = "No duplicates to move to the trash"),
+        (status = 404, description = "Medium not found"),
+    ),
+)]
+pub async fn resolve_duplicates(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<ResolveDuplicatesInput>,
+) -> ApiResult<StatusCode> {
+    let user_id = claims.user_id();
+
+    let command = ResolveDuplicatesCommand {
+        user_id,
+        keep_medium_id: input.keep_medium_id,
+        medium_ids: input.medium_ids,
+    };
+
+    state
+        .medium_handlers
+        .resolve_duplicates
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        keep_medium_id = %input.keep_medium_id,
+        "Duplicates resolved"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -44,7 +44,13 @@ impl IntoResponse for ApiError {
             ApplicationError::Internal { .. } => {
                 (StatusCode::INTERNAL_SERVER_ERROR, self.0.to_string())
             }
-            ApplicationError::Conflict { .. } => (StatusCode::CONFLICT, self.0.to_string()),
+            ApplicationError::Forbidden { .. } => (StatusCode::FORBIDDEN, self.0.to_string()),
+            ApplicationError::UnsupportedMediaType { .. } => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, self.0.to_string())
+            }
+            ApplicationError::Conflict { .. } | ApplicationError::Duplicate { .. } => {
+                (StatusCode::CONFLICT, self.0.to_string())
+            }
         };
 
         warn!(
@@ -53,6 +59,14 @@ impl IntoResponse for ApiError {
             "Application error converted to HTTP response"
         );
 
-        (status, Json(json!({ "error": message }))).into_response()
+        // Point the client to the medium the upload duplicates
+        let body = match &self.0 {
+            ApplicationError::Duplicate { medium_id } => {
+                json!({ "error": message, "medium_id": medium_id })
+            }
+            _ => json!({ "error": message }),
+        };
+
+        (status, Json(body)).into_response()
     }
 }
@@ -1,3 +1,4 @@
+use application::{error::format_error_with_backtrace, medium::commands::AddMediumItemCommand};
 use axum::{
     body::Body,
     debug_handler,
@@ -9,8 +10,11 @@ use axum_extra::{
     headers::{ContentLength, ContentType},
     TypedHeader,
 };
+use futures_util::TryStreamExt;
 use jwt_authorizer::JwtClaims;
-use tracing::instrument;
+use tokio::io::AsyncRead;
+use tokio_util::io::StreamReader;
+use tracing::{error, info, instrument};
 use uuid::Uuid;
 
 use super::dto::{CreateMediumItemInput, MediumItemTypeDto};
@@ -19,7 +23,7 @@ use crate::{
     auth::JwtUserClaims,
 };
 
-#[instrument(skip(state))]
+#[instrument(skip(state, body))]
 #[debug_handler]
 #[utoipa::path(
     post,
@@ -31,8 +35,14 @@ use crate::{
     ),
     responses(
         (status = 201, content_type = "application/json", description = "The id of the new medium item", body = Uuid),
+        (status = 400, description = "The file type does not fit the item format"),
+        (status = 404, description = "Medium not found"),
+    ),
+    params(
+        ("medium_id" = Uuid, Path, description = "The id of the medium to add the item to"),
+        ("format" = MediumItemTypeDto, Path, description = "The kind of item, e.g. an edit or a sidecar"),
+        CreateMediumItemInput,
     ),
-    params(CreateMediumItemInput),
 )]
 pub async fn add_medium_item(
     State(state): State<AppState>,
@@ -40,8 +50,54 @@ pub async fn add_medium_item(
     content_length: TypedHeader<ContentLength>,
     content_type: TypedHeader<ContentType>,
     Query(medium_item_opts): Query<CreateMediumItemInput>,
-    JwtClaims(user): JwtClaims<JwtUserClaims>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
     body: Body,
 ) -> ApiResult<(StatusCode, Json<Uuid>)> {
-    todo!()
+    let user_id = claims.user_id();
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        format = ?format,
+        file_size = content_length.0.0,
+        mime_type = %content_type.0,
+        filename = %medium_item_opts.filename,
+        "Medium item upload initiated"
+    );
+
+    let data_stream = body.into_data_stream();
+    let stream_reader = StreamReader::new(data_stream.map_err(std::io::Error::other));
+    let boxed_reader: Box<dyn AsyncRead + Send + Unpin> = Box::new(stream_reader);
+
+    let command = AddMediumItemCommand {
+        user_id,
+        medium_id,
+        medium_item_type: format.into(),
+        stream: boxed_reader,
+        file_size: content_length.0 .0.into(),
+        mime_type: content_type.0.into(),
+        filename: medium_item_opts.filename,
+        priority: Some(medium_item_opts.priority),
+    };
+
+    match state.medium_handlers.add_medium_item.handle(command).await {
+        Ok(item_id) => {
+            info!(
+                user_id = %user_id,
+                medium_id = %medium_id,
+                item_id = %item_id,
+                "Medium item added successfully"
+            );
+            Ok((StatusCode::CREATED, Json(item_id)))
+        }
+        Err(e) => {
+            error!(
+                user_id = %user_id,
+                medium_id = %medium_id,
+                error = %format_error_with_backtrace(&e),
+                "Failed to add medium item"
+            );
+            Err(e.into())
+        }
+    }
 }
@@ -1,5 +1,7 @@
 use application::{
-    error::format_error_with_backtrace, medium::commands::CreateMediumStreamCommand,
+    album::{commands::AddAlbumMediaCommand, queries::FindAlbumQuery},
+    error::format_error_with_backtrace,
+    medium::commands::{AddMediumTagsCommand, CreateMediumStreamCommand, UploadOutcome},
 };
 use axum::{
     body::Body,
@@ -12,6 +14,7 @@ use axum_extra::{
     headers::{ContentLength, ContentType},
     TypedHeader,
 };
+use domain::medium::Tag;
 use futures_util::TryStreamExt;
 use jwt_authorizer::JwtClaims;
 use tokio::io::AsyncRead;
@@ -37,6 +40,10 @@ use crate::{
     ),
     responses(
         (status = 201, content_type = "application/json", description = "The id of the newly created medium", body = Uuid),
+        (status = 200, content_type = "application/json", description = "The file is already in the library, the id of the existing medium", body = Uuid),
+        (status = 400, description = "A tag is invalid"),
+        (status = 404, description = "The album to put the medium into was not found"),
+        (status = 409, description = "The file is already in the library, the body names the existing medium"),
     ),
     params(CreateMediumInput, CreateMediumItemInput),
 )]
@@ -59,6 +66,13 @@ pub async fn create_medium(
         "Medium upload initiated"
     );
 
+    // Fail before the upload if a tag is invalid or the album does not exist
+    Tag::parse_all(&medium_opts.tags)?;
+    if let Some(album_id) = medium_opts.album_id {
+        let query = FindAlbumQuery { user_id, album_id };
+        state.album_handlers.find_album.handle(query).await?;
+    }
+
     let data_stream = body.into_data_stream();
     let stream_reader = StreamReader::new(data_stream.map_err(std::io::Error::other));
     let boxed_reader: Box<dyn AsyncRead + Send + Unpin> = Box::new(stream_reader);
@@ -76,13 +90,47 @@ pub async fn create_medium(
         camera_model: medium_item_opts.camera_model,
     };
 
-    match state
+    let outcome = state
         .medium_handlers
         .create_medium_stream
         .handle(command)
-        .await
-    {
-        Ok(medium_id) => {
+        .await;
+
+    if let Ok(UploadOutcome::Created(medium_id) | UploadOutcome::Linked(medium_id)) = &outcome {
+        // The medium is stored at this point, failing the upload would only
+        // make the client send the file again
+        if let Some(album_id) = medium_opts.album_id {
+            let command = AddAlbumMediaCommand {
+                user_id,
+                album_id,
+                medium_ids: vec![*medium_id],
+            };
+            if let Err(e) = state.album_handlers.add_album_media.handle(command).await {
+                error!(
+                    user_id = %user_id,
+                    medium_id = %medium_id,
+                    album_id = %album_id,
+                    error = %format_error_with_backtrace(&e),
+                    "Failed to put the uploaded medium into the album"
+                );
+            }
+        }
+        if !medium_opts.tags.is_empty() {
+            let command = AddMediumTagsCommand {
+                user_id,
+                medium_id: *medium_id,
+                tags: medium_opts.tags,
+            };
+            state
+                .medium_handlers
+                .add_medium_tags
+                .handle(command)
+                .await?;
+        }
+    }
+
+    match outcome {
+        Ok(UploadOutcome::Created(medium_id)) => {
             info!(
                 user_id = %user_id,
                 medium_id = %medium_id,
@@ -90,6 +138,14 @@ pub async fn create_medium(
             );
             Ok((StatusCode::CREATED, Json(medium_id)))
         }
+        Ok(UploadOutcome::Linked(medium_id)) => {
+            info!(
+                user_id = %user_id,
+                medium_id = %medium_id,
+                "Upload linked to the existing medium"
+            );
+            Ok((StatusCode::OK, Json(medium_id)))
+        }
         Err(e) => {
             error!(
                 user_id = %user_id,
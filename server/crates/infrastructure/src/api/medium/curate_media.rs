@@ -0,0 +1,47 @@
+use application::medium::commands::CurateMediaCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::{CurateMediaInput, MediaCuratedResponse};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/curation",
+    tag = "medium",
+    request_body = CurateMediaInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Sets the favorite flag, rating, color label or reject flag of the media", body = MediaCuratedResponse),
+        (status = 400, description = "Invalid rating, nothing to change or a medium is in the trash"),
+        (status = 404, description = "Medium not found"),
+    ),
+)]
+pub async fn curate_media(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<CurateMediaInput>,
+) -> ApiResult<(StatusCode, Json<MediaCuratedResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = CurateMediaCommand {
+        user_id,
+        patch: input.patch(),
+        medium_ids: input.medium_ids,
+    };
+
+    let changed = state.medium_handlers.curate_media.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        changed,
+        "Media curated"
+    );
+
+    Ok((StatusCode::OK, Json(MediaCuratedResponse { changed })))
+}
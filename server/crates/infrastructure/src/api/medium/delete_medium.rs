@@ -1,10 +1,11 @@
+use application::medium::commands::DeleteMediumCommand;
 use axum::{
     debug_handler,
     extract::{Path, State},
     http::StatusCode,
 };
 use jwt_authorizer::JwtClaims;
-use tracing::instrument;
+use tracing::{info, instrument};
 use uuid::Uuid;
 
 use crate::{
@@ -19,7 +20,9 @@ use crate::{
     path = "/{medium_id}",
     tag = "medium",
     responses(
-        (status = 204, description = "Deletes the medium"),
+        (status = 204, description = "Moves the medium to the trash"),
+        (status = 400, description = "The medium is already in the trash"),
+        (status = 404, description = "Medium not found"),
     ),
     params(
         ("medium_id" = Uuid, Path, description = "The id of the medium to delete"),
@@ -28,7 +31,19 @@ use crate::{
 pub async fn delete_medium(
     State(state): State<AppState>,
     Path(medium_id): Path<Uuid>,
-    JwtClaims(user): JwtClaims<JwtUserClaims>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
 ) -> ApiResult<StatusCode> {
-    todo!()
+    let user_id = claims.user_id();
+
+    let command = DeleteMediumCommand { user_id, medium_id };
+
+    state.medium_handlers.delete_medium.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        "Medium moved to trash"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
 }
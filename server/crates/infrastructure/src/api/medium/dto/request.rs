@@ -1,10 +1,18 @@
-use chrono::{DateTime, FixedOffset};
+use application::{medium::ports::CaptureDateShiftCriteria, metadata::commands::CaptureDateShift};
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
+use domain::{
+    error::{DomainResult, ValidationSnafu},
+    medium::{CurationFilter, CurationPatch},
+    metadata::{LocationInfo, MetadataOverridePatch},
+};
 use serde::{Deserialize, Serialize};
 use serde_default_utils::*;
+use serde_with::{formats::CommaSeparator, serde_as, StringWithSeparator};
+use snafu::OptionExt;
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use super::types::MediumTypeDto;
+use super::types::{ColorLabelDto, MediumTypeDto};
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Default)]
 pub enum DirectionDto {
@@ -13,11 +21,23 @@ pub enum DirectionDto {
     Desc,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Default)]
+pub enum TagMatchDto {
+    #[default]
+    Any,
+    All,
+}
+
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, IntoParams)]
 pub struct CreateMediumInput {
+    /// Comma separated tags the new medium is tagged with
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
     #[serde(default)]
+    #[param(style = Form, explode = false)]
     pub tags: Vec<String>,
     pub medium_type: Option<MediumTypeDto>,
+    /// Album the new medium is put into
     pub album_id: Option<Uuid>,
 }
 
@@ -32,6 +52,7 @@ pub struct CreateMediumItemInput {
     pub camera_model: Option<String>,
 }
 
+#[serde_as]
 #[derive(Debug, Clone, Deserialize, IntoParams)]
 pub struct FindAllMediaOptions {
     pub start_date: Option<DateTime<chrono::Utc>>,
@@ -41,19 +62,196 @@ pub struct FindAllMediaOptions {
     pub per_page: u64,
     pub page_last_date: Option<DateTime<chrono::Utc>>,
     pub page_last_id: Option<Uuid>,
+    /// Comma separated, a tag also matches the tags below it
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
     #[serde(default)]
+    #[param(style = Form, explode = false)]
     pub tags: Vec<String>,
+    /// Whether media need any or all of the `tags`
+    #[serde(default)]
+    #[param(inline, default = "Any")]
+    pub tag_match: TagMatchDto,
+    /// Only media of the album
     pub album_id: Option<Uuid>,
     #[serde(default)]
     #[param(inline, default = "Desc")]
     pub direction: DirectionDto,
+    /// Together with `album_id`, also media that are in no album
     #[serde(default)]
     #[param(default = false)]
     pub include_no_album: bool,
+    /// Country, region or city the media were taken in
+    pub place: Option<String>,
+    /// Only favorites, or only media that are no favorites
+    pub favorite: Option<bool>,
+    /// Only media rated with at least this many stars
+    #[param(minimum = 0, maximum = 5)]
+    pub min_rating: Option<u8>,
+    #[param(inline)]
+    pub color_label: Option<ColorLabelDto>,
+    /// Only rejected media, or only media that are not rejected
+    pub rejected: Option<bool>,
+}
+
+impl FindAllMediaOptions {
+    pub fn curation(&self) -> CurationFilter {
+        CurationFilter {
+            favorite: self.favorite,
+            min_rating: self.min_rating,
+            color_label: self.color_label.map(Into::into),
+            rejected: self.rejected,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, IntoParams)]
 pub struct GetMediumPreviewOptions {
+    /// Width the preview is displayed at, in pixels
     pub width: Option<i32>,
+    /// Height the preview is displayed at, in pixels
     pub height: Option<i32>,
 }
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct MediumTagsInput {
+    /// Levels of hierarchical tags are separated by `/`
+    pub tags: Vec<String>,
+}
+
+/// Curation applied to all given media. A missing field is left as it is,
+/// a `null` color label removes it.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CurateMediaInput {
+    pub medium_ids: Vec<Uuid>,
+    pub favorite: Option<bool>,
+    /// 0 for unrated and up to 5 stars
+    #[schema(minimum = 0, maximum = 5)]
+    pub rating: Option<u8>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[schema(nullable)]
+    pub color_label: Option<Option<ColorLabelDto>>,
+    pub rejected: Option<bool>,
+}
+
+impl CurateMediaInput {
+    pub fn patch(&self) -> CurationPatch {
+        CurationPatch {
+            favorite: self.favorite,
+            rating: self.rating,
+            color_label: self.color_label.map(|label| label.map(Into::into)),
+            rejected: self.rejected,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct StackMediaInput {
+    /// Media whose items are moved into the stack, they are removed afterwards
+    pub medium_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct SplitMediumInput {
+    /// Items taken out of the stack into a new medium
+    pub item_ids: Vec<Uuid>,
+}
+
+/// Overrides of extracted metadata. A missing field is left as it is, `null`
+/// removes its override and falls back to the extracted value.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct MetadataOverrideInput {
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[schema(nullable)]
+    pub taken_at: Option<Option<DateTime<FixedOffset>>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[schema(nullable)]
+    pub camera_make: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[schema(nullable)]
+    pub camera_model: Option<Option<String>>,
+    #[serde(default, with = "::serde_with::rust::double_option")]
+    #[schema(nullable)]
+    pub location: Option<Option<LocationOverrideInput>>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct LocationOverrideInput {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub altitude: Option<f64>,
+}
+
+impl From<MetadataOverrideInput> for MetadataOverridePatch {
+    fn from(input: MetadataOverrideInput) -> Self {
+        Self {
+            capture_date: input.taken_at,
+            camera_make: input.camera_make,
+            camera_model: input.camera_model,
+            location: input.location.map(|location| {
+                location.map(|l| LocationInfo {
+                    latitude: l.latitude,
+                    longitude: l.longitude,
+                    altitude: l.altitude,
+                    direction: None,
+                    horizontal_position_error: None,
+                })
+            }),
+        }
+    }
+}
+
+/// Corrects the capture dates of the media matching all given criteria, by
+/// either an offset or a time zone
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ShiftCaptureDatesInput {
+    #[serde(default)]
+    pub medium_ids: Vec<Uuid>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    /// Seconds added to the capture dates, negative to move them back
+    pub offset_seconds: Option<i64>,
+    /// Time zone the local capture times are read in, e.g. `Europe/Lisbon`
+    pub timezone: Option<String>,
+}
+
+impl ShiftCaptureDatesInput {
+    pub fn criteria(&self) -> CaptureDateShiftCriteria {
+        CaptureDateShiftCriteria {
+            medium_ids: self.medium_ids.clone(),
+            start_date: self.start_date,
+            end_date: self.end_date,
+            camera_make: self.camera_make.clone(),
+            camera_model: self.camera_model.clone(),
+        }
+    }
+
+    pub fn shift(&self) -> DomainResult<CaptureDateShift> {
+        match (self.offset_seconds, &self.timezone) {
+            (Some(seconds), None) => TimeDelta::try_seconds(seconds)
+                .map(CaptureDateShift::Offset)
+                .context(ValidationSnafu {
+                    message: "offset_seconds is out of range",
+                }),
+            (None, Some(zone)) => Ok(CaptureDateShift::Timezone(zone.clone())),
+            _ => ValidationSnafu {
+                message: "Either offset_seconds or timezone is required",
+            }
+            .fail(),
+        }
+    }
+}
+
+/// Options for geotagging media from a GPX or KML track
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct GeotagFromTrackOptions {
+    /// How far in seconds a capture time may be from the nearest track point
+    #[serde(default = "default_i64::<300>")]
+    #[param(default = 300)]
+    pub tolerance_seconds: i64,
+    /// Only return the proposed locations without applying them
+    #[serde(default)]
+    #[param(default = false)]
+    pub dry_run: bool,
+}
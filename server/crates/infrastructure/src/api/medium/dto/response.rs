@@ -1,16 +1,23 @@
 use std::collections::HashMap;
 
+use application::metadata::commands::GeotagAssignment;
 use byte_unit::Byte;
 use chrono::{DateTime, FixedOffset, Utc};
 use domain::{
     medium::{Medium, MediumItem, MediumListItem, StorageTier},
-    metadata::{CameraInfo, FileInfo, LocationInfo, Metadata, Orientation, TechnicalInfo},
+    metadata::{
+        CameraInfo, DescriptiveInfo, ExposureMode, ExposureProgram, FileInfo, LocationInfo,
+        Metadata, MetadataOverrides, MeteringMode, Orientation, Place, SidecarMetadata,
+        TechnicalInfo, VideoInfo, WhiteBalance,
+    },
 };
 use mime_serde_shim::Wrapper as Mime;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::types::{FileLocationDto, MediumItemTypeDto, MediumTypeDto, StorageTierDto};
+use super::types::{
+    CurationDto, FileLocationDto, MediumItemTypeDto, MediumTypeDto, StorageTierDto,
+};
 use crate::serde::serialize_byte_as_u64;
 
 /// Response for listing media - optimized for list views with minimal data
@@ -26,6 +33,12 @@ pub struct MediumListResponse {
     pub camera_make: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub camera_model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place: Option<PlaceDto>,
+    pub curation: CurationDto,
+    /// Set when the medium is in the trash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<FixedOffset>>,
     pub items: Vec<MediumItemResponse>,
 }
 
@@ -42,8 +55,14 @@ pub struct MediumDetailResponse {
     pub camera_make: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub camera_model: Option<String>,
+    /// Levels of hierarchical tags are separated by `/`
+    pub tags: Vec<String>,
+    pub curation: CurationDto,
     pub created_at: DateTime<FixedOffset>,
     pub updated_at: DateTime<FixedOffset>,
+    /// Set when the medium is in the trash
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<FixedOffset>>,
     pub items: Vec<MediumItemDetailResponse>,
 }
 
@@ -95,9 +114,63 @@ pub struct MediumMetadataDto {
     pub camera_info: Option<CameraInfoDto>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<LocationInfoDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place: Option<PlaceDto>,
     pub technical: TechnicalInfoDto,
+    /// Descriptive values embedded in the file
+    pub descriptive: DescriptiveInfoDto,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video: Option<VideoInfoDto>,
     #[serde(skip_serializing_if = "HashMap::is_empty")]
     pub additional: HashMap<String, String>,
+    /// Values of the XMP sidecar, they take precedence over the extracted ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sidecar: Option<SidecarMetadataDto>,
+    /// Values set by the owner, they take precedence over the extracted ones
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<MetadataOverridesDto>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct SidecarMetadataDto {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    /// -1 for rejected, 0 for unrated and up to 5 stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<i8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crop: Option<CropInfoDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LocationInfoDto>,
+}
+
+/// Edges as fractions of the width and height of the original
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CropInfoDto {
+    pub top: f64,
+    pub left: f64,
+    pub bottom: f64,
+    pub right: f64,
+    /// Rotation in degrees
+    pub angle: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct MetadataOverridesDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taken_at: Option<DateTime<FixedOffset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_make: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub camera_model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LocationInfoDto>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
@@ -133,6 +206,21 @@ pub struct CameraInfoDto {
     pub focal_length: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub flash: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lens_serial_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposure_program: Option<ExposureProgramDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposure_mode: Option<ExposureModeDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metering_mode: Option<MeteringModeDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub white_balance: Option<WhiteBalanceDto>,
+    /// Exposure compensation in EV
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exposure_bias: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focal_length_35mm: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
@@ -147,6 +235,18 @@ pub struct LocationInfoDto {
     pub horizontal_position_error: Option<f64>,
 }
 
+/// Place name resolved from the GPS coordinates
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PlaceDto {
+    /// ISO 3166-1 alpha-2 code
+    pub country_code: String,
+    pub country: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct TechnicalInfoDto {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -155,6 +255,43 @@ pub struct TechnicalInfoDto {
     pub height: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub orientation: Option<OrientationDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub software: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct DescriptiveInfoDto {
+    /// -1 for rejected, 0 for unrated and up to 5 stars
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<i8>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct VideoInfoDto {
+    /// Duration in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+    /// Frames per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frame_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub video_codec: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_codec: Option<String>,
+    /// Average bitrate in bits per second
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitrate: Option<u64>,
+    /// Clockwise rotation in degrees
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<u16>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
@@ -170,6 +307,47 @@ pub enum OrientationDto {
     Rotate270Cw,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExposureProgramDto {
+    Manual,
+    Program,
+    AperturePriority,
+    ShutterPriority,
+    Creative,
+    Action,
+    Portrait,
+    Landscape,
+    Bulb,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExposureModeDto {
+    Auto,
+    Manual,
+    AutoBracket,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MeteringModeDto {
+    Average,
+    CenterWeightedAverage,
+    Spot,
+    MultiSpot,
+    MultiSegment,
+    Partial,
+    Other,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WhiteBalanceDto {
+    Auto,
+    Manual,
+}
+
 // Conversion implementations
 
 impl From<(&MediumItem, bool)> for MediumItemResponse {
@@ -221,10 +399,13 @@ impl From<&MediumListItem> for MediumListResponse {
         Self {
             id: list_item.id,
             medium_type: MediumTypeDto::from(list_item.medium_type),
-            album_id: None, // TODO: Add album_id to event entity
+            album_id: list_item.album_id,
             taken_at: list_item.taken_at,
             camera_make: list_item.camera_make.clone(),
             camera_model: list_item.camera_model.clone(),
+            place: list_item.place.as_ref().map(Into::into),
+            curation: list_item.curation.into(),
+            deleted_at: list_item.deleted_at.map(Into::into),
             items: list_item
                 .items
                 .iter()
@@ -243,8 +424,11 @@ impl From<Medium> for MediumDetailResponse {
             taken_at: medium.taken_at,
             camera_make: medium.camera_make.clone(),
             camera_model: medium.camera_model.clone(),
+            tags: medium.tags.iter().map(ToString::to_string).collect(),
+            curation: medium.curation.into(),
             created_at: medium.created_at.into(),
             updated_at: medium.updated_at.into(),
+            deleted_at: medium.deleted_at.map(Into::into),
             items: medium
                 .items
                 .iter()
@@ -262,8 +446,44 @@ impl From<&Metadata> for MediumMetadataDto {
             file_info: (&metadata.file_info).into(),
             camera_info: metadata.camera_info.as_ref().map(|c| c.into()),
             location: metadata.location.as_ref().map(|l| l.into()),
+            place: metadata.place.as_ref().map(|p| p.into()),
             technical: (&metadata.technical).into(),
+            descriptive: (&metadata.descriptive).into(),
+            video: metadata.video.as_ref().map(|v| v.into()),
             additional: metadata.additional.clone(),
+            sidecar: metadata.sidecar.as_ref().map(|s| s.into()),
+            overrides: (!metadata.overrides.is_empty()).then(|| (&metadata.overrides).into()),
+        }
+    }
+}
+
+impl From<&SidecarMetadata> for SidecarMetadataDto {
+    fn from(sidecar: &SidecarMetadata) -> Self {
+        Self {
+            keywords: sidecar.keywords.clone(),
+            rating: sidecar.rating,
+            label: sidecar.label.clone(),
+            title: sidecar.title.clone(),
+            description: sidecar.description.clone(),
+            crop: sidecar.crop.as_ref().map(|c| CropInfoDto {
+                top: c.top,
+                left: c.left,
+                bottom: c.bottom,
+                right: c.right,
+                angle: c.angle,
+            }),
+            location: sidecar.location.as_ref().map(|l| l.into()),
+        }
+    }
+}
+
+impl From<&MetadataOverrides> for MetadataOverridesDto {
+    fn from(overrides: &MetadataOverrides) -> Self {
+        Self {
+            taken_at: overrides.capture_date,
+            camera_make: overrides.camera_make.clone(),
+            camera_model: overrides.camera_model.clone(),
+            location: overrides.location.as_ref().map(|l| l.into()),
         }
     }
 }
@@ -292,6 +512,13 @@ impl From<&CameraInfo> for CameraInfoDto {
             iso: info.iso,
             focal_length: info.focal_length,
             flash: info.flash,
+            lens_serial_number: info.lens_serial_number.clone(),
+            exposure_program: info.exposure_program.map(Into::into),
+            exposure_mode: info.exposure_mode.map(Into::into),
+            metering_mode: info.metering_mode.map(Into::into),
+            white_balance: info.white_balance.map(Into::into),
+            exposure_bias: info.exposure_bias,
+            focal_length_35mm: info.focal_length_35mm,
         }
     }
 }
@@ -308,12 +535,49 @@ impl From<&LocationInfo> for LocationInfoDto {
     }
 }
 
+impl From<&Place> for PlaceDto {
+    fn from(place: &Place) -> Self {
+        Self {
+            country_code: place.country_code.clone(),
+            country: place.country.clone(),
+            region: place.region.clone(),
+            city: place.city.clone(),
+        }
+    }
+}
+
 impl From<&TechnicalInfo> for TechnicalInfoDto {
     fn from(info: &TechnicalInfo) -> Self {
         Self {
             width: info.width,
             height: info.height,
             orientation: info.orientation.as_ref().map(|o| o.into()),
+            software: info.software.clone(),
+        }
+    }
+}
+
+impl From<&DescriptiveInfo> for DescriptiveInfoDto {
+    fn from(info: &DescriptiveInfo) -> Self {
+        Self {
+            rating: info.rating,
+            keywords: info.keywords.clone(),
+            caption: info.caption.clone(),
+            creator: info.creator.clone(),
+            copyright: info.copyright.clone(),
+        }
+    }
+}
+
+impl From<&VideoInfo> for VideoInfoDto {
+    fn from(info: &VideoInfo) -> Self {
+        Self {
+            duration: info.duration,
+            frame_rate: info.frame_rate,
+            video_codec: info.video_codec.clone(),
+            audio_codec: info.audio_codec.clone(),
+            bitrate: info.bitrate,
+            rotation: info.rotation,
         }
     }
 }
@@ -336,3 +600,90 @@ impl From<&Orientation> for OrientationDto {
         }
     }
 }
+
+impl From<ExposureProgram> for ExposureProgramDto {
+    fn from(program: ExposureProgram) -> Self {
+        match program {
+            ExposureProgram::Manual => ExposureProgramDto::Manual,
+            ExposureProgram::Program => ExposureProgramDto::Program,
+            ExposureProgram::AperturePriority => ExposureProgramDto::AperturePriority,
+            ExposureProgram::ShutterPriority => ExposureProgramDto::ShutterPriority,
+            ExposureProgram::Creative => ExposureProgramDto::Creative,
+            ExposureProgram::Action => ExposureProgramDto::Action,
+            ExposureProgram::Portrait => ExposureProgramDto::Portrait,
+            ExposureProgram::Landscape => ExposureProgramDto::Landscape,
+            ExposureProgram::Bulb => ExposureProgramDto::Bulb,
+        }
+    }
+}
+
+impl From<ExposureMode> for ExposureModeDto {
+    fn from(mode: ExposureMode) -> Self {
+        match mode {
+            ExposureMode::Auto => ExposureModeDto::Auto,
+            ExposureMode::Manual => ExposureModeDto::Manual,
+            ExposureMode::AutoBracket => ExposureModeDto::AutoBracket,
+        }
+    }
+}
+
+impl From<MeteringMode> for MeteringModeDto {
+    fn from(mode: MeteringMode) -> Self {
+        match mode {
+            MeteringMode::Average => MeteringModeDto::Average,
+            MeteringMode::CenterWeightedAverage => MeteringModeDto::CenterWeightedAverage,
+            MeteringMode::Spot => MeteringModeDto::Spot,
+            MeteringMode::MultiSpot => MeteringModeDto::MultiSpot,
+            MeteringMode::MultiSegment => MeteringModeDto::MultiSegment,
+            MeteringMode::Partial => MeteringModeDto::Partial,
+            MeteringMode::Other => MeteringModeDto::Other,
+        }
+    }
+}
+
+impl From<WhiteBalance> for WhiteBalanceDto {
+    fn from(balance: WhiteBalance) -> Self {
+        match balance {
+            WhiteBalance::Auto => WhiteBalanceDto::Auto,
+            WhiteBalance::Manual => WhiteBalanceDto::Manual,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct MediaCuratedResponse {
+    /// Media whose curation changed
+    pub changed: u64,
+}
+
+/// A capture date shift running in the background, tracked by the task of
+/// the same reference id
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct CaptureDateShiftResponse {
+    pub shift_id: Uuid,
+}
+
+/// The locations found for media on a track
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct GeotagFromTrackResponse {
+    /// Whether the locations were only proposed
+    pub dry_run: bool,
+    pub assignments: Vec<GeotagAssignmentDto>,
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct GeotagAssignmentDto {
+    pub medium_id: Uuid,
+    pub taken_at: DateTime<Utc>,
+    pub location: LocationInfoDto,
+}
+
+impl From<&GeotagAssignment> for GeotagAssignmentDto {
+    fn from(assignment: &GeotagAssignment) -> Self {
+        Self {
+            medium_id: assignment.medium_id,
+            taken_at: assignment.taken_at,
+            location: (&assignment.location).into(),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use domain::medium::{MediumItemType, MediumType};
+use domain::medium::{ColorLabel, Curation, MediumItemType, MediumType};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -11,6 +11,7 @@ pub enum MediumTypeDto {
     Vector,
     Sequence,
     Gif,
+    Raw,
     Other,
 }
 
@@ -23,6 +24,7 @@ impl From<MediumType> for MediumTypeDto {
             MediumType::Vector => MediumTypeDto::Vector,
             MediumType::Sequence => MediumTypeDto::Sequence,
             MediumType::Gif => MediumTypeDto::Gif,
+            MediumType::Raw => MediumTypeDto::Raw,
             MediumType::Other => MediumTypeDto::Other,
         }
     }
@@ -37,6 +39,7 @@ impl From<MediumTypeDto> for MediumType {
             MediumTypeDto::Vector => MediumType::Vector,
             MediumTypeDto::Sequence => MediumType::Sequence,
             MediumTypeDto::Gif => MediumType::Gif,
+            MediumTypeDto::Raw => MediumType::Raw,
             MediumTypeDto::Other => MediumType::Other,
         }
     }
@@ -62,6 +65,72 @@ impl From<MediumItemType> for MediumItemTypeDto {
     }
 }
 
+impl From<MediumItemTypeDto> for MediumItemType {
+    fn from(dto: MediumItemTypeDto) -> Self {
+        match dto {
+            MediumItemTypeDto::Original => MediumItemType::Original,
+            MediumItemTypeDto::Edit => MediumItemType::Edit,
+            MediumItemTypeDto::Preview => MediumItemType::Preview,
+            MediumItemTypeDto::Sidecar => MediumItemType::Sidecar,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorLabelDto {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+impl From<ColorLabel> for ColorLabelDto {
+    fn from(label: ColorLabel) -> Self {
+        match label {
+            ColorLabel::Red => ColorLabelDto::Red,
+            ColorLabel::Yellow => ColorLabelDto::Yellow,
+            ColorLabel::Green => ColorLabelDto::Green,
+            ColorLabel::Blue => ColorLabelDto::Blue,
+            ColorLabel::Purple => ColorLabelDto::Purple,
+        }
+    }
+}
+
+impl From<ColorLabelDto> for ColorLabel {
+    fn from(dto: ColorLabelDto) -> Self {
+        match dto {
+            ColorLabelDto::Red => ColorLabel::Red,
+            ColorLabelDto::Yellow => ColorLabel::Yellow,
+            ColorLabelDto::Green => ColorLabel::Green,
+            ColorLabelDto::Blue => ColorLabel::Blue,
+            ColorLabelDto::Purple => ColorLabel::Purple,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CurationDto {
+    pub favorite: bool,
+    /// 0 for unrated and up to 5 stars
+    pub rating: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_label: Option<ColorLabelDto>,
+    pub rejected: bool,
+}
+
+impl From<Curation> for CurationDto {
+    fn from(curation: Curation) -> Self {
+        Self {
+            favorite: curation.favorite,
+            rating: curation.rating,
+            color_label: curation.color_label.map(Into::into),
+            rejected: curation.rejected,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum StorageTierDto {
@@ -0,0 +1,106 @@
+use application::{error::UnsupportedMediaTypeSnafu, metadata::commands::GeotagFromTrackCommand};
+use axum::{
+    body::{self, Body},
+    debug_handler,
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use axum_extra::{headers::ContentType, TypedHeader};
+use chrono::TimeDelta;
+use domain::error::ValidationSnafu;
+use jwt_authorizer::JwtClaims;
+use mime::Mime;
+use snafu::{ensure, OptionExt};
+use tracing::{info, instrument};
+
+use super::dto::{GeotagFromTrackOptions, GeotagFromTrackResponse};
+use crate::{
+    api::{error::ApiResult, router::Binary, state::AppState},
+    auth::JwtUserClaims,
+    external::track::parse_track,
+};
+
+/// Tracks are read into memory, larger ones are rejected
+const MAX_TRACK_SIZE: usize = 50 * 1024 * 1024;
+
+const GPX_CONTENT_TYPE: &str = "application/gpx+xml";
+const KML_CONTENT_TYPE: &str = "application/vnd.google-earth.kml+xml";
+
+#[instrument(skip(state, body))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/geotag",
+    tag = "medium",
+    request_body(
+        description = "A GPX or KML track",
+        content(
+            (Binary = "application/gpx+xml"),
+            (Binary = "application/vnd.google-earth.kml+xml"),
+        ),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The locations found for media without GPS coordinates", body = GeotagFromTrackResponse),
+        (status = 400, description = "Invalid track or tolerance"),
+        (status = 415, description = "The track is neither GPX nor KML"),
+    ),
+    params(GeotagFromTrackOptions),
+)]
+pub async fn geotag_from_track(
+    State(state): State<AppState>,
+    Query(options): Query<GeotagFromTrackOptions>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    content_type: Option<TypedHeader<ContentType>>,
+    body: Body,
+) -> ApiResult<(StatusCode, Json<GeotagFromTrackResponse>)> {
+    let user_id = claims.user_id();
+
+    let mime = content_type.map(|TypedHeader(content_type)| Mime::from(content_type));
+    ensure!(
+        mime.as_ref().is_some_and(|mime| {
+            mime.essence_str() == GPX_CONTENT_TYPE || mime.essence_str() == KML_CONTENT_TYPE
+        }),
+        UnsupportedMediaTypeSnafu {
+            message: format!("Expected {GPX_CONTENT_TYPE} or {KML_CONTENT_TYPE}"),
+        }
+    );
+
+    let data = body::to_bytes(body, MAX_TRACK_SIZE)
+        .await
+        .ok()
+        .context(ValidationSnafu {
+            message: "The track could not be read",
+        })?;
+    let track = parse_track(&data)?;
+
+    let command = GeotagFromTrackCommand {
+        user_id,
+        track,
+        tolerance: TimeDelta::try_seconds(options.tolerance_seconds).context(ValidationSnafu {
+            message: "tolerance_seconds is out of range",
+        })?,
+        dry_run: options.dry_run,
+    };
+
+    let assignments = state
+        .metadata_handlers
+        .geotag_from_track
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        media = assignments.len(),
+        dry_run = options.dry_run,
+        "Media geotagged from track"
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(GeotagFromTrackResponse {
+            dry_run: options.dry_run,
+            assignments: assignments.iter().map(Into::into).collect(),
+        }),
+    ))
+}
@@ -6,13 +6,13 @@ use axum::{
     Json,
 };
 use domain::{
-    medium::MediumFilter,
+    medium::{MediumFilter, TagMatch},
     shared::{KeysetCursor, SortDirection},
 };
 use jwt_authorizer::JwtClaims;
 use tracing::{info, instrument};
 
-use super::dto::{DirectionDto, FindAllMediaOptions, MediumListResponse};
+use super::dto::{DirectionDto, FindAllMediaOptions, MediumListResponse, TagMatchDto};
 use crate::{
     api::{error::ApiResult, state::AppState},
     auth::JwtUserClaims,
@@ -26,6 +26,7 @@ use crate::{
     tag = "medium",
     responses(
         (status = 200, content_type = "application/json", description = "Gets all media. Can be filtered by date", body = [MediumListResponse]),
+        (status = 400, description = "Invalid filter, e.g. an empty tag"),
     ),
     params(FindAllMediaOptions),
 )]
@@ -43,6 +44,7 @@ pub async fn get_all_media(
         "Fetching all media for user"
     );
 
+    let curation = find_all_media_opts.curation();
     let filter = MediumFilter::new(
         find_all_media_opts.start_date,
         find_all_media_opts.end_date,
@@ -61,7 +63,13 @@ pub async fn get_all_media(
             DirectionDto::Desc => SortDirection::Descending,
         }),
         find_all_media_opts.include_no_album,
-    )?;
+    )?
+    .with_place(find_all_media_opts.place)
+    .with_tag_match(match find_all_media_opts.tag_match {
+        TagMatchDto::Any => TagMatch::Any,
+        TagMatchDto::All => TagMatch::All,
+    })
+    .with_curation(curation)?;
 
     let query = FindAllMediaQuery { user_id, filter };
 
@@ -1,11 +1,28 @@
+use std::io;
+
+use application::{
+    error::{format_error_with_backtrace, InternalSnafu},
+    medium::queries::{FindMediumItemContentQuery, MediumItemContent},
+};
 use axum::{
     body::Body,
     debug_handler,
     extract::{Path, State},
-    http::{HeaderMap, StatusCode},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+};
+use axum_extra::{
+    headers::{ETag, IfNoneMatch},
+    TypedHeader,
+};
+use bytes::Bytes;
+use futures_util::{
+    stream::{self, BoxStream},
+    StreamExt,
 };
 use jwt_authorizer::JwtClaims;
-use tracing::instrument;
+use tokio::io::AsyncReadExt;
+use tokio_util::io::ReaderStream;
+use tracing::{debug, error, info, instrument};
 use uuid::Uuid;
 
 use crate::{
@@ -13,7 +30,10 @@ use crate::{
     auth::JwtUserClaims,
 };
 
-#[instrument(skip(state))]
+/// Requests with more ranges than this are answered with the full file
+const MAX_RANGES: usize = 16;
+
+#[instrument(skip(state, headers))]
 #[debug_handler]
 #[utoipa::path(
     get,
@@ -21,14 +41,401 @@ use crate::{
     tag = "medium",
     responses(
         (status = 200, description = "The raw file", body = Binary, content_type = "*/*", headers(
-            ("content-type" = String)
+            ("content-type" = String),
+            ("etag" = String),
+            ("last-modified" = String),
+            ("content-disposition" = String),
+        )),
+        (status = 206, description = "The requested byte ranges of the raw file", body = Binary, content_type = "*/*", headers(
+            ("content-type" = String),
+            ("content-range" = String),
         )),
+        (status = 304, description = "The file has not changed"),
+        (status = 416, description = "None of the requested ranges can be satisfied"),
     ),
 )]
 pub async fn get_medium_item(
     State(state): State<AppState>,
     Path((medium_id, item_id)): Path<(Uuid, Uuid)>,
-    JwtClaims(user): JwtClaims<JwtUserClaims>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    if_none_match: Option<TypedHeader<IfNoneMatch>>,
+    headers: HeaderMap,
 ) -> ApiResult<(StatusCode, HeaderMap, Body)> {
-    todo!()
+    let user_id = claims.user_id();
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        item_id = %item_id,
+        "Fetching raw medium item"
+    );
+
+    let handler = &state.medium_handlers.find_medium_item_content;
+    let query = FindMediumItemContentQuery {
+        user_id,
+        medium_id,
+        item_id,
+    };
+    let content = handler.handle(query).await.inspect_err(|e| {
+        error!(
+            user_id = %user_id,
+            error = %format_error_with_backtrace(e),
+            "Failed to resolve medium item content"
+        );
+    })?;
+
+    let etag = format!("\"{}\"", content.validator());
+    let mut response_headers = entity_headers(&content, &etag)?;
+
+    if let Some(TypedHeader(if_none_match)) = if_none_match {
+        let matches = etag
+            .parse::<ETag>()
+            .is_ok_and(|etag| !if_none_match.precondition_passes(&etag));
+        if matches {
+            debug!("Client copy is up to date");
+            return Ok((StatusCode::NOT_MODIFIED, response_headers, Body::empty()));
+        }
+    }
+
+    let size = content.file.size_bytes;
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .filter(|_| if_range_matches(&headers, &etag));
+
+    let ranges = match range.map(|value| parse_range(value, size)) {
+        None | Some(RangeRequest::Full) => Vec::new(),
+        Some(RangeRequest::Partial(ranges)) => ranges,
+        Some(RangeRequest::Unsatisfiable) => {
+            debug!(size, "Requested range is not satisfiable");
+            response_headers.insert(
+                header::CONTENT_RANGE,
+                header_value(format!("bytes */{size}"))?,
+            );
+            return Ok((
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                response_headers,
+                Body::empty(),
+            ));
+        }
+    };
+
+    let mime = content.item.mime.to_string();
+    match ranges.as_slice() {
+        [] => {
+            let stream = handler.open(&content.location, 0).await?;
+            response_headers.insert(header::CONTENT_TYPE, header_value(mime)?);
+            response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(size));
+
+            info!(size, "Serving full medium item");
+            Ok((
+                StatusCode::OK,
+                response_headers,
+                Body::from_stream(ReaderStream::new(stream.take(size))),
+            ))
+        }
+        [range] => {
+            let stream = handler.open(&content.location, range.start).await?;
+            response_headers.insert(header::CONTENT_TYPE, header_value(mime)?);
+            response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(range.len()));
+            response_headers.insert(
+                header::CONTENT_RANGE,
+                header_value(range.content_range(size))?,
+            );
+
+            info!(
+                start = range.start,
+                end = range.end,
+                "Serving medium item range"
+            );
+            Ok((
+                StatusCode::PARTIAL_CONTENT,
+                response_headers,
+                Body::from_stream(ReaderStream::new(stream.take(range.len()))),
+            ))
+        }
+        ranges => {
+            let boundary = Uuid::new_v4().simple().to_string();
+            let closing = Bytes::from(format!("\r\n--{boundary}--\r\n"));
+            let mut content_length = closing.len() as u64;
+            let mut parts: Vec<BoxStream<'static, io::Result<Bytes>>> = Vec::new();
+
+            for range in ranges {
+                let part_header = Bytes::from(format!(
+                    "\r\n--{boundary}\r\n{}: {mime}\r\n{}: {}\r\n\r\n",
+                    header::CONTENT_TYPE,
+                    header::CONTENT_RANGE,
+                    range.content_range(size)
+                ));
+                content_length += part_header.len() as u64 + range.len();
+
+                let stream = handler.open(&content.location, range.start).await?;
+                parts.push(stream::once(async move { Ok(part_header) }).boxed());
+                parts.push(ReaderStream::new(stream.take(range.len())).boxed());
+            }
+            parts.push(stream::once(async move { Ok(closing) }).boxed());
+
+            response_headers.insert(
+                header::CONTENT_TYPE,
+                header_value(format!("multipart/byteranges; boundary={boundary}"))?,
+            );
+            response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(content_length));
+
+            info!(count = ranges.len(), "Serving multiple medium item ranges");
+            Ok((
+                StatusCode::PARTIAL_CONTENT,
+                response_headers,
+                Body::from_stream(stream::iter(parts).flatten()),
+            ))
+        }
+    }
+}
+
+/// Headers describing the stored file, shared by all responses
+fn entity_headers(content: &MediumItemContent, etag: &str) -> ApiResult<HeaderMap> {
+    let last_modified = content
+        .item
+        .updated_at
+        .format("%a, %d %b %Y %H:%M:%S GMT")
+        .to_string();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    headers.insert(header::ETAG, header_value(etag.to_string())?);
+    headers.insert(header::LAST_MODIFIED, header_value(last_modified)?);
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        header_value(content_disposition(content.item.filename.as_str()))?,
+    );
+    Ok(headers)
+}
+
+/// Only honor `Range` when `If-Range` is absent or still names the current entity
+fn if_range_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get(header::IF_RANGE)
+        .map(|value| value.as_bytes() == etag.as_bytes())
+        .unwrap_or(true)
+}
+
+/// Fails instead of sending a blank header when the value is not a valid header
+pub(super) fn header_value(value: String) -> ApiResult<HeaderValue> {
+    HeaderValue::try_from(value.as_str()).map_err(|_| {
+        InternalSnafu {
+            message: format!("{value:?} is not a valid header value"),
+        }
+        .build()
+        .into()
+    })
+}
+
+/// Builds an inline `Content-Disposition` with an ASCII fallback and the
+/// RFC 5987 encoded original filename
+fn content_disposition(filename: &str) -> String {
+    let fallback: String = filename
+        .chars()
+        .map(|c| match c {
+            '"' | '\\' => '_',
+            c if c.is_ascii() && !c.is_ascii_control() => c,
+            _ => '_',
+        })
+        .collect();
+
+    let encoded: String = filename
+        .bytes()
+        .map(|b| match b {
+            b'a'..=b'z'
+            | b'A'..=b'Z'
+            | b'0'..=b'9'
+            | b'!'
+            | b'#'
+            | b'$'
+            | b'&'
+            | b'+'
+            | b'-'
+            | b'.'
+            | b'^'
+            | b'_'
+            | b'`'
+            | b'|'
+            | b'~' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect();
+
+    format!("inline; filename=\"{fallback}\"; filename*=UTF-8''{encoded}")
+}
+
+/// Inclusive byte range within a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ByteRange {
+    start: u64,
+    end: u64,
+}
+
+impl ByteRange {
+    fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+
+    fn content_range(&self, size: u64) -> String {
+        format!("bytes {}-{}/{}", self.start, self.end, size)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RangeRequest {
+    /// The header is malformed or not worth honoring, serve the whole file
+    Full,
+    Partial(Vec<ByteRange>),
+    Unsatisfiable,
+}
+
+/// Parses a `Range` header value against a file of `size` bytes (RFC 9110 §14.2)
+fn parse_range(value: &str, size: u64) -> RangeRequest {
+    let Some(specs) = value.trim().strip_prefix("bytes=") else {
+        return RangeRequest::Full;
+    };
+
+    let mut ranges = Vec::new();
+    for spec in specs.split(',').map(str::trim) {
+        let Some((start, end)) = spec.split_once('-') else {
+            return RangeRequest::Full;
+        };
+
+        let range = match (start.trim(), end.trim()) {
+            ("", "") => return RangeRequest::Full,
+            ("", suffix) => match suffix.parse::<u64>() {
+                Ok(0) => None,
+                Ok(suffix) if size > 0 => Some(ByteRange {
+                    start: size.saturating_sub(suffix),
+                    end: size - 1,
+                }),
+                Ok(_) => None,
+                Err(_) => return RangeRequest::Full,
+            },
+            (start, end) => {
+                let Ok(start) = start.parse::<u64>() else {
+                    return RangeRequest::Full;
+                };
+                let end = match end {
+                    "" => None,
+                    end => match end.parse::<u64>() {
+                        Ok(end) if end >= start => Some(end),
+                        _ => return RangeRequest::Full,
+                    },
+                };
+                (start < size).then(|| ByteRange {
+                    start,
+                    end: end.map_or(size - 1, |end| end.min(size - 1)),
+                })
+            }
+        };
+        ranges.extend(range);
+    }
+
+    if ranges.is_empty() {
+        RangeRequest::Unsatisfiable
+    } else if ranges.len() > MAX_RANGES {
+        RangeRequest::Full
+    } else {
+        RangeRequest::Partial(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u64, end: u64) -> ByteRange {
+        ByteRange { start, end }
+    }
+
+    #[test]
+    fn test_parse_single_range() {
+        assert_eq!(
+            parse_range("bytes=0-99", 1000),
+            RangeRequest::Partial(vec![range(0, 99)])
+        );
+        assert_eq!(
+            parse_range("bytes=900-", 1000),
+            RangeRequest::Partial(vec![range(900, 999)])
+        );
+        assert_eq!(
+            parse_range("bytes=-100", 1000),
+            RangeRequest::Partial(vec![range(900, 999)])
+        );
+    }
+
+    #[test]
+    fn test_parse_range_clamps_to_size() {
+        assert_eq!(
+            parse_range("bytes=500-5000", 1000),
+            RangeRequest::Partial(vec![range(500, 999)])
+        );
+        assert_eq!(
+            parse_range("bytes=-5000", 1000),
+            RangeRequest::Partial(vec![range(0, 999)])
+        );
+    }
+
+    #[test]
+    fn test_parse_multiple_ranges() {
+        assert_eq!(
+            parse_range("bytes=0-9, 20-29,-5", 100),
+            RangeRequest::Partial(vec![range(0, 9), range(20, 29), range(95, 99)])
+        );
+    }
+
+    #[test]
+    fn test_parse_range_skips_unsatisfiable_parts() {
+        assert_eq!(
+            parse_range("bytes=0-9,2000-3000", 100),
+            RangeRequest::Partial(vec![range(0, 9)])
+        );
+        assert_eq!(
+            parse_range("bytes=1000-", 1000),
+            RangeRequest::Unsatisfiable
+        );
+        assert_eq!(parse_range("bytes=-0", 1000), RangeRequest::Unsatisfiable);
+        assert_eq!(parse_range("bytes=0-", 0), RangeRequest::Unsatisfiable);
+    }
+
+    #[test]
+    fn test_parse_range_ignores_invalid_headers() {
+        assert_eq!(parse_range("items=0-9", 100), RangeRequest::Full);
+        assert_eq!(parse_range("bytes=9-0", 100), RangeRequest::Full);
+        assert_eq!(parse_range("bytes=a-b", 100), RangeRequest::Full);
+        assert_eq!(parse_range("bytes=-", 100), RangeRequest::Full);
+        assert_eq!(parse_range("bytes=5", 100), RangeRequest::Full);
+
+        let many = (0..=MAX_RANGES)
+            .map(|i| format!("{i}-{i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        assert_eq!(
+            parse_range(&format!("bytes={many}"), 100),
+            RangeRequest::Full
+        );
+    }
+
+    #[test]
+    fn test_content_disposition_encodes_filename() {
+        assert_eq!(
+            content_disposition("IMG_0001.HEIC"),
+            "inline; filename=\"IMG_0001.HEIC\"; filename*=UTF-8''IMG_0001.HEIC"
+        );
+        assert_eq!(
+            content_disposition("Über \"uns\".jpg"),
+            "inline; filename=\"_ber _uns_.jpg\"; filename*=UTF-8''%C3%9Cber%20%22uns%22.jpg"
+        );
+    }
+
+    #[test]
+    fn test_header_value_rejects_invalid_values() {
+        assert!(header_value("\"abc\"".to_string()).is_ok());
+        assert!(header_value("line\nbreak".to_string()).is_err());
+        // Control characters in a filename never reach the header
+        assert!(header_value(content_disposition("new\nline.jpg")).is_ok());
+    }
 }
@@ -1,19 +1,31 @@
+use application::{error::format_error_with_backtrace, medium::queries::FindMediumPreviewQuery};
 use axum::{
     body::Body,
     debug_handler,
     extract::{Path, Query, State},
-    http::{HeaderMap, StatusCode},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
 };
+use axum_extra::{
+    headers::{ETag, IfNoneMatch},
+    TypedHeader,
+};
+use domain::medium::PreviewSize;
 use jwt_authorizer::JwtClaims;
-use tracing::instrument;
+use tokio::io::AsyncReadExt;
+use tokio_util::io::ReaderStream;
+use tracing::{debug, error, info, instrument};
 use uuid::Uuid;
 
-use super::dto::GetMediumPreviewOptions;
+use super::{dto::GetMediumPreviewOptions, get_medium_item::header_value};
 use crate::{
     api::{error::ApiResult, router::Binary, state::AppState},
     auth::JwtUserClaims,
 };
 
+/// Previews are rendered again at the same path when a medium is stacked or
+/// its previews are regenerated, so clients revalidate with the ETag
+const CACHE_CONTROL: &str = "private, no-cache";
+
 #[instrument(skip(state))]
 #[debug_handler]
 #[utoipa::path(
@@ -21,9 +33,13 @@ use crate::{
     path = "/{medium_id}/preview",
     tag = "medium",
     responses(
-        (status = 200, description = "The raw file", body = Binary, content_type = "*/*", headers(
-            ("content-type" = String)
+        (status = 200, description = "The smallest preview covering the requested size", body = Binary, content_type = "image/jpeg", headers(
+            ("content-type" = String),
+            ("etag" = String),
+            ("cache-control" = String),
         )),
+        (status = 304, description = "The preview has not changed"),
+        (status = 404, description = "The medium does not exist or no preview can be rendered for it"),
     ),
     params(GetMediumPreviewOptions),
 )]
@@ -32,6 +48,72 @@ pub async fn get_medium_preview(
     Path(medium_id): Path<Uuid>,
     Query(opts): Query<GetMediumPreviewOptions>,
     JwtClaims(user): JwtClaims<JwtUserClaims>,
+    if_none_match: Option<TypedHeader<IfNoneMatch>>,
 ) -> ApiResult<(StatusCode, HeaderMap, Body)> {
-    todo!()
+    let user_id = user.user_id();
+    let size = PreviewSize::best_fit(positive(opts.width), positive(opts.height));
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        %size,
+        "Fetching medium preview"
+    );
+
+    let content = state
+        .medium_handlers
+        .find_medium_preview
+        .handle(FindMediumPreviewQuery {
+            user_id,
+            medium_id,
+            size,
+        })
+        .await
+        .inspect_err(|e| {
+            error!(
+                user_id = %user_id,
+                error = %format_error_with_backtrace(e),
+                "Failed to resolve medium preview"
+            );
+        })?;
+
+    let etag = format!("\"{}\"", content.validator());
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(CACHE_CONTROL),
+    );
+    headers.insert(header::ETAG, header_value(etag.clone())?);
+
+    if let Some(TypedHeader(if_none_match)) = if_none_match {
+        let matches = etag
+            .parse::<ETag>()
+            .is_ok_and(|etag| !if_none_match.precondition_passes(&etag));
+        if matches {
+            debug!("Client copy is up to date");
+            return Ok((StatusCode::NOT_MODIFIED, headers, Body::empty()));
+        }
+    }
+
+    let size_bytes = content.file.size_bytes;
+    let stream = state
+        .medium_handlers
+        .find_medium_item_content
+        .open(&content.location, 0)
+        .await?;
+
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("image/jpeg"));
+    headers.insert(header::CONTENT_LENGTH, HeaderValue::from(size_bytes));
+
+    info!(size_bytes, "Serving medium preview");
+    Ok((
+        StatusCode::OK,
+        headers,
+        Body::from_stream(ReaderStream::new(stream.take(size_bytes))),
+    ))
+}
+
+/// Non-positive bounds are treated as absent
+fn positive(value: Option<i32>) -> Option<u32> {
+    value.and_then(|v| u32::try_from(v).ok()).filter(|v| *v > 0)
 }
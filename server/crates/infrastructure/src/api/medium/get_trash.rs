@@ -0,0 +1,41 @@
+use application::medium::queries::FindTrashQuery;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::MediumListResponse;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "/trash",
+    tag = "medium",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets all media in the trash, most recently deleted first", body = [MediumListResponse]),
+    ),
+)]
+pub async fn get_trash(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<Vec<MediumListResponse>>)> {
+    let user_id = claims.user_id();
+
+    let query = FindTrashQuery { user_id };
+
+    let media = state.medium_handlers.find_trash.handle(query).await?;
+
+    let responses: Vec<MediumListResponse> = media.iter().map(|m| m.into()).collect();
+
+    info!(
+        user_id = %user_id,
+        count = responses.len(),
+        "Trashed media retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(responses)))
+}
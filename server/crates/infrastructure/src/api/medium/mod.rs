@@ -8,14 +8,25 @@ use crate::{
 };
 
 mod add_medium_item;
+mod add_medium_tags;
 mod create_medium;
+mod curate_media;
 mod delete_medium;
 pub mod dto;
+mod geotag_from_track;
 mod get_all_media;
 mod get_medium;
 mod get_medium_item;
 mod get_medium_metadata;
 mod get_medium_preview;
+mod get_trash;
+mod override_medium_metadata;
+mod reextract_medium_metadata;
+mod remove_medium_tags;
+mod restore_medium;
+mod shift_capture_dates;
+mod split_medium;
+mod stack_media;
 
 /// Returns routes with OpenAPI metadata. No state or layers needed.
 pub fn routes() -> OpenApiRouter<AppState> {
@@ -25,13 +36,37 @@ pub fn routes() -> OpenApiRouter<AppState> {
             create_medium::create_medium,
             get_all_media::get_all_media,
         ))
+        // route /curation
+        .routes(routes!(curate_media::curate_media))
+        // route /geotag
+        .routes(routes!(geotag_from_track::geotag_from_track))
+        // route /shift-dates
+        .routes(routes!(shift_capture_dates::shift_capture_dates))
+        // route /trash
+        .routes(routes!(get_trash::get_trash))
         // route /{medium_id}
         .routes(routes!(
             get_medium::get_medium,
             delete_medium::delete_medium,
         ))
+        // route /{medium_id}/restore
+        .routes(routes!(restore_medium::restore_medium))
+        // route /{medium_id}/stack
+        .routes(routes!(stack_media::stack_media))
+        // route /{medium_id}/split
+        .routes(routes!(split_medium::split_medium))
+        // route /{medium_id}/tags
+        .routes(routes!(
+            add_medium_tags::add_medium_tags,
+            remove_medium_tags::remove_medium_tags,
+        ))
         // route /{medium_id}/metadata
-        .routes(routes!(get_medium_metadata::get_medium_metadata))
+        .routes(routes!(
+            get_medium_metadata::get_medium_metadata,
+            override_medium_metadata::override_medium_metadata,
+        ))
+        // route /{medium_id}/metadata/reextract
+        .routes(routes!(reextract_medium_metadata::reextract_medium_metadata))
         // route /{medium_id}/preview
         .routes(routes!(get_medium_preview::get_medium_preview))
         // route /{medium_id}/item/{format}
@@ -0,0 +1,61 @@
+use application::metadata::commands::OverrideMetadataCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::{MediumMetadataDto, MetadataOverrideInput};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    patch,
+    path = "/{medium_id}/metadata",
+    tag = "medium",
+    request_body = MetadataOverrideInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Overrides metadata of a medium", body = MediumMetadataDto),
+        (status = 400, description = "Invalid metadata"),
+        (status = 404, description = "Medium or metadata not found"),
+    ),
+    params(
+        ("medium_id" = Uuid, Path, description = "The id of the medium"),
+    ),
+)]
+pub async fn override_medium_metadata(
+    State(state): State<AppState>,
+    Path(medium_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<MetadataOverrideInput>,
+) -> ApiResult<(StatusCode, Json<MediumMetadataDto>)> {
+    let user_id = claims.user_id();
+
+    let command = OverrideMetadataCommand {
+        medium_id,
+        user_id,
+        patch: input.into(),
+    };
+
+    let metadata = state
+        .metadata_handlers
+        .override_metadata
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        "Metadata overridden"
+    );
+
+    Ok((StatusCode::OK, Json((&metadata).into())))
+}
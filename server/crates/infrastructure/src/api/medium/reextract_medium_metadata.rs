@@ -0,0 +1,53 @@
+use application::metadata::commands::ReextractMetadataCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/{medium_id}/metadata/reextract",
+    tag = "medium",
+    responses(
+        (status = 204, description = "Extracted the metadata of a medium again"),
+        (status = 400, description = "Medium has no file to extract metadata from"),
+        (status = 404, description = "Medium not found"),
+    ),
+    params(
+        ("medium_id" = Uuid, Path, description = "The id of the medium"),
+    ),
+)]
+pub async fn reextract_medium_metadata(
+    State(state): State<AppState>,
+    Path(medium_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<StatusCode> {
+    let user_id = claims.user_id();
+
+    let command = ReextractMetadataCommand { medium_id, user_id };
+
+    state
+        .metadata_handlers
+        .reextract_metadata
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        "Metadata extracted again"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,64 @@
+use application::medium::commands::RemoveMediumTagsCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::MediumTagsInput;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    delete,
+    path = "/{medium_id}/tags",
+    tag = "medium",
+    request_body = MediumTagsInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Untags the medium, returns the tags it keeps", body = [String]),
+        (status = 400, description = "A tag is invalid or the medium is in the trash"),
+        (status = 404, description = "Medium not found"),
+    ),
+    params(
+        ("medium_id" = Uuid, Path, description = "The id of the medium to untag"),
+    ),
+)]
+pub async fn remove_medium_tags(
+    State(state): State<AppState>,
+    Path(medium_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<MediumTagsInput>,
+) -> ApiResult<(StatusCode, Json<Vec<String>>)> {
+    let user_id = claims.user_id();
+
+    let command = RemoveMediumTagsCommand {
+        user_id,
+        medium_id,
+        tags: input.tags,
+    };
+
+    let tags = state
+        .medium_handlers
+        .remove_medium_tags
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        "Medium untagged"
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(tags.iter().map(ToString::to_string).collect()),
+    ))
+}
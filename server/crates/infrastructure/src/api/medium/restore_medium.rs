@@ -0,0 +1,49 @@
+use application::medium::commands::RestoreMediumCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/{medium_id}/restore",
+    tag = "medium",
+    responses(
+        (status = 204, description = "Restores the medium from the trash"),
+        (status = 400, description = "The medium is not in the trash"),
+        (status = 404, description = "Medium not found"),
+    ),
+    params(
+        ("medium_id" = Uuid, Path, description = "The id of the medium to restore"),
+    ),
+)]
+pub async fn restore_medium(
+    State(state): State<AppState>,
+    Path(medium_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<StatusCode> {
+    let user_id = claims.user_id();
+
+    let command = RestoreMediumCommand { user_id, medium_id };
+
+    state.medium_handlers.restore_medium.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        "Medium restored from trash"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,58 @@
+use application::metadata::commands::ShiftCaptureDatesCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::{CaptureDateShiftResponse, ShiftCaptureDatesInput};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/shift-dates",
+    tag = "medium",
+    request_body = ShiftCaptureDatesInput,
+    responses(
+        (status = 202, content_type = "application/json", description = "Starts shifting the capture dates of the matching media", body = CaptureDateShiftResponse),
+        (status = 400, description = "Invalid criteria or shift"),
+    ),
+)]
+pub async fn shift_capture_dates(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<ShiftCaptureDatesInput>,
+) -> ApiResult<(StatusCode, Json<CaptureDateShiftResponse>)> {
+    let user_id = claims.user_id();
+    let shift_id = Uuid::new_v4();
+
+    let command = ShiftCaptureDatesCommand {
+        shift_id,
+        user_id,
+        criteria: input.criteria(),
+        shift: input.shift()?,
+    };
+
+    let handler = state.metadata_handlers.shift_capture_dates.clone();
+    handler.validate(&command)?;
+
+    // Runs in the background, failures are recorded on the task
+    tokio::spawn(async move {
+        let _ = handler.handle(command).await;
+    });
+
+    info!(
+        user_id = %user_id,
+        shift_id = %shift_id,
+        "Capture date shift started"
+    );
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(CaptureDateShiftResponse { shift_id }),
+    ))
+}
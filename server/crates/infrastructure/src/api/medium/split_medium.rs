@@ -0,0 +1,58 @@
+use application::medium::commands::SplitMediumCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::SplitMediumInput;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/{medium_id}/split",
+    tag = "medium",
+    request_body = SplitMediumInput,
+    responses(
+        (status = 201, content_type = "application/json", description = "The id of the medium the items were moved to", body = Uuid),
+        (status = 400, description = "The items cannot be split off"),
+        (status = 404, description = "Medium or item not found"),
+    ),
+    params(
+        ("medium_id" = Uuid, Path, description = "The id of the stack to split"),
+    ),
+)]
+pub async fn split_medium(
+    State(state): State<AppState>,
+    Path(medium_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<SplitMediumInput>,
+) -> ApiResult<(StatusCode, Json<Uuid>)> {
+    let user_id = claims.user_id();
+
+    let command = SplitMediumCommand {
+        user_id,
+        medium_id,
+        item_ids: input.item_ids,
+    };
+
+    let new_medium_id = state.medium_handlers.split_medium.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        new_medium_id = %new_medium_id,
+        "Medium split"
+    );
+
+    Ok((StatusCode::CREATED, Json(new_medium_id)))
+}
@@ -0,0 +1,57 @@
+use application::medium::commands::MergeMediaCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::StackMediaInput;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/{medium_id}/stack",
+    tag = "medium",
+    request_body = StackMediaInput,
+    responses(
+        (status = 204, description = "Merges the given media into the stack"),
+        (status = 400, description = "The media cannot be stacked"),
+        (status = 404, description = "Medium not found"),
+    ),
+    params(
+        ("medium_id" = Uuid, Path, description = "The id of the medium to stack onto"),
+    ),
+)]
+pub async fn stack_media(
+    State(state): State<AppState>,
+    Path(medium_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<StackMediaInput>,
+) -> ApiResult<StatusCode> {
+    let user_id = claims.user_id();
+
+    let command = MergeMediaCommand {
+        user_id,
+        medium_id,
+        medium_ids: input.medium_ids,
+    };
+
+    state.medium_handlers.merge_media.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        medium_id = %medium_id,
+        "Media stacked"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,7 +1,14 @@
+pub mod admin;
+pub mod album;
+pub mod duplicate;
 pub mod error;
 pub mod medium;
 pub mod router;
+pub mod search;
+pub mod smart_album;
 pub mod state;
 pub mod system;
+pub mod tag;
 pub mod task;
+pub mod user;
 pub mod user_handler;
@@ -6,7 +6,7 @@ use utoipa::OpenApi;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_swagger_ui::SwaggerUi;
 
-use super::{medium, system};
+use super::{admin, album, duplicate, medium, search, smart_album, system, tag, user};
 use crate::{api::state::AppState, server::setup_auth};
 
 #[derive(utoipa::ToSchema)]
@@ -21,9 +21,14 @@ pub struct Binary(String);
     ),
     tags(
         (name = "medium", description = "Medium API"),
+        (name = "duplicate", description = "Duplicate API"),
         (name = "album", description = "Album API"),
+        (name = "smart-album", description = "Smart Album API"),
+        (name = "search", description = "Search API"),
+        (name = "tag", description = "Tag API"),
         (name = "system", description = "System API"),
         (name = "user", description = "User API"),
+        (name = "admin", description = "Admin API"),
     ),
     components(
         schemas(
@@ -48,14 +53,22 @@ pub async fn create_router_with_api(
             "/api/v1/medium",
             medium::router(state.clone(), auth.clone()),
         )
-        // .nest(
-        //     "/api/v1/album",
-        //     album::api::router(state.clone(), auth.clone()),
-        // )
-        // .nest(
-        //     "/api/v1/user",
-        //     user::api::router(state.clone(), auth.clone()),
-        // )
+        .nest(
+            "/api/v1/duplicates",
+            duplicate::router(state.clone(), auth.clone()),
+        )
+        .nest("/api/v1/album", album::router(state.clone(), auth.clone()))
+        .nest(
+            "/api/v1/smart-album",
+            smart_album::router(state.clone(), auth.clone()),
+        )
+        .nest(
+            "/api/v1/search",
+            search::router(state.clone(), auth.clone()),
+        )
+        .nest("/api/v1/tags", tag::router(state.clone(), auth.clone()))
+        .nest("/api/v1/user", user::router(state.clone(), auth.clone()))
+        .nest("/api/v1/admin", admin::router(state.clone(), auth.clone()))
         .nest("/api/v1/system", system::router(state.clone()))
         .layer(TraceLayer::new_for_http())
         .split_for_parts())
@@ -63,18 +76,14 @@ pub async fn create_router_with_api(
 
 pub fn create_api() -> utoipa::openapi::OpenApi {
     OpenApiRouter::with_openapi(ApiDoc::openapi())
-        .nest(
-            "/api/v1/medium",
-            medium::routes()
-        )
-        // .nest(
-        //     "/api/v1/album",
-        //     album::api::router(),
-        // )
-        // .nest(
-        //     "/api/v1/user",
-        //     user::api::router(),
-        // )
+        .nest("/api/v1/medium", medium::routes())
+        .nest("/api/v1/duplicates", duplicate::routes())
+        .nest("/api/v1/album", album::routes())
+        .nest("/api/v1/smart-album", smart_album::routes())
+        .nest("/api/v1/search", search::routes())
+        .nest("/api/v1/tags", tag::routes())
+        .nest("/api/v1/user", user::routes())
+        .nest("/api/v1/admin", admin::routes())
         .nest("/api/v1/system", system::routes())
         .into_openapi()
 }
@@ -0,0 +1,4 @@
+pub mod request;
+
+// Re-export commonly used items
+pub use request::*;
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    medium::MediumId,
+    shared::{KeysetCursor, SortDirection},
+};
+use serde::Deserialize;
+use serde_default_utils::*;
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::api::medium::dto::DirectionDto;
+
+/// A search with the same pagination as listing all media
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct SearchMediaOptions {
+    /// Words, `"quoted phrases"` and `field:value` pairs, e.g.
+    /// `camera:fuji lens:23mm iso:>3200 tag:beach place:lisbon "sunset"`
+    pub q: String,
+    #[serde(default = "default_u64::<50>")]
+    #[param(default = 50, minimum = 1, maximum = 100)]
+    pub per_page: u64,
+    pub page_last_date: Option<DateTime<Utc>>,
+    pub page_last_id: Option<Uuid>,
+    #[serde(default)]
+    #[param(inline, default = "Desc")]
+    pub direction: DirectionDto,
+}
+
+impl SearchMediaOptions {
+    pub fn cursor(&self) -> Option<KeysetCursor<MediumId>> {
+        Option::zip(self.page_last_date, self.page_last_id)
+            .map(|(date, id)| KeysetCursor::new(date, id))
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        match self.direction {
+            DirectionDto::Asc => SortDirection::Ascending,
+            DirectionDto::Desc => SortDirection::Descending,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use application::medium::queries::FindAllMediaQuery;
+use axum::{
+    debug_handler,
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use domain::medium::{MediumFilter, SearchQuery};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::SearchMediaOptions;
+use crate::{
+    api::{error::ApiResult, medium::dto::MediumListResponse, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "search",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets a page of the media matching every term of the search", body = [MediumListResponse]),
+        (status = 400, description = "Invalid search query, e.g. an unknown field, or invalid pagination"),
+    ),
+    params(SearchMediaOptions),
+)]
+pub async fn search_media(
+    State(state): State<AppState>,
+    Query(options): Query<SearchMediaOptions>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<Vec<MediumListResponse>>)> {
+    let user_id = claims.user_id();
+
+    let search = SearchQuery::parse(&options.q)?;
+    let filter = MediumFilter::new(
+        None,
+        None,
+        Some(options.per_page),
+        options.cursor(),
+        vec![],
+        None,
+        Some(options.direction()),
+        false,
+    )?
+    .with_search(search);
+
+    let query = FindAllMediaQuery { user_id, filter };
+
+    let media = state.medium_handlers.find_all_media.handle(query).await?;
+
+    let responses: Vec<MediumListResponse> = media.iter().map(|m| m.into()).collect();
+
+    info!(
+        user_id = %user_id,
+        count = responses.len(),
+        "Search completed successfully"
+    );
+
+    Ok((StatusCode::OK, Json(responses)))
+}
@@ -0,0 +1,51 @@
+use application::smart_album::commands::CreateSmartAlbumCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::{CreateSmartAlbumInput, SmartAlbumResponse};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "",
+    tag = "smart-album",
+    request_body = CreateSmartAlbumInput,
+    responses(
+        (status = 201, content_type = "application/json", description = "The newly created smart album", body = SmartAlbumResponse),
+        (status = 400, description = "The title or the filter is invalid"),
+    ),
+)]
+pub async fn create_smart_album(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<CreateSmartAlbumInput>,
+) -> ApiResult<(StatusCode, Json<SmartAlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = CreateSmartAlbumCommand {
+        user_id,
+        title: input.title,
+        description: input.description,
+        filter: input.filter.expression()?,
+    };
+
+    let smart_album = state
+        .smart_album_handlers
+        .create_smart_album
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        smart_album_id = %smart_album.id,
+        "Smart album created"
+    );
+
+    Ok((StatusCode::CREATED, Json(smart_album.into())))
+}
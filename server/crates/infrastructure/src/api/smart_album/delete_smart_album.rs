@@ -0,0 +1,55 @@
+use application::smart_album::commands::DeleteSmartAlbumCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    delete,
+    path = "/{smart_album_id}",
+    tag = "smart-album",
+    responses(
+        (status = 204, description = "Deletes the smart album, its media are kept"),
+        (status = 404, description = "Smart album not found"),
+    ),
+    params(
+        ("smart_album_id" = Uuid, Path, description = "The id of the smart album to delete"),
+    ),
+)]
+pub async fn delete_smart_album(
+    State(state): State<AppState>,
+    Path(smart_album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<StatusCode> {
+    let user_id = claims.user_id();
+
+    let command = DeleteSmartAlbumCommand {
+        user_id,
+        smart_album_id,
+    };
+
+    state
+        .smart_album_handlers
+        .delete_smart_album
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        smart_album_id = %smart_album_id,
+        "Smart album deleted"
+    );
+
+    Ok(StatusCode::NO_CONTENT)
+}
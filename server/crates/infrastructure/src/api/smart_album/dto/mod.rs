@@ -0,0 +1,8 @@
+pub mod request;
+pub mod response;
+pub mod types;
+
+// Re-export commonly used items
+pub use request::*;
+pub use response::*;
+pub use types::*;
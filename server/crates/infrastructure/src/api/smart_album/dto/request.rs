@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    medium::MediumId,
+    shared::{KeysetCursor, SortDirection},
+};
+use serde::Deserialize;
+use serde_default_utils::*;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+use super::types::FilterExpressionDto;
+use crate::api::medium::dto::DirectionDto;
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateSmartAlbumInput {
+    pub title: String,
+    pub description: Option<String>,
+    pub filter: FilterExpressionDto,
+}
+
+/// Fields left out stay as they are
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateSmartAlbumInput {
+    pub title: Option<String>,
+    /// An empty description removes it
+    pub description: Option<String>,
+    /// Replaces the whole filter
+    pub filter: Option<FilterExpressionDto>,
+}
+
+/// Pagination of the media of a smart album, like for listing all media
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct FindSmartAlbumMediaOptions {
+    #[serde(default = "default_u64::<50>")]
+    #[param(default = 50, minimum = 1, maximum = 100)]
+    pub per_page: u64,
+    pub page_last_date: Option<DateTime<Utc>>,
+    pub page_last_id: Option<Uuid>,
+    #[serde(default)]
+    #[param(inline, default = "Desc")]
+    pub direction: DirectionDto,
+}
+
+impl FindSmartAlbumMediaOptions {
+    pub fn cursor(&self) -> Option<KeysetCursor<MediumId>> {
+        Option::zip(self.page_last_date, self.page_last_id)
+            .map(|(date, id)| KeysetCursor::new(date, id))
+    }
+
+    pub fn direction(&self) -> SortDirection {
+        match self.direction {
+            DirectionDto::Asc => SortDirection::Ascending,
+            DirectionDto::Desc => SortDirection::Descending,
+        }
+    }
+}
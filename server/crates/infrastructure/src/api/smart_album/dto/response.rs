@@ -0,0 +1,31 @@
+use chrono::{DateTime, FixedOffset};
+use domain::smart_album::SmartAlbum;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::types::FilterExpressionDto;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SmartAlbumResponse {
+    pub id: Uuid,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub filter: FilterExpressionDto,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<SmartAlbum> for SmartAlbumResponse {
+    fn from(smart_album: SmartAlbum) -> Self {
+        Self {
+            id: smart_album.id,
+            title: smart_album.title,
+            description: smart_album.description,
+            filter: smart_album.filter.into(),
+            created_at: smart_album.created_at.into(),
+            updated_at: smart_album.updated_at.into(),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    error::DomainResult,
+    medium::{FilterExpression, Tag},
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::api::medium::dto::MediumTypeDto;
+
+/// A filter over the media of the user, e.g.
+/// `{"and": [{"camera": {"model": "X100V"}}, {"min_rating": 4}]}`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(no_recursion)]
+pub enum FilterExpressionDto {
+    /// Matches if every filter matches
+    And(Vec<FilterExpressionDto>),
+    /// Matches if at least one filter matches
+    Or(Vec<FilterExpressionDto>),
+    /// Matches media not matching the filter
+    Not(Box<FilterExpressionDto>),
+    /// Taken within the range, a missing bound leaves it open
+    Taken {
+        start: Option<DateTime<Utc>>,
+        end: Option<DateTime<Utc>>,
+    },
+    /// Make and model are compared ignoring case
+    Camera {
+        make: Option<String>,
+        model: Option<String>,
+    },
+    /// Lens model, compared ignoring case
+    Lens(String),
+    /// Also matches the tags below it
+    Tag(String),
+    /// Rated with at least this many stars, up to 5
+    MinRating(u8),
+    MediumType(MediumTypeDto),
+    /// Country, region or city
+    Place(String),
+    HasGps(bool),
+}
+
+impl FilterExpressionDto {
+    pub fn expression(self) -> DomainResult<FilterExpression> {
+        let expressions = |dtos: Vec<FilterExpressionDto>| {
+            dtos.into_iter()
+                .map(FilterExpressionDto::expression)
+                .collect::<DomainResult<Vec<_>>>()
+        };
+        Ok(match self {
+            FilterExpressionDto::And(dtos) => FilterExpression::And(expressions(dtos)?),
+            FilterExpressionDto::Or(dtos) => FilterExpression::Or(expressions(dtos)?),
+            FilterExpressionDto::Not(dto) => FilterExpression::Not(Box::new(dto.expression()?)),
+            FilterExpressionDto::Taken { start, end } => FilterExpression::Taken { start, end },
+            FilterExpressionDto::Camera { make, model } => FilterExpression::Camera { make, model },
+            FilterExpressionDto::Lens(lens) => FilterExpression::Lens(lens),
+            FilterExpressionDto::Tag(tag) => FilterExpression::Tag(Tag::new(&tag)?),
+            FilterExpressionDto::MinRating(min_rating) => FilterExpression::MinRating(min_rating),
+            FilterExpressionDto::MediumType(medium_type) => {
+                FilterExpression::MediumType(medium_type.into())
+            }
+            FilterExpressionDto::Place(place) => FilterExpression::Place(place),
+            FilterExpressionDto::HasGps(has_gps) => FilterExpression::HasGps(has_gps),
+        })
+    }
+}
+
+impl From<FilterExpression> for FilterExpressionDto {
+    fn from(expression: FilterExpression) -> Self {
+        let dtos = |expressions: Vec<FilterExpression>| {
+            expressions.into_iter().map(Into::into).collect::<Vec<_>>()
+        };
+        match expression {
+            FilterExpression::And(expressions) => FilterExpressionDto::And(dtos(expressions)),
+            FilterExpression::Or(expressions) => FilterExpressionDto::Or(dtos(expressions)),
+            FilterExpression::Not(expression) => {
+                FilterExpressionDto::Not(Box::new((*expression).into()))
+            }
+            FilterExpression::Taken { start, end } => FilterExpressionDto::Taken { start, end },
+            FilterExpression::Camera { make, model } => FilterExpressionDto::Camera { make, model },
+            FilterExpression::Lens(lens) => FilterExpressionDto::Lens(lens),
+            FilterExpression::Tag(tag) => FilterExpressionDto::Tag(tag.to_string()),
+            FilterExpression::MinRating(min_rating) => FilterExpressionDto::MinRating(min_rating),
+            FilterExpression::MediumType(medium_type) => {
+                FilterExpressionDto::MediumType(medium_type.into())
+            }
+            FilterExpression::Place(place) => FilterExpressionDto::Place(place),
+            FilterExpression::HasGps(has_gps) => FilterExpressionDto::HasGps(has_gps),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use application::smart_album::queries::FindSmartAlbumQuery;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::SmartAlbumResponse;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "/{smart_album_id}",
+    tag = "smart-album",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets a single smart album by ID", body = SmartAlbumResponse),
+        (status = 404, description = "Smart album not found"),
+    ),
+    params(
+        ("smart_album_id" = Uuid, Path, description = "The id of the smart album"),
+    ),
+)]
+pub async fn get_smart_album(
+    State(state): State<AppState>,
+    Path(smart_album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<SmartAlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let query = FindSmartAlbumQuery {
+        user_id,
+        smart_album_id,
+    };
+
+    let smart_album = state
+        .smart_album_handlers
+        .find_smart_album
+        .handle(query)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        smart_album_id = %smart_album_id,
+        "Smart album retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(smart_album.into())))
+}
@@ -0,0 +1,66 @@
+use application::smart_album::queries::FindSmartAlbumMediaQuery;
+use axum::{
+    debug_handler,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::FindSmartAlbumMediaOptions;
+use crate::{
+    api::{error::ApiResult, medium::dto::MediumListResponse, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "/{smart_album_id}/media",
+    tag = "smart-album",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets a page of the media matching the filter of the smart album", body = [MediumListResponse]),
+        (status = 400, description = "Invalid pagination"),
+        (status = 404, description = "Smart album not found"),
+    ),
+    params(
+        ("smart_album_id" = Uuid, Path, description = "The id of the smart album"),
+        FindSmartAlbumMediaOptions,
+    ),
+)]
+pub async fn get_smart_album_media(
+    State(state): State<AppState>,
+    Path(smart_album_id): Path<Uuid>,
+    Query(options): Query<FindSmartAlbumMediaOptions>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<Vec<MediumListResponse>>)> {
+    let user_id = claims.user_id();
+
+    let query = FindSmartAlbumMediaQuery {
+        user_id,
+        smart_album_id,
+        per_page: Some(options.per_page),
+        cursor: options.cursor(),
+        direction: Some(options.direction()),
+    };
+
+    let media = state
+        .smart_album_handlers
+        .find_smart_album_media
+        .handle(query)
+        .await?;
+
+    let responses: Vec<MediumListResponse> = media.iter().map(|m| m.into()).collect();
+
+    info!(
+        user_id = %user_id,
+        smart_album_id = %smart_album_id,
+        count = responses.len(),
+        "Smart album media retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(responses)))
+}
@@ -0,0 +1,44 @@
+use application::smart_album::queries::FindAllSmartAlbumsQuery;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::SmartAlbumResponse;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "smart-album",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets all smart albums of the user, newest first", body = [SmartAlbumResponse]),
+    ),
+)]
+pub async fn get_smart_albums(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<Vec<SmartAlbumResponse>>)> {
+    let user_id = claims.user_id();
+
+    let query = FindAllSmartAlbumsQuery { user_id };
+
+    let smart_albums = state
+        .smart_album_handlers
+        .find_all_smart_albums
+        .handle(query)
+        .await?;
+    let responses: Vec<SmartAlbumResponse> = smart_albums.into_iter().map(Into::into).collect();
+
+    info!(
+        user_id = %user_id,
+        count = responses.len(),
+        "Smart albums retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(responses)))
+}
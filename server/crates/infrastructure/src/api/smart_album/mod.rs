@@ -0,0 +1,45 @@
+use axum::middleware;
+use jwt_authorizer::layer::AuthorizationLayer;
+use utoipa_axum::{router::OpenApiRouter, routes};
+
+use crate::{
+    api::state::AppState,
+    auth::{ensure_user_exists, JwtUserClaims},
+};
+
+mod create_smart_album;
+mod delete_smart_album;
+pub mod dto;
+mod get_smart_album;
+mod get_smart_album_media;
+mod get_smart_albums;
+mod update_smart_album;
+
+/// Returns routes with OpenAPI metadata. No state or layers needed.
+pub fn routes() -> OpenApiRouter<AppState> {
+    OpenApiRouter::new()
+        // route /
+        .routes(routes!(
+            create_smart_album::create_smart_album,
+            get_smart_albums::get_smart_albums
+        ))
+        // route /{smart_album_id}
+        .routes(routes!(
+            get_smart_album::get_smart_album,
+            update_smart_album::update_smart_album,
+            delete_smart_album::delete_smart_album,
+        ))
+        // route /{smart_album_id}/media
+        .routes(routes!(get_smart_album_media::get_smart_album_media))
+}
+
+/// Full router with authorization layers and state.
+pub fn router(state: AppState, authorization: AuthorizationLayer<JwtUserClaims>) -> OpenApiRouter {
+    routes()
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            ensure_user_exists,
+        ))
+        .layer(authorization)
+        .with_state(state)
+}
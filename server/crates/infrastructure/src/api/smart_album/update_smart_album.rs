@@ -0,0 +1,63 @@
+use application::smart_album::commands::UpdateSmartAlbumCommand;
+use axum::{
+    debug_handler,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use super::dto::{SmartAlbumResponse, UpdateSmartAlbumInput};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    patch,
+    path = "/{smart_album_id}",
+    tag = "smart-album",
+    request_body = UpdateSmartAlbumInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Changes the title, description or filter of the smart album", body = SmartAlbumResponse),
+        (status = 400, description = "The title or the filter is invalid"),
+        (status = 404, description = "Smart album not found"),
+    ),
+    params(
+        ("smart_album_id" = Uuid, Path, description = "The id of the smart album to update"),
+    ),
+)]
+pub async fn update_smart_album(
+    State(state): State<AppState>,
+    Path(smart_album_id): Path<Uuid>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<UpdateSmartAlbumInput>,
+) -> ApiResult<(StatusCode, Json<SmartAlbumResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = UpdateSmartAlbumCommand {
+        user_id,
+        smart_album_id,
+        title: input.title,
+        description: input.description,
+        filter: input.filter.map(|f| f.expression()).transpose()?,
+    };
+
+    let smart_album = state
+        .smart_album_handlers
+        .update_smart_album
+        .handle(command)
+        .await?;
+
+    info!(
+        user_id = %user_id,
+        smart_album_id = %smart_album_id,
+        "Smart album updated"
+    );
+
+    Ok((StatusCode::OK, Json(smart_album.into())))
+}
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use application::{
-    medium::MediumApplicationHandlers, metadata::MetadataApplicationHandlers,
-    system::SystemApplicationHandlers, user::UserApplicationHandlers,
+    album::AlbumApplicationHandlers, medium::MediumApplicationHandlers,
+    metadata::MetadataApplicationHandlers, smart_album::SmartAlbumApplicationHandlers,
+    system::SystemApplicationHandlers, tag::TagApplicationHandlers, user::UserApplicationHandlers,
 };
 use snafu::Whatever;
 
@@ -15,6 +16,9 @@ pub struct AppState {
     pub medium_handlers: Arc<MediumApplicationHandlers>,
     pub metadata_handlers: Arc<MetadataApplicationHandlers>,
     pub system_handlers: Arc<SystemApplicationHandlers>,
+    pub album_handlers: Arc<AlbumApplicationHandlers>,
+    pub smart_album_handlers: Arc<SmartAlbumApplicationHandlers>,
+    pub tag_handlers: Arc<TagApplicationHandlers>,
 }
 
 impl AppState {
@@ -25,6 +29,9 @@ impl AppState {
             medium_handlers: container.medium_handlers(),
             metadata_handlers: container.metadata_handlers(),
             system_handlers: container.system_handlers(),
+            album_handlers: container.album_handlers(),
+            smart_album_handlers: container.smart_album_handlers(),
+            tag_handlers: container.tag_handlers(),
         })
     }
 }
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Deserialize, IntoParams)]
+pub struct FindTagsOptions {
+    /// Only tags whose path or last level starts with it, ignoring case
+    pub prefix: Option<String>,
+    #[param(default = 20, minimum = 1, maximum = 100)]
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RenameTagInput {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct MergeTagsInput {
+    /// Tags replaced by the target
+    pub sources: Vec<String>,
+    pub target: String,
+}
@@ -0,0 +1,29 @@
+use application::tag::ports::TagUsage;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TagResponse {
+    /// Levels of hierarchical tags are separated by `/`
+    pub tag: String,
+    /// The last level of the tag
+    pub name: String,
+    /// Media tagged with the tag or a tag below it
+    pub count: u64,
+}
+
+impl From<TagUsage> for TagResponse {
+    fn from(usage: TagUsage) -> Self {
+        Self {
+            name: usage.tag.name().to_string(),
+            tag: usage.tag.to_string(),
+            count: usage.count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TagsChangedResponse {
+    /// Media whose tags were rewritten
+    pub changed: u64,
+}
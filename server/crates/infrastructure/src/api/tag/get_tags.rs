@@ -0,0 +1,52 @@
+use application::tag::queries::FindTagsQuery;
+use axum::{
+    debug_handler,
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::{FindTagsOptions, TagResponse};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "",
+    tag = "tag",
+    responses(
+        (status = 200, content_type = "application/json", description = "Gets the tags of the user with their usage, most used first", body = [TagResponse]),
+        (status = 400, description = "Invalid limit"),
+    ),
+    params(FindTagsOptions),
+)]
+pub async fn get_tags(
+    State(state): State<AppState>,
+    Query(options): Query<FindTagsOptions>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<Vec<TagResponse>>)> {
+    let user_id = claims.user_id();
+
+    let query = FindTagsQuery {
+        user_id,
+        prefix: options.prefix,
+        limit: options.limit,
+    };
+
+    let tags = state.tag_handlers.find_tags.handle(query).await?;
+    let responses: Vec<TagResponse> = tags.into_iter().map(Into::into).collect();
+
+    info!(
+        user_id = %user_id,
+        count = responses.len(),
+        "Tags retrieved successfully"
+    );
+
+    Ok((StatusCode::OK, Json(responses)))
+}
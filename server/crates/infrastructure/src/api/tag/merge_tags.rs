@@ -0,0 +1,46 @@
+use application::tag::commands::MergeTagsCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::{MergeTagsInput, TagsChangedResponse};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/merge",
+    tag = "tag",
+    request_body = MergeTagsInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Replaces the source tags with the target on all media of the user", body = TagsChangedResponse),
+        (status = 400, description = "A tag is invalid or no source tag was given"),
+    ),
+)]
+pub async fn merge_tags(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<MergeTagsInput>,
+) -> ApiResult<(StatusCode, Json<TagsChangedResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = MergeTagsCommand {
+        user_id,
+        sources: input.sources,
+        target: input.target,
+    };
+
+    let changed = state.tag_handlers.merge_tags.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        changed,
+        "Tags merged"
+    );
+
+    Ok((StatusCode::OK, Json(TagsChangedResponse { changed })))
+}
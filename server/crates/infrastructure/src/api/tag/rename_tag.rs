@@ -0,0 +1,46 @@
+use application::tag::commands::RenameTagCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::{RenameTagInput, TagsChangedResponse};
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    post,
+    path = "/rename",
+    tag = "tag",
+    request_body = RenameTagInput,
+    responses(
+        (status = 200, content_type = "application/json", description = "Renames the tag on all media of the user, the tags below it move along", body = TagsChangedResponse),
+        (status = 400, description = "A tag is invalid"),
+    ),
+)]
+pub async fn rename_tag(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<RenameTagInput>,
+) -> ApiResult<(StatusCode, Json<TagsChangedResponse>)> {
+    let user_id = claims.user_id();
+
+    let command = RenameTagCommand {
+        user_id,
+        from: input.from,
+        to: input.to,
+    };
+
+    let changed = state.tag_handlers.rename_tag.handle(command).await?;
+
+    info!(
+        user_id = %user_id,
+        changed,
+        "Tag renamed"
+    );
+
+    Ok((StatusCode::OK, Json(TagsChangedResponse { changed })))
+}
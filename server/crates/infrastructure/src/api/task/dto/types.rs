@@ -7,6 +7,9 @@ use utoipa::ToSchema;
 pub enum TaskTypeDto {
     MetadataExtraction,
     TempCleanup,
+    PreviewGeneration,
+    CaptureDateShift,
+    MetadataReextraction,
 }
 
 impl From<TaskType> for TaskTypeDto {
@@ -14,6 +17,9 @@ impl From<TaskType> for TaskTypeDto {
         match mt {
             TaskType::MetadataExtraction => TaskTypeDto::MetadataExtraction,
             TaskType::TempCleanup => TaskTypeDto::TempCleanup,
+            TaskType::PreviewGeneration => TaskTypeDto::PreviewGeneration,
+            TaskType::CaptureDateShift => TaskTypeDto::CaptureDateShift,
+            TaskType::MetadataReextraction => TaskTypeDto::MetadataReextraction,
         }
     }
 }
@@ -23,6 +29,9 @@ impl From<TaskTypeDto> for TaskType {
         match dto {
             TaskTypeDto::MetadataExtraction => TaskType::MetadataExtraction,
             TaskTypeDto::TempCleanup => TaskType::TempCleanup,
+            TaskTypeDto::PreviewGeneration => TaskType::PreviewGeneration,
+            TaskTypeDto::CaptureDateShift => TaskType::CaptureDateShift,
+            TaskTypeDto::MetadataReextraction => TaskType::MetadataReextraction,
         }
     }
 }
@@ -0,0 +1,4 @@
+pub mod settings;
+
+// Re-export commonly used items
+pub use settings::*;
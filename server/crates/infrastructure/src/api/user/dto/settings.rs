@@ -0,0 +1,27 @@
+use domain::user::UserSettings;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserSettingsDto {
+    /// Keep an XMP sidecar next to each original in permanent storage up to
+    /// date with ratings, keywords and metadata overrides
+    #[serde(default)]
+    pub xmp_write_back: bool,
+}
+
+impl From<UserSettings> for UserSettingsDto {
+    fn from(settings: UserSettings) -> Self {
+        Self {
+            xmp_write_back: settings.xmp_write_back,
+        }
+    }
+}
+
+impl From<UserSettingsDto> for UserSettings {
+    fn from(settings: UserSettingsDto) -> Self {
+        Self {
+            xmp_write_back: settings.xmp_write_back,
+        }
+    }
+}
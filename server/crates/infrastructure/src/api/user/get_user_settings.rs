@@ -0,0 +1,37 @@
+use application::user::queries::FindUserSettingsQuery;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::UserSettingsDto;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    get,
+    path = "/settings",
+    tag = "user",
+    responses(
+        (status = 200, content_type = "application/json", description = "The settings of the current user", body = UserSettingsDto),
+    ),
+)]
+pub async fn get_user_settings(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+) -> ApiResult<(StatusCode, Json<UserSettingsDto>)> {
+    let user_id = claims.user_id();
+
+    let settings = state
+        .user_handlers
+        .find_user_settings
+        .handle(FindUserSettingsQuery { user_id })
+        .await?;
+
+    info!(user_id = %user_id, "User settings retrieved");
+
+    Ok((StatusCode::OK, Json(settings.into())))
+}
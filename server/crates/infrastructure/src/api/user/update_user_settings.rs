@@ -0,0 +1,44 @@
+use application::user::commands::UpdateUserSettingsCommand;
+use axum::{debug_handler, extract::State, http::StatusCode, Json};
+use jwt_authorizer::JwtClaims;
+use tracing::{info, instrument};
+
+use super::dto::UserSettingsDto;
+use crate::{
+    api::{error::ApiResult, state::AppState},
+    auth::JwtUserClaims,
+};
+
+#[instrument(skip(state))]
+#[debug_handler]
+#[utoipa::path(
+    put,
+    path = "/settings",
+    tag = "user",
+    request_body = UserSettingsDto,
+    responses(
+        (status = 200, content_type = "application/json", description = "Replaces the settings of the current user", body = UserSettingsDto),
+    ),
+)]
+pub async fn update_user_settings(
+    State(state): State<AppState>,
+    JwtClaims(claims): JwtClaims<JwtUserClaims>,
+    Json(input): Json<UserSettingsDto>,
+) -> ApiResult<(StatusCode, Json<UserSettingsDto>)> {
+    let user_id = claims.user_id();
+
+    let command = UpdateUserSettingsCommand {
+        user_id,
+        settings: input.into(),
+    };
+
+    let settings = state
+        .user_handlers
+        .update_user_settings
+        .handle(command)
+        .await?;
+
+    info!(user_id = %user_id, "User settings updated");
+
+    Ok((StatusCode::OK, Json(settings.into())))
+}
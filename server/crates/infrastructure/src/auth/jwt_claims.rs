@@ -12,6 +12,10 @@ pub struct JwtUserClaims {
     pub given_name: Option<String>,
     pub preferred_username: Option<String>,
     pub nickname: Option<String>,
+    /// IANA time zone of the user, e.g. `Europe/Berlin`
+    pub zoneinfo: Option<String>,
+    #[serde(default)]
+    pub groups: Vec<String>,
 
     // Custom claims your OAuth2 provider might include
     pub quota: Option<Byte>,
@@ -27,6 +31,11 @@ impl JwtUserClaims {
             .or_else(|| self.given_name.clone())
     }
 
+    /// Whether the user is a member of the given group
+    pub fn is_in_group(&self, group: &str) -> bool {
+        self.groups.iter().any(|g| g == group)
+    }
+
     /// Convert JWT claims to event user ID
     pub fn user_id(&self) -> Uuid {
         self.sub
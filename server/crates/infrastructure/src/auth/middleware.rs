@@ -34,6 +34,7 @@ pub async fn ensure_user_exists(
                 message: "No valid username found in JWT claims",
             })?,
             email: user_claims.email.clone(),
+            timezone: user_claims.zoneinfo.clone(),
             quota: user_claims.quota,
         };
 
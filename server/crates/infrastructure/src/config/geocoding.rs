@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+use confique::Config;
+
+#[derive(Debug, Config)]
+pub struct GeocodingConfig {
+    /// Directory holding the GeoNames `cities*.txt`, `admin1CodesASCII.txt` and `countryInfo.txt` dumps,
+    /// places are not resolved without it and the server does not start if it cannot be loaded
+    #[config(env = "GEOCODING_DATA_DIRECTORY")]
+    pub data_directory: Option<PathBuf>,
+    /// Maximum distance to the nearest city for a place to be resolved in kilometres (default: 50)
+    #[config(default = 50.0, env = "GEOCODING_MAX_DISTANCE_KM")]
+    pub max_distance_km: f64,
+}
@@ -0,0 +1,28 @@
+use confique::Config;
+use serde::Deserialize;
+use strum::EnumString;
+
+#[derive(Debug, Config)]
+pub struct MetadataConfig {
+    /// Which extractor reads the metadata of uploads: `exiftool`, `native` or
+    /// `exiftool_with_native_fallback` (default)
+    #[config(
+        default = "exiftool_with_native_fallback",
+        env = "METADATA_EXTRACTOR",
+        parse_env = std::str::FromStr::from_str
+    )]
+    pub extractor: MetadataExtractorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MetadataExtractorKind {
+    /// exiftool only, the server does not start without it
+    Exiftool,
+    /// The built-in EXIF reader, limited to the core values of images
+    Native,
+    /// exiftool, falling back to the built-in reader when exiftool is not
+    /// installed or fails on a file
+    ExiftoolWithNativeFallback,
+}
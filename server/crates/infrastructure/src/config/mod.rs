@@ -5,10 +5,14 @@ use snafu::{ResultExt, Whatever};
 use tracing::log::debug;
 
 mod database;
+mod geocoding;
+mod metadata;
 mod server;
 mod storage;
 
 pub use database::DatabaseConfig;
+pub use geocoding::GeocodingConfig;
+pub use metadata::{MetadataConfig, MetadataExtractorKind};
 pub use server::ServerConfig;
 pub use storage::StorageConfig;
 
@@ -20,6 +24,10 @@ pub struct GlobalConfig {
     pub storage: StorageConfig,
     #[config(nested)]
     pub database: DatabaseConfig,
+    #[config(nested)]
+    pub geocoding: GeocodingConfig,
+    #[config(nested)]
+    pub metadata: MetadataConfig,
 }
 
 impl GlobalConfig {
@@ -49,4 +57,14 @@ impl GlobalConfig {
     pub fn database(&self) -> &DatabaseConfig {
         &self.database
     }
+
+    /// Get geocoding configuration
+    pub fn geocoding(&self) -> &GeocodingConfig {
+        &self.geocoding
+    }
+
+    /// Get metadata configuration
+    pub fn metadata(&self) -> &MetadataConfig {
+        &self.metadata
+    }
 }
@@ -18,4 +18,7 @@ pub struct ServerConfig {
     pub authorize_url: String,
     #[config(env = "JWT_SECRET")]
     pub jwt_secret: Option<String>,
+    /// Group in the `groups` claim granting access to the admin API
+    #[config(default = "admin", env = "OAUTH_ADMIN_GROUP")]
+    pub admin_group: String,
 }
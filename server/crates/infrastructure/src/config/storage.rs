@@ -30,6 +30,24 @@ pub struct StorageConfig {
     /// Interval between cleanup sweeps in seconds (default: 1 hour)
     #[config(default = 3600_u64, env = "STORAGE_CLEANUP_INTERVAL_SECONDS")]
     pub cleanup_interval_seconds: u64,
+    /// How long deleted media stay in the trash before they are purged in seconds (default: 30 days)
+    #[config(default = 2592000_u64, env = "STORAGE_TRASH_RETENTION_SECONDS")]
+    pub trash_retention_seconds: u64,
+    /// How far apart the still and the video of a Live Photo may be uploaded to be paired in seconds (default: 1 hour)
+    #[config(default = 3600_u64, env = "STORAGE_LIVE_PHOTO_PAIRING_WINDOW_SECONDS")]
+    pub live_photo_pairing_window_seconds: u64,
+    /// Hand out the existing medium instead of rejecting an upload of a file already in the library (default: false)
+    #[config(default = false, env = "STORAGE_LINK_DUPLICATE_UPLOADS")]
+    pub link_duplicate_uploads: bool,
+    /// Maximum number of differing bits between the perceptual hashes of near-duplicates, out of 64 (default: 8)
+    #[config(default = 8_u32, env = "STORAGE_NEAR_DUPLICATE_THRESHOLD")]
+    pub near_duplicate_threshold: u32,
+    /// Interval between perceptual hash sweeps in seconds (default: 5 minutes)
+    #[config(default = 300_u64, env = "STORAGE_PERCEPTUAL_HASH_INTERVAL_SECONDS")]
+    pub perceptual_hash_interval_seconds: u64,
+    /// Maximum number of media hashed in one sweep (default: 100)
+    #[config(default = 100_i64, env = "STORAGE_PERCEPTUAL_HASH_BATCH_SIZE")]
+    pub perceptual_hash_batch_size: i64,
 }
 
 impl StorageConfig {
@@ -1,9 +1,12 @@
 use std::sync::Arc;
 
 use application::{
+    album::AlbumApplicationHandlers,
     medium::MediumApplicationHandlers,
     metadata::MetadataApplicationHandlers,
+    smart_album::SmartAlbumApplicationHandlers,
     system::SystemApplicationHandlers,
+    tag::TagApplicationHandlers,
     task::ProcessingApplicationHandlers,
     user::{QuotaManager, UserApplicationHandlers},
 };
@@ -20,7 +23,9 @@ use super::{
     listeners::register_listeners,
 };
 use crate::{
-    config::GlobalConfig, events::ProjectionEventBusAdapter, storage::cleanup::spawn_cleanup_task,
+    config::GlobalConfig,
+    events::ProjectionEventBusAdapter,
+    storage::cleanup::{spawn_cleanup_task, spawn_perceptual_hash_task, spawn_trash_purge_task},
 };
 
 /// Dependency injection container.
@@ -71,6 +76,16 @@ impl Container {
             config.storage.temp_ttl_seconds,
             config.storage.cleanup_interval_seconds,
         ));
+        background_tasks.push(spawn_trash_purge_task(
+            handlers.medium.purge_expired_trash.clone(),
+            config.storage.trash_retention_seconds,
+            config.storage.cleanup_interval_seconds,
+        ));
+        background_tasks.push(spawn_perceptual_hash_task(
+            handlers.medium.compute_perceptual_hashes.clone(),
+            config.storage.perceptual_hash_batch_size,
+            config.storage.perceptual_hash_interval_seconds,
+        ));
 
         Ok(Arc::new(Self {
             config,
@@ -110,6 +125,18 @@ impl Container {
         self.application_handlers.processing.clone()
     }
 
+    pub fn album_handlers(&self) -> Arc<AlbumApplicationHandlers> {
+        self.application_handlers.album.clone()
+    }
+
+    pub fn smart_album_handlers(&self) -> Arc<SmartAlbumApplicationHandlers> {
+        self.application_handlers.smart_album.clone()
+    }
+
+    pub fn tag_handlers(&self) -> Arc<TagApplicationHandlers> {
+        self.application_handlers.tag.clone()
+    }
+
     pub async fn shutdown(self: Arc<Self>) {
         tracing::info!(
             "Shutting down {} background tasks...",
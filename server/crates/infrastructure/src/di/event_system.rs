@@ -1,7 +1,17 @@
 use std::sync::{Arc, RwLock};
 
-use domain::medium::events::{
-    TempCleanupCompletedEvent, TempCleanupFailedEvent, TempCleanupStartedEvent,
+use domain::{
+    medium::events::{
+        PreviewGenerationCompletedEvent, PreviewGenerationFailedEvent,
+        PreviewGenerationStartedEvent, TempCleanupCompletedEvent, TempCleanupFailedEvent,
+        TempCleanupStartedEvent,
+    },
+    metadata::events::{
+        CaptureDateShiftCompletedEvent, CaptureDateShiftFailedEvent,
+        CaptureDateShiftProgressedEvent, CaptureDateShiftStartedEvent,
+        MetadataReextractionCompletedEvent, MetadataReextractionFailedEvent,
+        MetadataReextractionProgressedEvent, MetadataReextractionStartedEvent,
+    },
 };
 use event_sourcing::{
     bus::projection::ProjectionEventBus,
@@ -10,7 +20,9 @@ use event_sourcing::{
 use snafu::{ResultExt, Whatever};
 use sqlx::{PgPool, Postgres, Transaction};
 
-use super::stream_definitions::{medium_stream, metadata_stream, task_stream, user_stream};
+use super::stream_definitions::{
+    album_stream, medium_stream, metadata_stream, smart_album_stream, task_stream, user_stream,
+};
 use crate::{
     persistence::postgres::{
         checkpoint_store::PostgresTxCheckpointStore,
@@ -19,7 +31,8 @@ use crate::{
         transaction_provider::PostgresTransactionProvider,
     },
     projections::{
-        MediumProjection, MetadataProjection, RegisterProjection, TaskProjection, UserProjection,
+        AlbumProjection, MediumProjection, MetadataProjection, RegisterProjection,
+        SmartAlbumProjection, TaskProjection, UserProjection,
     },
 };
 
@@ -53,11 +66,32 @@ pub fn build_projection_bus(
             .whatever_context("Failed to register MetadataProjection")?;
         TaskProjection::register(&bus, &mut reg)
             .whatever_context("Failed to register TaskProjection")?;
+        AlbumProjection::register(&bus, &mut reg)
+            .whatever_context("Failed to register AlbumProjection")?;
+        SmartAlbumProjection::register(&bus, &mut reg)
+            .whatever_context("Failed to register SmartAlbumProjection")?;
 
         // TempCleanup events — persisted but no projections (only listeners)
         reg.register::<TempCleanupStartedEvent>();
         reg.register::<TempCleanupCompletedEvent>();
         reg.register::<TempCleanupFailedEvent>();
+
+        // PreviewGeneration events — persisted but no projections (only listeners)
+        reg.register::<PreviewGenerationStartedEvent>();
+        reg.register::<PreviewGenerationCompletedEvent>();
+        reg.register::<PreviewGenerationFailedEvent>();
+
+        // CaptureDateShift events — persisted but no projections (only listeners)
+        reg.register::<CaptureDateShiftStartedEvent>();
+        reg.register::<CaptureDateShiftProgressedEvent>();
+        reg.register::<CaptureDateShiftCompletedEvent>();
+        reg.register::<CaptureDateShiftFailedEvent>();
+
+        // MetadataReextraction events — persisted but no projections (only listeners)
+        reg.register::<MetadataReextractionStartedEvent>();
+        reg.register::<MetadataReextractionProgressedEvent>();
+        reg.register::<MetadataReextractionCompletedEvent>();
+        reg.register::<MetadataReextractionFailedEvent>();
     }
 
     // Stream linking projection — populates event_streams table
@@ -66,6 +100,8 @@ pub fn build_projection_bus(
         Arc::new(user_stream()),
         Arc::new(task_stream()),
         Arc::new(metadata_stream()),
+        Arc::new(album_stream()),
+        Arc::new(smart_album_stream()),
     ];
     bus.register_catch_all(StreamLinkingProjection::new(
         extractors,
@@ -1,34 +1,61 @@
 use std::sync::{Arc, RwLock};
 
 use application::{
-    config::{AuthConfig, QuotaConfig},
+    album::{AlbumApplicationHandlers, AlbumRepository},
+    config::{
+        AuthConfig, DuplicateConfig, DuplicatePolicy, LivePhotoConfig, MediumConfig, QuotaConfig,
+    },
     medium::{
-        ports::{FileStorage, MediumRepository},
+        ports::{
+            EmbeddedPreviewExtractor, FileStorage, MediumRepository, PosterFrameGenerator,
+            PreviewServices,
+        },
         MediumApplicationHandlers,
     },
     metadata::{
-        ports::{MetadataExtractor, MetadataRepository},
+        ports::{MetadataExtractor, MetadataRepository, MetadataServices, SidecarWriter},
         MetadataApplicationHandlers,
     },
+    smart_album::{SmartAlbumApplicationHandlers, SmartAlbumRepository},
     system::SystemApplicationHandlers,
+    tag::{TagApplicationHandlers, TagRepository},
     task::{ports::TaskRepository, ProcessingApplicationHandlers},
     user::{ports::UserRepository, QuotaManager, UserApplicationHandlers},
 };
 use byte_unit::Byte;
-use domain::{medium::Medium, metadata::Metadata, task::Task, user::User};
+use domain::{
+    album::Album, medium::Medium, metadata::Metadata, smart_album::SmartAlbum, task::Task,
+    user::User,
+};
 use event_sourcing::aggregate::repository::AggregateRepository;
+use snafu::{whatever, Report, ResultExt};
 use sqlx::PgPool;
+use tracing::{info, warn};
 
 use crate::{
-    config::GlobalConfig,
-    di::stream_definitions::{medium_stream, metadata_stream, task_stream, user_stream},
+    config::{GlobalConfig, MetadataExtractorKind},
+    di::stream_definitions::{
+        album_stream, medium_stream, metadata_stream, smart_album_stream, task_stream, user_stream,
+    },
     events::ProjectionEventBusAdapter,
-    external::exif::{Exiftool, ExiftoolMetadataExtractor},
+    external::{
+        exif::{
+            Exiftool, ExiftoolMetadataExtractor, ExiftoolPreviewExtractor, ExiftoolSidecarWriter,
+            ExiftoolUnavailable,
+        },
+        geocoding::GeoNamesReverseGeocoder,
+        native_exif::{FallbackMetadataExtractor, NativeMetadataExtractor},
+        preview::{FfmpegPosterFrameGenerator, ImagePerceptualHasher, ImagePreviewRenderer},
+        timezone::BoundaryTimezoneResolver,
+    },
     persistence::postgres::{
+        album::PostgresAlbumRepository,
         es_snapshot_store::PostgresSnapshotStore,
         events::{aggregate_store::PostgresAggregateEventStore, type_registry::EventTypeRegistry},
         medium::PostgresMediumRepository,
         metadata::PostgresMetadataRepository,
+        smart_album::PostgresSmartAlbumRepository,
+        tag::PostgresTagRepository,
         task::PostgresTaskRepository,
         user::PostgresUserRepository,
     },
@@ -42,11 +69,15 @@ pub struct Repositories {
     pub medium: Arc<dyn MediumRepository>,
     pub metadata: Arc<dyn MetadataRepository>,
     pub task: Arc<dyn TaskRepository>,
+    pub album: Arc<dyn AlbumRepository>,
+    pub smart_album: Arc<dyn SmartAlbumRepository>,
+    pub tag: Arc<dyn TagRepository>,
 }
 
 pub struct StorageServices {
     pub file_storage: Arc<dyn FileStorage>,
-    pub metadata_extractor: Arc<dyn MetadataExtractor>,
+    pub metadata: MetadataServices,
+    pub previews: PreviewServices,
     pub storage_path_service: Arc<domain::medium::StoragePathService>,
 }
 
@@ -56,6 +87,9 @@ pub struct ApplicationHandlers {
     pub metadata: Arc<MetadataApplicationHandlers>,
     pub system: Arc<SystemApplicationHandlers>,
     pub processing: Arc<ProcessingApplicationHandlers>,
+    pub album: Arc<AlbumApplicationHandlers>,
+    pub smart_album: Arc<SmartAlbumApplicationHandlers>,
+    pub tag: Arc<TagApplicationHandlers>,
 }
 
 // -- Factory functions --
@@ -66,20 +100,109 @@ pub fn build_repositories(db_pool: &PgPool) -> Repositories {
         medium: Arc::new(PostgresMediumRepository::new(db_pool.clone())),
         metadata: Arc::new(PostgresMetadataRepository::new(db_pool.clone())),
         task: Arc::new(PostgresTaskRepository::new(db_pool.clone())),
+        album: Arc::new(PostgresAlbumRepository::new(db_pool.clone())),
+        smart_album: Arc::new(PostgresSmartAlbumRepository::new(db_pool.clone())),
+        tag: Arc::new(PostgresTagRepository::new(db_pool.clone())),
     }
 }
 
 pub async fn build_storage(config: Arc<GlobalConfig>) -> Result<StorageServices, snafu::Whatever> {
     let filesystem = Arc::new(FilesystemStorageAdapter::new(config.clone()));
-    let exiftool = Arc::new(Exiftool::new().await?);
-    let metadata_extractor = Arc::new(ExiftoolMetadataExtractor::new(exiftool, filesystem.clone()));
+    // Only an explicit choice of exiftool makes it a requirement
+    let extractor_kind = config.metadata.extractor;
+    let exiftool = match (extractor_kind, Exiftool::new().await) {
+        (_, Ok(exiftool)) => Some(Arc::new(exiftool)),
+        (MetadataExtractorKind::Exiftool, Err(e)) => return Err(e),
+        (_, Err(e)) => {
+            warn!(error = %e, "exiftool not found, using the native metadata extractor");
+            None
+        }
+    };
+    let native_extractor = Arc::new(NativeMetadataExtractor::new(filesystem.clone()));
+    let metadata_extractor: Arc<dyn MetadataExtractor> = match (extractor_kind, &exiftool) {
+        (MetadataExtractorKind::Native, _) | (_, None) => native_extractor,
+        (MetadataExtractorKind::Exiftool, Some(exiftool)) => Arc::new(
+            ExiftoolMetadataExtractor::new(exiftool.clone(), filesystem.clone()),
+        ),
+        (MetadataExtractorKind::ExiftoolWithNativeFallback, Some(exiftool)) => {
+            Arc::new(FallbackMetadataExtractor::new(
+                Arc::new(ExiftoolMetadataExtractor::new(
+                    exiftool.clone(),
+                    filesystem.clone(),
+                )),
+                native_extractor,
+            ))
+        }
+    };
+    let preview_extractor = exiftool.as_ref().map(|exiftool| {
+        Arc::new(ExiftoolPreviewExtractor::new(
+            exiftool.clone(),
+            filesystem.clone(),
+        ))
+    });
+    let sidecar_writer: Arc<dyn SidecarWriter> = match exiftool {
+        Some(exiftool) => Arc::new(ExiftoolSidecarWriter::new(exiftool, filesystem.clone())),
+        None => Arc::new(ExiftoolUnavailable),
+    };
+    let embedded_extractor: Arc<dyn EmbeddedPreviewExtractor> = match &preview_extractor {
+        Some(extractor) => extractor.clone(),
+        None => Arc::new(ExiftoolUnavailable),
+    };
+    // Without ffmpeg, videos fall back to the thumbnail embedded by the camera
+    let poster_frame_generator: Arc<dyn PosterFrameGenerator> =
+        if FfmpegPosterFrameGenerator::is_available().await {
+            Arc::new(FfmpegPosterFrameGenerator::new(filesystem.clone()))
+        } else if let Some(extractor) = preview_extractor {
+            warn!("ffmpeg not found, using embedded video thumbnails as poster frames");
+            extractor
+        } else {
+            warn!("Neither ffmpeg nor exiftool found, videos get no poster frames");
+            Arc::new(ExiftoolUnavailable)
+        };
+    let previews = PreviewServices {
+        renderer: Arc::new(ImagePreviewRenderer::new(filesystem.clone())),
+        embedded_extractor,
+        poster_frame_generator,
+        perceptual_hasher: Arc::new(ImagePerceptualHasher::new(filesystem.clone())),
+    };
     let storage_path_service = Arc::new(domain::medium::StoragePathService::new(
         config.storage.pattern.clone(),
     ));
+    // Without a dataset, media simply get no place names. A configured one
+    // that cannot be loaded is an error rather than silently resolving nothing.
+    let reverse_geocoder = match config.geocoding.data_directory.clone() {
+        Some(directory) => {
+            let max_distance_km = config.geocoding.max_distance_km;
+            // `Whatever` is not `Send`, so the error crosses the task as its report
+            let loaded = tokio::task::spawn_blocking(move || {
+                GeoNamesReverseGeocoder::load(&directory, max_distance_km)
+                    .map_err(|error| Report::from_error(error).to_string())
+            })
+            .await
+            .whatever_context("GeoNames loading task failed")?;
+            match loaded {
+                Ok(geocoder) => geocoder,
+                Err(report) => whatever!("Could not load the GeoNames dataset: {report}"),
+            }
+        }
+        None => {
+            info!("No GeoNames dataset configured, places are not resolved");
+            GeoNamesReverseGeocoder::empty()
+        }
+    };
+    let timezone_resolver = tokio::task::spawn_blocking(BoundaryTimezoneResolver::new)
+        .await
+        .whatever_context("Time zone boundaries loading task failed")?;
 
     Ok(StorageServices {
         file_storage: filesystem,
-        metadata_extractor,
+        metadata: MetadataServices {
+            extractor: metadata_extractor,
+            reverse_geocoder: Arc::new(reverse_geocoder),
+            timezone_resolver: Arc::new(timezone_resolver),
+            sidecar_writer,
+        },
+        previews,
         storage_path_service,
     })
 }
@@ -102,19 +225,39 @@ pub fn build_handlers(
         quota_config,
     ));
 
+    let medium_config = Arc::new(MediumConfig {
+        live_photo: LivePhotoConfig {
+            pairing_window: chrono::Duration::seconds(
+                config.storage.live_photo_pairing_window_seconds as i64,
+            ),
+        },
+        duplicates: DuplicateConfig {
+            upload_policy: if config.storage.link_duplicate_uploads {
+                DuplicatePolicy::Link
+            } else {
+                DuplicatePolicy::Reject
+            },
+            similarity_threshold: config.storage.near_duplicate_threshold,
+        },
+    });
+
     let medium_handlers = Arc::new(MediumApplicationHandlers::new(
         repositories.medium.clone(),
         storage.file_storage.clone(),
         quota_manager,
         event_bus.clone(),
-        event_bus.clone(),
         storage.storage_path_service.clone(),
-        event_bus.clone(),
+        storage.previews.clone(),
+        medium_config,
     ));
 
     let metadata_handlers = Arc::new(MetadataApplicationHandlers::new(
-        storage.metadata_extractor.clone(),
+        storage.metadata.clone(),
         repositories.metadata.clone(),
+        repositories.medium.clone(),
+        repositories.user.clone(),
+        storage.file_storage.clone(),
+        medium_handlers.add_medium_item.clone(),
         event_bus.clone(),
     ));
 
@@ -130,12 +273,33 @@ pub fn build_handlers(
         repositories.task.clone(),
     ));
 
+    let album_handlers = Arc::new(AlbumApplicationHandlers::new(
+        repositories.album.clone(),
+        repositories.medium.clone(),
+        event_bus.clone(),
+    ));
+
+    let smart_album_handlers = Arc::new(SmartAlbumApplicationHandlers::new(
+        repositories.smart_album.clone(),
+        repositories.medium.clone(),
+        event_bus.clone(),
+    ));
+
+    let tag_handlers = Arc::new(TagApplicationHandlers::new(
+        repositories.tag.clone(),
+        repositories.medium.clone(),
+        event_bus.clone(),
+    ));
+
     ApplicationHandlers {
         user: user_handlers,
         medium: medium_handlers,
         metadata: metadata_handlers,
         system: system_handlers,
         processing: processing_handlers,
+        album: album_handlers,
+        smart_album: smart_album_handlers,
+        tag: tag_handlers,
     }
 }
 
@@ -151,6 +315,8 @@ pub fn build_aggregate_repository(
     repo.register_with_snapshots::<User>(user_stream(), snapshot_store);
     repo.register::<Task>(task_stream());
     repo.register::<Metadata>(metadata_stream());
+    repo.register::<Album>(album_stream());
+    repo.register::<SmartAlbum>(smart_album_stream());
 
     Arc::new(repo)
 }
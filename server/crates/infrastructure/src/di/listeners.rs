@@ -1,17 +1,32 @@
 use application::{
-    medium::listeners::{MediumMetadataEnrichmentListener, MoveToPermanentStorageListener},
-    metadata::listeners::MetadataExtractionListeners,
+    medium::listeners::{
+        LivePhotoPairingListener, MediumMetadataEnrichmentListener, MediumStackingListener,
+        MoveToPermanentStorageListener, PreviewGenerationListener,
+    },
+    metadata::listeners::{
+        LocationResolutionListener, MetadataExtractionListeners, SidecarListener,
+        SidecarWriteBackListener,
+    },
     task::listeners::{
-        TaskCompletedListeners, TaskCreationListeners, TaskFailedListeners, TaskStartedListeners,
+        TaskCompletedListeners, TaskCreationListeners, TaskFailedListeners, TaskProgressListeners,
+        TaskStartedListeners,
     },
 };
 use domain::{
     medium::events::{
-        MediumCreatedEvent, MediumUpdatedEvent, TempCleanupCompletedEvent, TempCleanupFailedEvent,
+        LivePhotoPairedEvent, MediaStackedEvent, MediumCreatedEvent, MediumCurationChangedEvent,
+        MediumItemCreatedEvent, MediumSplitOffEvent, MediumTagsAddedEvent, MediumTagsRemovedEvent,
+        MediumUpdatedEvent, PreviewGenerationCompletedEvent, PreviewGenerationFailedEvent,
+        PreviewGenerationStartedEvent, TempCleanupCompletedEvent, TempCleanupFailedEvent,
         TempCleanupStartedEvent,
     },
     metadata::events::{
-        MetadataExtractedEvent, MetadataExtractionFailedEvent, MetadataExtractionStartedEvent,
+        CaptureDateShiftCompletedEvent, CaptureDateShiftFailedEvent,
+        CaptureDateShiftProgressedEvent, CaptureDateShiftStartedEvent, MetadataExtractedEvent,
+        MetadataExtractionFailedEvent, MetadataExtractionStartedEvent, MetadataOverriddenEvent,
+        MetadataReextractionCompletedEvent, MetadataReextractionFailedEvent,
+        MetadataReextractionProgressedEvent, MetadataReextractionStartedEvent,
+        MetadataSidecarAppliedEvent,
     },
 };
 
@@ -45,12 +60,54 @@ pub fn register_listeners(
         MetadataExtractionListeners::new(handlers.metadata.extract_metadata_handler.clone()),
     )?;
 
+    register_listener::<MediumCreatedEvent, _>(
+        bus,
+        registry,
+        PreviewGenerationListener::new(handlers.medium.generate_previews.clone()),
+    )?;
+
     register_listener::<MediumUpdatedEvent, _>(
         bus,
         registry,
         MoveToPermanentStorageListener::new(handlers.medium.move_to_permanent_storage.clone()),
     )?;
 
+    register_listener::<MediumItemCreatedEvent, _>(
+        bus,
+        registry,
+        MoveToPermanentStorageListener::new(handlers.medium.move_to_permanent_storage.clone()),
+    )?;
+
+    register_listener::<MediumItemCreatedEvent, _>(
+        bus,
+        registry,
+        SidecarListener::new(handlers.metadata.apply_sidecar.clone()),
+    )?;
+
+    register_listener::<LivePhotoPairedEvent, _>(
+        bus,
+        registry,
+        MoveToPermanentStorageListener::new(handlers.medium.move_to_permanent_storage.clone()),
+    )?;
+
+    register_listener::<MediaStackedEvent, _>(
+        bus,
+        registry,
+        MoveToPermanentStorageListener::new(handlers.medium.move_to_permanent_storage.clone()),
+    )?;
+
+    register_listener::<MediumSplitOffEvent, _>(
+        bus,
+        registry,
+        MoveToPermanentStorageListener::new(handlers.medium.move_to_permanent_storage.clone()),
+    )?;
+
+    register_listener::<MediumSplitOffEvent, _>(
+        bus,
+        registry,
+        PreviewGenerationListener::new(handlers.medium.generate_previews.clone()),
+    )?;
+
     // -- Metadata event listeners --
 
     register_listener::<MetadataExtractionStartedEvent, _>(
@@ -77,6 +134,78 @@ pub fn register_listeners(
         MediumMetadataEnrichmentListener::new(handlers.medium.enrich_medium_with_metadata.clone()),
     )?;
 
+    register_listener::<MetadataExtractedEvent, _>(
+        bus,
+        registry,
+        LivePhotoPairingListener::new(handlers.medium.pair_live_photo.clone()),
+    )?;
+
+    register_listener::<MetadataExtractedEvent, _>(
+        bus,
+        registry,
+        MediumStackingListener::new(handlers.medium.stack_medium.clone()),
+    )?;
+
+    register_listener::<MetadataExtractedEvent, _>(
+        bus,
+        registry,
+        LocationResolutionListener::new(handlers.metadata.resolve_location.clone()),
+    )?;
+
+    register_listener::<MetadataOverriddenEvent, _>(
+        bus,
+        registry,
+        MediumMetadataEnrichmentListener::new(handlers.medium.enrich_medium_with_metadata.clone()),
+    )?;
+
+    register_listener::<MetadataOverriddenEvent, _>(
+        bus,
+        registry,
+        LocationResolutionListener::new(handlers.metadata.resolve_location.clone()),
+    )?;
+
+    register_listener::<MetadataOverriddenEvent, _>(
+        bus,
+        registry,
+        SidecarWriteBackListener::new(handlers.metadata.write_sidecar.clone()),
+    )?;
+
+    register_listener::<MediumCurationChangedEvent, _>(
+        bus,
+        registry,
+        SidecarWriteBackListener::new(handlers.metadata.write_sidecar.clone()),
+    )?;
+
+    register_listener::<MediumTagsAddedEvent, _>(
+        bus,
+        registry,
+        SidecarWriteBackListener::new(handlers.metadata.write_sidecar.clone()),
+    )?;
+
+    register_listener::<MediumTagsRemovedEvent, _>(
+        bus,
+        registry,
+        SidecarWriteBackListener::new(handlers.metadata.write_sidecar.clone()),
+    )?;
+
+    register_listener::<MetadataExtractedEvent, _>(
+        bus,
+        registry,
+        SidecarListener::new(handlers.metadata.apply_sidecar.clone()),
+    )?;
+
+    register_listener::<MetadataSidecarAppliedEvent, _>(
+        bus,
+        registry,
+        MediumMetadataEnrichmentListener::new(handlers.medium.enrich_medium_with_metadata.clone()),
+    )?;
+
+    register_listener::<MetadataSidecarAppliedEvent, _>(
+        bus,
+        registry,
+        LocationResolutionListener::new(handlers.metadata.resolve_location.clone()),
+    )?;
+
     // -- TempCleanup event listeners --
 
     register_listener::<TempCleanupStartedEvent, _>(
@@ -97,5 +226,77 @@ pub fn register_listeners(
         TaskFailedListeners::new(handlers.processing.fail_task.clone()),
     )?;
 
+    // -- Capture date shift event listeners --
+
+    register_listener::<CaptureDateShiftStartedEvent, _>(
+        bus,
+        registry,
+        TaskStartedListeners::new(handlers.processing.start_task.clone()),
+    )?;
+
+    register_listener::<CaptureDateShiftProgressedEvent, _>(
+        bus,
+        registry,
+        TaskProgressListeners::new(handlers.processing.report_task_progress.clone()),
+    )?;
+
+    register_listener::<CaptureDateShiftCompletedEvent, _>(
+        bus,
+        registry,
+        TaskCompletedListeners::new(handlers.processing.complete_task.clone()),
+    )?;
+
+    register_listener::<CaptureDateShiftFailedEvent, _>(
+        bus,
+        registry,
+        TaskFailedListeners::new(handlers.processing.fail_task.clone()),
+    )?;
+
+    // -- Metadata re-extraction event listeners --
+
+    register_listener::<MetadataReextractionStartedEvent, _>(
+        bus,
+        registry,
+        TaskStartedListeners::new(handlers.processing.start_task.clone()),
+    )?;
+
+    register_listener::<MetadataReextractionProgressedEvent, _>(
+        bus,
+        registry,
+        TaskProgressListeners::new(handlers.processing.report_task_progress.clone()),
+    )?;
+
+    register_listener::<MetadataReextractionCompletedEvent, _>(
+        bus,
+        registry,
+        TaskCompletedListeners::new(handlers.processing.complete_task.clone()),
+    )?;
+
+    register_listener::<MetadataReextractionFailedEvent, _>(
+        bus,
+        registry,
+        TaskFailedListeners::new(handlers.processing.fail_task.clone()),
+    )?;
+
+    // -- Preview generation event listeners --
+
+    register_listener::<PreviewGenerationStartedEvent, _>(
+        bus,
+        registry,
+        TaskStartedListeners::new(handlers.processing.start_task.clone()),
+    )?;
+
+    register_listener::<PreviewGenerationCompletedEvent, _>(
+        bus,
+        registry,
+        TaskCompletedListeners::new(handlers.processing.complete_task.clone()),
+    )?;
+
+    register_listener::<PreviewGenerationFailedEvent, _>(
+        bus,
+        registry,
+        TaskFailedListeners::new(handlers.processing.fail_task.clone()),
+    )?;
+
     Ok(())
 }
@@ -1,14 +1,33 @@
 use domain::{
+    album::{
+        events::{
+            AlbumCoverChangedEvent, AlbumCreatedEvent, AlbumDeletedEvent, AlbumMediumAddedEvent,
+            AlbumMediumRemovedEvent, AlbumRenamedEvent,
+        },
+        Album,
+    },
     medium::{
-        events::{MediumCreatedEvent, MediumItemCreatedEvent, MediumUpdatedEvent},
+        events::{
+            LivePhotoPairedEvent, MediaStackedEvent, MediumCreatedEvent,
+            MediumCurationChangedEvent, MediumDeletedEvent, MediumItemCreatedEvent,
+            MediumItemLocationAddedEvent, MediumItemLocationRemovedEvent, MediumMergedEvent,
+            MediumPurgedEvent, MediumRestoredEvent, MediumSplitEvent, MediumSplitOffEvent,
+            MediumTagsAddedEvent, MediumTagsRemovedEvent, MediumUpdatedEvent,
+            PerceptualHashComputedEvent,
+        },
         Medium,
     },
     metadata::{
         events::{
-            MetadataExtractedEvent, MetadataExtractionFailedEvent, MetadataExtractionStartedEvent,
+            LocationResolvedEvent, MetadataExtractedEvent, MetadataExtractionFailedEvent,
+            MetadataExtractionStartedEvent, MetadataOverriddenEvent, MetadataSidecarAppliedEvent,
         },
         Metadata,
     },
+    smart_album::{
+        events::{SmartAlbumCreatedEvent, SmartAlbumDeletedEvent, SmartAlbumUpdatedEvent},
+        SmartAlbum,
+    },
     task::{
         events::{TaskCompletedEvent, TaskCreatedEvent, TaskFailedEvent, TaskStartedEvent},
         Task,
@@ -16,7 +35,7 @@ use domain::{
     user::{
         events::{
             QuotaCommittedEvent, QuotaReleasedEvent, QuotaReservedEvent, UserCreatedEvent,
-            UserUpdatedEvent,
+            UserSettingsUpdatedEvent, UserUpdatedEvent,
         },
         User,
     },
@@ -27,7 +46,21 @@ pub fn medium_stream() -> StreamDefinition<Medium> {
     StreamDefinition::<Medium>::builder()
         .with::<MediumCreatedEvent>(|e| Some(e.medium_id.to_string()))
         .with::<MediumItemCreatedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumItemLocationAddedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumItemLocationRemovedEvent>(|e| Some(e.medium_id.to_string()))
         .with::<MediumUpdatedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumDeletedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumRestoredEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumPurgedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<LivePhotoPairedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumMergedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediaStackedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumSplitEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumSplitOffEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<PerceptualHashComputedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumTagsAddedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumTagsRemovedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MediumCurationChangedEvent>(|e| Some(e.medium_id.to_string()))
         .build()
 }
 
@@ -35,6 +68,7 @@ pub fn user_stream() -> StreamDefinition<User> {
     StreamDefinition::<User>::builder()
         .with::<UserCreatedEvent>(|e| Some(e.user_id.to_string()))
         .with::<UserUpdatedEvent>(|e| Some(e.user_id.to_string()))
+        .with::<UserSettingsUpdatedEvent>(|e| Some(e.user_id.to_string()))
         .with::<QuotaReservedEvent>(|e| Some(e.user_id.to_string()))
         .with::<QuotaCommittedEvent>(|e| Some(e.user_id.to_string()))
         .with::<QuotaReleasedEvent>(|e| Some(e.user_id.to_string()))
@@ -50,10 +84,32 @@ pub fn task_stream() -> StreamDefinition<Task> {
         .build()
 }
 
+pub fn album_stream() -> StreamDefinition<Album> {
+    StreamDefinition::<Album>::builder()
+        .with::<AlbumCreatedEvent>(|e| Some(e.album_id.to_string()))
+        .with::<AlbumRenamedEvent>(|e| Some(e.album_id.to_string()))
+        .with::<AlbumCoverChangedEvent>(|e| Some(e.album_id.to_string()))
+        .with::<AlbumMediumAddedEvent>(|e| Some(e.album_id.to_string()))
+        .with::<AlbumMediumRemovedEvent>(|e| Some(e.album_id.to_string()))
+        .with::<AlbumDeletedEvent>(|e| Some(e.album_id.to_string()))
+        .build()
+}
+
+pub fn smart_album_stream() -> StreamDefinition<SmartAlbum> {
+    StreamDefinition::<SmartAlbum>::builder()
+        .with::<SmartAlbumCreatedEvent>(|e| Some(e.smart_album_id.to_string()))
+        .with::<SmartAlbumUpdatedEvent>(|e| Some(e.smart_album_id.to_string()))
+        .with::<SmartAlbumDeletedEvent>(|e| Some(e.smart_album_id.to_string()))
+        .build()
+}
+
 pub fn metadata_stream() -> StreamDefinition<Metadata> {
     StreamDefinition::<Metadata>::builder()
         .with::<MetadataExtractionStartedEvent>(|e| Some(e.medium_id.to_string()))
         .with::<MetadataExtractedEvent>(|e| Some(e.medium_id.to_string()))
         .with::<MetadataExtractionFailedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<LocationResolvedEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MetadataOverriddenEvent>(|e| Some(e.medium_id.to_string()))
+        .with::<MetadataSidecarAppliedEvent>(|e| Some(e.medium_id.to_string()))
         .build()
 }
@@ -263,6 +263,44 @@ impl Exiftool {
         Ok(items)
     }
 
+    /// Reads only the given tags with their unconverted values. Binary tags
+    /// are returned as `base64:` prefixed strings.
+    pub async fn read_tags<P>(&self, file: P, tags: &[&str]) -> DomainResult<HashMap<String, Value>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = Self::validate_path(&file)?;
+        // -n numerical values, -b binary data instead of a placeholder
+        let tags: String = tags.iter().map(|tag| format!("\n-{tag}")).collect();
+        let cmd = format!("\n-j\n-n\n-b{}\n{}", tags, path);
+        let result = self.send_command(cmd).await?;
+        let result: Value = serde_json::from_str(&result).map_err(|err| {
+            ParseSnafu {
+                message: err.to_string(),
+            }
+            .build()
+        })?;
+
+        Ok(Self::extract_fields(&result)
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect())
+    }
+
+    /// Writes tags into the file in place, each assignment is an argument
+    /// like `-XMP-dc:Title=Sunrise`. HTML entities in values are decoded, so
+    /// line breaks can be passed as `&#xa;`.
+    pub async fn write_tags<P>(&self, file: P, assignments: &[String]) -> DomainResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = Self::validate_path(&file)?;
+        let assignments: String = assignments.iter().map(|a| format!("\n{a}")).collect();
+        let cmd = format!("\n-overwrite_original\n-E{}\n{}", assignments, path);
+        let result = self.send_command(cmd).await?;
+        debug!(result = %result.trim(), "Tags written");
+        Ok(())
+    }
+
     pub async fn read_file_grouped<P>(
         &self,
         file: P,
@@ -6,12 +6,22 @@ use chrono::{DateTime, FixedOffset, NaiveDateTime, TimeZone, Utc};
 use domain::{
     error::DomainResult,
     medium::{FileLocation, MediumId},
-    metadata::{CameraInfo, FileInfo, LocationInfo, Metadata, Orientation, TechnicalInfo},
+    metadata::{
+        CameraInfo, CropInfo, DescriptiveInfo, ExposureMode, ExposureProgram, FileInfo,
+        LocationInfo, Metadata, MeteringMode, Orientation, SidecarMetadata, TechnicalInfo,
+        TimezoneSource, VideoInfo, WhiteBalance,
+    },
 };
+use serde_json::Value;
+use tracing::warn;
 use uuid::Uuid;
 
 use super::{Exiftool, Field};
 
+/// Version of `convert_exif_to_metadata`. Raise it with every change to the
+/// values it reads, media extracted before are then extracted again.
+pub const EXTRACTOR_VERSION: u32 = 2;
+
 /// Infrastructure adapter that implements MetadataExtractor using exiftool
 pub struct ExiftoolMetadataExtractor {
     exiftool: Arc<Exiftool>,
@@ -29,6 +39,10 @@ impl ExiftoolMetadataExtractor {
 
 #[async_trait]
 impl MetadataExtractor for ExiftoolMetadataExtractor {
+    fn version(&self) -> u32 {
+        EXTRACTOR_VERSION
+    }
+
     async fn extract(
         &self,
         location: &FileLocation,
@@ -40,10 +54,135 @@ impl MetadataExtractor for ExiftoolMetadataExtractor {
         // Convert exiftool output to event Metadata
         Ok(convert_exif_to_metadata(&exif_data, medium_id))
     }
+
+    async fn extract_sidecar(&self, location: &FileLocation) -> DomainResult<SidecarMetadata> {
+        let path = self.file_storage.get_local_path(location).await?;
+        let xmp = self.exiftool.read_tags(path, &SIDECAR_TAGS).await?;
+
+        Ok(convert_xmp_to_sidecar(&xmp))
+    }
+}
+
+/// Tags read from XMP sidecars, the `crs` crop tags are written by Lightroom
+const SIDECAR_TAGS: [&str; 14] = [
+    "XMP-dc:Subject",
+    "XMP-xmp:Rating",
+    "XMP-xmp:Label",
+    "XMP-dc:Title",
+    "XMP-dc:Description",
+    "XMP-crs:HasCrop",
+    "XMP-crs:CropTop",
+    "XMP-crs:CropLeft",
+    "XMP-crs:CropBottom",
+    "XMP-crs:CropRight",
+    "XMP-crs:CropAngle",
+    "XMP-exif:GPSLatitude",
+    "XMP-exif:GPSLongitude",
+    "XMP-exif:GPSAltitude",
+];
+
+/// Convert the numerical values of exiftool read from an XMP sidecar
+fn convert_xmp_to_sidecar(xmp: &HashMap<String, Value>) -> SidecarMetadata {
+    let text = |key: &str| {
+        xmp.get(key)
+            .map(|v| match v {
+                Value::String(s) => s.trim().to_string(),
+                v => v.to_string(),
+            })
+            .filter(|s| !s.is_empty())
+    };
+    let number = |key: &str| {
+        xmp.get(key).and_then(|v| match v {
+            Value::String(s) => s.trim().parse::<f64>().ok(),
+            v => v.as_f64(),
+        })
+    };
+
+    // Keywords too long to become a tag are dropped instead of rejecting the
+    // whole sidecar
+    let keywords = keywords_of(xmp.get("Subject"))
+        .into_iter()
+        .filter(|keyword| {
+            let fits = keyword.chars().count() <= SidecarMetadata::MAX_KEYWORD_LENGTH;
+            if !fits {
+                warn!(keyword, "Skipping sidecar keyword that is too long");
+            }
+            fits
+        })
+        .collect();
+
+    let has_crop = xmp
+        .get("HasCrop")
+        .is_some_and(|v| v.as_bool().unwrap_or(v.as_i64() == Some(1)));
+    let crop = match (
+        number("CropTop"),
+        number("CropLeft"),
+        number("CropBottom"),
+        number("CropRight"),
+    ) {
+        (Some(top), Some(left), Some(bottom), Some(right)) if has_crop => Some(CropInfo {
+            top,
+            left,
+            bottom,
+            right,
+            angle: number("CropAngle").unwrap_or(0.0),
+        }),
+        _ => None,
+    };
+
+    let location =
+        number("GPSLatitude")
+            .zip(number("GPSLongitude"))
+            .map(|(latitude, longitude)| LocationInfo {
+                latitude,
+                longitude,
+                altitude: number("GPSAltitude"),
+                direction: None,
+                horizontal_position_error: None,
+            });
+
+    SidecarMetadata {
+        keywords,
+        rating: number("Rating").map(|r| r.round() as i8),
+        label: text("Label"),
+        title: text("Title"),
+        description: text("Description"),
+        crop,
+        location,
+    }
+}
+
+/// The trimmed keywords of a tag without duplicates. A single keyword is not
+/// returned as a list.
+fn keywords_of(value: Option<&Value>) -> Vec<String> {
+    let values = match value {
+        Some(Value::Array(values)) => values.iter().map(value_text).collect(),
+        Some(value) => vec![value_text(value)],
+        None => Vec::new(),
+    };
+
+    let mut keywords: Vec<String> = Vec::new();
+    for keyword in values {
+        let keyword = keyword.trim();
+        if !keyword.is_empty() && !keywords.iter().any(|k| k == keyword) {
+            keywords.push(keyword.to_string());
+        }
+    }
+    keywords
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
 }
 
 /// Convert exiftool's raw output to our event Metadata
-fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId) -> Metadata {
+pub(crate) fn convert_exif_to_metadata(
+    exif: &HashMap<String, Field>,
+    medium_id: MediumId,
+) -> Metadata {
     // Extract basic file info
     let file_info = FileInfo {
         mime_type: exif
@@ -63,8 +202,19 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
             .and_then(parse_exif_date),
     };
 
+    // Prefer the unconverted value, exiftool only adds it when it differs
+    let number = |key: &str| {
+        exif.get(key)
+            .and_then(|v| v.raw.as_ref().unwrap_or(&v.value).as_f64())
+    };
+    let code = |key: &str| {
+        exif.get(key)
+            .and_then(|v| v.raw.as_ref().unwrap_or(&v.value).as_u64())
+    };
+
     // Extract camera info
     let camera_info = if has_camera_info(exif) {
+        let (capture_date, capture_timezone_source) = extract_capture_date(exif);
         Some(CameraInfo {
             make: exif
                 .get("Make")
@@ -74,14 +224,8 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
                 .get("Model")
                 .and_then(|v| v.value.as_str())
                 .map(String::from),
-            capture_date: exif
-                .get("SubSecDateTimeOriginal")
-                .and_then(|v| v.value.as_str())
-                .and_then(parse_exif_date)
-                .or(exif
-                    .get("DateTimeOriginal")
-                    .and_then(|v| v.value.as_str())
-                    .and_then(parse_exif_date)),
+            capture_date,
+            capture_timezone_source,
             modified_date: exif
                 .get("SubSecModifyDate")
                 .and_then(|v| v.value.as_str())
@@ -111,6 +255,13 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
                 .get("Flash")
                 .and_then(|v| v.value.as_str())
                 .map(|s| s.contains("Fired")),
+            lens_serial_number: serial_number(exif, &["LensSerialNumber"]),
+            exposure_program: code("ExposureProgram").and_then(ExposureProgram::from_exif),
+            exposure_mode: code("ExposureMode").and_then(ExposureMode::from_exif),
+            metering_mode: code("MeteringMode").and_then(MeteringMode::from_exif),
+            white_balance: code("WhiteBalance").and_then(WhiteBalance::from_exif),
+            exposure_bias: number("ExposureCompensation"),
+            focal_length_35mm: number("FocalLengthIn35mmFormat").filter(|f| *f > 0.0),
         })
     } else {
         None
@@ -134,8 +285,41 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
             .and_then(|v| v.raw.as_ref())
             .and_then(|v| v.as_u64())
             .map(|o| Orientation::from(o as u8)),
+        software: first_text(exif, &["Software"]),
+    };
+
+    // IPTC values take precedence over their EXIF and XMP counterparts
+    let descriptive = DescriptiveInfo {
+        rating: number("Rating").map(|r| r.round().clamp(-1.0, 5.0) as i8),
+        keywords: extract_keywords(exif),
+        caption: first_text(
+            exif,
+            &["Caption-Abstract", "ImageDescription", "Description"],
+        ),
+        creator: first_text(exif, &["By-line", "Artist", "Creator"]),
+        copyright: first_text(exif, &["CopyrightNotice", "Copyright", "Rights"]),
+    };
+
+    // Extract video info from the QuickTime or Matroska tags
+    let video = if file_info.mime_type.type_() == mime::VIDEO {
+        Some(extract_video(exif))
+    } else {
+        None
     };
 
+    // Apple writes the same identifier into both files of a Live Photo
+    let content_identifier = exif
+        .get("ContentIdentifier")
+        .and_then(|v| v.value.as_str())
+        .map(String::from);
+
+    // Used to stack the RAW and JPEG of a shot and the frames of a burst
+    let camera_serial_number = serial_number(exif, &["SerialNumber", "InternalSerialNumber"]);
+    let burst_id = exif
+        .get("BurstUUID")
+        .and_then(|v| v.value.as_str())
+        .map(String::from);
+
     // Collect any additional EXIF fields not captured in structured fields
     let mut additional = HashMap::new();
     for (key, field) in exif.iter() {
@@ -149,6 +333,7 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
                 | "Model"
                 | "SubSecDateTimeOriginal"
                 | "DateTimeOriginal"
+                | "OffsetTimeOriginal"
                 | "SubSecModifyDate"
                 | "LensMake"
                 | "LensModel"
@@ -157,6 +342,26 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
                 | "ISO"
                 | "FocalLength"
                 | "Flash"
+                | "LensSerialNumber"
+                | "ExposureProgram"
+                | "ExposureMode"
+                | "MeteringMode"
+                | "WhiteBalance"
+                | "ExposureCompensation"
+                | "FocalLengthIn35mmFormat"
+                | "Software"
+                | "Rating"
+                | "Keywords"
+                | "Subject"
+                | "Caption-Abstract"
+                | "ImageDescription"
+                | "Description"
+                | "By-line"
+                | "Artist"
+                | "Creator"
+                | "CopyrightNotice"
+                | "Copyright"
+                | "Rights"
                 | "GPSLatitude"
                 | "GPSLongitude"
                 | "GPSAltitude"
@@ -165,6 +370,18 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
                 | "ImageWidth"
                 | "ImageHeight"
                 | "Orientation"
+                | "Duration"
+                | "VideoFrameRate"
+                | "CompressorID"
+                | "VideoCodecID"
+                | "AudioFormat"
+                | "AudioCodecID"
+                | "AvgBitrate"
+                | "Rotation"
+                | "ContentIdentifier"
+                | "SerialNumber"
+                | "InternalSerialNumber"
+                | "BurstUUID"
         );
 
         if !is_extracted {
@@ -178,21 +395,89 @@ fn convert_exif_to_metadata(exif: &HashMap<String, Field>, medium_id: MediumId)
         id: Uuid::new_v4(),
         medium_id,
         extracted_at: Utc::now(),
+        extractor_version: EXTRACTOR_VERSION,
         file_info,
         camera_info,
         location,
+        place: None,
         technical,
+        descriptive,
+        video,
+        content_identifier,
+        camera_serial_number,
+        burst_id,
         additional,
+        sidecar: None,
+        overrides: Default::default(),
         version: 0,
     }
 }
 
+/// The first of the tags with a non-empty text
+fn first_text(exif: &HashMap<String, Field>, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| exif.get(*key))
+        .map(|v| value_text(&v.value).trim().to_string())
+        .find(|v| !v.is_empty())
+}
+
+fn serial_number(exif: &HashMap<String, Field>, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|key| exif.get(*key))
+        .and_then(|v| match &v.value {
+            // Some cameras store the serial as a plain number
+            Value::Number(n) => Some(n.to_string()),
+            value => value.as_str().map(|s| s.trim().to_string()),
+        })
+        .filter(|v| !v.is_empty())
+}
+
+/// IPTC `Keywords`, or else XMP `Subject`
+fn extract_keywords(exif: &HashMap<String, Field>) -> Vec<String> {
+    keywords_of(
+        ["Keywords", "Subject"]
+            .iter()
+            .find_map(|key| exif.get(*key))
+            .map(|v| &v.value),
+    )
+}
+
 fn has_camera_info(exif: &HashMap<String, Field>) -> bool {
     exif.get("Make").is_some()
         || exif.get("Model").is_some()
         || exif.get("SubSecDateTimeOriginal").is_some()
 }
 
+/// Read the capture date and whether the camera recorded its UTC offset.
+/// Cameras that keep the offset in `OffsetTimeOriginal` get it attached to
+/// the local time.
+fn extract_capture_date(
+    exif: &HashMap<String, Field>,
+) -> (Option<DateTime<FixedOffset>>, Option<TimezoneSource>) {
+    let offset = exif
+        .get("OffsetTimeOriginal")
+        .and_then(|v| v.value.as_str())
+        .and_then(|v| v.trim().parse::<FixedOffset>().ok());
+
+    for date in ["SubSecDateTimeOriginal", "DateTimeOriginal"]
+        .iter()
+        .filter_map(|key| exif.get(*key).and_then(|v| v.value.as_str()))
+    {
+        if let Ok(date) = DateTime::parse_from_str(date, "%Y:%m:%d %H:%M:%S%.f%:z") {
+            return (Some(date), Some(TimezoneSource::Exif));
+        }
+        if let Ok(local) = NaiveDateTime::parse_from_str(date, "%Y:%m:%d %H:%M:%S%.f") {
+            return match offset.and_then(|o| o.from_local_datetime(&local).single()) {
+                Some(date) => (Some(date), Some(TimezoneSource::Exif)),
+                // The time zone is inferred after the extraction
+                None => (Some(local.and_utc().fixed_offset()), None),
+            };
+        }
+    }
+
+    (None, None)
+}
+
 fn extract_location(exif: &HashMap<String, Field>) -> Option<LocationInfo> {
     let lat = exif
         .get("GPSLatitude")
@@ -220,6 +505,29 @@ fn extract_location(exif: &HashMap<String, Field>) -> Option<LocationInfo> {
     }
 }
 
+fn extract_video(exif: &HashMap<String, Field>) -> VideoInfo {
+    // Prefer the unconverted value, exiftool formats e.g. "0:01:05" or "5.2 Mbps"
+    let number = |key: &str| {
+        exif.get(key)
+            .and_then(|v| v.raw.as_ref().unwrap_or(&v.value).as_f64())
+    };
+    let text = |keys: &[&str]| {
+        keys.iter()
+            .find_map(|key| exif.get(*key).and_then(|v| v.value.as_str()))
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    VideoInfo {
+        duration: number("Duration"),
+        frame_rate: number("VideoFrameRate"),
+        video_codec: text(&["CompressorID", "VideoCodecID"]),
+        audio_codec: text(&["AudioFormat", "AudioCodecID"]),
+        bitrate: number("AvgBitrate").map(|b| b.round() as u64),
+        rotation: number("Rotation").map(|r| r.rem_euclid(360.0) as u16),
+    }
+}
+
 fn parse_exif_date(date_str: &str) -> Option<DateTime<FixedOffset>> {
     // Parse various EXIF date formats
     // Common format: "2024:01:15 14:30:45"
@@ -236,3 +544,249 @@ fn parse_exif_date(date_str: &str) -> Option<DateTime<FixedOffset>> {
                 })
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn field(value: Value, raw: Option<Value>) -> Field {
+        Field {
+            description: String::new(),
+            value,
+            raw,
+        }
+    }
+
+    #[test]
+    fn test_sidecar_is_read_from_xmp_tags() {
+        let xmp = HashMap::from([
+            (
+                "Subject".to_string(),
+                json!(["beach", " family ", "beach", 2024]),
+            ),
+            ("Rating".to_string(), json!(4)),
+            ("Label".to_string(), json!("Red")),
+            ("Title".to_string(), json!("Sunset")),
+            ("Description".to_string(), json!("")),
+            ("HasCrop".to_string(), json!(true)),
+            ("CropTop".to_string(), json!(0.1)),
+            ("CropLeft".to_string(), json!(0.05)),
+            ("CropBottom".to_string(), json!(0.9)),
+            ("CropRight".to_string(), json!(0.95)),
+            ("GPSLatitude".to_string(), json!(38.7223)),
+            ("GPSLongitude".to_string(), json!(-9.1393)),
+        ]);
+
+        let sidecar = convert_xmp_to_sidecar(&xmp);
+
+        assert_eq!(sidecar.keywords, vec!["beach", "family", "2024"]);
+        assert_eq!(sidecar.rating, Some(4));
+        assert_eq!(sidecar.label.as_deref(), Some("Red"));
+        assert_eq!(sidecar.title.as_deref(), Some("Sunset"));
+        assert_eq!(sidecar.description, None);
+        assert_eq!(
+            sidecar.crop,
+            Some(CropInfo {
+                top: 0.1,
+                left: 0.05,
+                bottom: 0.9,
+                right: 0.95,
+                angle: 0.0,
+            })
+        );
+        assert_eq!(sidecar.location.map(|l| l.longitude), Some(-9.1393));
+    }
+
+    #[test]
+    fn test_single_sidecar_keyword_is_not_a_list() {
+        let xmp = HashMap::from([
+            ("Subject".to_string(), json!("holiday")),
+            ("HasCrop".to_string(), json!(false)),
+            ("CropTop".to_string(), json!(0.1)),
+        ]);
+
+        let sidecar = convert_xmp_to_sidecar(&xmp);
+
+        assert_eq!(sidecar.keywords, vec!["holiday"]);
+        assert_eq!(sidecar.crop, None);
+    }
+
+    #[test]
+    fn test_sidecar_keywords_too_long_for_a_tag_are_skipped() {
+        let xmp = HashMap::from([
+            ("Subject".to_string(), json!(["beach", "x".repeat(101)])),
+            ("Rating".to_string(), json!(3)),
+        ]);
+
+        let sidecar = convert_xmp_to_sidecar(&xmp);
+
+        assert_eq!(sidecar.keywords, vec!["beach"]);
+        assert_eq!(sidecar.rating, Some(3));
+        assert!(sidecar.validate().is_ok());
+    }
+
+    #[test]
+    fn test_video_info_is_read_from_quicktime_tags() {
+        let exif = HashMap::from([
+            (
+                "MIMEType".to_string(),
+                field(json!("video/quicktime"), None),
+            ),
+            (
+                "Duration".to_string(),
+                field(json!("0:01:05"), Some(json!(65.5))),
+            ),
+            ("VideoFrameRate".to_string(), field(json!(29.97), None)),
+            ("CompressorID".to_string(), field(json!("hvc1"), None)),
+            ("AudioFormat".to_string(), field(json!("mp4a"), None)),
+            (
+                "AvgBitrate".to_string(),
+                field(json!("5.2 Mbps"), Some(json!(5200000))),
+            ),
+            ("Rotation".to_string(), field(json!(90), None)),
+            (
+                "ContentIdentifier".to_string(),
+                field(json!("8D1F6A3C-6C0B-4B5E-9F1A-2E4C7D9B0A11"), None),
+            ),
+        ]);
+
+        let metadata = convert_exif_to_metadata(&exif, Uuid::new_v4());
+
+        assert_eq!(
+            metadata.video,
+            Some(VideoInfo {
+                duration: Some(65.5),
+                frame_rate: Some(29.97),
+                video_codec: Some("hvc1".to_string()),
+                audio_codec: Some("mp4a".to_string()),
+                bitrate: Some(5_200_000),
+                rotation: Some(90),
+            })
+        );
+        assert_eq!(
+            metadata.content_identifier.as_deref(),
+            Some("8D1F6A3C-6C0B-4B5E-9F1A-2E4C7D9B0A11")
+        );
+        assert!(metadata.additional.is_empty());
+    }
+
+    #[test]
+    fn test_capture_date_takes_offset_from_offset_time_original() {
+        let exif = HashMap::from([
+            ("Make".to_string(), field(json!("FUJIFILM"), None)),
+            (
+                "DateTimeOriginal".to_string(),
+                field(json!("2024:06:20 23:30:00"), None),
+            ),
+            (
+                "OffsetTimeOriginal".to_string(),
+                field(json!("+09:00"), None),
+            ),
+        ]);
+
+        let camera = convert_exif_to_metadata(&exif, Uuid::new_v4())
+            .camera_info
+            .unwrap();
+
+        assert_eq!(
+            camera.capture_date,
+            DateTime::parse_from_rfc3339("2024-06-20T23:30:00+09:00").ok()
+        );
+        assert_eq!(camera.capture_timezone_source, Some(TimezoneSource::Exif));
+    }
+
+    #[test]
+    fn test_capture_date_without_offset_keeps_local_time() {
+        let exif = HashMap::from([
+            ("Make".to_string(), field(json!("Canon"), None)),
+            (
+                "DateTimeOriginal".to_string(),
+                field(json!("2024:06:20 23:30:00"), None),
+            ),
+        ]);
+
+        let camera = convert_exif_to_metadata(&exif, Uuid::new_v4())
+            .camera_info
+            .unwrap();
+
+        assert_eq!(
+            camera.capture_date,
+            DateTime::parse_from_rfc3339("2024-06-20T23:30:00+00:00").ok()
+        );
+        assert_eq!(camera.capture_timezone_source, None);
+    }
+
+    #[test]
+    fn test_exposure_and_iptc_values_are_read_into_fields() {
+        let exif = HashMap::from([
+            ("Make".to_string(), field(json!("NIKON"), None)),
+            ("LensSerialNumber".to_string(), field(json!(20512345), None)),
+            (
+                "ExposureProgram".to_string(),
+                field(json!("Aperture-priority AE"), Some(json!(3))),
+            ),
+            (
+                "ExposureMode".to_string(),
+                field(json!("Auto bracket"), Some(json!(2))),
+            ),
+            (
+                "MeteringMode".to_string(),
+                field(json!("Unknown"), Some(json!(0))),
+            ),
+            (
+                "WhiteBalance".to_string(),
+                field(json!("Manual"), Some(json!(1))),
+            ),
+            (
+                "ExposureCompensation".to_string(),
+                field(json!("-2/3"), Some(json!(-0.67))),
+            ),
+            (
+                "FocalLengthIn35mmFormat".to_string(),
+                field(json!("52 mm"), Some(json!(52))),
+            ),
+            ("Software".to_string(), field(json!("Ver.1.10 "), None)),
+            ("Rating".to_string(), field(json!(4), None)),
+            (
+                "Keywords".to_string(),
+                field(json!(["beach", "family", "beach"]), None),
+            ),
+            ("Subject".to_string(), field(json!("ignored"), None)),
+            ("ImageDescription".to_string(), field(json!("  "), None)),
+            ("Caption-Abstract".to_string(), field(json!("Sunset"), None)),
+            ("Artist".to_string(), field(json!("Jane Doe"), None)),
+            (
+                "CopyrightNotice".to_string(),
+                field(json!("(c) Jane Doe"), None),
+            ),
+        ]);
+
+        let metadata = convert_exif_to_metadata(&exif, Uuid::new_v4());
+        let camera = metadata.camera_info.unwrap();
+
+        assert_eq!(camera.lens_serial_number.as_deref(), Some("20512345"));
+        assert_eq!(
+            camera.exposure_program,
+            Some(ExposureProgram::AperturePriority)
+        );
+        assert_eq!(camera.exposure_mode, Some(ExposureMode::AutoBracket));
+        assert_eq!(camera.metering_mode, None);
+        assert_eq!(camera.white_balance, Some(WhiteBalance::Manual));
+        assert_eq!(camera.exposure_bias, Some(-0.67));
+        assert_eq!(camera.focal_length_35mm, Some(52.0));
+        assert_eq!(metadata.technical.software.as_deref(), Some("Ver.1.10"));
+        assert_eq!(
+            metadata.descriptive,
+            DescriptiveInfo {
+                rating: Some(4),
+                keywords: vec!["beach".to_string(), "family".to_string()],
+                caption: Some("Sunset".to_string()),
+                creator: Some("Jane Doe".to_string()),
+                copyright: Some("(c) Jane Doe".to_string()),
+            }
+        );
+        assert!(metadata.additional.is_empty());
+    }
+}
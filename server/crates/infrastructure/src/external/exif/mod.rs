@@ -1,6 +1,12 @@
 mod exiftool;
 pub mod metadata_extractor;
+pub mod preview_extractor;
 pub mod service;
+pub mod sidecar_writer;
+mod unavailable;
 
 pub use exiftool::*;
 pub use metadata_extractor::ExiftoolMetadataExtractor;
+pub use preview_extractor::ExiftoolPreviewExtractor;
+pub use sidecar_writer::ExiftoolSidecarWriter;
+pub use unavailable::ExiftoolUnavailable;
@@ -0,0 +1,220 @@
+use std::{collections::HashMap, io::Cursor, sync::Arc};
+
+use application::medium::ports::{
+    EmbeddedPreviewExtractor, FileStorage, PosterFrameGenerator, SourcePreview,
+};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use domain::{
+    error::{DomainResult, ParseSnafu, StorageSnafu},
+    medium::{Dimensions, FileLocation},
+};
+use image::{
+    codecs::jpeg::JpegEncoder, metadata::Orientation, DynamicImage, ImageFormat, ImageReader,
+};
+use serde_json::Value;
+use tracing::{debug, instrument};
+
+use super::Exiftool;
+
+/// Tags cameras store their embedded JPEGs in
+const PREVIEW_TAGS: [&str; 3] = ["PreviewImage", "JpgFromRaw", "OtherImage"];
+
+/// Tags cameras and phones store a thumbnail of a video in
+const VIDEO_THUMBNAIL_TAGS: [&str; 3] = ["PreviewImage", "ThumbnailImage", "CoverArt"];
+
+/// Quality the embedded JPEG is encoded with again after rotating it
+const REENCODE_QUALITY: u8 = 92;
+
+/// Infrastructure adapter that implements EmbeddedPreviewExtractor using
+/// exiftool. It also serves as the PosterFrameGenerator on machines without
+/// ffmpeg, using the thumbnail embedded in the video.
+pub struct ExiftoolPreviewExtractor {
+    exiftool: Arc<Exiftool>,
+    file_storage: Arc<dyn FileStorage>,
+}
+
+impl ExiftoolPreviewExtractor {
+    pub fn new(exiftool: Arc<Exiftool>, file_storage: Arc<dyn FileStorage>) -> Self {
+        Self {
+            exiftool,
+            file_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddedPreviewExtractor for ExiftoolPreviewExtractor {
+    #[instrument(skip(self), fields(path = ?location.relative_path))]
+    async fn extract(&self, location: &FileLocation) -> DomainResult<Option<SourcePreview>> {
+        let path = self.file_storage.get_local_path(location).await?;
+        let mut tags = PREVIEW_TAGS.to_vec();
+        tags.push("Orientation");
+        let values = self.exiftool.read_tags(path, &tags).await?;
+
+        let orientation = values
+            .get("Orientation")
+            .and_then(Value::as_u64)
+            .and_then(|value| u8::try_from(value).ok())
+            .and_then(Orientation::from_exif)
+            .unwrap_or(Orientation::NoTransforms);
+        extract_upright(&values, &PREVIEW_TAGS, orientation).await
+    }
+}
+
+#[async_trait]
+impl PosterFrameGenerator for ExiftoolPreviewExtractor {
+    #[instrument(skip(self), fields(path = ?location.relative_path))]
+    async fn generate(&self, location: &FileLocation) -> DomainResult<Option<SourcePreview>> {
+        let path = self.file_storage.get_local_path(location).await?;
+        let mut tags = VIDEO_THUMBNAIL_TAGS.to_vec();
+        tags.push("Rotation");
+        let values = self.exiftool.read_tags(path, &tags).await?;
+
+        let orientation = match values.get("Rotation").and_then(Value::as_i64) {
+            Some(90) => Orientation::Rotate90,
+            Some(180) => Orientation::Rotate180,
+            Some(270) => Orientation::Rotate270,
+            _ => Orientation::NoTransforms,
+        };
+        extract_upright(&values, &VIDEO_THUMBNAIL_TAGS, orientation).await
+    }
+}
+
+/// Picks the largest JPEG of the given tags and rotates it upright
+async fn extract_upright(
+    values: &HashMap<String, Value>,
+    tags: &[&str],
+    orientation: Orientation,
+) -> DomainResult<Option<SourcePreview>> {
+    let Some(jpeg) = largest_jpeg(values, tags)? else {
+        return Ok(None);
+    };
+    debug!(
+        size_bytes = jpeg.len(),
+        ?orientation,
+        "Found embedded preview"
+    );
+
+    // Decoding and encoding are CPU bound, keep them off the async workers
+    tokio::task::spawn_blocking(move || upright(jpeg, orientation))
+        .await
+        .map_err(|e| {
+            StorageSnafu {
+                message: format!("Embedded preview task failed: {e}"),
+            }
+            .build()
+        })?
+        .map(Some)
+}
+
+/// Decodes the `base64:` values of the given tags and picks the largest JPEG
+fn largest_jpeg(values: &HashMap<String, Value>, tags: &[&str]) -> DomainResult<Option<Vec<u8>>> {
+    let mut largest: Option<Vec<u8>> = None;
+    for tag in tags {
+        let Some(encoded) = values
+            .get(*tag)
+            .and_then(Value::as_str)
+            .and_then(|value| value.strip_prefix("base64:"))
+        else {
+            continue;
+        };
+        let content = STANDARD.decode(encoded).map_err(|e| {
+            ParseSnafu {
+                message: format!("Invalid {tag} data: {e}"),
+            }
+            .build()
+        })?;
+
+        let is_jpeg = image::guess_format(&content).is_ok_and(|f| f == ImageFormat::Jpeg);
+        if is_jpeg && largest.as_ref().is_none_or(|l| content.len() > l.len()) {
+            largest = Some(content);
+        }
+    }
+    Ok(largest)
+}
+
+/// Applies the orientation of the RAW file or video, the embedded JPEG is
+/// stored as the sensor captured it. Unrotated previews are kept as they are.
+fn upright(jpeg: Vec<u8>, orientation: Orientation) -> DomainResult<SourcePreview> {
+    let mut image = ImageReader::with_format(Cursor::new(&jpeg), ImageFormat::Jpeg)
+        .decode()
+        .map_err(|e| {
+            ParseSnafu {
+                message: format!("Failed to decode embedded preview: {e}"),
+            }
+            .build()
+        })?;
+
+    if orientation == Orientation::NoTransforms {
+        return Ok(SourcePreview {
+            dimensions: Dimensions::new(image.width(), image.height())?,
+            content: jpeg,
+        });
+    }
+
+    image.apply_orientation(orientation);
+    let rgb = DynamicImage::ImageRgb8(image.to_rgb8());
+    let mut content = Vec::new();
+    rgb.write_with_encoder(JpegEncoder::new_with_quality(
+        Cursor::new(&mut content),
+        REENCODE_QUALITY,
+    ))
+    .map_err(|e| {
+        StorageSnafu {
+            message: format!("Failed to encode embedded preview: {e}"),
+        }
+        .build()
+    })?;
+
+    Ok(SourcePreview {
+        dimensions: Dimensions::new(rgb.width(), rgb.height())?,
+        content,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use image::RgbImage;
+
+    use super::*;
+
+    fn jpeg(width: u32, height: u32) -> Vec<u8> {
+        let mut content = Vec::new();
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut Cursor::new(&mut content), ImageFormat::Jpeg)
+            .unwrap();
+        content
+    }
+
+    #[test]
+    fn test_largest_jpeg_is_picked() {
+        let small = jpeg(16, 8);
+        let large = jpeg(64, 32);
+        let values = HashMap::from([
+            (
+                "PreviewImage".to_string(),
+                Value::from(format!("base64:{}", STANDARD.encode(&small))),
+            ),
+            (
+                "JpgFromRaw".to_string(),
+                Value::from(format!("base64:{}", STANDARD.encode(&large))),
+            ),
+            ("Orientation".to_string(), Value::from(6)),
+        ]);
+
+        assert_eq!(largest_jpeg(&values, &PREVIEW_TAGS).unwrap(), Some(large));
+        assert_eq!(largest_jpeg(&HashMap::new(), &PREVIEW_TAGS).unwrap(), None);
+    }
+
+    #[test]
+    fn test_orientation_is_applied() {
+        let preview = upright(jpeg(64, 32), Orientation::Rotate90).unwrap();
+        assert_eq!(preview.dimensions, Dimensions::new(32, 64).unwrap());
+
+        let content = jpeg(64, 32);
+        let preview = upright(content.clone(), Orientation::NoTransforms).unwrap();
+        assert_eq!(preview.dimensions, Dimensions::new(64, 32).unwrap());
+        assert_eq!(preview.content, content);
+    }
+}
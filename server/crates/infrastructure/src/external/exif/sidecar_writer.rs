@@ -0,0 +1,230 @@
+use std::sync::Arc;
+
+use application::{medium::ports::FileStorage, metadata::ports::SidecarWriter};
+use async_trait::async_trait;
+use domain::{
+    error::{DomainError, DomainResult},
+    medium::{FileLocation, Medium},
+    metadata::Metadata,
+};
+
+use super::Exiftool;
+
+/// Skeleton of a new sidecar, exiftool fills in the values
+const EMPTY_XMP: &str = "<?xpacket begin='\u{feff}' id='W5M0MpCehiHzreSzNTczkc9d'?>
+<x:xmpmeta xmlns:x='adobe:ns:meta/'>
+<rdf:RDF xmlns:rdf='http://www.w3.org/1999/02/22-rdf-syntax-ns#'>
+</rdf:RDF>
+</x:xmpmeta>
+<?xpacket end='w'?>
+";
+
+/// Infrastructure adapter that implements SidecarWriter using exiftool, which
+/// keeps the values of other tools like the develop settings of Lightroom
+pub struct ExiftoolSidecarWriter {
+    exiftool: Arc<Exiftool>,
+    file_storage: Arc<dyn FileStorage>,
+}
+
+impl ExiftoolSidecarWriter {
+    pub fn new(exiftool: Arc<Exiftool>, file_storage: Arc<dyn FileStorage>) -> Self {
+        Self {
+            exiftool,
+            file_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl SidecarWriter for ExiftoolSidecarWriter {
+    async fn write_sidecar(
+        &self,
+        location: &FileLocation,
+        medium: &Medium,
+        metadata: &Metadata,
+    ) -> DomainResult<()> {
+        let path = match self.file_storage.get_local_path(location).await {
+            Err(DomainError::FileNotExists { .. }) => {
+                self.file_storage
+                    .store_file(location, EMPTY_XMP.as_bytes().to_vec())
+                    .await?;
+                self.file_storage.get_local_path(location).await?
+            }
+            path => path?,
+        };
+
+        self.exiftool
+            .write_tags(path, &sidecar_assignments(medium, metadata))
+            .await
+    }
+}
+
+/// The exiftool arguments that write the tags and curation of the medium and
+/// the effective values of the metadata. The title and description of the
+/// merged sidecar replace the ones in the file, without a merged sidecar they
+/// are left alone. Unknown capture dates, cameras and locations are never
+/// removed.
+fn sidecar_assignments(medium: &Medium, metadata: &Metadata) -> Vec<String> {
+    let mut assignments = Vec::new();
+    let mut assign = |tag: &str, value: Option<String>| {
+        assignments.push(format!(
+            "-{tag}={}",
+            value.as_deref().map(escape).unwrap_or_default()
+        ));
+    };
+
+    // An empty assignment clears the list before the keywords are added
+    assign("XMP-dc:Subject", None);
+    for tag in &medium.tags {
+        assign("XMP-dc:Subject", Some(tag.to_string()));
+    }
+    assign(
+        "XMP-xmp:Rating",
+        Some(medium.curation.xmp_rating().to_string()),
+    );
+    assign(
+        "XMP-xmp:Label",
+        medium
+            .curation
+            .color_label
+            .map(|label| label.xmp_label().to_string()),
+    );
+
+    if let Some(sidecar) = &metadata.sidecar {
+        assign("XMP-dc:Title", sidecar.title.clone());
+        assign("XMP-dc:Description", sidecar.description.clone());
+    }
+
+    if let Some(capture_date) = metadata.capture_date() {
+        let date = capture_date.format("%Y:%m:%d %H:%M:%S%:z").to_string();
+        assign("XMP-exif:DateTimeOriginal", Some(date.clone()));
+        assign("XMP-photoshop:DateCreated", Some(date));
+    }
+    if let Some(make) = metadata.camera_make() {
+        assign("XMP-tiff:Make", Some(make.to_string()));
+    }
+    if let Some(model) = metadata.camera_model() {
+        assign("XMP-tiff:Model", Some(model.to_string()));
+    }
+
+    // `#` writes the signed decimal degrees without print conversion
+    if let Some(location) = metadata.effective_location() {
+        assign("XMP-exif:GPSLatitude#", Some(location.latitude.to_string()));
+        assign(
+            "XMP-exif:GPSLongitude#",
+            Some(location.longitude.to_string()),
+        );
+        if let Some(altitude) = location.altitude {
+            assign("XMP-exif:GPSAltitude#", Some(altitude.abs().to_string()));
+            let below_sea_level = if altitude < 0.0 { "1" } else { "0" };
+            assign(
+                "XMP-exif:GPSAltitudeRef#",
+                Some(below_sea_level.to_string()),
+            );
+        }
+    }
+
+    assignments
+}
+
+/// exiftool reads its arguments line by line, `-E` decodes the entities
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('\n', "&#xa;")
+        .replace('\r', "&#xd;")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+    use domain::{
+        medium::{ColorLabel, Curation, Tag},
+        metadata::{LocationInfo, SidecarMetadata},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_tags_and_curation_of_the_medium_are_written() {
+        let medium = Medium {
+            tags: vec![
+                Tag::new("Travel/Lisbon").unwrap(),
+                Tag::new("Tom & Jerry").unwrap(),
+            ],
+            curation: Curation {
+                rating: 4,
+                color_label: Some(ColorLabel::Green),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        // The keywords and rating read from the sidecar before are replaced
+        let metadata = Metadata {
+            sidecar: Some(SidecarMetadata {
+                keywords: vec!["beach".to_string()],
+                rating: Some(2),
+                description: Some("First line\nSecond line".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sidecar_assignments(&medium, &metadata),
+            vec![
+                "-XMP-dc:Subject=",
+                "-XMP-dc:Subject=Travel/Lisbon",
+                "-XMP-dc:Subject=Tom &amp; Jerry",
+                "-XMP-xmp:Rating=4",
+                "-XMP-xmp:Label=Green",
+                "-XMP-dc:Title=",
+                "-XMP-dc:Description=First line&#xa;Second line",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejected_media_are_rated_minus_one() {
+        let medium = Medium {
+            curation: Curation {
+                rejected: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sidecar_assignments(&medium, &Metadata::default()),
+            vec!["-XMP-dc:Subject=", "-XMP-xmp:Rating=-1", "-XMP-xmp:Label="]
+        );
+    }
+
+    #[test]
+    fn test_overrides_are_written_without_touching_the_sidecar_values() {
+        let mut metadata = Metadata::default();
+        metadata.overrides.capture_date =
+            Some(DateTime::parse_from_rfc3339("2024-12-15T14:22:00+01:00").unwrap());
+        metadata.overrides.camera_model = Some("EOS R5".to_string());
+        metadata.overrides.location = Some(LocationInfo {
+            latitude: -33.8568,
+            longitude: 151.2153,
+            altitude: Some(-4.5),
+            direction: None,
+            horizontal_position_error: None,
+        });
+
+        assert_eq!(
+            sidecar_assignments(&Medium::default(), &metadata)[3..],
+            [
+                "-XMP-exif:DateTimeOriginal=2024:12:15 14:22:00+01:00",
+                "-XMP-photoshop:DateCreated=2024:12:15 14:22:00+01:00",
+                "-XMP-tiff:Model=EOS R5",
+                "-XMP-exif:GPSLatitude#=-33.8568",
+                "-XMP-exif:GPSLongitude#=151.2153",
+                "-XMP-exif:GPSAltitude#=4.5",
+                "-XMP-exif:GPSAltitudeRef#=1",
+            ]
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use application::{
+    medium::ports::{EmbeddedPreviewExtractor, PosterFrameGenerator, SourcePreview},
+    metadata::ports::SidecarWriter,
+};
+use async_trait::async_trait;
+use domain::{
+    error::{DomainResult, StorageSnafu},
+    medium::{FileLocation, Medium},
+    metadata::Metadata,
+};
+
+/// Stands in for the exiftool adapters on machines without exiftool. RAW
+/// files and videos get no embedded previews, sidecars are not written.
+pub struct ExiftoolUnavailable;
+
+#[async_trait]
+impl EmbeddedPreviewExtractor for ExiftoolUnavailable {
+    async fn extract(&self, _location: &FileLocation) -> DomainResult<Option<SourcePreview>> {
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl PosterFrameGenerator for ExiftoolUnavailable {
+    async fn generate(&self, _location: &FileLocation) -> DomainResult<Option<SourcePreview>> {
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl SidecarWriter for ExiftoolUnavailable {
+    async fn write_sidecar(
+        &self,
+        _location: &FileLocation,
+        _medium: &Medium,
+        _metadata: &Metadata,
+    ) -> DomainResult<()> {
+        StorageSnafu {
+            message: "XMP sidecars can only be written with exiftool",
+        }
+        .fail()
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+};
+
+use application::metadata::ports::ReverseGeocoder;
+use async_trait::async_trait;
+use domain::{error::DomainResult, metadata::Place};
+use snafu::{OptionExt, ResultExt, Whatever};
+use tracing::{info, warn};
+
+use super::kd_tree::{distance_km, unit_vector, KdTree};
+
+/// GeoNames city dumps, most detailed first, the first one found is loaded
+const CITIES_FILES: [&str; 4] = [
+    "cities500.txt",
+    "cities1000.txt",
+    "cities5000.txt",
+    "cities15000.txt",
+];
+/// Names of the first-level administrative divisions, keyed `CC.code`
+const ADMIN1_FILE: &str = "admin1CodesASCII.txt";
+/// Country names, keyed by ISO code
+const COUNTRY_INFO_FILE: &str = "countryInfo.txt";
+
+struct City {
+    name: String,
+    country_code: String,
+    admin1_code: String,
+}
+
+/// Infrastructure adapter that implements ReverseGeocoder offline with the
+/// GeoNames cities and admin areas dumps, held in an in-memory k-d tree
+pub struct GeoNamesReverseGeocoder {
+    cities: KdTree<City>,
+    regions: HashMap<String, String>,
+    countries: HashMap<String, String>,
+    max_distance_km: f64,
+}
+
+impl GeoNamesReverseGeocoder {
+    /// Loads the dumps from the directory, blocking while the files are read
+    pub fn load(directory: &Path, max_distance_km: f64) -> Result<Self, Whatever> {
+        let cities_path = CITIES_FILES
+            .iter()
+            .map(|file| directory.join(file))
+            .find(|path| path.exists())
+            .with_whatever_context(|| {
+                format!("No GeoNames cities file found in {}", directory.display())
+            })?;
+        let open = |path: &Path| {
+            File::open(path)
+                .map(BufReader::new)
+                .with_whatever_context(|_| format!("Could not open {}", path.display()))
+        };
+
+        let cities = open(&cities_path)?;
+        let admin1 = open(&directory.join(ADMIN1_FILE))
+            .inspect_err(|e| warn!(error = %e, "Loading GeoNames without region names"))
+            .ok();
+        let countries = open(&directory.join(COUNTRY_INFO_FILE))
+            .inspect_err(|e| warn!(error = %e, "Loading GeoNames without country names"))
+            .ok();
+
+        let geocoder = Self::from_readers(cities, admin1, countries, max_distance_km)
+            .with_whatever_context(|_| format!("Could not read {}", cities_path.display()))?;
+        info!(
+            cities = geocoder.cities.len(),
+            file = %cities_path.display(),
+            "GeoNames dataset loaded"
+        );
+
+        Ok(geocoder)
+    }
+
+    /// A geocoder that knows no places, used when no dataset is configured
+    pub fn empty() -> Self {
+        Self {
+            cities: KdTree::new(Vec::new()),
+            regions: HashMap::new(),
+            countries: HashMap::new(),
+            max_distance_km: 0.0,
+        }
+    }
+
+    fn from_readers(
+        cities: impl BufRead,
+        admin1: Option<impl BufRead>,
+        countries: Option<impl BufRead>,
+        max_distance_km: f64,
+    ) -> std::io::Result<Self> {
+        let mut points = Vec::new();
+        for line in cities.lines() {
+            let line = line?;
+            let columns: Vec<&str> = line.split('\t').collect();
+            let [_, name, _, _, latitude, longitude, _, _, country_code, _, admin1_code, ..] =
+                columns.as_slice()
+            else {
+                continue;
+            };
+            let (Ok(latitude), Ok(longitude)) = (latitude.parse(), longitude.parse()) else {
+                continue;
+            };
+
+            points.push((
+                unit_vector(latitude, longitude),
+                City {
+                    name: name.to_string(),
+                    country_code: country_code.to_string(),
+                    admin1_code: admin1_code.to_string(),
+                },
+            ));
+        }
+
+        Ok(Self {
+            cities: KdTree::new(points),
+            // admin1CodesASCII.txt: code, name, ascii name, geoname id
+            regions: match admin1 {
+                Some(reader) => read_names(reader, 1)?,
+                None => HashMap::new(),
+            },
+            // countryInfo.txt: ISO, ISO3, ISO numeric, fips, name, ...
+            countries: match countries {
+                Some(reader) => read_names(reader, 4)?,
+                None => HashMap::new(),
+            },
+            max_distance_km,
+        })
+    }
+
+    fn lookup(&self, latitude: f64, longitude: f64) -> Option<Place> {
+        let (city, squared_chord) = self.cities.nearest(&unit_vector(latitude, longitude))?;
+        if distance_km(squared_chord) > self.max_distance_km {
+            return None;
+        }
+
+        let region = self
+            .regions
+            .get(&format!("{}.{}", city.country_code, city.admin1_code))
+            .cloned();
+        let country = self
+            .countries
+            .get(&city.country_code)
+            .cloned()
+            .unwrap_or_else(|| city.country_code.clone());
+
+        Some(Place {
+            country_code: city.country_code.clone(),
+            country,
+            region,
+            city: Some(city.name.clone()),
+        })
+    }
+}
+
+/// Reads a tab separated GeoNames file into a map from the first column to
+/// the given one, skipping `#` comments
+fn read_names(reader: impl BufRead, column: usize) -> std::io::Result<HashMap<String, String>> {
+    let mut names = HashMap::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.starts_with('#') {
+            continue;
+        }
+        let mut columns = line.split('\t');
+        if let (Some(key), Some(name)) = (columns.next(), columns.nth(column - 1)) {
+            names.insert(key.to_string(), name.to_string());
+        }
+    }
+    Ok(names)
+}
+
+#[async_trait]
+impl ReverseGeocoder for GeoNamesReverseGeocoder {
+    async fn resolve(&self, latitude: f64, longitude: f64) -> DomainResult<Option<Place>> {
+        Ok(self.lookup(latitude, longitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CITIES: &str = "\
+2267057\tLisbon\tLisbon\tLisboa\t38.71667\t-9.13333\tP\tPPLC\tPT\t\t14\t1106\t\t\t517802\t\t45\tEurope/Lisbon\t2022-03-03
+2735943\tPorto\tPorto\tOporto\t41.14961\t-8.61099\tP\tPPLA\tPT\t\t17\t1312\t\t\t249633\t\t104\tEurope/Lisbon\t2022-03-03
+2950159\tBerlin\tBerlin\t\t52.52437\t13.41053\tP\tPPLC\tDE\t\t16\t00\t11000\t11000000\t3426354\t74\t43\tEurope/Berlin\t2022-03-03
+";
+    const ADMIN1: &str = "PT.14\tLisbon\tLisbon\t2267056\nPT.17\tPorto\tPorto\t2735941\n";
+    const COUNTRIES: &str =
+        "#ISO\tISO3\tISO-Numeric\tfips\tCountry\nPT\tPRT\t620\tPO\tPortugal\tLisbon\n";
+
+    fn geocoder() -> GeoNamesReverseGeocoder {
+        GeoNamesReverseGeocoder::from_readers(
+            CITIES.as_bytes(),
+            Some(ADMIN1.as_bytes()),
+            Some(COUNTRIES.as_bytes()),
+            50.0,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_lookup_resolves_the_nearest_city() {
+        // Belém, on the western edge of Lisbon
+        let place = geocoder().lookup(38.6979, -9.2065).unwrap();
+
+        assert_eq!(
+            place,
+            Place {
+                country_code: "PT".to_string(),
+                country: "Portugal".to_string(),
+                region: Some("Lisbon".to_string()),
+                city: Some("Lisbon".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_lookup_falls_back_to_the_country_code() {
+        let place = geocoder().lookup(52.5, 13.4).unwrap();
+
+        assert_eq!(place.country, "DE");
+        assert_eq!(place.region, None);
+    }
+
+    #[test]
+    fn test_lookup_ignores_places_too_far_away() {
+        // Atlantic ocean, west of Portugal
+        assert_eq!(geocoder().lookup(39.0, -15.0), None);
+    }
+}
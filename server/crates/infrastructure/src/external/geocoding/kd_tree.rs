@@ -0,0 +1,141 @@
+/// Mean radius of the earth in kilometres
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Position on the unit sphere
+pub(super) type UnitVector = [f64; 3];
+
+/// Converts latitude and longitude in degrees into a position on the unit
+/// sphere, straight-line distances between those order like great-circle
+/// distances and have no seam at the antimeridian
+pub(super) fn unit_vector(latitude: f64, longitude: f64) -> UnitVector {
+    let (lat, lon) = (latitude.to_radians(), longitude.to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// Great-circle distance in kilometres for a squared chord length between
+/// two unit vectors
+pub(super) fn distance_km(squared_chord: f64) -> f64 {
+    2.0 * (squared_chord.sqrt() / 2.0).min(1.0).asin() * EARTH_RADIUS_KM
+}
+
+fn squared_distance(a: &UnitVector, b: &UnitVector) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+/// Static k-d tree stored as a sorted array, the median of each slice is
+/// the node and the halves left and right of it are its subtrees
+pub(super) struct KdTree<T> {
+    points: Vec<(UnitVector, T)>,
+}
+
+impl<T> KdTree<T> {
+    pub fn new(mut points: Vec<(UnitVector, T)>) -> Self {
+        build(&mut points, 0);
+        Self { points }
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Value closest to the target with its squared chord distance
+    pub fn nearest(&self, target: &UnitVector) -> Option<(&T, f64)> {
+        let mut best = None;
+        search(&self.points, target, 0, &mut best);
+        best
+    }
+}
+
+fn build<T>(points: &mut [(UnitVector, T)], depth: usize) {
+    if points.len() <= 1 {
+        return;
+    }
+
+    let axis = depth % 3;
+    let mid = points.len() / 2;
+    points.select_nth_unstable_by(mid, |a, b| a.0[axis].total_cmp(&b.0[axis]));
+
+    let (left, right) = points.split_at_mut(mid);
+    build(left, depth + 1);
+    build(&mut right[1..], depth + 1);
+}
+
+fn search<'a, T>(
+    points: &'a [(UnitVector, T)],
+    target: &UnitVector,
+    depth: usize,
+    best: &mut Option<(&'a T, f64)>,
+) {
+    if points.is_empty() {
+        return;
+    }
+
+    let mid = points.len() / 2;
+    let (point, value) = &points[mid];
+    let distance = squared_distance(point, target);
+    if best.is_none_or(|(_, d)| distance < d) {
+        *best = Some((value, distance));
+    }
+
+    let axis = depth % 3;
+    let offset = target[axis] - point[axis];
+    let (near, far) = if offset < 0.0 {
+        (&points[..mid], &points[mid + 1..])
+    } else {
+        (&points[mid + 1..], &points[..mid])
+    };
+
+    search(near, target, depth + 1, best);
+    // The other half can only hold a closer point if the splitting plane is
+    // closer than the best match so far
+    if best.is_none_or(|(_, d)| offset * offset < d) {
+        search(far, target, depth + 1, best);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_matches_linear_scan() {
+        // Deterministic pseudo random coordinates
+        let mut seed = 42u64;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (seed >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let points: Vec<(UnitVector, usize)> = (0..500)
+            .map(|i| {
+                (
+                    unit_vector(next() * 180.0 - 90.0, next() * 360.0 - 180.0),
+                    i,
+                )
+            })
+            .collect();
+        let tree = KdTree::new(points.clone());
+
+        for _ in 0..100 {
+            let target = unit_vector(next() * 180.0 - 90.0, next() * 360.0 - 180.0);
+            let expected = points
+                .iter()
+                .min_by(|a, b| {
+                    squared_distance(&a.0, &target).total_cmp(&squared_distance(&b.0, &target))
+                })
+                .map(|(_, i)| *i);
+
+            assert_eq!(tree.nearest(&target).map(|(i, _)| *i), expected);
+        }
+    }
+
+    #[test]
+    fn test_distance_km_across_the_antimeridian() {
+        let a = unit_vector(0.0, 179.5);
+        let b = unit_vector(0.0, -179.5);
+
+        let distance = distance_km(squared_distance(&a, &b));
+
+        // One degree of longitude at the equator
+        assert!((distance - 111.2).abs() < 0.5, "{distance}");
+    }
+}
@@ -0,0 +1,4 @@
+mod geonames;
+mod kd_tree;
+
+pub use geonames::GeoNamesReverseGeocoder;
@@ -1 +1,6 @@
 pub mod exif;
+pub mod geocoding;
+pub mod native_exif;
+pub mod preview;
+pub mod timezone;
+pub mod track;
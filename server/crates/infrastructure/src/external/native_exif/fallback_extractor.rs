@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use application::metadata::ports::MetadataExtractor;
+use async_trait::async_trait;
+use domain::{
+    error::DomainResult,
+    medium::{FileLocation, MediumId},
+    metadata::{Metadata, SidecarMetadata},
+};
+use tracing::warn;
+
+/// MetadataExtractor that reads files with `primary` and turns to `fallback`
+/// for files the primary fails on
+pub struct FallbackMetadataExtractor {
+    primary: Arc<dyn MetadataExtractor>,
+    fallback: Arc<dyn MetadataExtractor>,
+}
+
+impl FallbackMetadataExtractor {
+    pub fn new(primary: Arc<dyn MetadataExtractor>, fallback: Arc<dyn MetadataExtractor>) -> Self {
+        Self { primary, fallback }
+    }
+}
+
+#[async_trait]
+impl MetadataExtractor for FallbackMetadataExtractor {
+    /// The version of the primary. Metadata read by the fallback carries the
+    /// version of the fallback instead, a re-extraction that ends with the
+    /// fallback again is recorded and not repeated until the version rises.
+    fn version(&self) -> u32 {
+        self.primary.version()
+    }
+
+    async fn extract(
+        &self,
+        location: &FileLocation,
+        medium_id: MediumId,
+    ) -> DomainResult<Metadata> {
+        match self.primary.extract(location, medium_id).await {
+            Ok(metadata) => Ok(metadata),
+            Err(e) => {
+                warn!(
+                    %medium_id,
+                    error = %e,
+                    "Metadata extraction failed, using the fallback extractor"
+                );
+                self.fallback.extract(location, medium_id).await
+            }
+        }
+    }
+
+    async fn extract_sidecar(&self, location: &FileLocation) -> DomainResult<SidecarMetadata> {
+        self.primary.extract_sidecar(location).await
+    }
+}
@@ -0,0 +1,575 @@
+use std::{collections::HashMap, fs::File, io::BufReader, path::Path, sync::Arc};
+
+use application::{medium::ports::FileStorage, metadata::ports::MetadataExtractor};
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
+use domain::{
+    error::{DomainResult, StorageSnafu, ValidationSnafu},
+    medium::{FileLocation, MediumId},
+    metadata::{
+        CameraInfo, DescriptiveInfo, ExposureMode, ExposureProgram, FileInfo, LocationInfo,
+        Metadata, MeteringMode, Orientation, SidecarMetadata, TechnicalInfo, TimezoneSource,
+        WhiteBalance,
+    },
+};
+use exif::{Exif, In, Reader, Tag, Value};
+use image::ImageReader;
+use tracing::debug;
+use uuid::Uuid;
+
+/// Version of `convert_exif_to_metadata`. It stays below the version of the
+/// exiftool extractor, media read natively are extracted again once exiftool
+/// is available.
+pub const NATIVE_EXTRACTOR_VERSION: u32 = 1;
+
+/// Infrastructure adapter that implements MetadataExtractor by parsing the
+/// EXIF of TIFF, JPEG, PNG, HEIF and WebP files in process. It reads the core
+/// values of images only, videos and XMP are left to exiftool.
+pub struct NativeMetadataExtractor {
+    file_storage: Arc<dyn FileStorage>,
+}
+
+impl NativeMetadataExtractor {
+    pub fn new(file_storage: Arc<dyn FileStorage>) -> Self {
+        Self { file_storage }
+    }
+}
+
+#[async_trait]
+impl MetadataExtractor for NativeMetadataExtractor {
+    fn version(&self) -> u32 {
+        NATIVE_EXTRACTOR_VERSION
+    }
+
+    async fn extract(
+        &self,
+        location: &FileLocation,
+        medium_id: MediumId,
+    ) -> DomainResult<Metadata> {
+        let path = self.file_storage.get_local_path(location).await?;
+
+        // Parsing is blocking file IO, keep it off the async workers
+        tokio::task::spawn_blocking(move || read_metadata(&path, medium_id))
+            .await
+            .map_err(|e| {
+                StorageSnafu {
+                    message: format!("Metadata extraction task failed: {e}"),
+                }
+                .build()
+            })?
+    }
+
+    async fn extract_sidecar(&self, _location: &FileLocation) -> DomainResult<SidecarMetadata> {
+        ValidationSnafu {
+            message: "XMP sidecars can only be read with exiftool",
+        }
+        .fail()
+    }
+}
+
+/// Read the file info and EXIF of the file at `path`. Files without EXIF,
+/// e.g. videos, get the file info only.
+pub(crate) fn read_metadata(path: &Path, medium_id: MediumId) -> DomainResult<Metadata> {
+    let file_info = read_file_info(path)?;
+
+    let mut reader = BufReader::new(File::open(path)?);
+    let exif = match Reader::new().read_from_container(&mut reader) {
+        Ok(exif) => Some(exif),
+        Err(exif::Error::Io(e)) => return Err(e.into()),
+        Err(e) => {
+            debug!(?path, error = %e, "No EXIF read from file");
+            None
+        }
+    };
+
+    let mut metadata = convert_exif_to_metadata(exif.as_ref(), file_info, medium_id);
+    if metadata.technical.width.is_none() || metadata.technical.height.is_none() {
+        // Only the header is decoded, formats unknown to `image` are skipped
+        if let Some((width, height)) = ImageReader::open(path)
+            .ok()
+            .and_then(|reader| reader.with_guessed_format().ok())
+            .and_then(|reader| reader.into_dimensions().ok())
+        {
+            metadata.technical.width = Some(width);
+            metadata.technical.height = Some(height);
+        }
+    }
+
+    Ok(metadata)
+}
+
+fn read_file_info(path: &Path) -> DomainResult<FileInfo> {
+    let file = std::fs::metadata(path)?;
+    Ok(FileInfo {
+        mime_type: mime_guess::from_path(path).first_or_octet_stream(),
+        file_size: file.len(),
+        file_modified_at: file
+            .modified()
+            .ok()
+            .map(|modified| DateTime::<Utc>::from(modified).fixed_offset()),
+    })
+}
+
+/// Convert the EXIF of a file to our event Metadata, the counterpart of the
+/// exiftool conversion for the values EXIF holds
+fn convert_exif_to_metadata(
+    exif: Option<&Exif>,
+    file_info: FileInfo,
+    medium_id: MediumId,
+) -> Metadata {
+    let text = |tag: Tag| exif.and_then(|exif| text(exif, tag));
+    let number = |tag: Tag| exif.and_then(|exif| number(exif, tag));
+    let code = |tag: Tag| exif.and_then(|exif| code(exif, tag));
+
+    let has_camera_info = text(Tag::Make).is_some()
+        || text(Tag::Model).is_some()
+        || text(Tag::DateTimeOriginal).is_some();
+    let camera_info = has_camera_info.then(|| {
+        let (capture_date, capture_timezone_source) = exif
+            .and_then(|exif| {
+                date(
+                    exif,
+                    Tag::DateTimeOriginal,
+                    Tag::SubSecTimeOriginal,
+                    Tag::OffsetTimeOriginal,
+                )
+            })
+            .map_or((None, None), |(date, source)| (Some(date), source));
+        // Like exiftool, a modify date without offset is taken as UTC
+        let modified_date = exif
+            .and_then(|exif| date(exif, Tag::DateTime, Tag::SubSecTime, Tag::OffsetTime))
+            .map(|(date, _)| date);
+
+        CameraInfo {
+            make: text(Tag::Make),
+            model: text(Tag::Model),
+            capture_date,
+            capture_timezone_source,
+            modified_date,
+            lens_make: text(Tag::LensMake),
+            lens_model: text(Tag::LensModel),
+            exposure_time: number(Tag::ExposureTime),
+            f_number: number(Tag::FNumber),
+            iso: code(Tag::PhotographicSensitivity).map(|i| i as u16),
+            focal_length: number(Tag::FocalLength),
+            // The lowest bit tells whether the flash fired
+            flash: code(Tag::Flash).map(|f| f & 1 == 1),
+            lens_serial_number: text(Tag::LensSerialNumber),
+            exposure_program: code(Tag::ExposureProgram).and_then(ExposureProgram::from_exif),
+            exposure_mode: code(Tag::ExposureMode).and_then(ExposureMode::from_exif),
+            metering_mode: code(Tag::MeteringMode).and_then(MeteringMode::from_exif),
+            white_balance: code(Tag::WhiteBalance).and_then(WhiteBalance::from_exif),
+            exposure_bias: number(Tag::ExposureBiasValue),
+            focal_length_35mm: number(Tag::FocalLengthIn35mmFilm).filter(|f| *f > 0.0),
+        }
+    });
+
+    let location = exif.and_then(extract_location);
+
+    // The pixel dimensions of the Exif IFD describe the image as stored, the
+    // TIFF dimensions may be those of a RAW sensor
+    let technical = TechnicalInfo {
+        width: code(Tag::PixelXDimension)
+            .or(code(Tag::ImageWidth))
+            .map(|w| w as u32),
+        height: code(Tag::PixelYDimension)
+            .or(code(Tag::ImageLength))
+            .map(|h| h as u32),
+        orientation: code(Tag::Orientation).map(|o| Orientation::from(o as u8)),
+        software: text(Tag::Software),
+    };
+
+    // Ratings and keywords live in IPTC and XMP, which only exiftool reads
+    let descriptive = DescriptiveInfo {
+        caption: text(Tag::ImageDescription),
+        creator: text(Tag::Artist),
+        copyright: text(Tag::Copyright),
+        ..Default::default()
+    };
+
+    Metadata {
+        id: Uuid::new_v4(),
+        medium_id,
+        extracted_at: Utc::now(),
+        extractor_version: NATIVE_EXTRACTOR_VERSION,
+        file_info,
+        camera_info,
+        location,
+        place: None,
+        technical,
+        descriptive,
+        video: None,
+        content_identifier: None,
+        camera_serial_number: text(Tag::BodySerialNumber),
+        burst_id: None,
+        additional: HashMap::new(),
+        sidecar: None,
+        overrides: Default::default(),
+        version: 0,
+    }
+}
+
+/// The trimmed text of an ASCII tag of the primary image
+fn text(exif: &Exif, tag: Tag) -> Option<String> {
+    match &exif.get_field(tag, In::PRIMARY)?.value {
+        Value::Ascii(values) => values
+            .first()
+            .map(|v| String::from_utf8_lossy(v).trim().to_string())
+            .filter(|v| !v.is_empty()),
+        _ => None,
+    }
+}
+
+/// The first value of a numeric tag of the primary image
+fn number(exif: &Exif, tag: Tag) -> Option<f64> {
+    nth_number(&exif.get_field(tag, In::PRIMARY)?.value, 0)
+}
+
+fn nth_number(value: &Value, index: usize) -> Option<f64> {
+    let number = match value {
+        Value::Rational(v) => v.get(index)?.to_f64(),
+        Value::SRational(v) => v.get(index)?.to_f64(),
+        Value::SShort(v) => f64::from(*v.get(index)?),
+        Value::SLong(v) => f64::from(*v.get(index)?),
+        Value::Float(v) => f64::from(*v.get(index)?),
+        Value::Double(v) => *v.get(index)?,
+        value => f64::from(value.get_uint(index)?),
+    };
+    // Rationals with a zero denominator stand for unknown values
+    number.is_finite().then_some(number)
+}
+
+/// The first value of an integer tag of the primary image
+fn code(exif: &Exif, tag: Tag) -> Option<u64> {
+    exif.get_field(tag, In::PRIMARY)?
+        .value
+        .get_uint(0)
+        .map(u64::from)
+}
+
+/// Read a date and its sub-seconds and offset from their tags. Dates with an
+/// offset are returned with [`TimezoneSource::Exif`], others in local time
+/// for the time zone to be inferred after the extraction.
+fn date(
+    exif: &Exif,
+    date_tag: Tag,
+    subsec_tag: Tag,
+    offset_tag: Tag,
+) -> Option<(DateTime<FixedOffset>, Option<TimezoneSource>)> {
+    let ascii = |tag: Tag| match &exif.get_field(tag, In::PRIMARY)?.value {
+        Value::Ascii(values) => values.first().cloned(),
+        _ => None,
+    };
+
+    let mut date = exif::DateTime::from_ascii(&ascii(date_tag)?).ok()?;
+    if let Some(subsec) = ascii(subsec_tag) {
+        let _ = date.parse_subsec(&subsec);
+    }
+    if let Some(offset) = ascii(offset_tag) {
+        let _ = date.parse_offset(&offset);
+    }
+
+    let local = NaiveDate::from_ymd_opt(date.year.into(), date.month.into(), date.day.into())?
+        .and_hms_nano_opt(
+            date.hour.into(),
+            date.minute.into(),
+            date.second.into(),
+            date.nanosecond.unwrap_or(0),
+        )?;
+    match date
+        .offset
+        .and_then(|minutes| FixedOffset::east_opt(i32::from(minutes) * 60))
+        .and_then(|offset| offset.from_local_datetime(&local).single())
+    {
+        Some(date) => Some((date, Some(TimezoneSource::Exif))),
+        None => Some((local.and_utc().fixed_offset(), None)),
+    }
+}
+
+fn extract_location(exif: &Exif) -> Option<LocationInfo> {
+    let latitude = coordinate(exif, Tag::GPSLatitude, Tag::GPSLatitudeRef, b'S')?;
+    let longitude = coordinate(exif, Tag::GPSLongitude, Tag::GPSLongitudeRef, b'W')?;
+
+    // A reference of 1 means the altitude is below sea level
+    let below_sea_level = code(exif, Tag::GPSAltitudeRef) == Some(1);
+    let altitude = number(exif, Tag::GPSAltitude).map(|a| if below_sea_level { -a } else { a });
+
+    Some(LocationInfo {
+        latitude,
+        longitude,
+        altitude,
+        direction: number(exif, Tag::GPSImgDirection),
+        horizontal_position_error: number(exif, Tag::GPSHPositioningError),
+    })
+}
+
+/// Degrees, minutes and seconds of a coordinate as signed decimal degrees
+fn coordinate(exif: &Exif, tag: Tag, ref_tag: Tag, negative_ref: u8) -> Option<f64> {
+    let value = &exif.get_field(tag, In::PRIMARY)?.value;
+    let degrees = nth_number(value, 0)?;
+    let minutes = nth_number(value, 1).unwrap_or(0.0);
+    let seconds = nth_number(value, 2).unwrap_or(0.0);
+    let decimal = degrees + minutes / 60.0 + seconds / 3600.0;
+
+    let is_negative = matches!(
+        &exif.get_field(ref_tag, In::PRIMARY)?.value,
+        Value::Ascii(values) if values.first().and_then(|v| v.first()) == Some(&negative_ref)
+    );
+    Some(if is_negative { -decimal } else { decimal })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, path::PathBuf};
+
+    use exif::{experimental::Writer, Field, Rational, SRational};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ascii(tag: Tag, value: &str) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Ascii(vec![value.as_bytes().to_vec()]),
+        }
+    }
+
+    fn short(tag: Tag, value: u16) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Short(vec![value]),
+        }
+    }
+
+    fn rational(tag: Tag, values: &[(u32, u32)]) -> Field {
+        Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value: Value::Rational(
+                values
+                    .iter()
+                    .map(|&(num, denom)| Rational { num, denom })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Write the fields into a TIFF in memory and parse it again
+    fn exif_of(fields: &[Field]) -> Exif {
+        let mut writer = Writer::new();
+        for field in fields {
+            writer.push_field(field);
+        }
+        let mut tiff = Cursor::new(Vec::new());
+        writer.write(&mut tiff, false).unwrap();
+        Reader::new().read_raw(tiff.into_inner()).unwrap()
+    }
+
+    fn file_info() -> FileInfo {
+        FileInfo {
+            mime_type: mime::IMAGE_JPEG,
+            file_size: 1024,
+            file_modified_at: None,
+        }
+    }
+
+    #[test]
+    fn test_core_values_are_read_from_exif() {
+        let exif = exif_of(&[
+            ascii(Tag::Make, "FUJIFILM"),
+            ascii(Tag::Model, "X-T5 "),
+            ascii(Tag::DateTimeOriginal, "2024:06:01 18:30:05"),
+            ascii(Tag::SubSecTimeOriginal, "25"),
+            ascii(Tag::OffsetTimeOriginal, "+02:00"),
+            ascii(Tag::LensModel, "XF35mmF1.4 R"),
+            ascii(Tag::BodySerialNumber, "5CA12345"),
+            rational(Tag::ExposureTime, &[(1, 250)]),
+            rational(Tag::FNumber, &[(14, 10)]),
+            rational(Tag::FocalLength, &[(35, 1)]),
+            short(Tag::PhotographicSensitivity, 400),
+            short(Tag::Flash, 0x10),
+            short(Tag::ExposureProgram, 3),
+            short(Tag::MeteringMode, 5),
+            short(Tag::FocalLengthIn35mmFilm, 53),
+            short(Tag::Orientation, 6),
+            Field {
+                tag: Tag::ExposureBiasValue,
+                ifd_num: In::PRIMARY,
+                value: Value::SRational(vec![SRational { num: -2, denom: 3 }]),
+            },
+            ascii(Tag::Artist, "Jane Doe"),
+            ascii(Tag::Software, "Digital Camera X-T5 Ver2.00"),
+        ]);
+
+        let metadata = convert_exif_to_metadata(Some(&exif), file_info(), Uuid::new_v4());
+
+        let camera = metadata.camera_info.unwrap();
+        assert_eq!(camera.make.as_deref(), Some("FUJIFILM"));
+        assert_eq!(camera.model.as_deref(), Some("X-T5"));
+        assert_eq!(
+            camera.capture_date.unwrap().to_rfc3339(),
+            "2024-06-01T18:30:05.250+02:00"
+        );
+        assert_eq!(camera.capture_timezone_source, Some(TimezoneSource::Exif));
+        assert_eq!(camera.lens_model.as_deref(), Some("XF35mmF1.4 R"));
+        assert_eq!(camera.exposure_time, Some(0.004));
+        assert_eq!(camera.f_number, Some(1.4));
+        assert_eq!(camera.focal_length, Some(35.0));
+        assert_eq!(camera.iso, Some(400));
+        assert_eq!(camera.flash, Some(false));
+        assert_eq!(
+            camera.exposure_program,
+            Some(ExposureProgram::AperturePriority)
+        );
+        assert_eq!(camera.metering_mode, Some(MeteringMode::MultiSegment));
+        assert_eq!(camera.focal_length_35mm, Some(53.0));
+        assert!((camera.exposure_bias.unwrap() + 0.667).abs() < 0.001);
+        assert_eq!(metadata.camera_serial_number.as_deref(), Some("5CA12345"));
+        assert!(matches!(
+            metadata.technical.orientation,
+            Some(Orientation::Rotate90CW)
+        ));
+        assert_eq!(
+            metadata.technical.software.as_deref(),
+            Some("Digital Camera X-T5 Ver2.00")
+        );
+        assert_eq!(metadata.descriptive.creator.as_deref(), Some("Jane Doe"));
+        assert_eq!(metadata.extractor_version, NATIVE_EXTRACTOR_VERSION);
+    }
+
+    #[test]
+    fn test_capture_date_without_offset_keeps_local_time() {
+        let exif = exif_of(&[ascii(Tag::DateTimeOriginal, "2024:06:01 18:30:05")]);
+
+        let metadata = convert_exif_to_metadata(Some(&exif), file_info(), Uuid::new_v4());
+
+        let camera = metadata.camera_info.unwrap();
+        assert_eq!(
+            camera.capture_date.unwrap().to_rfc3339(),
+            "2024-06-01T18:30:05+00:00"
+        );
+        assert_eq!(camera.capture_timezone_source, None);
+    }
+
+    #[test]
+    fn test_gps_coordinates_are_signed_by_their_reference() {
+        let exif = exif_of(&[
+            rational(Tag::GPSLatitude, &[(33, 1), (51, 1), (3180, 100)]),
+            ascii(Tag::GPSLatitudeRef, "S"),
+            rational(Tag::GPSLongitude, &[(151, 1), (12, 1), (3600, 100)]),
+            ascii(Tag::GPSLongitudeRef, "E"),
+            rational(Tag::GPSAltitude, &[(12, 1)]),
+            Field {
+                tag: Tag::GPSAltitudeRef,
+                ifd_num: In::PRIMARY,
+                value: Value::Byte(vec![1]),
+            },
+        ]);
+
+        let location = convert_exif_to_metadata(Some(&exif), file_info(), Uuid::new_v4())
+            .location
+            .unwrap();
+
+        assert!((location.latitude + 33.8588).abs() < 0.0001);
+        assert!((location.longitude - 151.21).abs() < 0.0001);
+        assert_eq!(location.altitude, Some(-12.0));
+    }
+
+    #[test]
+    fn test_file_without_exif_gets_file_info_only() {
+        let metadata = convert_exif_to_metadata(None, file_info(), Uuid::new_v4());
+
+        assert!(metadata.camera_info.is_none());
+        assert!(metadata.location.is_none());
+        assert_eq!(metadata.file_info.file_size, 1024);
+    }
+
+    fn fixture(name: &str) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/images")
+            .join(name)
+    }
+
+    #[rstest]
+    #[case("IMG_0001.JPG", Some("Canon EOS R6"))]
+    #[case("DSC_0002.DNG", Some("NIKON Z 6"))]
+    #[case("screenshot.png", None)]
+    fn test_native_extractor_reads_fixtures(#[case] name: &str, #[case] model: Option<&str>) {
+        let metadata = read_metadata(&fixture(name), Uuid::new_v4()).unwrap();
+
+        assert_eq!(
+            metadata
+                .camera_info
+                .and_then(|camera| camera.model)
+                .as_deref(),
+            model
+        );
+        assert_eq!(metadata.technical.width, Some(16));
+    }
+
+    /// Compares the core values read by both extractors from a fixture image
+    #[rstest]
+    #[case("IMG_4598.HEIC")]
+    #[case("IMG_0001.JPG")]
+    #[case("DSC_0002.DNG")]
+    #[case("screenshot.png")]
+    #[tokio::test]
+    #[ignore = "needs exiftool"]
+    async fn test_native_and_exiftool_extractors_agree_on_fixtures(#[case] name: &str) {
+        let exiftool = crate::external::exif::Exiftool::new().await.unwrap();
+        let path = fixture(name);
+        let medium_id = Uuid::new_v4();
+        let exif_data = exiftool.read_file(&path, false).await.unwrap();
+        let expected = crate::external::exif::metadata_extractor::convert_exif_to_metadata(
+            &exif_data, medium_id,
+        );
+        let actual = read_metadata(&path, medium_id).unwrap();
+
+        let close = |a: Option<f64>, b: Option<f64>| match (a, b) {
+            (Some(a), Some(b)) => (a - b).abs() < 0.001,
+            (a, b) => a == b,
+        };
+        let (expected_camera, actual_camera) =
+            (expected.camera_info.as_ref(), actual.camera_info.as_ref());
+        assert_eq!(
+            expected_camera.map(|c| (&c.make, &c.model, c.iso, c.capture_date)),
+            actual_camera.map(|c| (&c.make, &c.model, c.iso, c.capture_date)),
+            "{path:?}"
+        );
+        assert!(
+            close(
+                expected_camera.and_then(|c| c.f_number),
+                actual_camera.and_then(|c| c.f_number)
+            ),
+            "{path:?}"
+        );
+        assert!(
+            close(
+                expected_camera.and_then(|c| c.focal_length),
+                actual_camera.and_then(|c| c.focal_length)
+            ),
+            "{path:?}"
+        );
+        assert_eq!(
+            format!("{:?}", expected.technical.orientation),
+            format!("{:?}", actual.technical.orientation),
+            "{path:?}"
+        );
+        assert!(
+            close(
+                expected.location.as_ref().map(|l| l.latitude),
+                actual.location.as_ref().map(|l| l.latitude)
+            ),
+            "{path:?}"
+        );
+        assert!(
+            close(
+                expected.location.as_ref().map(|l| l.longitude),
+                actual.location.as_ref().map(|l| l.longitude)
+            ),
+            "{path:?}"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod fallback_extractor;
+pub mod metadata_extractor;
+
+pub use fallback_extractor::FallbackMetadataExtractor;
+pub use metadata_extractor::{NativeMetadataExtractor, NATIVE_EXTRACTOR_VERSION};
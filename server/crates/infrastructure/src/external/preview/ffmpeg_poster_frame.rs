@@ -0,0 +1,117 @@
+use std::{io::Cursor, process::Stdio, sync::Arc, time::Duration};
+
+use application::medium::ports::{FileStorage, PosterFrameGenerator, SourcePreview};
+use async_trait::async_trait;
+use domain::{
+    error::{DomainResult, ParseSnafu, StorageSnafu},
+    medium::{Dimensions, FileLocation},
+};
+use image::ImageReader;
+use tokio::{process::Command, time::timeout};
+use tracing::{debug, instrument};
+
+/// How long ffmpeg may take to grab a frame, a malformed video could
+/// otherwise keep a preview worker busy forever
+const FFMPEG_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Infrastructure adapter that implements PosterFrameGenerator by running a
+/// local `ffmpeg` binary
+pub struct FfmpegPosterFrameGenerator {
+    file_storage: Arc<dyn FileStorage>,
+}
+
+impl FfmpegPosterFrameGenerator {
+    pub fn new(file_storage: Arc<dyn FileStorage>) -> Self {
+        Self { file_storage }
+    }
+
+    /// Whether an `ffmpeg` binary can be run on this machine
+    pub async fn is_available() -> bool {
+        Command::new("ffmpeg")
+            .arg("-version")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await
+            .is_ok_and(|status| status.success())
+    }
+}
+
+#[async_trait]
+impl PosterFrameGenerator for FfmpegPosterFrameGenerator {
+    #[instrument(skip(self), fields(path = ?location.relative_path))]
+    async fn generate(&self, location: &FileLocation) -> DomainResult<Option<SourcePreview>> {
+        let path = self.file_storage.get_local_path(location).await?;
+
+        // The thumbnail filter picks the most representative of the first
+        // frames, skipping black fade-ins. ffmpeg applies the rotation itself.
+        let mut command = Command::new("ffmpeg");
+        command
+            .args(["-v", "error", "-i"])
+            .arg(&path)
+            .args([
+                "-vf",
+                "thumbnail",
+                "-frames:v",
+                "1",
+                "-c:v",
+                "mjpeg",
+                "-q:v",
+                "2",
+                "-f",
+                "image2pipe",
+                "pipe:1",
+            ])
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+        let output = timeout(FFMPEG_TIMEOUT, command.output())
+            .await
+            .map_err(|_| {
+                ParseSnafu {
+                    message: format!(
+                        "ffmpeg did not finish on {} within {}s",
+                        path.display(),
+                        FFMPEG_TIMEOUT.as_secs()
+                    ),
+                }
+                .build()
+            })?
+            .map_err(|e| {
+                StorageSnafu {
+                    message: format!("Failed to run ffmpeg: {e}"),
+                }
+                .build()
+            })?;
+
+        if !output.status.success() {
+            return ParseSnafu {
+                message: format!(
+                    "ffmpeg failed on {}: {}",
+                    path.display(),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ),
+            }
+            .fail();
+        }
+        if output.stdout.is_empty() {
+            debug!("Video has no frames");
+            return Ok(None);
+        }
+
+        let (width, height) = ImageReader::new(Cursor::new(&output.stdout))
+            .with_guessed_format()?
+            .into_dimensions()
+            .map_err(|e| {
+                ParseSnafu {
+                    message: format!("Invalid poster frame: {e}"),
+                }
+                .build()
+            })?;
+        debug!(width, height, "Grabbed poster frame");
+
+        Ok(Some(SourcePreview {
+            dimensions: Dimensions::new(width, height)?,
+            content: output.stdout,
+        }))
+    }
+}
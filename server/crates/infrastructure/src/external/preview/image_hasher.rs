@@ -0,0 +1,94 @@
+use std::{path::Path, sync::Arc};
+
+use application::medium::ports::{FileStorage, PerceptualHasher};
+use async_trait::async_trait;
+use domain::{
+    error::{DomainResult, ParseSnafu, StorageSnafu},
+    medium::{FileLocation, PerceptualHash},
+};
+use image::{imageops::FilterType, ImageReader};
+use tracing::instrument;
+
+/// Infrastructure adapter that implements PerceptualHasher with the `image`
+/// crate, computing a difference hash (dHash)
+pub struct ImagePerceptualHasher {
+    file_storage: Arc<dyn FileStorage>,
+}
+
+impl ImagePerceptualHasher {
+    pub fn new(file_storage: Arc<dyn FileStorage>) -> Self {
+        Self { file_storage }
+    }
+}
+
+#[async_trait]
+impl PerceptualHasher for ImagePerceptualHasher {
+    #[instrument(skip(self), fields(path = ?location.relative_path))]
+    async fn hash(&self, location: &FileLocation) -> DomainResult<PerceptualHash> {
+        let path = self.file_storage.get_local_path(location).await?;
+
+        tokio::task::spawn_blocking(move || hash_file(&path))
+            .await
+            .map_err(|e| {
+                StorageSnafu {
+                    message: format!("Perceptual hash task failed: {e}"),
+                }
+                .build()
+            })?
+    }
+}
+
+fn hash_file(path: &Path) -> DomainResult<PerceptualHash> {
+    let image = ImageReader::open(path)?
+        .with_guessed_format()?
+        .decode()
+        .map_err(|e| {
+            ParseSnafu {
+                message: format!("Failed to decode {}: {e}", path.display()),
+            }
+            .build()
+        })?;
+
+    // Thumbnails are already upright, the aspect ratio is deliberately dropped
+    let gray = image.resize_exact(9, 8, FilterType::Triangle).to_luma8();
+    let pixels: &[u8; 72] = gray
+        .as_raw()
+        .as_slice()
+        .try_into()
+        .expect("A 9x8 grayscale image has 72 pixels");
+
+    Ok(PerceptualHash::from_gradient(pixels))
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{imageops, ImageFormat, Rgb, RgbImage};
+
+    use super::*;
+
+    #[test]
+    fn test_hash_file_is_stable_across_resizes() {
+        let dir = tempfile::tempdir().unwrap();
+        let gradient = RgbImage::from_fn(360, 320, |x, y| {
+            let v = ((x * 255 / 360) ^ (y * 255 / 320)) as u8;
+            Rgb([v, v, v])
+        });
+        let original = dir.path().join("original.png");
+        gradient
+            .save_with_format(&original, ImageFormat::Png)
+            .unwrap();
+        let resized = dir.path().join("resized.jpg");
+        imageops::resize(&gradient, 180, 160, FilterType::Lanczos3)
+            .save_with_format(&resized, ImageFormat::Jpeg)
+            .unwrap();
+        let other = dir.path().join("other.png");
+        imageops::flip_horizontal(&gradient)
+            .save_with_format(&other, ImageFormat::Png)
+            .unwrap();
+
+        let original = hash_file(&original).unwrap();
+
+        assert!(original.distance(&hash_file(&resized).unwrap()) <= 4);
+        assert!(original.distance(&hash_file(&other).unwrap()) > 16);
+    }
+}
@@ -0,0 +1,153 @@
+use std::{io::Cursor, path::Path, sync::Arc};
+
+use application::medium::ports::{FileStorage, PreviewRenderer, RenderedPreview};
+use async_trait::async_trait;
+use domain::{
+    error::{DomainResult, ParseSnafu, StorageSnafu},
+    medium::{Dimensions, FileLocation, PreviewSize},
+};
+use image::{
+    codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage, ExtendedColorType, ImageDecoder,
+    ImageError, ImageReader,
+};
+use mime::Mime;
+use tracing::{debug, instrument};
+
+/// MIME types the CPU decoder handles
+const SUPPORTED_TYPES: [&str; 4] = ["image/jpeg", "image/png", "image/webp", "image/tiff"];
+
+/// Infrastructure adapter that implements PreviewRenderer with the `image` crate
+pub struct ImagePreviewRenderer {
+    file_storage: Arc<dyn FileStorage>,
+}
+
+impl ImagePreviewRenderer {
+    pub fn new(file_storage: Arc<dyn FileStorage>) -> Self {
+        Self { file_storage }
+    }
+}
+
+#[async_trait]
+impl PreviewRenderer for ImagePreviewRenderer {
+    fn supports(&self, mime: &Mime) -> bool {
+        SUPPORTED_TYPES.contains(&mime.essence_str())
+    }
+
+    #[instrument(skip(self), fields(path = ?location.relative_path))]
+    async fn render(
+        &self,
+        location: &FileLocation,
+        sizes: &[PreviewSize],
+    ) -> DomainResult<Vec<RenderedPreview>> {
+        let path = self.file_storage.get_local_path(location).await?;
+        let sizes = sizes.to_vec();
+
+        // Decoding and resampling are CPU bound, keep them off the async workers
+        tokio::task::spawn_blocking(move || render_sizes(&path, &sizes))
+            .await
+            .map_err(|e| {
+                StorageSnafu {
+                    message: format!("Preview rendering task failed: {e}"),
+                }
+                .build()
+            })?
+    }
+}
+
+fn render_sizes(path: &Path, sizes: &[PreviewSize]) -> DomainResult<Vec<RenderedPreview>> {
+    let image = decode_upright(path)?;
+    debug!(
+        width = image.width(),
+        height = image.height(),
+        "Decoded source image"
+    );
+
+    // Downscale from the largest size to the smallest, each step starting
+    // from the previous result instead of the full resolution source
+    let mut ordered = sizes.to_vec();
+    ordered.sort_by_key(|size| std::cmp::Reverse(size.longest_side()));
+
+    let mut current = image;
+    let mut previews = Vec::with_capacity(ordered.len());
+    for size in ordered {
+        let side = size.longest_side();
+        if current.width().max(current.height()) > side {
+            current = current.resize(side, side, FilterType::Lanczos3);
+        }
+        previews.push(encode(&current, size)?);
+    }
+
+    previews.sort_by_key(|preview| sizes.iter().position(|size| *size == preview.size));
+    Ok(previews)
+}
+
+/// Decodes the file and rotates/flips it according to its EXIF `Orientation`
+fn decode_upright(path: &Path) -> DomainResult<DynamicImage> {
+    let parse_error = |e: ImageError| {
+        ParseSnafu {
+            message: format!("Failed to decode {}: {e}", path.display()),
+        }
+        .build()
+    };
+
+    let mut decoder = ImageReader::open(path)?
+        .with_guessed_format()?
+        .into_decoder()
+        .map_err(parse_error)?;
+    let orientation = decoder.orientation().map_err(parse_error)?;
+
+    let mut image = DynamicImage::from_decoder(decoder).map_err(parse_error)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+fn encode(image: &DynamicImage, size: PreviewSize) -> DomainResult<RenderedPreview> {
+    let rgb = image.to_rgb8();
+    let mut content = Vec::new();
+    JpegEncoder::new_with_quality(Cursor::new(&mut content), size.quality())
+        .encode(
+            rgb.as_raw(),
+            rgb.width(),
+            rgb.height(),
+            ExtendedColorType::Rgb8,
+        )
+        .map_err(|e| {
+            StorageSnafu {
+                message: format!("Failed to encode {size} preview: {e}"),
+            }
+            .build()
+        })?;
+
+    Ok(RenderedPreview {
+        size,
+        dimensions: Dimensions::new(rgb.width(), rgb.height())?,
+        content,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{ImageFormat, RgbImage};
+
+    use super::*;
+
+    #[test]
+    fn test_render_sizes_downscales_without_upscaling() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("source.png");
+        RgbImage::new(400, 100)
+            .save_with_format(&path, ImageFormat::Png)
+            .unwrap();
+
+        let previews = render_sizes(&path, &[PreviewSize::Thumbnail, PreviewSize::Medium]).unwrap();
+
+        assert_eq!(previews.len(), 2);
+        assert_eq!(previews[0].size, PreviewSize::Thumbnail);
+        assert_eq!(previews[0].dimensions, Dimensions::new(200, 50).unwrap());
+        assert_eq!(previews[1].size, PreviewSize::Medium);
+        assert_eq!(previews[1].dimensions, Dimensions::new(400, 100).unwrap());
+        assert!(previews
+            .iter()
+            .all(|p| image::guess_format(&p.content).unwrap() == ImageFormat::Jpeg));
+    }
+}
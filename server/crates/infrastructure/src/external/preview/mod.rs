@@ -0,0 +1,7 @@
+mod ffmpeg_poster_frame;
+mod image_hasher;
+mod image_renderer;
+
+pub use ffmpeg_poster_frame::FfmpegPosterFrameGenerator;
+pub use image_hasher::ImagePerceptualHasher;
+pub use image_renderer::ImagePreviewRenderer;
@@ -0,0 +1,96 @@
+use application::metadata::ports::TimezoneResolver;
+use chrono::{FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+use tzf_rs::DefaultFinder;
+
+/// Resolves time zones offline, with the boundaries of timezone-boundary-builder
+/// embedded by `tzf-rs` and the rules of the IANA database embedded by `chrono-tz`
+pub struct BoundaryTimezoneResolver {
+    finder: DefaultFinder,
+}
+
+impl BoundaryTimezoneResolver {
+    /// Decodes the embedded boundaries, which takes a moment
+    pub fn new() -> Self {
+        Self {
+            finder: DefaultFinder::new(),
+        }
+    }
+}
+
+impl Default for BoundaryTimezoneResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimezoneResolver for BoundaryTimezoneResolver {
+    fn zone_at(&self, latitude: f64, longitude: f64) -> Option<String> {
+        Some(self.finder.get_tz_name(longitude, latitude))
+            .filter(|zone| !zone.is_empty())
+            .map(String::from)
+    }
+
+    fn offset_at(&self, zone: &str, local: NaiveDateTime) -> Option<FixedOffset> {
+        let tz = zone.parse::<Tz>().ok()?;
+        let offset = match tz.from_local_datetime(&local) {
+            LocalResult::Single(date) => date.offset().fix(),
+            // Repeated when the clocks go back, the first one is more likely
+            LocalResult::Ambiguous(earliest, _) => earliest.offset().fix(),
+            // Skipped when the clocks go forward, read with the new offset
+            LocalResult::None => tz.offset_from_utc_datetime(&local).fix(),
+        };
+        Some(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(date: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_zone_at_coordinates() {
+        let resolver = BoundaryTimezoneResolver::new();
+
+        assert_eq!(
+            resolver.zone_at(38.7223, -9.1393).as_deref(),
+            Some("Europe/Lisbon")
+        );
+        assert_eq!(
+            resolver.zone_at(35.6762, 139.6503).as_deref(),
+            Some("Asia/Tokyo")
+        );
+    }
+
+    #[test]
+    fn test_offset_follows_daylight_saving_time() {
+        let resolver = BoundaryTimezoneResolver::new();
+
+        assert_eq!(
+            resolver.offset_at("Europe/Berlin", local("2024-01-15 12:00:00")),
+            FixedOffset::east_opt(3600)
+        );
+        assert_eq!(
+            resolver.offset_at("Europe/Berlin", local("2024-07-15 12:00:00")),
+            FixedOffset::east_opt(7200)
+        );
+        assert_eq!(
+            resolver.offset_at("Europe/Berlin", local("2024-03-31 02:30:00")),
+            FixedOffset::east_opt(7200)
+        );
+    }
+
+    #[test]
+    fn test_unknown_zone_has_no_offset() {
+        let resolver = BoundaryTimezoneResolver::new();
+
+        assert_eq!(
+            resolver.offset_at("Mars/Olympus_Mons", local("2024-07-15 12:00:00")),
+            None
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use domain::{
+    error::{DomainResult, ValidationSnafu},
+    metadata::{GpsTrack, TrackPoint},
+};
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+use snafu::OptionExt;
+
+/// Reads the timed points of a GPX file (`trkpt`) or a KML file (`gx:Track`)
+pub fn parse_track(data: &[u8]) -> DomainResult<GpsTrack> {
+    let mut reader = Reader::from_reader(data);
+    reader.config_mut().trim_text(true);
+
+    let mut buf = Vec::new();
+    let mut points = Vec::new();
+    // Name of the element whose text is read next
+    let mut element = Vec::new();
+
+    // GPX: the attributes and children of the current trkpt
+    let mut trkpt: Option<(f64, f64)> = None;
+    let mut elevation = None;
+    let mut time = None;
+
+    // KML: the when and gx:coord elements of the current gx:Track, in order
+    let mut whens = Vec::new();
+    let mut coords = Vec::new();
+
+    loop {
+        let event = reader
+            .read_event_into(&mut buf)
+            .ok()
+            .context(ValidationSnafu {
+                message: "The track is not valid XML",
+            })?;
+
+        match event {
+            Event::Start(start) => {
+                let name = start.local_name().as_ref().to_vec();
+                if name == b"trkpt" {
+                    trkpt = Some(coordinates(&start)?);
+                    elevation = None;
+                    time = None;
+                }
+                element = name;
+            }
+            Event::Text(text) => {
+                let text = text.unescape().ok().context(ValidationSnafu {
+                    message: "The track is not valid XML",
+                })?;
+                match (element.as_slice(), trkpt.is_some()) {
+                    (b"ele", true) => elevation = text.trim().parse().ok(),
+                    (b"time", true) => time = Some(parse_time(&text)?),
+                    (b"when", false) => whens.push(parse_time(&text)?),
+                    (b"coord", false) => coords.push(text.into_owned()),
+                    _ => {}
+                }
+            }
+            Event::End(end) => {
+                match end.local_name().as_ref() {
+                    b"trkpt" => {
+                        // Points without a time cannot be matched to a capture date
+                        if let (Some((latitude, longitude)), Some(time)) = (trkpt.take(), time) {
+                            points.push(TrackPoint {
+                                time,
+                                latitude,
+                                longitude,
+                                altitude: elevation,
+                            });
+                        }
+                    }
+                    b"Track" => {
+                        for (time, coord) in whens.drain(..).zip(coords.drain(..)) {
+                            points.push(parse_coord(time, &coord)?);
+                        }
+                    }
+                    _ => {}
+                }
+                element.clear();
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    GpsTrack::new(points)
+}
+
+fn coordinates(start: &BytesStart) -> DomainResult<(f64, f64)> {
+    let attribute = |name: &str| -> DomainResult<f64> {
+        start
+            .try_get_attribute(name)
+            .ok()
+            .flatten()
+            .and_then(|value| value.unescape_value().ok()?.trim().parse().ok())
+            .context(ValidationSnafu {
+                message: format!("A track point has no valid {}", name),
+            })
+    };
+    Ok((attribute("lat")?, attribute("lon")?))
+}
+
+/// Reads a `gx:coord`, which is `longitude latitude altitude`
+fn parse_coord(time: DateTime<Utc>, coord: &str) -> DomainResult<TrackPoint> {
+    let mut values = coord.split_whitespace().map(str::parse::<f64>);
+    let mut next = || values.next().and_then(Result::ok);
+    let (longitude, latitude) = next().zip(next()).context(ValidationSnafu {
+        message: format!("Invalid track coordinates {}", coord),
+    })?;
+
+    Ok(TrackPoint {
+        time,
+        latitude,
+        longitude,
+        altitude: next(),
+    })
+}
+
+/// Reads an XML date time, which is taken as UTC when it has no offset
+fn parse_time(text: &str) -> DomainResult<DateTime<Utc>> {
+    let text = text.trim();
+    DateTime::parse_from_rfc3339(text)
+        .map(|date| date.to_utc())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f").map(|date| date.and_utc())
+        })
+        .ok()
+        .context(ValidationSnafu {
+            message: format!("Invalid track time {}", text),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gpx() {
+        let gpx = br#"<?xml version="1.0" encoding="UTF-8"?>
+            <gpx version="1.1" creator="test" xmlns="http://www.topografix.com/GPX/1/1">
+              <trk><trkseg>
+                <trkpt lat="38.72" lon="-9.12"><ele>40.5</ele><time>2024-07-15T10:10:00Z</time></trkpt>
+                <trkpt lat="38.70" lon="-9.14"><time>2024-07-15T10:00:00.250Z</time></trkpt>
+                <trkpt lat="38.71" lon="-9.13"><ele>12</ele></trkpt>
+              </trkseg></trk>
+            </gpx>"#;
+
+        let track = parse_track(gpx).unwrap();
+
+        assert_eq!(track.points().len(), 2);
+        assert_eq!(
+            track.start(),
+            "2024-07-15T10:00:00.250Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(track.points()[0].altitude, None);
+        assert_eq!(track.points()[1].latitude, 38.72);
+        assert_eq!(track.points()[1].altitude, Some(40.5));
+    }
+
+    #[test]
+    fn test_parse_kml() {
+        let kml = br#"<?xml version="1.0" encoding="UTF-8"?>
+            <kml xmlns="http://www.opengis.net/kml/2.2" xmlns:gx="http://www.google.com/kml/ext/2.2">
+              <Placemark><gx:Track>
+                <when>2024-07-15T10:00:00Z</when>
+                <when>2024-07-15T12:10:00+02:00</when>
+                <gx:coord>-9.14 38.70 35</gx:coord>
+                <gx:coord>-9.12 38.72 40</gx:coord>
+              </gx:Track></Placemark>
+            </kml>"#;
+
+        let track = parse_track(kml).unwrap();
+
+        assert_eq!(track.points().len(), 2);
+        assert_eq!(
+            track.end(),
+            "2024-07-15T10:10:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            (track.points()[0].latitude, track.points()[0].longitude),
+            (38.70, -9.14)
+        );
+        assert_eq!(track.points()[0].altitude, Some(35.0));
+    }
+
+    #[test]
+    fn test_reject_track_without_times() {
+        let gpx = br#"<gpx><trk><trkseg><trkpt lat="38.72" lon="-9.12"/></trkseg></trk></gpx>"#;
+
+        assert!(parse_track(gpx).is_err());
+        assert!(parse_track(b"not a track").is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use domain::album::Album;
+use uuid::Uuid;
+
+/// Columns selected for an album, its media are aggregated into `medium_ids`.
+/// A purged cover medium reads as no cover.
+pub(super) const ALBUM_COLUMNS: &str = r#"
+    a.id,
+    a.owner_id,
+    a.title,
+    a.description,
+    (SELECT c.id FROM media c WHERE c.id = a.cover_medium_id AND c.album_id = a.id) AS cover_medium_id,
+    ARRAY(SELECT m.id FROM media m WHERE m.album_id = a.id ORDER BY m.taken_at, m.id) AS medium_ids,
+    a.created_at,
+    a.updated_at,
+    a.deleted_at
+"#;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(super) struct AlbumRow {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub cover_medium_id: Option<Uuid>,
+    pub medium_ids: Vec<Uuid>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+// Reconstitute Album from database - no validation, trust DB state
+impl From<AlbumRow> for Album {
+    fn from(row: AlbumRow) -> Self {
+        Album {
+            id: row.id,
+            owner_id: row.owner_id,
+            title: row.title,
+            description: row.description,
+            cover_medium_id: row.cover_medium_id,
+            medium_ids: row.medium_ids,
+            created_at: row.created_at.and_utc(),
+            updated_at: row.updated_at.and_utc(),
+            deleted_at: row.deleted_at.map(|d| d.and_utc()),
+            version: 0,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use domain::{album::Album, error::DomainResult, user::UserId};
+use tracing::info;
+
+use crate::persistence::postgres::{
+    album::{
+        entity::{AlbumRow, ALBUM_COLUMNS},
+        PostgresAlbumRepository,
+    },
+    repo_error,
+};
+
+impl PostgresAlbumRepository {
+    pub(super) async fn find_all_impl(&self, user_id: UserId) -> DomainResult<Vec<Album>> {
+        let rows = sqlx::query_as::<_, AlbumRow>(&format!(
+            "SELECT {ALBUM_COLUMNS} FROM albums a \
+             WHERE a.owner_id = $1 AND a.deleted_at IS NULL \
+             ORDER BY a.created_at DESC, a.id"
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(count = rows.len(), "Albums query completed");
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
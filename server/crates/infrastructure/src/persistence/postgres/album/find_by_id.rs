@@ -0,0 +1,41 @@
+use domain::{
+    album::{Album, AlbumId},
+    error::DomainResult,
+    user::UserId,
+};
+use tracing::debug;
+
+use crate::persistence::postgres::{
+    album::{
+        entity::{AlbumRow, ALBUM_COLUMNS},
+        PostgresAlbumRepository,
+    },
+    repo_error,
+};
+
+impl PostgresAlbumRepository {
+    pub(super) async fn find_by_id_impl(
+        &self,
+        id: AlbumId,
+        user_id: UserId,
+    ) -> DomainResult<Option<Album>> {
+        debug!("Querying album by id");
+
+        let row = sqlx::query_as::<_, AlbumRow>(&format!(
+            "SELECT {ALBUM_COLUMNS} FROM albums a \
+             WHERE a.id = $1 AND a.owner_id = $2 AND a.deleted_at IS NULL"
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        match &row {
+            Some(_) => debug!("Album found"),
+            None => debug!("Album not found"),
+        }
+
+        Ok(row.map(Into::into))
+    }
+}
@@ -0,0 +1,22 @@
+use domain::{album::AlbumId, error::DomainResult, medium::MediumId, user::UserId};
+
+use crate::persistence::postgres::{album::PostgresAlbumRepository, repo_error};
+
+impl PostgresAlbumRepository {
+    pub(super) async fn find_by_medium_id_impl(
+        &self,
+        medium_id: MediumId,
+        user_id: UserId,
+    ) -> DomainResult<Option<AlbumId>> {
+        sqlx::query_scalar::<_, AlbumId>(
+            "SELECT a.id FROM media m \
+             JOIN albums a ON a.id = m.album_id AND a.deleted_at IS NULL \
+             WHERE m.id = $1 AND m.owner_id = $2",
+        )
+        .bind(medium_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(repo_error)
+    }
+}
@@ -0,0 +1,46 @@
+mod entity;
+mod find_all;
+mod find_by_id;
+mod find_by_medium_id;
+
+use application::album::ports::AlbumRepository;
+use async_trait::async_trait;
+use domain::{
+    album::{Album, AlbumId},
+    error::DomainResult,
+    medium::MediumId,
+    user::UserId,
+};
+use sqlx::PgPool;
+
+pub struct PostgresAlbumRepository {
+    pool: PgPool,
+}
+
+impl PostgresAlbumRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AlbumRepository for PostgresAlbumRepository {
+    #[tracing::instrument(skip(self))]
+    async fn find_by_id(&self, id: AlbumId, user_id: UserId) -> DomainResult<Option<Album>> {
+        self.find_by_id_impl(id, user_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_all(&self, user_id: UserId) -> DomainResult<Vec<Album>> {
+        self.find_all_impl(user_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_by_medium_id(
+        &self,
+        medium_id: MediumId,
+        user_id: UserId,
+    ) -> DomainResult<Option<AlbumId>> {
+        self.find_by_medium_id_impl(medium_id, user_id).await
+    }
+}
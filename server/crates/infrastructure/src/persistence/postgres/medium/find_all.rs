@@ -5,31 +5,34 @@ use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 use domain::{
     error::DomainResult,
     medium::{
-        storage::FileLocation, Dimensions, Filename, GpsCoordinates, MediumFilter, MediumItem,
-        MediumListItem, Priority,
+        storage::FileLocation, Comparison, Curation, Dimensions, Filename, FilterExpression,
+        GpsCoordinates, MediumFilter, MediumItem, MediumListItem, Priority, SearchQuery,
+        SearchTerm, TagMatch,
     },
+    metadata::Place,
     shared::SortDirection,
 };
 use futures_util::TryStreamExt;
-use sqlx::QueryBuilder;
+use sqlx::{Postgres, QueryBuilder};
 use tracing::{debug, error, info};
 use uuid::Uuid;
 
 use crate::persistence::postgres::{
     groups::{GroupedRow, GroupedStreamExt},
     medium::{
-        types::{MediumItemTypeDb, MediumTypeDb, StorageTierDb},
+        types::{checksum_from_db, ColorLabelDb, MediumItemTypeDb, MediumTypeDb, StorageTierDb},
         PostgresMediumRepository,
     },
     repo_error,
 };
 
 #[derive(Debug, Clone, sqlx::FromRow)]
-struct FindAllMediumRow {
+pub(super) struct FindAllMediumRow {
     pub id: Uuid,
     pub owner_id: Uuid,
     pub medium_type: MediumTypeDb,
     pub leading_item_id: Uuid,
+    pub album_id: Option<Uuid>,
     pub taken_at: Option<DateTime<Utc>>,
     pub taken_at_timezone: Option<i32>,
     pub camera_make: Option<String>,
@@ -37,8 +40,17 @@ struct FindAllMediumRow {
     pub gps_latitude: Option<f64>,
     pub gps_longitude: Option<f64>,
     pub gps_altitude: Option<f64>,
+    pub favorite: bool,
+    pub rating: i16,
+    pub color_label: Option<ColorLabelDb>,
+    pub rejected: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub country_code: Option<String>,
+    pub country: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
     pub item_id: Uuid,
     pub medium_item_type: MediumItemTypeDb,
     pub mime: String,
@@ -47,6 +59,7 @@ struct FindAllMediumRow {
     pub priority: i32,
     pub width: Option<i32>,
     pub height: Option<i32>,
+    pub checksum: Option<Vec<u8>>,
     pub item_created_at: NaiveDateTime,
     pub item_updated_at: NaiveDateTime,
     pub storage_tier: StorageTierDb,
@@ -78,6 +91,7 @@ impl PostgresMediumRepository {
                 m.owner_id,
                 m.medium_type,
                 m.leading_item_id,
+                m.album_id,
                 m.taken_at,
                 m.taken_at_timezone,
                 m.camera_make,
@@ -85,8 +99,17 @@ impl PostgresMediumRepository {
                 m.gps_latitude,
                 m.gps_longitude,
                 m.gps_altitude,
+                m.favorite,
+                m.rating,
+                m.color_label,
+                m.rejected,
                 m.created_at,
                 m.updated_at,
+                m.deleted_at,
+                md.country_code,
+                md.country,
+                md.region,
+                md.city,
                 mi.id as item_id,
                 mi.medium_item_type,
                 mi.mime,
@@ -95,13 +118,15 @@ impl PostgresMediumRepository {
                 mi.priority,
                 mi.width,
                 mi.height,
+                mi.checksum,
                 mi.created_at as item_created_at,
                 mi.updated_at as item_updated_at,
                 l.variant as storage_tier,
                 l.path as relative_path
             FROM media m
             JOIN medium_items mi ON m.id = mi.medium_id AND mi.deleted_at IS NULL
-            JOIN locations l ON mi.id = l.item_id"#,
+            JOIN locations l ON mi.id = l.item_id
+            LEFT JOIN metadata md ON md.medium_id = m.id"#,
         );
 
         // WHERE clauses
@@ -119,6 +144,81 @@ impl PostgresMediumRepository {
             query.push_bind(end_date);
         }
 
+        // Album filter, optionally together with the media in no album
+        if let Some(album_id) = filter.album_id {
+            query.push(" AND (m.album_id = ");
+            query.push_bind(album_id);
+            if filter.include_no_album {
+                query.push(" OR m.album_id IS NULL");
+            }
+            query.push(") ");
+        }
+
+        // Place filter, a country, region or city name
+        if let Some(place) = &filter.place {
+            query.push(" AND LOWER(");
+            query.push_bind(place.trim().to_string());
+            query.push(
+                ") IN (LOWER(md.country_code), LOWER(md.country), LOWER(md.region), LOWER(md.city)) ",
+            );
+        }
+
+        // Tag filter, a tag matches itself and the tags below it
+        if !filter.tags.is_empty() {
+            let tags: Vec<String> = filter
+                .tags
+                .iter()
+                .map(|t| t.as_str().to_lowercase())
+                .collect();
+            let tag_matches = "EXISTS (SELECT 1 FROM media_tags t WHERE t.medium_id = m.id \
+                 AND (LOWER(t.tag_title) = q.tag OR starts_with(LOWER(t.tag_title), q.tag || '/')))";
+            match filter.tag_match {
+                TagMatch::Any => {
+                    query.push(" AND EXISTS (SELECT 1 FROM UNNEST(");
+                    query.push_bind(tags);
+                    query.push("::TEXT[]) AS q(tag) WHERE ");
+                    query.push(tag_matches);
+                    query.push(") ");
+                }
+                TagMatch::All => {
+                    query.push(" AND NOT EXISTS (SELECT 1 FROM UNNEST(");
+                    query.push_bind(tags);
+                    query.push("::TEXT[]) AS q(tag) WHERE NOT ");
+                    query.push(tag_matches);
+                    query.push(") ");
+                }
+            }
+        }
+
+        // Curation filters
+        if let Some(favorite) = filter.curation.favorite {
+            query.push(" AND m.favorite = ");
+            query.push_bind(favorite);
+        }
+        if let Some(min_rating) = filter.curation.min_rating {
+            query.push(" AND m.rating >= ");
+            query.push_bind(min_rating as i16);
+        }
+        if let Some(color_label) = filter.curation.color_label {
+            query.push(" AND m.color_label = ");
+            query.push_bind(ColorLabelDb::from(color_label));
+        }
+        if let Some(rejected) = filter.curation.rejected {
+            query.push(" AND m.rejected = ");
+            query.push_bind(rejected);
+        }
+
+        // Filter expression of a smart album
+        if let Some(expression) = &filter.expression {
+            query.push(" AND ");
+            push_expression(&mut query, expression);
+        }
+
+        // Search terms, each of them has to match
+        if let Some(search) = &filter.search {
+            push_search(&mut query, search);
+        }
+
         // Keyset pagination cursor
         if let Some(cursor) = filter.cursor {
             query.push(" AND (m.taken_at, m.id) ");
@@ -157,6 +257,182 @@ impl PostgresMediumRepository {
     }
 }
 
+/// Appends a filter expression as a condition. Conditions on missing values
+/// are false instead of `NULL`, so `NOT` matches the media without them.
+fn push_expression(query: &mut QueryBuilder<'_, Postgres>, expression: &FilterExpression) {
+    match expression {
+        FilterExpression::And(expressions) | FilterExpression::Or(expressions) => {
+            let operator = match expression {
+                FilterExpression::And(_) => " AND ",
+                _ => " OR ",
+            };
+            query.push("(");
+            for (index, expression) in expressions.iter().enumerate() {
+                if index > 0 {
+                    query.push(operator);
+                }
+                push_expression(query, expression);
+            }
+            query.push(")");
+        }
+        FilterExpression::Not(expression) => {
+            query.push("NOT ");
+            push_expression(query, expression);
+        }
+        condition => {
+            query.push("COALESCE(");
+            push_condition(query, condition);
+            query.push(", FALSE)");
+        }
+    }
+}
+
+fn push_condition(query: &mut QueryBuilder<'_, Postgres>, condition: &FilterExpression) {
+    match condition {
+        FilterExpression::Taken { start, end } => {
+            let mut bounds = query.separated(" AND ");
+            if let Some(start) = start {
+                bounds.push("m.taken_at >= ").push_bind_unseparated(*start);
+            }
+            if let Some(end) = end {
+                bounds.push("m.taken_at <= ").push_bind_unseparated(*end);
+            }
+        }
+        FilterExpression::Camera { make, model } => {
+            let mut columns = query.separated(" AND ");
+            for (column, value) in [("m.camera_make", make), ("m.camera_model", model)] {
+                if let Some(value) = value.as_deref().filter(|v| !v.trim().is_empty()) {
+                    columns
+                        .push(format!("LOWER({column}) = "))
+                        .push_bind_unseparated(value.trim().to_lowercase());
+                }
+            }
+        }
+        FilterExpression::Lens(lens) => {
+            query.push("LOWER(md.lens_model) = ");
+            query.push_bind(lens.trim().to_lowercase());
+        }
+        FilterExpression::Tag(tag) => {
+            let tag = tag.as_str().to_lowercase();
+            query.push(
+                "EXISTS (SELECT 1 FROM media_tags t WHERE t.medium_id = m.id \
+                 AND (LOWER(t.tag_title) = ",
+            );
+            query.push_bind(tag.clone());
+            query.push(" OR starts_with(LOWER(t.tag_title), ");
+            query.push_bind(format!("{tag}/"));
+            query.push(")))");
+        }
+        FilterExpression::MinRating(min_rating) => {
+            query.push("m.rating >= ");
+            query.push_bind(*min_rating as i16);
+        }
+        FilterExpression::MediumType(medium_type) => {
+            query.push("m.medium_type = ");
+            query.push_bind(MediumTypeDb::from(*medium_type));
+        }
+        FilterExpression::Place(place) => {
+            query.push("LOWER(");
+            query.push_bind(place.trim().to_string());
+            query.push(
+                ") IN (LOWER(md.country_code), LOWER(md.country), LOWER(md.region), LOWER(md.city))",
+            );
+        }
+        FilterExpression::HasGps(has_gps) => {
+            query.push("(m.gps_latitude IS NOT NULL AND m.gps_longitude IS NOT NULL) = ");
+            query.push_bind(*has_gps);
+        }
+        FilterExpression::And(_) | FilterExpression::Or(_) | FilterExpression::Not(_) => {
+            unreachable!("combinations are pushed by push_expression")
+        }
+    }
+}
+
+/// Appends the terms of a search as conditions. The words and phrases are
+/// combined into a single full text query on the search document, which the
+/// database keeps for every medium from its title, description, place names,
+/// filenames and tags.
+fn push_search(query: &mut QueryBuilder<'_, Postgres>, search: &SearchQuery) {
+    let texts: Vec<&str> = search.texts().collect();
+    if !texts.is_empty() {
+        query.push(" AND m.search_document @@ (");
+        let mut phrases = query.separated(" && ");
+        for text in texts {
+            phrases
+                .push("phraseto_tsquery('simple', ")
+                .push_bind_unseparated(text.to_string())
+                .push_unseparated(")");
+        }
+        query.push(") ");
+    }
+
+    for term in &search.terms {
+        match term {
+            SearchTerm::Text(_) => {}
+            SearchTerm::Camera(camera) => {
+                let pattern = contains_pattern(camera);
+                query.push(" AND (m.camera_make ILIKE ");
+                query.push_bind(pattern.clone());
+                query.push(" OR m.camera_model ILIKE ");
+                query.push_bind(pattern);
+                query.push(") ");
+            }
+            SearchTerm::Lens(lens) => {
+                query.push(" AND md.lens_model ILIKE ");
+                query.push_bind(contains_pattern(lens));
+            }
+            SearchTerm::Iso(iso) => {
+                query.push(" AND md.iso");
+                query.push(comparison_sql(iso.comparison));
+                query.push_bind(i32::try_from(iso.value).unwrap_or(i32::MAX));
+            }
+            SearchTerm::Rating(rating) => {
+                query.push(" AND m.rating");
+                query.push(comparison_sql(rating.comparison));
+                query.push_bind(rating.value as i16);
+            }
+            SearchTerm::Taken { start, end } => {
+                query.push(" AND m.taken_at >= ");
+                query.push_bind(*start);
+                query.push(" AND m.taken_at < ");
+                query.push_bind(*end);
+            }
+            SearchTerm::Tag(tag) => {
+                query.push(" AND ");
+                push_condition(query, &FilterExpression::Tag(tag.clone()));
+            }
+            SearchTerm::Place(place) => {
+                query.push(" AND ");
+                push_condition(query, &FilterExpression::Place(place.clone()));
+            }
+            SearchTerm::MediumType(medium_type) => {
+                query.push(" AND ");
+                push_condition(query, &FilterExpression::MediumType(*medium_type));
+            }
+        }
+    }
+}
+
+fn comparison_sql(comparison: Comparison) -> &'static str {
+    match comparison {
+        Comparison::Equal => " = ",
+        Comparison::Less => " < ",
+        Comparison::LessOrEqual => " <= ",
+        Comparison::Greater => " > ",
+        Comparison::GreaterOrEqual => " >= ",
+    }
+}
+
+/// An `ILIKE` pattern matching values that contain the text
+fn contains_pattern(text: &str) -> String {
+    let escaped = text
+        .trim()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
 impl GroupedRow<MediumListItem, Uuid> for FindAllMediumRow {
     fn key(&self) -> &Uuid {
         &self.id
@@ -202,6 +478,7 @@ impl From<&FindAllMediumRow> for MediumItem {
             priority: Priority::new(row.priority),
             dimensions,
             locations: vec![FileLocation::from(row)],
+            checksum: row.checksum.as_deref().and_then(checksum_from_db),
             created_at: row.item_created_at.and_utc(),
             updated_at: row.item_updated_at.and_utc(),
         }
@@ -232,13 +509,76 @@ impl From<&FindAllMediumRow> for MediumListItem {
             owner_id: row.owner_id,
             medium_type: row.medium_type.into(),
             leading_item_id: row.leading_item_id,
+            album_id: row.album_id,
             taken_at,
             camera_make: row.camera_make.clone(),
             camera_model: row.camera_model.clone(),
             gps_coordinates,
+            curation: Curation {
+                favorite: row.favorite,
+                rating: row.rating as u8,
+                color_label: row.color_label.map(Into::into),
+                rejected: row.rejected,
+            },
+            place: Option::zip(row.country_code.clone(), row.country.clone()).map(
+                |(country_code, country)| Place {
+                    country_code,
+                    country,
+                    region: row.region.clone(),
+                    city: row.city.clone(),
+                },
+            ),
             created_at: row.item_created_at.and_utc(),
             updated_at: row.item_updated_at.and_utc(),
+            deleted_at: row.deleted_at.map(|d| d.and_utc()),
             items: vec![MediumItem::from(row)],
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search_sql(input: &str) -> String {
+        let mut query = QueryBuilder::new("SELECT m.id FROM media m WHERE TRUE");
+        push_search(&mut query, &SearchQuery::parse(input).unwrap());
+        query.sql().to_string()
+    }
+
+    #[test]
+    fn test_words_and_phrases_are_one_full_text_query() {
+        assert_eq!(
+            search_sql("beach \"sunset over lisbon\""),
+            "SELECT m.id FROM media m WHERE TRUE AND m.search_document @@ \
+             (phraseto_tsquery('simple', $1) && phraseto_tsquery('simple', $2)) "
+        );
+    }
+
+    #[test]
+    fn test_number_fields_use_their_comparison() {
+        assert_eq!(
+            search_sql("iso:>=3200 rating:<3"),
+            "SELECT m.id FROM media m WHERE TRUE AND md.iso >= $1 AND m.rating < $2"
+        );
+        assert!(search_sql("iso:800").ends_with("AND md.iso = $1"));
+        assert!(search_sql("rating:>4").ends_with("AND m.rating > $1"));
+        assert!(search_sql("rating:<=2").ends_with("AND m.rating <= $1"));
+    }
+
+    #[test]
+    fn test_camera_and_lens_match_parts_ignoring_case() {
+        assert_eq!(
+            search_sql("camera:fuji lens:xf"),
+            "SELECT m.id FROM media m WHERE TRUE \
+             AND (m.camera_make ILIKE $1 OR m.camera_model ILIKE $2)  AND md.lens_model ILIKE $3"
+        );
+    }
+
+    #[test]
+    fn test_contains_pattern_escapes_wildcards() {
+        assert_eq!(contains_pattern(" 100% "), "%100\\%%");
+        assert_eq!(contains_pattern("EF_50"), "%EF\\_50%");
+        assert_eq!(contains_pattern("C:\\"), "%C:\\\\%");
+    }
+}
@@ -0,0 +1,36 @@
+use domain::{error::DomainResult, medium::MediumId, shared::crypto::Sha256, user::UserId};
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::persistence::postgres::{medium::PostgresMediumRepository, repo_error};
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_by_checksum_impl(
+        &self,
+        checksum: &Sha256,
+        user_id: UserId,
+    ) -> DomainResult<Option<MediumId>> {
+        debug!("Finding medium by checksum");
+
+        let id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT m.id
+            FROM media m
+            JOIN medium_items mi ON mi.medium_id = m.id
+            WHERE m.owner_id = $1
+              AND mi.checksum = $2
+              AND mi.medium_item_type = 'original'
+              AND m.deleted_at IS NULL
+            ORDER BY m.created_at ASC
+            LIMIT 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(checksum.as_bytes().as_slice())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        Ok(id)
+    }
+}
@@ -0,0 +1,39 @@
+use domain::{error::DomainResult, medium::MediumId, user::UserId};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::persistence::postgres::{medium::PostgresMediumRepository, repo_error};
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_by_content_identifier_impl(
+        &self,
+        content_identifier: &str,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>> {
+        debug!("Finding media by content identifier");
+
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT m.id
+            FROM media m
+            JOIN metadata md ON md.medium_id = m.id
+            WHERE m.owner_id = $1
+              AND md.content_identifier = $2
+              AND m.deleted_at IS NULL
+            ORDER BY m.created_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(content_identifier)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(
+            count = ids.len(),
+            "Found media sharing the content identifier"
+        );
+
+        Ok(ids)
+    }
+}
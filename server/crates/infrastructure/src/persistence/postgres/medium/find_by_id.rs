@@ -5,8 +5,8 @@ use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 use domain::{
     error::DomainResult,
     medium::{
-        storage::FileLocation, Dimensions, Filename, GpsCoordinates, Medium, MediumId, MediumItem,
-        Priority,
+        storage::FileLocation, Curation, Dimensions, Filename, GpsCoordinates, Medium, MediumId,
+        MediumItem, PerceptualHash, Priority, Tag,
     },
     user::UserId,
 };
@@ -17,7 +17,7 @@ use uuid::Uuid;
 use crate::persistence::postgres::{
     groups::{GroupedRow, GroupedStreamExt},
     medium::{
-        types::{MediumItemTypeDb, MediumTypeDb, StorageTierDb},
+        types::{checksum_from_db, ColorLabelDb, MediumItemTypeDb, MediumTypeDb, StorageTierDb},
         PostgresMediumRepository,
     },
     repo_error,
@@ -36,8 +36,16 @@ struct FindMediumRow {
     pub gps_latitude: Option<f64>,
     pub gps_longitude: Option<f64>,
     pub gps_altitude: Option<f64>,
+    pub perceptual_hash: Option<i64>,
+    pub tags: Vec<String>,
+    pub favorite: bool,
+    pub rating: i16,
+    pub color_label: Option<ColorLabelDb>,
+    pub rejected: bool,
+    pub curated: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
     pub item_id: Uuid,
     pub medium_item_type: MediumItemTypeDb,
     pub mime: String,
@@ -46,6 +54,7 @@ struct FindMediumRow {
     pub priority: i32,
     pub width: Option<i32>,
     pub height: Option<i32>,
+    pub checksum: Option<Vec<u8>>,
     pub item_created_at: NaiveDateTime,
     pub item_updated_at: NaiveDateTime,
     pub storage_tier: StorageTierDb,
@@ -73,8 +82,20 @@ impl PostgresMediumRepository {
                 m.gps_latitude,
                 m.gps_longitude,
                 m.gps_altitude,
+                m.perceptual_hash,
+                ARRAY(
+                    SELECT t.tag_title FROM media_tags t
+                    WHERE t.medium_id = m.id
+                    ORDER BY t.tag_title
+                ) AS "tags!",
+                m.favorite,
+                m.rating,
+                m.color_label as "color_label: ColorLabelDb",
+                m.rejected,
+                m.curated,
                 m.created_at,
                 m.updated_at,
+                m.deleted_at,
                 mi.id as item_id,
                 mi.medium_item_type as "medium_item_type: MediumItemTypeDb",
                 mi.mime,
@@ -83,6 +104,7 @@ impl PostgresMediumRepository {
                 mi.priority,
                 mi.width,
                 mi.height,
+                mi.checksum,
                 mi.created_at as item_created_at,
                 mi.updated_at as item_updated_at,
                 l.variant as "storage_tier: StorageTierDb",
@@ -155,6 +177,7 @@ impl From<&FindMediumRow> for MediumItem {
             priority: Priority::new(row.priority),
             dimensions,
             locations: vec![FileLocation::from(row)],
+            checksum: row.checksum.as_deref().and_then(checksum_from_db),
             created_at: row.item_created_at.and_utc(),
             updated_at: row.item_updated_at.and_utc(),
         }
@@ -189,8 +212,18 @@ impl From<&FindMediumRow> for Medium {
             camera_make: row.camera_make.clone(),
             camera_model: row.camera_model.clone(),
             gps_coordinates,
+            perceptual_hash: row.perceptual_hash.map(|h| PerceptualHash::new(h as u64)),
+            tags: row.tags.iter().cloned().map(Tag::from_trusted).collect(),
+            curation: Curation {
+                favorite: row.favorite,
+                rating: row.rating as u8,
+                color_label: row.color_label.map(Into::into),
+                rejected: row.rejected,
+            },
+            curated: row.curated,
             created_at: row.created_at.and_utc(),
             updated_at: row.updated_at.and_utc(),
+            deleted_at: row.deleted_at.map(|d| d.and_utc()),
             items: vec![MediumItem::from(row)],
             version: 0,
         }
@@ -222,8 +255,16 @@ mod tests {
             gps_latitude: None,
             gps_longitude: None,
             gps_altitude: None,
+            perceptual_hash: None,
+            tags: vec![],
+            favorite: false,
+            rating: 0,
+            color_label: None,
+            rejected: false,
+            curated: false,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
             item_id,
             medium_item_type: MediumItemTypeDb::Original,
             mime: "image/jpeg".to_string(),
@@ -232,6 +273,7 @@ mod tests {
             priority: 0,
             width: Some(1920),
             height: Some(1080),
+            checksum: None,
             item_created_at: now,
             item_updated_at: now,
             storage_tier,
@@ -0,0 +1,48 @@
+use application::medium::ports::CaptureDateShiftCriteria;
+use domain::{error::DomainResult, medium::MediumId, user::UserId};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::persistence::postgres::{medium::PostgresMediumRepository, repo_error};
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_capture_date_shift_candidates_impl(
+        &self,
+        criteria: &CaptureDateShiftCriteria,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>> {
+        debug!(?criteria, "Finding media to shift the capture date of");
+
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT m.id
+            FROM media m
+            WHERE m.owner_id = $1
+              AND m.deleted_at IS NULL
+              AND m.taken_at IS NOT NULL
+              AND (cardinality($2::uuid[]) = 0 OR m.id = ANY($2))
+              AND ($3::timestamptz IS NULL OR m.taken_at >= $3)
+              AND ($4::timestamptz IS NULL OR m.taken_at <= $4)
+              AND ($5::text IS NULL OR lower(m.camera_make) = lower($5))
+              AND ($6::text IS NULL OR lower(m.camera_model) = lower($6))
+            ORDER BY m.taken_at ASC, m.id ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(&criteria.medium_ids)
+        .bind(criteria.start_date)
+        .bind(criteria.end_date)
+        .bind(criteria.camera_make.as_deref())
+        .bind(criteria.camera_model.as_deref())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(
+            count = ids.len(),
+            "Found media to shift the capture date of"
+        );
+
+        Ok(ids)
+    }
+}
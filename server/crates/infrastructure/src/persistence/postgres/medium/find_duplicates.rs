@@ -0,0 +1,71 @@
+use domain::{
+    error::DomainResult,
+    medium::{MediumId, PerceptualHash},
+    user::UserId,
+};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::persistence::postgres::{medium::PostgresMediumRepository, repo_error};
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_checksum_duplicates_impl(
+        &self,
+        user_id: UserId,
+    ) -> DomainResult<Vec<Vec<MediumId>>> {
+        debug!("Finding media sharing a checksum");
+
+        let groups = sqlx::query_scalar::<_, Vec<Uuid>>(
+            r#"
+            SELECT array_agg(o.id ORDER BY o.created_at ASC)
+            FROM (
+                SELECT DISTINCT mi.checksum, m.id, m.created_at
+                FROM media m
+                JOIN medium_items mi ON mi.medium_id = m.id
+                WHERE m.owner_id = $1
+                  AND mi.checksum IS NOT NULL
+                  AND mi.medium_item_type = 'original'
+                  AND m.deleted_at IS NULL
+            ) o
+            GROUP BY o.checksum
+            HAVING COUNT(*) > 1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(count = groups.len(), "Found groups of exact duplicates");
+
+        Ok(groups)
+    }
+
+    pub(super) async fn find_perceptual_hashes_impl(
+        &self,
+        user_id: UserId,
+    ) -> DomainResult<Vec<(MediumId, PerceptualHash)>> {
+        debug!("Finding perceptual hashes");
+
+        let rows = sqlx::query_as::<_, (Uuid, i64)>(
+            r#"
+            SELECT id, perceptual_hash
+            FROM media
+            WHERE owner_id = $1
+              AND perceptual_hash IS NOT NULL
+              AND deleted_at IS NULL
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        // BIGINT is signed, the hash is stored bit for bit
+        Ok(rows
+            .into_iter()
+            .map(|(id, hash)| (id, PerceptualHash::new(hash as u64)))
+            .collect())
+    }
+}
@@ -0,0 +1,50 @@
+use application::medium::ports::GeotaggingCandidate;
+use chrono::{DateTime, Utc};
+use domain::{error::DomainResult, user::UserId};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::persistence::postgres::{medium::PostgresMediumRepository, repo_error};
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_geotagging_candidates_impl(
+        &self,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<GeotaggingCandidate>> {
+        debug!(%from, %to, "Finding media to geotag");
+
+        // The capture date has to come from the camera or the owner, the
+        // time the file was modified says little about where it was taken
+        let rows = sqlx::query_as::<_, (Uuid, DateTime<Utc>)>(
+            r#"
+            SELECT m.id, m.taken_at
+            FROM media m
+            JOIN metadata md ON md.medium_id = m.id
+            WHERE m.owner_id = $1
+              AND m.deleted_at IS NULL
+              AND m.gps_latitude IS NULL
+              AND m.taken_at BETWEEN $2 AND $3
+              AND (md.capture_date IS NOT NULL OR md.overrides->>'capture_date' IS NOT NULL)
+            ORDER BY m.taken_at ASC, m.id ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(count = rows.len(), "Found media to geotag");
+
+        Ok(rows
+            .into_iter()
+            .map(|(medium_id, taken_at)| GeotaggingCandidate {
+                medium_id,
+                taken_at,
+            })
+            .collect())
+    }
+}
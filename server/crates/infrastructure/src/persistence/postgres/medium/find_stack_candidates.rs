@@ -0,0 +1,61 @@
+use application::medium::ports::StackCriteria;
+use domain::{error::DomainResult, medium::MediumId, user::UserId};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::persistence::postgres::{medium::PostgresMediumRepository, repo_error};
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_stack_candidates_impl(
+        &self,
+        criteria: &StackCriteria,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>> {
+        debug!(?criteria, "Finding media to stack");
+
+        let query = match criteria {
+            StackCriteria::Burst { burst_id } => sqlx::query_scalar::<_, Uuid>(
+                r#"
+                SELECT m.id
+                FROM media m
+                JOIN metadata md ON md.medium_id = m.id
+                WHERE m.owner_id = $1
+                  AND md.burst_id = $2
+                  AND m.deleted_at IS NULL
+                ORDER BY m.created_at ASC
+                "#,
+            )
+            .bind(user_id)
+            .bind(burst_id),
+            // The RAW and JPEG of a shot share the name up to the extension
+            StackCriteria::Shot {
+                taken_at,
+                camera_serial_number,
+                filename_stem,
+            } => sqlx::query_scalar::<_, Uuid>(
+                r#"
+                SELECT m.id
+                FROM media m
+                JOIN metadata md ON md.medium_id = m.id
+                JOIN medium_items mi ON mi.id = m.leading_item_id
+                WHERE m.owner_id = $1
+                  AND md.capture_date = $2
+                  AND md.camera_serial_number IS NOT DISTINCT FROM $3
+                  AND lower(regexp_replace(mi.filename, '\.[^.]*$', '')) = lower($4)
+                  AND m.deleted_at IS NULL
+                ORDER BY m.created_at ASC
+                "#,
+            )
+            .bind(user_id)
+            .bind(taken_at.to_utc())
+            .bind(camera_serial_number.as_deref())
+            .bind(filename_stem),
+        };
+
+        let ids = query.fetch_all(&self.pool).await.map_err(repo_error)?;
+
+        info!(count = ids.len(), "Found media to stack");
+
+        Ok(ids)
+    }
+}
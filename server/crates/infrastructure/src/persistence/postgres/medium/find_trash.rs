@@ -0,0 +1,119 @@
+use application::medium::ports::ExpiredTrashedMedium;
+use chrono::{DateTime, Utc};
+use domain::{error::DomainResult, medium::MediumListItem, user::UserId};
+use futures_util::TryStreamExt;
+use tracing::{debug, error, info};
+use uuid::Uuid;
+
+use crate::persistence::postgres::{
+    groups::GroupedStreamExt,
+    medium::{find_all::FindAllMediumRow, PostgresMediumRepository},
+    repo_error,
+};
+
+#[derive(Debug, sqlx::FromRow)]
+struct ExpiredTrashedMediumRow {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+}
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_trash_impl(
+        &self,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumListItem>> {
+        debug!("Querying trashed media");
+
+        let media = sqlx::query_as::<_, FindAllMediumRow>(
+            r#"
+            SELECT
+                m.id,
+                m.owner_id,
+                m.medium_type,
+                m.leading_item_id,
+                m.album_id,
+                m.taken_at,
+                m.taken_at_timezone,
+                m.camera_make,
+                m.camera_model,
+                m.gps_latitude,
+                m.gps_longitude,
+                m.gps_altitude,
+                m.favorite,
+                m.rating,
+                m.color_label,
+                m.rejected,
+                m.created_at,
+                m.updated_at,
+                m.deleted_at,
+                md.country_code,
+                md.country,
+                md.region,
+                md.city,
+                mi.id as item_id,
+                mi.medium_item_type,
+                mi.mime,
+                mi.filename,
+                mi.size,
+                mi.priority,
+                mi.width,
+                mi.height,
+                mi.checksum,
+                mi.created_at as item_created_at,
+                mi.updated_at as item_updated_at,
+                l.variant as storage_tier,
+                l.path as relative_path
+            FROM media m
+            JOIN medium_items mi ON m.id = mi.medium_id AND mi.deleted_at IS NULL
+            JOIN locations l ON mi.id = l.item_id
+            LEFT JOIN metadata md ON md.medium_id = m.id
+            WHERE m.owner_id = $1 AND m.deleted_at IS NOT NULL
+            ORDER BY m.deleted_at DESC, m.id, mi.priority DESC, mi.id, l.variant
+            "#,
+        )
+        .bind(user_id)
+        .fetch(&self.pool)
+        .grouped()
+        .try_collect::<Vec<MediumListItem>>()
+        .await
+        .map_err(|err| {
+            error!("Failed to load trashed media: {}", err);
+            repo_error(err)
+        })?;
+
+        info!(count = media.len(), "Trash query completed");
+
+        Ok(media)
+    }
+
+    pub(super) async fn find_expired_trash_impl(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> DomainResult<Vec<ExpiredTrashedMedium>> {
+        debug!("Finding expired trashed media");
+
+        let rows = sqlx::query_as::<_, ExpiredTrashedMediumRow>(
+            r#"
+            SELECT id, owner_id
+            FROM media
+            WHERE deleted_at IS NOT NULL
+              AND deleted_at < $1
+            ORDER BY deleted_at ASC
+            "#,
+        )
+        .bind(deleted_before.naive_utc())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(count = rows.len(), "Found expired trashed media for purge");
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ExpiredTrashedMedium {
+                medium_id: row.id,
+                owner_id: row.owner_id,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,51 @@
+use application::medium::ports::UnhashedMedium;
+use domain::{error::DomainResult, medium::PreviewSize};
+use tracing::{debug, info};
+use uuid::Uuid;
+
+use crate::persistence::postgres::{medium::PostgresMediumRepository, repo_error};
+
+#[derive(Debug, sqlx::FromRow)]
+struct UnhashedMediumRow {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+}
+
+impl PostgresMediumRepository {
+    pub(super) async fn find_unhashed_impl(&self, limit: i64) -> DomainResult<Vec<UnhashedMedium>> {
+        debug!(limit, "Finding media without perceptual hash");
+
+        let rows = sqlx::query_as::<_, UnhashedMediumRow>(
+            r#"
+            SELECT m.id, m.owner_id
+            FROM media m
+            WHERE m.perceptual_hash IS NULL
+              AND m.deleted_at IS NULL
+              AND EXISTS (
+                  SELECT 1
+                  FROM medium_items mi
+                  WHERE mi.medium_id = m.id
+                    AND mi.medium_item_type = 'preview'
+                    AND mi.filename = $1
+              )
+            ORDER BY m.created_at ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(PreviewSize::Thumbnail.filename().as_str())
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(count = rows.len(), "Found media without perceptual hash");
+
+        Ok(rows
+            .into_iter()
+            .map(|row| UnhashedMedium {
+                medium_id: row.id,
+                owner_id: row.owner_id,
+            })
+            .collect())
+    }
+}
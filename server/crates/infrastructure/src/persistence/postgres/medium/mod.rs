@@ -1,18 +1,30 @@
-use application::medium::ports::{ExpiredTempLocation, MediumRepository};
+use application::medium::ports::{
+    CaptureDateShiftCriteria, ExpiredTempLocation, ExpiredTrashedMedium, GeotaggingCandidate,
+    MediumRepository, StackCriteria, UnhashedMedium,
+};
 use async_trait::async_trait;
 use byte_unit::Byte;
 use chrono::{DateTime, Utc};
 use domain::{
     error::DomainResult,
-    medium::{Medium, MediumFilter, MediumId, MediumListItem},
+    medium::{Medium, MediumFilter, MediumId, MediumListItem, PerceptualHash},
+    shared::crypto::Sha256,
     user::UserId,
 };
 use sqlx::PgPool;
 
 mod delete;
 mod find_all;
+mod find_by_checksum;
+mod find_by_content_identifier;
 mod find_by_id;
+mod find_capture_date_shift_candidates;
+mod find_duplicates;
 mod find_expired_temp;
+mod find_geotagging_candidates;
+mod find_stack_candidates;
+mod find_trash;
+mod find_unhashed;
 mod save;
 pub mod types;
 
@@ -63,4 +75,84 @@ impl MediumRepository for PostgresMediumRepository {
     ) -> DomainResult<Vec<ExpiredTempLocation>> {
         self.find_expired_temp_locations_impl(created_before).await
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_trash(&self, user_id: UserId) -> DomainResult<Vec<MediumListItem>> {
+        self.find_trash_impl(user_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_expired_trash(
+        &self,
+        deleted_before: DateTime<Utc>,
+    ) -> DomainResult<Vec<ExpiredTrashedMedium>> {
+        self.find_expired_trash_impl(deleted_before).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_by_content_identifier(
+        &self,
+        content_identifier: &str,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>> {
+        self.find_by_content_identifier_impl(content_identifier, user_id)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_stack_candidates(
+        &self,
+        criteria: &StackCriteria,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>> {
+        self.find_stack_candidates_impl(criteria, user_id).await
+    }
+
+    #[tracing::instrument(skip(self, checksum))]
+    async fn find_by_checksum(
+        &self,
+        checksum: &Sha256,
+        user_id: UserId,
+    ) -> DomainResult<Option<MediumId>> {
+        self.find_by_checksum_impl(checksum, user_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_checksum_duplicates(&self, user_id: UserId) -> DomainResult<Vec<Vec<MediumId>>> {
+        self.find_checksum_duplicates_impl(user_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_perceptual_hashes(
+        &self,
+        user_id: UserId,
+    ) -> DomainResult<Vec<(MediumId, PerceptualHash)>> {
+        self.find_perceptual_hashes_impl(user_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_unhashed(&self, limit: i64) -> DomainResult<Vec<UnhashedMedium>> {
+        self.find_unhashed_impl(limit).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_capture_date_shift_candidates(
+        &self,
+        criteria: &CaptureDateShiftCriteria,
+        user_id: UserId,
+    ) -> DomainResult<Vec<MediumId>> {
+        self.find_capture_date_shift_candidates_impl(criteria, user_id)
+            .await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_geotagging_candidates(
+        &self,
+        user_id: UserId,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> DomainResult<Vec<GeotaggingCandidate>> {
+        self.find_geotagging_candidates_impl(user_id, from, to)
+            .await
+    }
 }
@@ -1,4 +1,7 @@
-use domain::medium::{MediumItemType, MediumType, StorageTier};
+use domain::{
+    medium::{ColorLabel, MediumItemType, MediumType, StorageTier},
+    shared::crypto::Sha256,
+};
 
 #[derive(Debug, Copy, Clone, sqlx::Type)]
 #[sqlx(type_name = "medium_type_enum", rename_all = "snake_case")]
@@ -9,6 +12,7 @@ pub enum MediumTypeDb {
     Vector,
     Sequence,
     Gif,
+    Raw,
     Other,
 }
 
@@ -21,6 +25,7 @@ impl From<MediumType> for MediumTypeDb {
             MediumType::Vector => MediumTypeDb::Vector,
             MediumType::Sequence => MediumTypeDb::Sequence,
             MediumType::Gif => MediumTypeDb::Gif,
+            MediumType::Raw => MediumTypeDb::Raw,
             MediumType::Other => MediumTypeDb::Other,
         }
     }
@@ -35,6 +40,7 @@ impl From<MediumTypeDb> for MediumType {
             MediumTypeDb::Vector => MediumType::Vector,
             MediumTypeDb::Sequence => MediumType::Sequence,
             MediumTypeDb::Gif => MediumType::Gif,
+            MediumTypeDb::Raw => MediumType::Raw,
             MediumTypeDb::Other => MediumType::Other,
         }
     }
@@ -98,3 +104,42 @@ impl From<StorageTierDb> for StorageTier {
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "color_label_enum", rename_all = "snake_case")]
+pub enum ColorLabelDb {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+}
+
+impl From<ColorLabel> for ColorLabelDb {
+    fn from(label: ColorLabel) -> Self {
+        match label {
+            ColorLabel::Red => ColorLabelDb::Red,
+            ColorLabel::Yellow => ColorLabelDb::Yellow,
+            ColorLabel::Green => ColorLabelDb::Green,
+            ColorLabel::Blue => ColorLabelDb::Blue,
+            ColorLabel::Purple => ColorLabelDb::Purple,
+        }
+    }
+}
+
+impl From<ColorLabelDb> for ColorLabel {
+    fn from(db: ColorLabelDb) -> Self {
+        match db {
+            ColorLabelDb::Red => ColorLabel::Red,
+            ColorLabelDb::Yellow => ColorLabel::Yellow,
+            ColorLabelDb::Green => ColorLabel::Green,
+            ColorLabelDb::Blue => ColorLabel::Blue,
+            ColorLabelDb::Purple => ColorLabel::Purple,
+        }
+    }
+}
+
+/// Reads a checksum column, values that are not 32 bytes long are ignored
+pub(super) fn checksum_from_db(bytes: &[u8]) -> Option<Sha256> {
+    <[u8; 32]>::try_from(bytes).ok().map(Sha256::new)
+}
@@ -1,7 +1,11 @@
 use std::{collections::HashMap, str::FromStr};
 
 use chrono::{DateTime, Utc};
-use domain::metadata::{CameraInfo, FileInfo, LocationInfo, Metadata, Orientation, TechnicalInfo};
+use domain::metadata::{
+    CameraInfo, DescriptiveInfo, ExposureMode, ExposureProgram, FileInfo, LocationInfo, Metadata,
+    MetadataOverrides, MeteringMode, Orientation, Place, SidecarMetadata, TechnicalInfo, VideoInfo,
+    WhiteBalance,
+};
 use serde::{Deserialize, Serialize};
 use sqlx::types::Json;
 use uuid::Uuid;
@@ -11,6 +15,7 @@ pub struct MetadataDb {
     pub id: Uuid,
     pub medium_id: Uuid,
     pub extracted_at: DateTime<Utc>,
+    pub extractor_version: i32,
     // File info
     pub mime_type: String,
     pub file_size: i64,
@@ -27,18 +32,49 @@ pub struct MetadataDb {
     pub iso: Option<i16>,
     pub focal_length: Option<f64>,
     pub flash: Option<bool>,
+    pub lens_serial_number: Option<String>,
+    pub exposure_program: Option<ExposureProgramDb>,
+    pub exposure_mode: Option<ExposureModeDb>,
+    pub metering_mode: Option<MeteringModeDb>,
+    pub white_balance: Option<WhiteBalanceDb>,
+    pub exposure_bias: Option<f64>,
+    pub focal_length_35mm: Option<f64>,
     // Location
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     pub altitude: Option<f64>,
     pub direction: Option<f64>,
     pub horizontal_position_error: Option<f64>,
+    // Place
+    pub country_code: Option<String>,
+    pub country: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
     // Technical
     pub width: Option<i32>,
     pub height: Option<i32>,
     pub orientation: Option<OrientationDb>,
+    pub software: Option<String>,
+    // Descriptive
+    pub rating: Option<i16>,
+    pub keywords: Vec<String>,
+    pub caption: Option<String>,
+    pub creator: Option<String>,
+    pub copyright: Option<String>,
+    // Video
+    pub video_duration: Option<f64>,
+    pub video_frame_rate: Option<f64>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    pub video_bitrate: Option<i64>,
+    pub video_rotation: Option<i16>,
+    pub content_identifier: Option<String>,
+    pub camera_serial_number: Option<String>,
+    pub burst_id: Option<String>,
     // Additional
     pub additional: Json<HashMap<String, String>>,
+    pub overrides: Json<MetadataOverrides>,
+    pub sidecar: Option<Json<SidecarMetadata>>,
 }
 
 #[derive(Debug, Clone, sqlx::Type, Serialize, Deserialize)]
@@ -88,6 +124,145 @@ impl From<OrientationDb> for Orientation {
     }
 }
 
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "exposure_program_enum", rename_all = "snake_case")]
+pub enum ExposureProgramDb {
+    Manual,
+    Program,
+    AperturePriority,
+    ShutterPriority,
+    Creative,
+    Action,
+    Portrait,
+    Landscape,
+    Bulb,
+}
+
+impl From<ExposureProgram> for ExposureProgramDb {
+    fn from(p: ExposureProgram) -> Self {
+        match p {
+            ExposureProgram::Manual => ExposureProgramDb::Manual,
+            ExposureProgram::Program => ExposureProgramDb::Program,
+            ExposureProgram::AperturePriority => ExposureProgramDb::AperturePriority,
+            ExposureProgram::ShutterPriority => ExposureProgramDb::ShutterPriority,
+            ExposureProgram::Creative => ExposureProgramDb::Creative,
+            ExposureProgram::Action => ExposureProgramDb::Action,
+            ExposureProgram::Portrait => ExposureProgramDb::Portrait,
+            ExposureProgram::Landscape => ExposureProgramDb::Landscape,
+            ExposureProgram::Bulb => ExposureProgramDb::Bulb,
+        }
+    }
+}
+
+impl From<ExposureProgramDb> for ExposureProgram {
+    fn from(p: ExposureProgramDb) -> Self {
+        match p {
+            ExposureProgramDb::Manual => ExposureProgram::Manual,
+            ExposureProgramDb::Program => ExposureProgram::Program,
+            ExposureProgramDb::AperturePriority => ExposureProgram::AperturePriority,
+            ExposureProgramDb::ShutterPriority => ExposureProgram::ShutterPriority,
+            ExposureProgramDb::Creative => ExposureProgram::Creative,
+            ExposureProgramDb::Action => ExposureProgram::Action,
+            ExposureProgramDb::Portrait => ExposureProgram::Portrait,
+            ExposureProgramDb::Landscape => ExposureProgram::Landscape,
+            ExposureProgramDb::Bulb => ExposureProgram::Bulb,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "exposure_mode_enum", rename_all = "snake_case")]
+pub enum ExposureModeDb {
+    Auto,
+    Manual,
+    AutoBracket,
+}
+
+impl From<ExposureMode> for ExposureModeDb {
+    fn from(m: ExposureMode) -> Self {
+        match m {
+            ExposureMode::Auto => ExposureModeDb::Auto,
+            ExposureMode::Manual => ExposureModeDb::Manual,
+            ExposureMode::AutoBracket => ExposureModeDb::AutoBracket,
+        }
+    }
+}
+
+impl From<ExposureModeDb> for ExposureMode {
+    fn from(m: ExposureModeDb) -> Self {
+        match m {
+            ExposureModeDb::Auto => ExposureMode::Auto,
+            ExposureModeDb::Manual => ExposureMode::Manual,
+            ExposureModeDb::AutoBracket => ExposureMode::AutoBracket,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "metering_mode_enum", rename_all = "snake_case")]
+pub enum MeteringModeDb {
+    Average,
+    CenterWeightedAverage,
+    Spot,
+    MultiSpot,
+    MultiSegment,
+    Partial,
+    Other,
+}
+
+impl From<MeteringMode> for MeteringModeDb {
+    fn from(m: MeteringMode) -> Self {
+        match m {
+            MeteringMode::Average => MeteringModeDb::Average,
+            MeteringMode::CenterWeightedAverage => MeteringModeDb::CenterWeightedAverage,
+            MeteringMode::Spot => MeteringModeDb::Spot,
+            MeteringMode::MultiSpot => MeteringModeDb::MultiSpot,
+            MeteringMode::MultiSegment => MeteringModeDb::MultiSegment,
+            MeteringMode::Partial => MeteringModeDb::Partial,
+            MeteringMode::Other => MeteringModeDb::Other,
+        }
+    }
+}
+
+impl From<MeteringModeDb> for MeteringMode {
+    fn from(m: MeteringModeDb) -> Self {
+        match m {
+            MeteringModeDb::Average => MeteringMode::Average,
+            MeteringModeDb::CenterWeightedAverage => MeteringMode::CenterWeightedAverage,
+            MeteringModeDb::Spot => MeteringMode::Spot,
+            MeteringModeDb::MultiSpot => MeteringMode::MultiSpot,
+            MeteringModeDb::MultiSegment => MeteringMode::MultiSegment,
+            MeteringModeDb::Partial => MeteringMode::Partial,
+            MeteringModeDb::Other => MeteringMode::Other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, sqlx::Type)]
+#[sqlx(type_name = "white_balance_enum", rename_all = "snake_case")]
+pub enum WhiteBalanceDb {
+    Auto,
+    Manual,
+}
+
+impl From<WhiteBalance> for WhiteBalanceDb {
+    fn from(w: WhiteBalance) -> Self {
+        match w {
+            WhiteBalance::Auto => WhiteBalanceDb::Auto,
+            WhiteBalance::Manual => WhiteBalanceDb::Manual,
+        }
+    }
+}
+
+impl From<WhiteBalanceDb> for WhiteBalance {
+    fn from(w: WhiteBalanceDb) -> Self {
+        match w {
+            WhiteBalanceDb::Auto => WhiteBalance::Auto,
+            WhiteBalanceDb::Manual => WhiteBalance::Manual,
+        }
+    }
+}
+
 impl From<MetadataDb> for Metadata {
     fn from(db: MetadataDb) -> Self {
         let file_info = FileInfo {
@@ -103,6 +278,8 @@ impl From<MetadataDb> for Metadata {
                     make: db.camera_make,
                     model: db.camera_model,
                     capture_date: db.capture_date.map(|dt| dt.fixed_offset()),
+                    // Only the instant is stored
+                    capture_timezone_source: None,
                     modified_date: db.modified_date.map(|dt| dt.fixed_offset()),
                     lens_make: db.lens_make,
                     lens_model: db.lens_model,
@@ -111,6 +288,13 @@ impl From<MetadataDb> for Metadata {
                     iso: db.iso.map(|i| i as u16),
                     focal_length: db.focal_length,
                     flash: db.flash,
+                    lens_serial_number: db.lens_serial_number,
+                    exposure_program: db.exposure_program.map(Into::into),
+                    exposure_mode: db.exposure_mode.map(Into::into),
+                    metering_mode: db.metering_mode.map(Into::into),
+                    white_balance: db.white_balance.map(Into::into),
+                    exposure_bias: db.exposure_bias,
+                    focal_length_35mm: db.focal_length_35mm,
                 })
             } else {
                 None
@@ -131,17 +315,49 @@ impl From<MetadataDb> for Metadata {
             width: db.width.map(|w| w as u32),
             height: db.height.map(|h| h as u32),
             orientation: db.orientation.map(Into::into),
+            software: db.software,
+        };
+
+        let descriptive = DescriptiveInfo {
+            rating: db.rating.map(|r| r as i8),
+            keywords: db.keywords,
+            caption: db.caption,
+            creator: db.creator,
+            copyright: db.copyright,
+        };
+
+        let video = VideoInfo {
+            duration: db.video_duration,
+            frame_rate: db.video_frame_rate,
+            video_codec: db.video_codec,
+            audio_codec: db.audio_codec,
+            bitrate: db.video_bitrate.map(|b| b as u64),
+            rotation: db.video_rotation.map(|r| r as u16),
         };
 
         Metadata {
             id: db.id,
             medium_id: db.medium_id,
             extracted_at: db.extracted_at,
+            extractor_version: db.extractor_version as u32,
             file_info,
             camera_info,
             location,
+            place: Option::zip(db.country_code, db.country).map(|(country_code, country)| Place {
+                country_code,
+                country,
+                region: db.region,
+                city: db.city,
+            }),
             technical,
+            descriptive,
+            video: (video != VideoInfo::default()).then_some(video),
+            content_identifier: db.content_identifier,
+            camera_serial_number: db.camera_serial_number,
+            burst_id: db.burst_id,
             additional: db.additional.0,
+            sidecar: db.sidecar.map(|s| s.0),
+            overrides: db.overrides.0,
             version: 0,
         }
     }
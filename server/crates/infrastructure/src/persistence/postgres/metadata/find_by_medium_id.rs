@@ -11,13 +11,21 @@ impl PostgresMetadataRepository {
         let result = sqlx::query_as::<_, MetadataDb>(
             r#"
             SELECT
-                id, medium_id, extracted_at,
+                id, medium_id, extracted_at, extractor_version,
                 mime_type, file_size, file_modified_at,
                 camera_make, camera_model, capture_date, modified_date,
                 lens_make, lens_model, exposure_time, f_number, iso, focal_length, flash,
                 latitude, longitude, altitude, direction, horizontal_position_error,
                 width, height, orientation,
-                additional
+                lens_serial_number, exposure_program, exposure_mode, metering_mode,
+                white_balance, exposure_bias, focal_length_35mm, software,
+                rating, keywords, caption, creator, copyright,
+                video_duration, video_frame_rate, video_codec, audio_codec,
+                video_bitrate, video_rotation,
+                content_identifier, camera_serial_number, burst_id,
+                additional,
+                country_code, country, region, city,
+                overrides, sidecar
             FROM metadata
             WHERE medium_id = $1
             "#,
@@ -0,0 +1,35 @@
+use domain::{error::DomainResult, medium::MediumId, user::UserId};
+use tracing::info;
+
+use super::PostgresMetadataRepository;
+use crate::persistence::postgres::repo_error;
+
+impl PostgresMetadataRepository {
+    pub(super) async fn find_outdated_impl(
+        &self,
+        extractor_version: u32,
+    ) -> DomainResult<Vec<(MediumId, UserId)>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT m.id, m.owner_id
+            FROM metadata md
+            JOIN media m ON m.id = md.medium_id
+            WHERE md.extractor_version < $1
+              AND COALESCE(md.reextraction_failed_version, 0) < $1
+              AND m.deleted_at IS NULL
+            ORDER BY m.created_at ASC, m.id ASC
+            "#,
+            extractor_version as i32,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(
+            count = rows.len(),
+            extractor_version, "Found media with outdated metadata"
+        );
+
+        Ok(rows.into_iter().map(|row| (row.id, row.owner_id)).collect())
+    }
+}
@@ -0,0 +1,23 @@
+use domain::{error::DomainResult, medium::MediumId};
+
+use super::PostgresMetadataRepository;
+use crate::persistence::postgres::repo_error;
+
+impl PostgresMetadataRepository {
+    pub(super) async fn mark_reextraction_failed_impl(
+        &self,
+        medium_id: MediumId,
+        extractor_version: u32,
+    ) -> DomainResult<()> {
+        sqlx::query!(
+            "UPDATE metadata SET reextraction_failed_version = $2 WHERE medium_id = $1",
+            medium_id,
+            extractor_version as i32,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        Ok(())
+    }
+}
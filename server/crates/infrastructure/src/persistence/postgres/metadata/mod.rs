@@ -2,6 +2,8 @@ mod delete;
 pub(crate) mod entity;
 mod find_by_id;
 mod find_by_medium_id;
+mod find_outdated;
+mod mark_reextraction_failed;
 mod save;
 
 use application::metadata::ports::MetadataRepository;
@@ -10,6 +12,7 @@ use domain::{
     error::DomainResult,
     medium::MediumId,
     metadata::{Metadata, MetadataId},
+    user::UserId,
 };
 use sqlx::PgPool;
 
@@ -44,4 +47,19 @@ impl MetadataRepository for PostgresMetadataRepository {
     async fn delete(&self, id: MetadataId) -> DomainResult<()> {
         self.delete_impl(id).await
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_outdated(&self, extractor_version: u32) -> DomainResult<Vec<(MediumId, UserId)>> {
+        self.find_outdated_impl(extractor_version).await
+    }
+
+    #[tracing::instrument(skip(self), fields(medium_id = %medium_id))]
+    async fn mark_reextraction_failed(
+        &self,
+        medium_id: MediumId,
+        extractor_version: u32,
+    ) -> DomainResult<()> {
+        self.mark_reextraction_failed_impl(medium_id, extractor_version)
+            .await
+    }
 }
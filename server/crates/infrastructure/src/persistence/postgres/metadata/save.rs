@@ -1,13 +1,19 @@
 use domain::{error::DomainResult, metadata::Metadata};
 use sqlx::types::Json;
 
-use super::{entity::OrientationDb, PostgresMetadataRepository};
+use super::{
+    entity::{ExposureModeDb, ExposureProgramDb, MeteringModeDb, OrientationDb, WhiteBalanceDb},
+    PostgresMetadataRepository,
+};
 use crate::persistence::postgres::repo_error;
 
 impl PostgresMetadataRepository {
     pub(super) async fn save_impl(&self, metadata: &Metadata) -> DomainResult<()> {
         let orientation: Option<OrientationDb> =
             metadata.technical.orientation.as_ref().map(Into::into);
+        let camera = metadata.camera_info.as_ref();
+        let video = metadata.video.as_ref();
+        let place = metadata.place.as_ref();
 
         sqlx::query(
             r#"
@@ -18,7 +24,16 @@ impl PostgresMetadataRepository {
                 lens_make, lens_model, exposure_time, f_number, iso, focal_length, flash,
                 latitude, longitude, altitude, direction, horizontal_position_error,
                 width, height, orientation,
-                additional
+                video_duration, video_frame_rate, video_codec, audio_codec,
+                video_bitrate, video_rotation,
+                content_identifier, camera_serial_number, burst_id,
+                additional,
+                country_code, country, region, city,
+                overrides, sidecar,
+                extractor_version,
+                lens_serial_number, exposure_program, exposure_mode, metering_mode,
+                white_balance, exposure_bias, focal_length_35mm, software,
+                rating, keywords, caption, creator, copyright
             ) VALUES (
                 $1, $2, $3,
                 $4, $5, $6,
@@ -26,7 +41,16 @@ impl PostgresMetadataRepository {
                 $11, $12, $13, $14, $15, $16, $17,
                 $18, $19, $20, $21, $22,
                 $23, $24, $25,
-                $26
+                $26, $27, $28, $29,
+                $30, $31,
+                $32, $33, $34,
+                $35,
+                $36, $37, $38, $39,
+                $40, $41,
+                $42,
+                $43, $44, $45, $46,
+                $47, $48, $49, $50,
+                $51, $52, $53, $54, $55
             )
             ON CONFLICT (medium_id) DO UPDATE SET
                 extracted_at = EXCLUDED.extracted_at,
@@ -52,7 +76,36 @@ impl PostgresMetadataRepository {
                 width = EXCLUDED.width,
                 height = EXCLUDED.height,
                 orientation = EXCLUDED.orientation,
-                additional = EXCLUDED.additional
+                video_duration = EXCLUDED.video_duration,
+                video_frame_rate = EXCLUDED.video_frame_rate,
+                video_codec = EXCLUDED.video_codec,
+                audio_codec = EXCLUDED.audio_codec,
+                video_bitrate = EXCLUDED.video_bitrate,
+                video_rotation = EXCLUDED.video_rotation,
+                content_identifier = EXCLUDED.content_identifier,
+                camera_serial_number = EXCLUDED.camera_serial_number,
+                burst_id = EXCLUDED.burst_id,
+                additional = EXCLUDED.additional,
+                country_code = EXCLUDED.country_code,
+                country = EXCLUDED.country,
+                region = EXCLUDED.region,
+                city = EXCLUDED.city,
+                overrides = EXCLUDED.overrides,
+                sidecar = EXCLUDED.sidecar,
+                extractor_version = EXCLUDED.extractor_version,
+                lens_serial_number = EXCLUDED.lens_serial_number,
+                exposure_program = EXCLUDED.exposure_program,
+                exposure_mode = EXCLUDED.exposure_mode,
+                metering_mode = EXCLUDED.metering_mode,
+                white_balance = EXCLUDED.white_balance,
+                exposure_bias = EXCLUDED.exposure_bias,
+                focal_length_35mm = EXCLUDED.focal_length_35mm,
+                software = EXCLUDED.software,
+                rating = EXCLUDED.rating,
+                keywords = EXCLUDED.keywords,
+                caption = EXCLUDED.caption,
+                creator = EXCLUDED.creator,
+                copyright = EXCLUDED.copyright
             "#,
         )
         .bind(metadata.id)
@@ -114,8 +167,43 @@ impl PostgresMetadataRepository {
         .bind(metadata.technical.width.map(|w| w as i32))
         .bind(metadata.technical.height.map(|h| h as i32))
         .bind(orientation)
+        // Video
+        .bind(video.and_then(|v| v.duration))
+        .bind(video.and_then(|v| v.frame_rate))
+        .bind(video.and_then(|v| v.video_codec.clone()))
+        .bind(video.and_then(|v| v.audio_codec.clone()))
+        .bind(video.and_then(|v| v.bitrate.map(|b| b as i64)))
+        .bind(video.and_then(|v| v.rotation.map(|r| r as i16)))
+        .bind(metadata.content_identifier.as_deref())
+        .bind(metadata.camera_serial_number.as_deref())
+        .bind(metadata.burst_id.as_deref())
         // Additional
         .bind(Json(&metadata.additional))
+        // Place
+        .bind(place.map(|p| p.country_code.as_str()))
+        .bind(place.map(|p| p.country.as_str()))
+        .bind(place.and_then(|p| p.region.as_deref()))
+        .bind(place.and_then(|p| p.city.as_deref()))
+        // Overrides
+        .bind(Json(&metadata.overrides))
+        // Sidecar
+        .bind(metadata.sidecar.as_ref().map(Json))
+        .bind(metadata.extractor_version as i32)
+        // Camera info, continued
+        .bind(camera.and_then(|c| c.lens_serial_number.as_deref()))
+        .bind(camera.and_then(|c| c.exposure_program.map(ExposureProgramDb::from)))
+        .bind(camera.and_then(|c| c.exposure_mode.map(ExposureModeDb::from)))
+        .bind(camera.and_then(|c| c.metering_mode.map(MeteringModeDb::from)))
+        .bind(camera.and_then(|c| c.white_balance.map(WhiteBalanceDb::from)))
+        .bind(camera.and_then(|c| c.exposure_bias))
+        .bind(camera.and_then(|c| c.focal_length_35mm))
+        .bind(metadata.technical.software.as_deref())
+        // Descriptive
+        .bind(metadata.descriptive.rating.map(|r| r as i16))
+        .bind(&metadata.descriptive.keywords)
+        .bind(metadata.descriptive.caption.as_deref())
+        .bind(metadata.descriptive.creator.as_deref())
+        .bind(metadata.descriptive.copyright.as_deref())
         .execute(&self.pool)
         .await
         .map_err(repo_error)?;
@@ -1,10 +1,13 @@
+pub mod album;
 pub mod checkpoint_store;
 pub mod es_snapshot_store;
 pub mod events;
 mod groups;
 pub mod medium;
 pub mod metadata;
+pub mod smart_album;
 pub mod stream_link_store;
+pub mod tag;
 pub mod task;
 pub mod transaction_provider;
 pub mod user;
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use domain::{medium::FilterExpression, smart_album::SmartAlbum};
+use sqlx::types::Json;
+use uuid::Uuid;
+
+pub(super) const SMART_ALBUM_COLUMNS: &str = r#"
+    s.id,
+    s.owner_id,
+    s.title,
+    s.description,
+    s.filter,
+    s.created_at,
+    s.updated_at,
+    s.deleted_at
+"#;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub(super) struct SmartAlbumRow {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub filter: Json<FilterExpression>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+// Reconstitute SmartAlbum from database - no validation, trust DB state
+impl From<SmartAlbumRow> for SmartAlbum {
+    fn from(row: SmartAlbumRow) -> Self {
+        SmartAlbum {
+            id: row.id,
+            owner_id: row.owner_id,
+            title: row.title,
+            description: row.description,
+            filter: row.filter.0,
+            created_at: row.created_at.and_utc(),
+            updated_at: row.updated_at.and_utc(),
+            deleted_at: row.deleted_at.map(|d| d.and_utc()),
+            version: 0,
+        }
+    }
+}
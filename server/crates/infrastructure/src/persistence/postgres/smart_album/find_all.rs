@@ -0,0 +1,28 @@
+use domain::{error::DomainResult, smart_album::SmartAlbum, user::UserId};
+use tracing::info;
+
+use crate::persistence::postgres::{
+    repo_error,
+    smart_album::{
+        entity::{SmartAlbumRow, SMART_ALBUM_COLUMNS},
+        PostgresSmartAlbumRepository,
+    },
+};
+
+impl PostgresSmartAlbumRepository {
+    pub(super) async fn find_all_impl(&self, user_id: UserId) -> DomainResult<Vec<SmartAlbum>> {
+        let rows = sqlx::query_as::<_, SmartAlbumRow>(&format!(
+            "SELECT {SMART_ALBUM_COLUMNS} FROM smart_albums s \
+             WHERE s.owner_id = $1 AND s.deleted_at IS NULL \
+             ORDER BY s.created_at DESC, s.id"
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(count = rows.len(), "Smart albums query completed");
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+}
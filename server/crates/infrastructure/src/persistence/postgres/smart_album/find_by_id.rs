@@ -0,0 +1,41 @@
+use domain::{
+    error::DomainResult,
+    smart_album::{SmartAlbum, SmartAlbumId},
+    user::UserId,
+};
+use tracing::debug;
+
+use crate::persistence::postgres::{
+    repo_error,
+    smart_album::{
+        entity::{SmartAlbumRow, SMART_ALBUM_COLUMNS},
+        PostgresSmartAlbumRepository,
+    },
+};
+
+impl PostgresSmartAlbumRepository {
+    pub(super) async fn find_by_id_impl(
+        &self,
+        id: SmartAlbumId,
+        user_id: UserId,
+    ) -> DomainResult<Option<SmartAlbum>> {
+        debug!("Querying smart album by id");
+
+        let row = sqlx::query_as::<_, SmartAlbumRow>(&format!(
+            "SELECT {SMART_ALBUM_COLUMNS} FROM smart_albums s \
+             WHERE s.id = $1 AND s.owner_id = $2 AND s.deleted_at IS NULL"
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        match &row {
+            Some(_) => debug!("Smart album found"),
+            None => debug!("Smart album not found"),
+        }
+
+        Ok(row.map(Into::into))
+    }
+}
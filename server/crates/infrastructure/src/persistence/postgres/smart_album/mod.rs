@@ -0,0 +1,39 @@
+mod entity;
+mod find_all;
+mod find_by_id;
+
+use application::smart_album::ports::SmartAlbumRepository;
+use async_trait::async_trait;
+use domain::{
+    error::DomainResult,
+    smart_album::{SmartAlbum, SmartAlbumId},
+    user::UserId,
+};
+use sqlx::PgPool;
+
+pub struct PostgresSmartAlbumRepository {
+    pool: PgPool,
+}
+
+impl PostgresSmartAlbumRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SmartAlbumRepository for PostgresSmartAlbumRepository {
+    #[tracing::instrument(skip(self))]
+    async fn find_by_id(
+        &self,
+        id: SmartAlbumId,
+        user_id: UserId,
+    ) -> DomainResult<Option<SmartAlbum>> {
+        self.find_by_id_impl(id, user_id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_all(&self, user_id: UserId) -> DomainResult<Vec<SmartAlbum>> {
+        self.find_all_impl(user_id).await
+    }
+}
@@ -0,0 +1,27 @@
+use domain::{
+    error::DomainResult,
+    medium::{MediumId, Tag},
+    user::UserId,
+};
+
+use crate::persistence::postgres::{repo_error, tag::PostgresTagRepository};
+
+impl PostgresTagRepository {
+    pub(super) async fn find_medium_ids_impl(
+        &self,
+        user_id: UserId,
+        tag: &Tag,
+    ) -> DomainResult<Vec<MediumId>> {
+        sqlx::query_scalar::<_, MediumId>(
+            "SELECT DISTINCT t.medium_id FROM media_tags t \
+             JOIN media m ON m.id = t.medium_id \
+             WHERE m.owner_id = $1 \
+             AND (LOWER(t.tag_title) = $2 OR starts_with(LOWER(t.tag_title), $2 || '/'))",
+        )
+        .bind(user_id)
+        .bind(tag.as_str().to_lowercase())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)
+    }
+}
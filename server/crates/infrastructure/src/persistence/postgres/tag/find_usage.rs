@@ -0,0 +1,62 @@
+use application::tag::ports::TagUsage;
+use domain::{error::DomainResult, medium::Tag, user::UserId};
+use tracing::info;
+
+use crate::persistence::postgres::{repo_error, tag::PostgresTagRepository};
+
+#[derive(Debug, sqlx::FromRow)]
+struct TagUsageRow {
+    tag: String,
+    count: i64,
+}
+
+impl PostgresTagRepository {
+    pub(super) async fn find_usage_impl(
+        &self,
+        user_id: UserId,
+        prefix: Option<&str>,
+        limit: u64,
+    ) -> DomainResult<Vec<TagUsage>> {
+        // Every tag also counts for the tags above it, `Travel/Lisbon` makes
+        // `Travel` show up. Spellings that only differ in case are one tag.
+        let rows = sqlx::query_as::<_, TagUsageRow>(
+            r#"
+            WITH paths AS (
+                SELECT
+                    t.medium_id,
+                    array_to_string(s.segments[1:n], '/') AS tag,
+                    s.segments[n] AS name
+                FROM media_tags t
+                JOIN media m ON m.id = t.medium_id
+                CROSS JOIN LATERAL (SELECT string_to_array(t.tag_title, '/') AS segments) s
+                CROSS JOIN LATERAL generate_series(1, cardinality(s.segments)) AS n
+                WHERE m.owner_id = $1 AND m.deleted_at IS NULL
+            )
+            SELECT MIN(tag) AS tag, COUNT(DISTINCT medium_id) AS count
+            FROM paths
+            WHERE $2::TEXT IS NULL
+                OR starts_with(LOWER(tag), $2)
+                OR starts_with(LOWER(name), $2)
+            GROUP BY LOWER(tag)
+            ORDER BY count DESC, LOWER(tag)
+            LIMIT $3
+            "#,
+        )
+        .bind(user_id)
+        .bind(prefix.map(str::to_lowercase))
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        info!(count = rows.len(), "Tag usage query completed");
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TagUsage {
+                tag: Tag::from_trusted(row.tag),
+                count: row.count as u64,
+            })
+            .collect())
+    }
+}
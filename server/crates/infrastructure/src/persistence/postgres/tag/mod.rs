@@ -0,0 +1,40 @@
+mod find_medium_ids;
+mod find_usage;
+
+use application::tag::{ports::TagUsage, TagRepository};
+use async_trait::async_trait;
+use domain::{
+    error::DomainResult,
+    medium::{MediumId, Tag},
+    user::UserId,
+};
+use sqlx::PgPool;
+
+/// Tags are read from the `media_tags` rows the medium projection maintains
+pub struct PostgresTagRepository {
+    pool: PgPool,
+}
+
+impl PostgresTagRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TagRepository for PostgresTagRepository {
+    #[tracing::instrument(skip(self))]
+    async fn find_usage(
+        &self,
+        user_id: UserId,
+        prefix: Option<&str>,
+        limit: u64,
+    ) -> DomainResult<Vec<TagUsage>> {
+        self.find_usage_impl(user_id, prefix, limit).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_medium_ids(&self, user_id: UserId, tag: &Tag) -> DomainResult<Vec<MediumId>> {
+        self.find_medium_ids_impl(user_id, tag).await
+    }
+}
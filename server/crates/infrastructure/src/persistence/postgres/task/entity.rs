@@ -1,5 +1,5 @@
 use chrono::NaiveDateTime;
-use domain::task::{Task, TaskStatus};
+use domain::task::{Task, TaskProgress, TaskStatus};
 use uuid::Uuid;
 
 use crate::persistence::postgres::task::task_types::{TaskStatusDb, TaskTypeDb};
@@ -15,6 +15,8 @@ pub struct TaskDb {
     pub created_at: NaiveDateTime,
     pub started_at: Option<NaiveDateTime>,
     pub completed_at: Option<NaiveDateTime>,
+    pub processed_count: Option<i32>,
+    pub total_count: Option<i32>,
 }
 
 impl From<TaskDb> for Task {
@@ -34,6 +36,12 @@ impl From<TaskDb> for Task {
             created_at: val.created_at.and_utc(),
             started_at: val.started_at.map(|d| d.and_utc()),
             completed_at: val.completed_at.map(|d| d.and_utc()),
+            progress: Option::zip(val.processed_count, val.total_count).map(
+                |(processed, total)| TaskProgress {
+                    processed: processed as usize,
+                    total: total as usize,
+                },
+            ),
             version: 0,
         }
     }
@@ -55,6 +63,8 @@ impl From<Task> for TaskDb {
             created_at: task.created_at.naive_utc(),
             started_at: task.started_at.map(|d| d.naive_utc()),
             completed_at: task.completed_at.map(|d| d.naive_utc()),
+            processed_count: task.progress.map(|p| p.processed as i32),
+            total_count: task.progress.map(|p| p.total as i32),
         }
     }
 }
@@ -37,7 +37,8 @@ impl PostgresTaskRepository {
         let mut query = QueryBuilder::new(
             r#"
             SELECT id, reference_id, user_id, task_type,
-                   status, error, created_at, started_at, completed_at
+                   status, error, created_at, started_at, completed_at,
+                   processed_count, total_count
             FROM tasks
             WHERE user_id = "#,
         );
@@ -37,9 +37,13 @@ impl PostgresTaskRepository {
                 error,
                 created_at,
                 started_at,
-                completed_at
+                completed_at,
+                processed_count,
+                total_count
             FROM tasks
             WHERE reference_id = $1 AND task_type = $2 AND user_id = $3
+            ORDER BY created_at DESC
+            LIMIT 1
             "#,
             reference_id,
             task_type as TaskTypeDb,
@@ -29,9 +29,11 @@ impl PostgresTaskRepository {
                 error,
                 created_at,
                 started_at,
-                completed_at
+                completed_at,
+                processed_count,
+                total_count
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             ON CONFLICT (id) DO UPDATE
             SET status = CASE
                     -- Only update status if transitioning forward in the state machine
@@ -45,7 +47,10 @@ impl PostgresTaskRepository {
                     ELSE tasks.error
                 END,
                 started_at = COALESCE(tasks.started_at, EXCLUDED.started_at),
-                completed_at = COALESCE(tasks.completed_at, EXCLUDED.completed_at)
+                completed_at = COALESCE(tasks.completed_at, EXCLUDED.completed_at),
+                -- Progress reports may arrive out of order, never go back
+                processed_count = GREATEST(tasks.processed_count, EXCLUDED.processed_count),
+                total_count = COALESCE(EXCLUDED.total_count, tasks.total_count)
             "#,
             task_db.id,
             task_db.reference_id,
@@ -56,6 +61,8 @@ impl PostgresTaskRepository {
             task_db.created_at,
             task_db.started_at,
             task_db.completed_at,
+            task_db.processed_count,
+            task_db.total_count,
         )
         .execute(&self.pool)
         .await
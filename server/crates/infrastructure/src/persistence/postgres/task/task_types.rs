@@ -5,6 +5,9 @@ use domain::task::{status::TaskStatus, TaskType};
 pub enum TaskTypeDb {
     MetadataExtraction,
     TempCleanup,
+    PreviewGeneration,
+    CaptureDateShift,
+    MetadataReextraction,
 }
 
 impl From<TaskTypeDb> for TaskType {
@@ -12,6 +15,9 @@ impl From<TaskTypeDb> for TaskType {
         match task_type {
             TaskTypeDb::MetadataExtraction => TaskType::MetadataExtraction,
             TaskTypeDb::TempCleanup => TaskType::TempCleanup,
+            TaskTypeDb::PreviewGeneration => TaskType::PreviewGeneration,
+            TaskTypeDb::CaptureDateShift => TaskType::CaptureDateShift,
+            TaskTypeDb::MetadataReextraction => TaskType::MetadataReextraction,
         }
     }
 }
@@ -21,6 +27,9 @@ impl From<TaskType> for TaskTypeDb {
         match task_type {
             TaskType::MetadataExtraction => TaskTypeDb::MetadataExtraction,
             TaskType::TempCleanup => TaskTypeDb::TempCleanup,
+            TaskType::PreviewGeneration => TaskTypeDb::PreviewGeneration,
+            TaskType::CaptureDateShift => TaskTypeDb::CaptureDateShift,
+            TaskType::MetadataReextraction => TaskTypeDb::MetadataReextraction,
         }
     }
 }
@@ -1,5 +1,5 @@
 use byte_unit::Byte;
-use domain::user::{QuotaState, User, UserId};
+use domain::user::{QuotaState, User, UserId, UserSettings};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -9,6 +9,8 @@ pub struct UserDb {
     pub version: i64,
     pub username: Option<String>,
     pub email: Option<String>,
+    pub timezone: Option<String>,
+    pub xmp_write_back: bool,
     pub quota: i64,
     pub quota_used: i64,
 }
@@ -26,6 +28,10 @@ impl From<UserDb> for User {
             version: val.version,
             username,
             email: val.email,
+            timezone: val.timezone,
+            settings: UserSettings {
+                xmp_write_back: val.xmp_write_back,
+            },
             quota: QuotaState::new_unchecked(
                 Byte::from_i64(val.quota_used).expect("invalid quota used"),
                 Byte::from_i64(val.quota).expect("invalid quota"),
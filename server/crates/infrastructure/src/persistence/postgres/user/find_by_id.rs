@@ -15,7 +15,7 @@ impl PostgresUserRepository {
 
         let queried = sqlx::query_as!(
             UserDb,
-            "SELECT id, version, username, email, quota, quota_used FROM users WHERE id = $1",
+            "SELECT id, version, username, email, timezone, xmp_write_back, quota, quota_used FROM users WHERE id = $1",
             id
         )
         .fetch_optional(&self.pool)
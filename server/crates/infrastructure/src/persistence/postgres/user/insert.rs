@@ -13,12 +13,14 @@ impl PostgresUserRepository {
 
         let result = sqlx::query!(
             r#"
-            INSERT INTO users (id, username, email, quota, quota_used, version, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, NOW(), NOW())
+            INSERT INTO users (id, username, email, timezone, xmp_write_back, quota, quota_used, version, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW(), NOW())
             "#,
             user.id,
             user.username,
             user.email,
+            user.timezone,
+            user.settings.xmp_write_back,
             user.quota.limit().as_u64() as i64,
             user.quota.used().as_u64() as i64,
             user.version
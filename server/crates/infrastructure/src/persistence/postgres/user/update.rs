@@ -16,15 +16,19 @@ impl PostgresUserRepository {
             UPDATE users
             SET username = $2,
                 email = $3,
-                quota = $4,
-                quota_used = $5,
+                timezone = $4,
+                xmp_write_back = $5,
+                quota = $6,
+                quota_used = $7,
                 version = version + 1,
                 updated_at = NOW()
-            WHERE id = $1 AND version = $6
+            WHERE id = $1 AND version = $8
             "#,
             user.id,
             user.username,
             user.email,
+            user.timezone,
+            user.settings.xmp_write_back,
             user.quota.limit().as_u64() as i64,
             user.quota.used().as_u64() as i64,
             user.version
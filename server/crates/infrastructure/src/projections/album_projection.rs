@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use domain::album::events::{
+    AlbumCoverChangedEvent, AlbumCreatedEvent, AlbumDeletedEvent, AlbumMediumAddedEvent,
+    AlbumMediumRemovedEvent, AlbumRenamedEvent,
+};
+use event_sourcing::{
+    error::{EventSourcingError, Result},
+    projection::handler::ProjectionHandler,
+};
+use sqlx::{Postgres, Transaction};
+use tracing::info;
+
+use super::{register_event, RegisterProjection};
+
+/// Projection that maintains the albums read model table and the album of
+/// each medium in the media table.
+pub struct AlbumProjection;
+
+impl AlbumProjection {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl RegisterProjection for AlbumProjection {
+    fn register(
+        bus: &super::PgProjectionBus,
+        registry: &mut super::EventTypeRegistry,
+    ) -> Result<()> {
+        register_event::<AlbumCreatedEvent, _>(bus, registry, Self::new())?;
+        register_event::<AlbumRenamedEvent, _>(bus, registry, Self::new())?;
+        register_event::<AlbumCoverChangedEvent, _>(bus, registry, Self::new())?;
+        register_event::<AlbumMediumAddedEvent, _>(bus, registry, Self::new())?;
+        register_event::<AlbumMediumRemovedEvent, _>(bus, registry, Self::new())?;
+        register_event::<AlbumDeletedEvent, _>(bus, registry, Self::new())?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<AlbumCreatedEvent, i64, Transaction<'static, Postgres>> for AlbumProjection {
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &AlbumCreatedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO albums (id, owner_id, title, description, created_at, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $5) \
+             ON CONFLICT (id) DO NOTHING",
+        )
+        .bind(event.album_id)
+        .bind(event.owner_id)
+        .bind(&event.title)
+        .bind(&event.description)
+        .bind(event.created_at.naive_utc())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to insert album: {}", e),
+        })?;
+
+        info!(album_id = %event.album_id, "AlbumProjection: album created");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<AlbumRenamedEvent, i64, Transaction<'static, Postgres>> for AlbumProjection {
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &AlbumRenamedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query(
+            "UPDATE albums SET title = $2, description = $3, updated_at = NOW() WHERE id = $1",
+        )
+        .bind(event.album_id)
+        .bind(&event.title)
+        .bind(&event.description)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to rename album: {}", e),
+        })?;
+
+        info!(album_id = %event.album_id, "AlbumProjection: album renamed");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<AlbumCoverChangedEvent, i64, Transaction<'static, Postgres>>
+    for AlbumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &AlbumCoverChangedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE albums SET cover_medium_id = $2, updated_at = NOW() WHERE id = $1")
+            .bind(event.album_id)
+            .bind(event.cover_medium_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to change album cover: {}", e),
+            })?;
+
+        info!(album_id = %event.album_id, "AlbumProjection: cover changed");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<AlbumMediumAddedEvent, i64, Transaction<'static, Postgres>>
+    for AlbumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &AlbumMediumAddedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE media SET album_id = $1 WHERE id = $2")
+            .bind(event.album_id)
+            .bind(event.medium_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to add medium to album: {}", e),
+            })?;
+
+        // The first medium of an album becomes its cover
+        sqlx::query(
+            "UPDATE albums SET cover_medium_id = COALESCE(cover_medium_id, $2), updated_at = NOW() \
+             WHERE id = $1",
+        )
+        .bind(event.album_id)
+        .bind(event.medium_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update album cover: {}", e),
+        })?;
+
+        info!(
+            album_id = %event.album_id,
+            medium_id = %event.medium_id,
+            "AlbumProjection: medium added"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<AlbumMediumRemovedEvent, i64, Transaction<'static, Postgres>>
+    for AlbumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &AlbumMediumRemovedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        // Only clears the album if the medium was not moved on in the meantime
+        sqlx::query("UPDATE media SET album_id = NULL WHERE id = $2 AND album_id = $1")
+            .bind(event.album_id)
+            .bind(event.medium_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to remove medium from album: {}", e),
+            })?;
+
+        sqlx::query(
+            "UPDATE albums SET \
+             cover_medium_id = CASE WHEN cover_medium_id = $2 THEN NULL ELSE cover_medium_id END, \
+             updated_at = NOW() \
+             WHERE id = $1",
+        )
+        .bind(event.album_id)
+        .bind(event.medium_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update album cover: {}", e),
+        })?;
+
+        info!(
+            album_id = %event.album_id,
+            medium_id = %event.medium_id,
+            "AlbumProjection: medium removed"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<AlbumDeletedEvent, i64, Transaction<'static, Postgres>> for AlbumProjection {
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &AlbumDeletedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE media SET album_id = NULL WHERE album_id = $1")
+            .bind(event.album_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to release media of album: {}", e),
+            })?;
+
+        sqlx::query(
+            "UPDATE albums SET cover_medium_id = NULL, deleted_at = $2, updated_at = $2 \
+             WHERE id = $1",
+        )
+        .bind(event.album_id)
+        .bind(event.deleted_at.naive_utc())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to delete album: {}", e),
+        })?;
+
+        info!(album_id = %event.album_id, "AlbumProjection: album deleted");
+        Ok(())
+    }
+}
@@ -1,14 +1,26 @@
 use async_trait::async_trait;
-use domain::medium::events::{MediumCreatedEvent, MediumItemCreatedEvent, MediumUpdatedEvent};
+use domain::medium::{
+    events::{
+        LivePhotoPairedEvent, MediaStackedEvent, MediumCreatedEvent, MediumCurationChangedEvent,
+        MediumDeletedEvent, MediumItemCreatedEvent, MediumItemLocationAddedEvent,
+        MediumItemLocationRemovedEvent, MediumMergedEvent, MediumPurgedEvent, MediumRestoredEvent,
+        MediumSplitEvent, MediumSplitOffEvent, MediumTagsAddedEvent, MediumTagsRemovedEvent,
+        MediumUpdatedEvent, PerceptualHashComputedEvent,
+    },
+    MediumId, MediumType,
+};
 use event_sourcing::{
     error::{EventSourcingError, Result},
     projection::handler::ProjectionHandler,
 };
 use sqlx::{Postgres, Transaction};
 use tracing::info;
+use uuid::Uuid;
 
 use super::{register_event, RegisterProjection};
-use crate::persistence::postgres::medium::types::{MediumItemTypeDb, MediumTypeDb, StorageTierDb};
+use crate::persistence::postgres::medium::types::{
+    ColorLabelDb, MediumItemTypeDb, MediumTypeDb, StorageTierDb,
+};
 
 /// Projection that maintains the media, medium_items, and locations read model tables.
 pub struct MediumProjection;
@@ -19,6 +31,67 @@ impl MediumProjection {
     }
 }
 
+/// Removes a medium with its items and their locations from the read model
+async fn delete_medium_rows(
+    medium_id: MediumId,
+    tx: &mut Transaction<'static, Postgres>,
+) -> Result<()> {
+    sqlx::query(
+        "DELETE FROM locations \
+         WHERE item_id IN (SELECT id FROM medium_items WHERE medium_id = $1)",
+    )
+    .bind(medium_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| EventSourcingError::Projection {
+        message: format!("Failed to delete locations: {}", e),
+    })?;
+
+    sqlx::query("DELETE FROM medium_items WHERE medium_id = $1")
+        .bind(medium_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to delete medium_items: {}", e),
+        })?;
+
+    sqlx::query("DELETE FROM media_tags WHERE medium_id = $1")
+        .bind(medium_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to delete media_tags: {}", e),
+        })?;
+
+    sqlx::query("DELETE FROM media WHERE id = $1")
+        .bind(medium_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to delete media: {}", e),
+        })?;
+
+    Ok(())
+}
+
+/// Moves items, with their locations, over to another medium
+async fn move_medium_items(
+    medium_id: MediumId,
+    item_ids: &[Uuid],
+    tx: &mut Transaction<'static, Postgres>,
+) -> Result<()> {
+    sqlx::query("UPDATE medium_items SET medium_id = $1, updated_at = NOW() WHERE id = ANY($2)")
+        .bind(medium_id)
+        .bind(item_ids)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to move medium_items: {}", e),
+        })?;
+
+    Ok(())
+}
+
 impl RegisterProjection for MediumProjection {
     fn register(
         bus: &super::PgProjectionBus,
@@ -26,7 +99,21 @@ impl RegisterProjection for MediumProjection {
     ) -> Result<()> {
         register_event::<MediumCreatedEvent, _>(bus, registry, Self::new())?;
         register_event::<MediumItemCreatedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumItemLocationAddedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumItemLocationRemovedEvent, _>(bus, registry, Self::new())?;
         register_event::<MediumUpdatedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumDeletedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumRestoredEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumPurgedEvent, _>(bus, registry, Self::new())?;
+        register_event::<LivePhotoPairedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumMergedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediaStackedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumSplitOffEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumSplitEvent, _>(bus, registry, Self::new())?;
+        register_event::<PerceptualHashComputedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumTagsAddedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumTagsRemovedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumCurationChangedEvent, _>(bus, registry, Self::new())?;
         Ok(())
     }
 }
@@ -48,16 +135,31 @@ impl ProjectionHandler<MediumCreatedEvent, i64, Transaction<'static, Postgres>>
         let item_type_db = MediumItemTypeDb::from(item.medium_item_type);
         let location = item.locations.first().expect("Item must have a location");
         let storage_tier_db = StorageTierDb::from(location.storage_tier.clone());
+        let (taken_at_utc, taken_at_tz) = event
+            .taken_at
+            .as_ref()
+            .map(|dt| {
+                (
+                    Some(dt.with_timezone(&chrono::Utc)),
+                    Some(dt.offset().local_minus_utc()),
+                )
+            })
+            .unwrap_or((None, None));
 
         sqlx::query(
-            "INSERT INTO media (id, owner_id, medium_type, leading_item_id, updated_at) \
-             VALUES ($1, $2, $3, $4, NOW()) \
+            "INSERT INTO media (id, owner_id, medium_type, leading_item_id, \
+             taken_at, taken_at_timezone, camera_make, camera_model, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW()) \
              ON CONFLICT (id) DO NOTHING",
         )
         .bind(event.medium_id)
         .bind(event.user_id)
         .bind(medium_type_db as MediumTypeDb)
         .bind(item.id)
+        .bind(taken_at_utc)
+        .bind(taken_at_tz)
+        .bind(&event.camera_make)
+        .bind(&event.camera_model)
         .execute(&mut **tx)
         .await
         .map_err(|e| EventSourcingError::Projection {
@@ -65,8 +167,8 @@ impl ProjectionHandler<MediumCreatedEvent, i64, Transaction<'static, Postgres>>
         })?;
 
         sqlx::query(
-            "INSERT INTO medium_items (id, medium_id, medium_item_type, mime, filename, size, priority, width, height, updated_at) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW()) \
+            "INSERT INTO medium_items (id, medium_id, medium_item_type, mime, filename, size, priority, width, height, checksum, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, NOW()) \
              ON CONFLICT (id) DO NOTHING",
         )
         .bind(item.id)
@@ -78,6 +180,7 @@ impl ProjectionHandler<MediumCreatedEvent, i64, Transaction<'static, Postgres>>
         .bind(item.priority.value())
         .bind(item.dimensions.as_ref().map(|d| d.width() as i32))
         .bind(item.dimensions.as_ref().map(|d| d.height() as i32))
+        .bind(item.checksum.as_ref().map(|c| c.as_bytes().to_vec()))
         .execute(&mut **tx)
         .await
         .map_err(|e| EventSourcingError::Projection {
@@ -119,8 +222,8 @@ impl ProjectionHandler<MediumItemCreatedEvent, i64, Transaction<'static, Postgre
         let storage_tier_db = StorageTierDb::from(event.file_location.storage_tier.clone());
 
         sqlx::query(
-            "INSERT INTO medium_items (id, medium_id, medium_item_type, mime, filename, size, priority, width, height, updated_at) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, NOW()) \
+            "INSERT INTO medium_items (id, medium_id, medium_item_type, mime, filename, size, priority, width, height, checksum, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, NOW()) \
              ON CONFLICT (id) DO NOTHING",
         )
         .bind(event.item_id)
@@ -132,6 +235,7 @@ impl ProjectionHandler<MediumItemCreatedEvent, i64, Transaction<'static, Postgre
         .bind(event.priority.value())
         .bind(event.dimensions.as_ref().map(|d| d.width() as i32))
         .bind(event.dimensions.as_ref().map(|d| d.height() as i32))
+        .bind(event.checksum.as_ref().map(|c| c.as_bytes().to_vec()))
         .execute(&mut **tx)
         .await
         .map_err(|e| EventSourcingError::Projection {
@@ -161,6 +265,95 @@ impl ProjectionHandler<MediumItemCreatedEvent, i64, Transaction<'static, Postgre
     }
 }
 
+#[async_trait]
+impl ProjectionHandler<MediumItemLocationAddedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumItemLocationAddedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        let storage_tier_db = StorageTierDb::from(event.location.storage_tier.clone());
+
+        sqlx::query(
+            "INSERT INTO locations (item_id, path, variant) \
+             VALUES ($1, $2, $3) \
+             ON CONFLICT (item_id, variant) DO UPDATE SET path = EXCLUDED.path",
+        )
+        .bind(event.item_id)
+        .bind(event.location.relative_path.to_str().unwrap_or(""))
+        .bind(storage_tier_db as StorageTierDb)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to insert location: {}", e),
+        })?;
+
+        sqlx::query("UPDATE medium_items SET updated_at = $2 WHERE id = $1")
+            .bind(event.item_id)
+            .bind(event.metadata.occurred_at)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to update medium_item: {}", e),
+            })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            item_id = %event.item_id,
+            storage_tier = %event.location.storage_tier,
+            "MediumProjection: location added"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumItemLocationRemovedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumItemLocationRemovedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        let storage_tier_db = StorageTierDb::from(event.storage_tier.clone());
+
+        sqlx::query("DELETE FROM locations WHERE item_id = $1 AND variant = $2")
+            .bind(event.item_id)
+            .bind(storage_tier_db as StorageTierDb)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to delete location: {}", e),
+            })?;
+
+        sqlx::query("UPDATE medium_items SET updated_at = $2 WHERE id = $1")
+            .bind(event.item_id)
+            .bind(event.metadata.occurred_at)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to update medium_item: {}", e),
+            })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            item_id = %event.item_id,
+            storage_tier = %event.storage_tier,
+            "MediumProjection: location removed"
+        );
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl ProjectionHandler<MediumUpdatedEvent, i64, Transaction<'static, Postgres>>
     for MediumProjection
@@ -215,3 +408,420 @@ impl ProjectionHandler<MediumUpdatedEvent, i64, Transaction<'static, Postgres>>
         Ok(())
     }
 }
+
+#[async_trait]
+impl ProjectionHandler<MediumDeletedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumDeletedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE media SET deleted_at = $2, updated_at = NOW() WHERE id = $1")
+            .bind(event.medium_id)
+            .bind(event.deleted_at.naive_utc())
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to mark media as deleted: {}", e),
+            })?;
+
+        info!(medium_id = %event.medium_id, "MediumProjection: media moved to trash");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumRestoredEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumRestoredEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE media SET deleted_at = NULL, updated_at = NOW() WHERE id = $1")
+            .bind(event.medium_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to restore media: {}", e),
+            })?;
+
+        info!(medium_id = %event.medium_id, "MediumProjection: media restored from trash");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumPurgedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumPurgedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        delete_medium_rows(event.medium_id, tx).await?;
+
+        info!(medium_id = %event.medium_id, "MediumProjection: media purged");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<LivePhotoPairedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &LivePhotoPairedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE media SET medium_type = $2, updated_at = NOW() WHERE id = $1")
+            .bind(event.medium_id)
+            .bind(MediumTypeDb::from(MediumType::LivePhoto) as MediumTypeDb)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to update media: {}", e),
+            })?;
+
+        let item_ids: Vec<Uuid> = event.items.iter().map(|item| item.id).collect();
+        move_medium_items(event.medium_id, &item_ids, tx).await?;
+
+        info!(
+            medium_id = %event.medium_id,
+            video_medium_id = %event.video_medium_id,
+            items = item_ids.len(),
+            "MediumProjection: live photo paired"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumMergedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumMergedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        delete_medium_rows(event.medium_id, tx).await?;
+
+        info!(
+            medium_id = %event.medium_id,
+            into_medium_id = %event.into_medium_id,
+            "MediumProjection: media merged"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediaStackedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediaStackedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("DELETE FROM locations WHERE item_id = ANY($1)")
+            .bind(&event.dropped_item_ids)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to delete locations: {}", e),
+            })?;
+
+        sqlx::query("DELETE FROM medium_items WHERE id = ANY($1)")
+            .bind(&event.dropped_item_ids)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to delete medium_items: {}", e),
+            })?;
+
+        let item_ids: Vec<Uuid> = event.items.iter().map(|item| item.id).collect();
+        move_medium_items(event.medium_id, &item_ids, tx).await?;
+
+        // The perceptual hash was computed from the previews of the old leading item
+        sqlx::query(
+            "UPDATE media SET medium_type = $2, leading_item_id = $3, \
+             perceptual_hash = CASE WHEN leading_item_id = $3 THEN perceptual_hash END, \
+             updated_at = NOW() \
+             WHERE id = $1",
+        )
+        .bind(event.medium_id)
+        .bind(MediumTypeDb::from(event.medium_type) as MediumTypeDb)
+        .bind(event.leading_item_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update media: {}", e),
+        })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            source_medium_id = %event.source_medium_id,
+            items = item_ids.len(),
+            dropped = event.dropped_item_ids.len(),
+            "MediumProjection: media stacked"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumSplitOffEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumSplitOffEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        let (taken_at_utc, taken_at_tz) = event
+            .taken_at
+            .as_ref()
+            .map(|dt| {
+                (
+                    Some(dt.with_timezone(&chrono::Utc)),
+                    Some(dt.offset().local_minus_utc()),
+                )
+            })
+            .unwrap_or((None, None));
+        let (gps_lat, gps_lng, gps_alt) = event
+            .gps_coordinates
+            .map(|gps| (Some(gps.latitude()), Some(gps.longitude()), gps.altitude()))
+            .unwrap_or((None, None, None));
+
+        sqlx::query(
+            "INSERT INTO media (id, owner_id, medium_type, leading_item_id, \
+             taken_at, taken_at_timezone, camera_make, camera_model, \
+             gps_latitude, gps_longitude, gps_altitude, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, NOW()) \
+             ON CONFLICT (id) DO NOTHING",
+        )
+        .bind(event.medium_id)
+        .bind(event.owner_id)
+        .bind(MediumTypeDb::from(event.medium_type) as MediumTypeDb)
+        .bind(event.leading_item_id)
+        .bind(taken_at_utc)
+        .bind(taken_at_tz)
+        .bind(&event.camera_make)
+        .bind(&event.camera_model)
+        .bind(gps_lat)
+        .bind(gps_lng)
+        .bind(gps_alt)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to insert media: {}", e),
+        })?;
+
+        let item_ids: Vec<Uuid> = event.items.iter().map(|item| item.id).collect();
+        move_medium_items(event.medium_id, &item_ids, tx).await?;
+
+        info!(
+            medium_id = %event.medium_id,
+            split_from_medium_id = %event.split_from_medium_id,
+            items = item_ids.len(),
+            "MediumProjection: medium split off"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumSplitEvent, i64, Transaction<'static, Postgres>> for MediumProjection {
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumSplitEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        // The items were already moved by the MediumSplitOffEvent
+        sqlx::query("UPDATE media SET medium_type = $2, updated_at = NOW() WHERE id = $1")
+            .bind(event.medium_id)
+            .bind(MediumTypeDb::from(event.medium_type) as MediumTypeDb)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to update media: {}", e),
+            })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            new_medium_id = %event.new_medium_id,
+            "MediumProjection: medium split"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<PerceptualHashComputedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &PerceptualHashComputedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        // BIGINT is signed, the hash is stored bit for bit
+        sqlx::query("UPDATE media SET perceptual_hash = $2 WHERE id = $1")
+            .bind(event.medium_id)
+            .bind(event.perceptual_hash.value() as i64)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to update perceptual_hash: {}", e),
+            })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            "MediumProjection: perceptual hash computed"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumTagsAddedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumTagsAddedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        let tags: Vec<&str> = event.tags.iter().map(|t| t.as_str()).collect();
+
+        sqlx::query(
+            "INSERT INTO media_tags (medium_id, tag_title) \
+             SELECT $1, UNNEST($2::VARCHAR[]) \
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(event.medium_id)
+        .bind(&tags)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to insert media_tags: {}", e),
+        })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            tags = tags.len(),
+            "MediumProjection: tags added"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumTagsRemovedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumTagsRemovedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        let tags: Vec<&str> = event.tags.iter().map(|t| t.as_str()).collect();
+
+        sqlx::query("DELETE FROM media_tags WHERE medium_id = $1 AND tag_title = ANY($2)")
+            .bind(event.medium_id)
+            .bind(&tags)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to delete media_tags: {}", e),
+            })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            tags = tags.len(),
+            "MediumProjection: tags removed"
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumCurationChangedEvent, i64, Transaction<'static, Postgres>>
+    for MediumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumCurationChangedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        let curation = &event.curation;
+
+        sqlx::query(
+            "UPDATE media \
+             SET favorite = $2, rating = $3, color_label = $4, rejected = $5, \
+                 curated = curated OR $6, updated_at = NOW() \
+             WHERE id = $1",
+        )
+        .bind(event.medium_id)
+        .bind(curation.favorite)
+        .bind(curation.rating as i16)
+        .bind(curation.color_label.map(ColorLabelDb::from))
+        .bind(curation.rejected)
+        .bind(!event.embedded)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update media curation: {}", e),
+        })?;
+
+        info!(
+            medium_id = %event.medium_id,
+            embedded = event.embedded,
+            "MediumProjection: curation changed"
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 use async_trait::async_trait;
-use domain::metadata::events::{
-    MetadataExtractedEvent, MetadataExtractionFailedEvent, MetadataExtractionStartedEvent,
+use domain::{
+    medium::events::{MediumMergedEvent, MediumPurgedEvent},
+    metadata::events::{
+        LocationResolvedEvent, MetadataExtractedEvent, MetadataExtractionFailedEvent,
+        MetadataExtractionStartedEvent, MetadataOverriddenEvent, MetadataSidecarAppliedEvent,
+    },
 };
 use event_sourcing::{
     error::{EventSourcingError, Result},
@@ -10,7 +14,9 @@ use sqlx::{Postgres, Transaction};
 use tracing::info;
 
 use super::{register_event, RegisterProjection};
-use crate::persistence::postgres::metadata::entity::OrientationDb;
+use crate::persistence::postgres::metadata::entity::{
+    ExposureModeDb, ExposureProgramDb, MeteringModeDb, OrientationDb, WhiteBalanceDb,
+};
 
 /// Projection that maintains the metadata read model table.
 pub struct MetadataProjection;
@@ -29,6 +35,11 @@ impl RegisterProjection for MetadataProjection {
         register_event::<MetadataExtractionStartedEvent, _>(bus, registry, Self::new())?;
         register_event::<MetadataExtractedEvent, _>(bus, registry, Self::new())?;
         register_event::<MetadataExtractionFailedEvent, _>(bus, registry, Self::new())?;
+        register_event::<LocationResolvedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MetadataOverriddenEvent, _>(bus, registry, Self::new())?;
+        register_event::<MetadataSidecarAppliedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumPurgedEvent, _>(bus, registry, Self::new())?;
+        register_event::<MediumMergedEvent, _>(bus, registry, Self::new())?;
         Ok(())
     }
 }
@@ -65,6 +76,8 @@ impl ProjectionHandler<MetadataExtractedEvent, i64, Transaction<'static, Postgre
         let m = &event.metadata;
         let camera = m.camera_info.as_ref();
         let loc = m.location.as_ref();
+        let video = m.video.as_ref();
+        let place = m.place.as_ref();
         let orientation_db = m.technical.orientation.as_ref().map(OrientationDb::from);
 
         sqlx::query(
@@ -75,7 +88,16 @@ impl ProjectionHandler<MetadataExtractedEvent, i64, Transaction<'static, Postgre
                 lens_make, lens_model, exposure_time, f_number, iso, focal_length, flash,
                 latitude, longitude, altitude, direction, horizontal_position_error,
                 width, height, orientation,
-                additional
+                video_duration, video_frame_rate, video_codec, audio_codec,
+                video_bitrate, video_rotation,
+                content_identifier, camera_serial_number, burst_id,
+                additional,
+                country_code, country, region, city,
+                overrides, sidecar,
+                extractor_version,
+                lens_serial_number, exposure_program, exposure_mode, metering_mode,
+                white_balance, exposure_bias, focal_length_35mm, software,
+                rating, keywords, caption, creator, copyright
              ) VALUES (
                 $1, $2, $3,
                 $4, $5, $6,
@@ -83,7 +105,16 @@ impl ProjectionHandler<MetadataExtractedEvent, i64, Transaction<'static, Postgre
                 $11, $12, $13, $14, $15, $16, $17,
                 $18, $19, $20, $21, $22,
                 $23, $24, $25,
-                $26
+                $26, $27, $28, $29,
+                $30, $31,
+                $32, $33, $34,
+                $35,
+                $36, $37, $38, $39,
+                $40, $41,
+                $42,
+                $43, $44, $45, $46,
+                $47, $48, $49, $50,
+                $51, $52, $53, $54, $55
              )
              ON CONFLICT (medium_id) DO UPDATE SET
                 extracted_at = EXCLUDED.extracted_at,
@@ -109,7 +140,36 @@ impl ProjectionHandler<MetadataExtractedEvent, i64, Transaction<'static, Postgre
                 width = EXCLUDED.width,
                 height = EXCLUDED.height,
                 orientation = EXCLUDED.orientation,
-                additional = EXCLUDED.additional",
+                video_duration = EXCLUDED.video_duration,
+                video_frame_rate = EXCLUDED.video_frame_rate,
+                video_codec = EXCLUDED.video_codec,
+                audio_codec = EXCLUDED.audio_codec,
+                video_bitrate = EXCLUDED.video_bitrate,
+                video_rotation = EXCLUDED.video_rotation,
+                content_identifier = EXCLUDED.content_identifier,
+                camera_serial_number = EXCLUDED.camera_serial_number,
+                burst_id = EXCLUDED.burst_id,
+                additional = EXCLUDED.additional,
+                country_code = EXCLUDED.country_code,
+                country = EXCLUDED.country,
+                region = EXCLUDED.region,
+                city = EXCLUDED.city,
+                overrides = EXCLUDED.overrides,
+                sidecar = EXCLUDED.sidecar,
+                extractor_version = EXCLUDED.extractor_version,
+                lens_serial_number = EXCLUDED.lens_serial_number,
+                exposure_program = EXCLUDED.exposure_program,
+                exposure_mode = EXCLUDED.exposure_mode,
+                metering_mode = EXCLUDED.metering_mode,
+                white_balance = EXCLUDED.white_balance,
+                exposure_bias = EXCLUDED.exposure_bias,
+                focal_length_35mm = EXCLUDED.focal_length_35mm,
+                software = EXCLUDED.software,
+                rating = EXCLUDED.rating,
+                keywords = EXCLUDED.keywords,
+                caption = EXCLUDED.caption,
+                creator = EXCLUDED.creator,
+                copyright = EXCLUDED.copyright",
         )
         .bind(m.id)
         .bind(m.medium_id)
@@ -144,8 +204,43 @@ impl ProjectionHandler<MetadataExtractedEvent, i64, Transaction<'static, Postgre
         .bind(m.technical.width.map(|w| w as i32))
         .bind(m.technical.height.map(|h| h as i32))
         .bind(orientation_db)
+        // Video
+        .bind(video.and_then(|v| v.duration))
+        .bind(video.and_then(|v| v.frame_rate))
+        .bind(video.and_then(|v| v.video_codec.as_deref()))
+        .bind(video.and_then(|v| v.audio_codec.as_deref()))
+        .bind(video.and_then(|v| v.bitrate.map(|b| b as i64)))
+        .bind(video.and_then(|v| v.rotation.map(|r| r as i16)))
+        .bind(m.content_identifier.as_deref())
+        .bind(m.camera_serial_number.as_deref())
+        .bind(m.burst_id.as_deref())
         // Additional
         .bind(sqlx::types::Json(&m.additional))
+        // Place, cleared until the location is resolved again
+        .bind(place.map(|p| p.country_code.as_str()))
+        .bind(place.map(|p| p.country.as_str()))
+        .bind(place.and_then(|p| p.region.as_deref()))
+        .bind(place.and_then(|p| p.city.as_deref()))
+        // Overrides, kept across extractions
+        .bind(sqlx::types::Json(&m.overrides))
+        // Sidecar, kept across extractions
+        .bind(m.sidecar.as_ref().map(sqlx::types::Json))
+        .bind(m.extractor_version as i32)
+        // Camera info, continued
+        .bind(camera.and_then(|c| c.lens_serial_number.as_deref()))
+        .bind(camera.and_then(|c| c.exposure_program.map(ExposureProgramDb::from)))
+        .bind(camera.and_then(|c| c.exposure_mode.map(ExposureModeDb::from)))
+        .bind(camera.and_then(|c| c.metering_mode.map(MeteringModeDb::from)))
+        .bind(camera.and_then(|c| c.white_balance.map(WhiteBalanceDb::from)))
+        .bind(camera.and_then(|c| c.exposure_bias))
+        .bind(camera.and_then(|c| c.focal_length_35mm))
+        .bind(m.technical.software.as_deref())
+        // Descriptive
+        .bind(m.descriptive.rating.map(|r| r as i16))
+        .bind(&m.descriptive.keywords)
+        .bind(m.descriptive.caption.as_deref())
+        .bind(m.descriptive.creator.as_deref())
+        .bind(m.descriptive.copyright.as_deref())
         .execute(&mut **tx)
         .await
         .map_err(|e| EventSourcingError::Projection {
@@ -173,3 +268,155 @@ impl ProjectionHandler<MetadataExtractionFailedEvent, i64, Transaction<'static,
         Ok(())
     }
 }
+
+#[async_trait]
+impl ProjectionHandler<LocationResolvedEvent, i64, Transaction<'static, Postgres>>
+    for MetadataProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &LocationResolvedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query(
+            "UPDATE metadata SET country_code = $2, country = $3, region = $4, city = $5 \
+             WHERE medium_id = $1",
+        )
+        .bind(event.medium_id)
+        .bind(&event.place.country_code)
+        .bind(&event.place.country)
+        .bind(&event.place.region)
+        .bind(&event.place.city)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update place: {}", e),
+        })?;
+
+        info!(medium_id = %event.medium_id, "MetadataProjection: location resolved");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MetadataOverriddenEvent, i64, Transaction<'static, Postgres>>
+    for MetadataProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MetadataOverriddenEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        // The place no longer matches an overridden location until it is resolved again
+        sqlx::query(
+            "UPDATE metadata SET overrides = $2, \
+             country_code = CASE WHEN $3 THEN NULL ELSE country_code END, \
+             country = CASE WHEN $3 THEN NULL ELSE country END, \
+             region = CASE WHEN $3 THEN NULL ELSE region END, \
+             city = CASE WHEN $3 THEN NULL ELSE city END \
+             WHERE medium_id = $1",
+        )
+        .bind(event.medium_id)
+        .bind(sqlx::types::Json(&event.metadata.overrides))
+        .bind(event.changes.location.is_some())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update overrides: {}", e),
+        })?;
+
+        info!(medium_id = %event.medium_id, "MetadataProjection: metadata overridden");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MetadataSidecarAppliedEvent, i64, Transaction<'static, Postgres>>
+    for MetadataProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MetadataSidecarAppliedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        // The place no longer matches a location taken from the sidecar until it is resolved again
+        sqlx::query(
+            "UPDATE metadata SET sidecar = $2, \
+             country_code = CASE WHEN $3 THEN NULL ELSE country_code END, \
+             country = CASE WHEN $3 THEN NULL ELSE country END, \
+             region = CASE WHEN $3 THEN NULL ELSE region END, \
+             city = CASE WHEN $3 THEN NULL ELSE city END \
+             WHERE medium_id = $1",
+        )
+        .bind(event.medium_id)
+        .bind(event.metadata.sidecar.as_ref().map(sqlx::types::Json))
+        .bind(event.location.is_some())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update sidecar: {}", e),
+        })?;
+
+        info!(medium_id = %event.medium_id, "MetadataProjection: sidecar applied");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumPurgedEvent, i64, Transaction<'static, Postgres>>
+    for MetadataProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumPurgedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("DELETE FROM metadata WHERE medium_id = $1")
+            .bind(event.medium_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to delete metadata: {}", e),
+            })?;
+
+        info!(medium_id = %event.medium_id, "MetadataProjection: metadata removed for purged medium");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<MediumMergedEvent, i64, Transaction<'static, Postgres>>
+    for MetadataProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &MediumMergedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("DELETE FROM metadata WHERE medium_id = $1")
+            .bind(event.medium_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to delete metadata: {}", e),
+            })?;
+
+        info!(medium_id = %event.medium_id, "MetadataProjection: metadata removed for merged medium");
+        Ok(())
+    }
+}
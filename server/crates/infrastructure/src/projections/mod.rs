@@ -1,8 +1,11 @@
+mod album_projection;
 mod medium_projection;
 mod metadata_projection;
+mod smart_album_projection;
 mod task_projection;
 mod user_projection;
 
+pub use album_projection::AlbumProjection;
 use async_trait::async_trait;
 use domain::event::DomainEvent;
 use event_sourcing::{
@@ -11,6 +14,7 @@ use event_sourcing::{
 pub use medium_projection::MediumProjection;
 pub use metadata_projection::MetadataProjection;
 use serde::{de::DeserializeOwned, Serialize};
+pub use smart_album_projection::SmartAlbumProjection;
 use sqlx::{Postgres, Transaction};
 pub use task_projection::TaskProjection;
 pub use user_projection::UserProjection;
@@ -0,0 +1,124 @@
+use async_trait::async_trait;
+use domain::smart_album::events::{
+    SmartAlbumCreatedEvent, SmartAlbumDeletedEvent, SmartAlbumUpdatedEvent,
+};
+use event_sourcing::{
+    error::{EventSourcingError, Result},
+    projection::handler::ProjectionHandler,
+};
+use sqlx::{Postgres, Transaction};
+use tracing::info;
+
+use super::{register_event, RegisterProjection};
+
+/// Projection that maintains the smart_albums read model table.
+pub struct SmartAlbumProjection;
+
+impl SmartAlbumProjection {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl RegisterProjection for SmartAlbumProjection {
+    fn register(
+        bus: &super::PgProjectionBus,
+        registry: &mut super::EventTypeRegistry,
+    ) -> Result<()> {
+        register_event::<SmartAlbumCreatedEvent, _>(bus, registry, Self::new())?;
+        register_event::<SmartAlbumUpdatedEvent, _>(bus, registry, Self::new())?;
+        register_event::<SmartAlbumDeletedEvent, _>(bus, registry, Self::new())?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<SmartAlbumCreatedEvent, i64, Transaction<'static, Postgres>>
+    for SmartAlbumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &SmartAlbumCreatedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query(
+            "INSERT INTO smart_albums (id, owner_id, title, description, filter, created_at, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $6) \
+             ON CONFLICT (id) DO NOTHING",
+        )
+        .bind(event.smart_album_id)
+        .bind(event.owner_id)
+        .bind(&event.title)
+        .bind(&event.description)
+        .bind(sqlx::types::Json(&event.filter))
+        .bind(event.created_at.naive_utc())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to insert smart album: {}", e),
+        })?;
+
+        info!(smart_album_id = %event.smart_album_id, "SmartAlbumProjection: smart album created");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<SmartAlbumUpdatedEvent, i64, Transaction<'static, Postgres>>
+    for SmartAlbumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &SmartAlbumUpdatedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query(
+            "UPDATE smart_albums SET title = $2, description = $3, filter = $4, updated_at = NOW() \
+             WHERE id = $1",
+        )
+        .bind(event.smart_album_id)
+        .bind(&event.title)
+        .bind(&event.description)
+        .bind(sqlx::types::Json(&event.filter))
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| EventSourcingError::Projection {
+            message: format!("Failed to update smart album: {}", e),
+        })?;
+
+        info!(smart_album_id = %event.smart_album_id, "SmartAlbumProjection: smart album updated");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProjectionHandler<SmartAlbumDeletedEvent, i64, Transaction<'static, Postgres>>
+    for SmartAlbumProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &SmartAlbumDeletedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE smart_albums SET deleted_at = $2, updated_at = $2 WHERE id = $1")
+            .bind(event.smart_album_id)
+            .bind(event.deleted_at.naive_utc())
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to delete smart album: {}", e),
+            })?;
+
+        info!(smart_album_id = %event.smart_album_id, "SmartAlbumProjection: smart album deleted");
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use domain::user::events::{
-    QuotaCommittedEvent, QuotaReleasedEvent, QuotaReservedEvent, UserCreatedEvent, UserUpdatedEvent,
+    QuotaCommittedEvent, QuotaReleasedEvent, QuotaReservedEvent, UserCreatedEvent,
+    UserSettingsUpdatedEvent, UserUpdatedEvent,
 };
 use event_sourcing::{
     error::{EventSourcingError, Result},
@@ -27,6 +28,7 @@ impl RegisterProjection for UserProjection {
     ) -> Result<()> {
         register_event::<UserCreatedEvent, _>(bus, registry, Self::new())?;
         register_event::<UserUpdatedEvent, _>(bus, registry, Self::new())?;
+        register_event::<UserSettingsUpdatedEvent, _>(bus, registry, Self::new())?;
         register_event::<QuotaReservedEvent, _>(bus, registry, Self::new())?;
         register_event::<QuotaCommittedEvent, _>(bus, registry, Self::new())?;
         register_event::<QuotaReleasedEvent, _>(bus, registry, Self::new())?;
@@ -45,13 +47,14 @@ impl ProjectionHandler<UserCreatedEvent, i64, Transaction<'static, Postgres>> fo
         tx: &mut Transaction<'static, Postgres>,
     ) -> Result<()> {
         sqlx::query(
-            "INSERT INTO users (id, username, email, quota, quota_used, created_at, updated_at) \
-             VALUES ($1, $2, $3, $4, 0, NOW(), NOW()) \
+            "INSERT INTO users (id, username, email, timezone, quota, quota_used, created_at, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, 0, NOW(), NOW()) \
              ON CONFLICT (id) DO NOTHING",
         )
         .bind(event.user_id)
         .bind(&event.username)
         .bind(&event.email)
+        .bind(&event.timezone)
         .bind(event.quota.as_u64() as i64)
         .execute(&mut **tx)
         .await
@@ -79,6 +82,7 @@ impl ProjectionHandler<UserUpdatedEvent, i64, Transaction<'static, Postgres>> fo
              username = COALESCE($2, username), \
              email = COALESCE($3, email), \
              quota = COALESCE($4, quota), \
+             timezone = COALESCE($5, timezone), \
              updated_at = NOW() \
              WHERE id = $1",
         )
@@ -86,6 +90,7 @@ impl ProjectionHandler<UserUpdatedEvent, i64, Transaction<'static, Postgres>> fo
         .bind(&event.new_username)
         .bind(&event.new_email)
         .bind(event.new_quota.map(|q| q.as_u64() as i64))
+        .bind(&event.new_timezone)
         .execute(&mut **tx)
         .await
         .map_err(|e| EventSourcingError::Projection {
@@ -97,6 +102,32 @@ impl ProjectionHandler<UserUpdatedEvent, i64, Transaction<'static, Postgres>> fo
     }
 }
 
+#[async_trait]
+impl ProjectionHandler<UserSettingsUpdatedEvent, i64, Transaction<'static, Postgres>>
+    for UserProjection
+{
+    type Error = event_sourcing::error::EventSourcingError;
+
+    async fn handle(
+        &self,
+        event: &UserSettingsUpdatedEvent,
+        _sequence: i64,
+        tx: &mut Transaction<'static, Postgres>,
+    ) -> Result<()> {
+        sqlx::query("UPDATE users SET xmp_write_back = $2, updated_at = NOW() WHERE id = $1")
+            .bind(event.user_id)
+            .bind(event.settings.xmp_write_back)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| EventSourcingError::Projection {
+                message: format!("Failed to update user settings: {}", e),
+            })?;
+
+        info!(user_id = %event.user_id, "UserProjection: settings updated");
+        Ok(())
+    }
+}
+
 #[async_trait]
 impl ProjectionHandler<QuotaReservedEvent, i64, Transaction<'static, Postgres>> for UserProjection {
     type Error = event_sourcing::error::EventSourcingError;
@@ -2,6 +2,8 @@ use std::sync::Arc;
 
 use application::medium::commands::{
     CleanupExpiredTempStorageCommand, CleanupExpiredTempStorageHandler,
+    ComputePerceptualHashesCommand, ComputePerceptualHashesHandler, PurgeExpiredTrashCommand,
+    PurgeExpiredTrashHandler,
 };
 use chrono::{Duration, Utc};
 use tokio::time;
@@ -39,3 +41,63 @@ pub fn spawn_cleanup_task(
         }
     })
 }
+
+pub fn spawn_trash_purge_task(
+    handler: Arc<PurgeExpiredTrashHandler>,
+    trash_retention_seconds: u64,
+    purge_interval_seconds: u64,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let interval_duration = std::time::Duration::from_secs(purge_interval_seconds);
+        let mut interval = time::interval(interval_duration);
+
+        // Skip the first immediate tick
+        interval.tick().await;
+
+        info!(
+            interval_seconds = purge_interval_seconds,
+            retention_seconds = trash_retention_seconds,
+            "Trash purge task started"
+        );
+
+        loop {
+            interval.tick().await;
+
+            let cutoff = Utc::now() - Duration::seconds(trash_retention_seconds as i64);
+
+            if let Err(e) = handler.handle(PurgeExpiredTrashCommand { cutoff }).await {
+                error!(error = %e, "Trash purge sweep encountered an error");
+            }
+        }
+    })
+}
+
+pub fn spawn_perceptual_hash_task(
+    handler: Arc<ComputePerceptualHashesHandler>,
+    batch_size: i64,
+    hash_interval_seconds: u64,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let interval_duration = std::time::Duration::from_secs(hash_interval_seconds);
+        let mut interval = time::interval(interval_duration);
+
+        // Skip the first immediate tick
+        interval.tick().await;
+
+        info!(
+            interval_seconds = hash_interval_seconds,
+            batch_size, "Perceptual hash task started"
+        );
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = handler
+                .handle(ComputePerceptualHashesCommand { batch_size })
+                .await
+            {
+                error!(error = %e, "Perceptual hash sweep encountered an error");
+            }
+        }
+    })
+}
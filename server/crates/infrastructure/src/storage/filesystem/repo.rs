@@ -1,10 +1,10 @@
 use std::{path::PathBuf, sync::Arc};
 
-use application::medium::ports::FileStorage;
+use application::medium::ports::{FileStorage, FileStream};
 use async_trait::async_trait;
 use domain::{
-    error::{DomainResult, FileNotExistsSnafu},
-    medium::storage::{FileLocation, FileMetadata, StorageTier},
+    error::{DomainResult, FileExistsSnafu, FileNotExistsSnafu},
+    medium::storage::{FileLocation, FileMetadata, FileStat, StorageTier},
     shared::crypto::hash,
 };
 use snafu::ensure;
@@ -139,6 +139,13 @@ impl FileStorage for FilesystemStorageAdapter {
             })?;
         }
 
+        ensure!(
+            !fs::try_exists(&dest_path).await?,
+            FileExistsSnafu {
+                path: dest_path.clone()
+            }
+        );
+
         let bytes_copied = fs::copy(&src_path, &dest_path).await.map_err(|e| {
             error!(src = ?src_path, dest = ?dest_path, error = ?e, "Failed to copy file");
             e
@@ -175,6 +182,13 @@ impl FileStorage for FilesystemStorageAdapter {
             })?;
         }
 
+        ensure!(
+            !fs::try_exists(&dest_path).await?,
+            FileExistsSnafu {
+                path: dest_path.clone()
+            }
+        );
+
         fs::rename(&src_path, &dest_path).await.map_err(|e| {
             error!(src = ?src_path, dest = ?dest_path, error = ?e, "Failed to move file");
             e
@@ -219,7 +233,7 @@ impl FileStorage for FilesystemStorageAdapter {
     async fn retrieve_file_stream(
         &self,
         location: &FileLocation,
-    ) -> DomainResult<Box<dyn AsyncRead + Unpin>> {
+    ) -> DomainResult<Box<dyn FileStream>> {
         debug!("Opening file stream for retrieval");
 
         let path = self.get_full_path(location);
@@ -233,6 +247,10 @@ impl FileStorage for FilesystemStorageAdapter {
         Ok(Box::new(file))
     }
 
+    async fn file_exists(&self, location: &FileLocation) -> DomainResult<bool> {
+        Ok(fs::try_exists(self.get_full_path(location)).await?)
+    }
+
     #[tracing::instrument(skip(self), fields(
         storage_tier = ?location.storage_tier,
         path = ?location.relative_path
@@ -309,4 +327,75 @@ impl FileStorage for FilesystemStorageAdapter {
             checksum,
         })
     }
+
+    #[tracing::instrument(skip(self), fields(
+        storage_tier = ?location.storage_tier,
+        path = ?location.relative_path
+    ))]
+    async fn stat_file(&self, location: &FileLocation) -> DomainResult<FileStat> {
+        let path = self.get_full_path(location);
+
+        let metadata = fs::metadata(&path).await.map_err(|e| {
+            debug!(path = ?path, error = ?e, "Failed to stat file");
+            e
+        })?;
+
+        Ok(FileStat {
+            size_bytes: metadata.len(),
+            modified_at: metadata.modified()?.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use confique::Config;
+    use domain::error::DomainError;
+    use serde_json::json;
+
+    use super::*;
+
+    fn make_adapter(dir: &Path) -> FilesystemStorageAdapter {
+        let partial: <GlobalConfig as Config>::Partial = serde_json::from_value(json!({
+            "server": {
+                "client_id": "photonic",
+                "jwks_url": "http://localhost/jwks",
+                "token_url": "http://localhost/token",
+                "authorize_url": "http://localhost/authorize",
+            },
+            "database": { "url": "postgres://localhost/photonic" },
+            "storage": {
+                "base_path": dir.join("storage"),
+                "cache_path": dir.join("cache"),
+                "tmp_path": dir.join("tmp"),
+            },
+        }))
+        .unwrap();
+
+        let config = GlobalConfig::builder().preloaded(partial).load().unwrap();
+        FilesystemStorageAdapter::new(Arc::new(config))
+    }
+
+    #[tokio::test]
+    async fn test_move_and_copy_do_not_replace_an_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = make_adapter(dir.path());
+        let first = FileLocation::permanent("2024/0620/unknown_unknown/IMG_0001.JPG".into());
+        let second = FileLocation::permanent("2024/0621/unknown_unknown/IMG_0001.JPG".into());
+        storage.store_file(&first, b"first".to_vec()).await.unwrap();
+        storage
+            .store_file(&second, b"second".to_vec())
+            .await
+            .unwrap();
+
+        let moved = storage.move_file(&second, &first).await;
+        let copied = storage.copy_file(&second, &first).await;
+
+        assert!(matches!(moved, Err(DomainError::FileExists { .. })));
+        assert!(matches!(copied, Err(DomainError::FileExists { .. })));
+        assert_eq!(storage.retrieve_file(&first).await.unwrap(), b"first");
+        assert_eq!(storage.retrieve_file(&second).await.unwrap(), b"second");
+    }
 }
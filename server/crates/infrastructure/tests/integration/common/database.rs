@@ -24,7 +24,7 @@ pub async fn get_test_pool() -> PgPool {
 /// Clean all data from test database
 pub async fn cleanup_test_db(pool: &PgPool) {
     sqlx::query(
-        "TRUNCATE users, albums, media, medium_items, locations, media_tags, \
+        "TRUNCATE users, albums, smart_albums, media, medium_items, locations, media_tags, \
          tasks, metadata, event_streams, events, snapshots, projection_checkpoints CASCADE",
     )
     .execute(pool)
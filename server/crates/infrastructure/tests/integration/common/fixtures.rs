@@ -1,7 +1,7 @@
 use std::path::PathBuf;
 
 use byte_unit::Byte;
-use domain::user::{QuotaState, User};
+use domain::user::{QuotaState, User, UserSettings};
 use fake::{Fake, Faker};
 use rstest::*;
 use uuid::Uuid;
@@ -22,6 +22,8 @@ pub fn user(#[default(10_000_000_000)] quota_limit: u64) -> User {
         version: 1,
         username: Faker.fake(),
         email: Faker.fake(),
+        timezone: None,
+        settings: UserSettings::default(),
         quota: QuotaState::new_unchecked(Byte::from_u64(0), Byte::from_u64(quota_limit)),
     }
 }
@@ -0,0 +1,147 @@
+use std::error::Error;
+
+use domain::user::User;
+use photonic_client::types::MediumItemTypeDto;
+use reqwest::StatusCode;
+use rstest::*;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::integration::{
+    common::{
+        fixtures::{app, image, user, ImageFixture},
+        polling::{poll_until, PollingConfig},
+    },
+    test_app::{medium::CreateMediumRequest, TestApp},
+};
+
+// ============================================================================
+// ADD MEDIUM ITEM TESTS - POST /api/v1/medium/{id}/item/{format}
+// ============================================================================
+// This file tests adding items to an existing medium, focusing on:
+// - Adding an edit next to the original
+// - Files that do not fit the item format
+// - Unknown media
+// ============================================================================
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_add_edit_to_medium(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+    #[from(image)]
+    #[with("IMG_0001.JPG")]
+    edit: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+
+    // Act
+    let response = app
+        .add_medium_item(&user, &medium_id, MediumItemTypeDto::Edit, edit.into())
+        .await?;
+
+    // Assert: Poll until the projection has added the item to the medium
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let item_id = response.into_inner();
+    let medium = poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            async move {
+                let medium = client.get_medium(&medium_id).await.ok()?.into_inner();
+                medium
+                    .items
+                    .iter()
+                    .any(|item| item.id == item_id)
+                    .then_some(medium)
+            }
+        },
+        PollingConfig::quick("edit to be added to the medium"),
+    )
+    .await
+    .expect("Edit should be added to the medium");
+
+    let item = medium
+        .items
+        .iter()
+        .find(|item| item.id == item_id)
+        .expect("Edit not found");
+    assert!(matches!(item.medium_item_type, MediumItemTypeDto::Edit));
+    assert!(
+        !item.is_primary,
+        "The original should stay the primary item"
+    );
+    assert_eq!(medium.items.len(), 2, "Medium should have two items");
+
+    app.cleanup().await;
+    Ok(())
+}
+
+// === Error Tests ===
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_add_item_that_does_not_fit_the_format_fails(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange: A text file is no edit of a photo
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+    let mut notes: CreateMediumRequest = ImageFixture {
+        filename: "notes.txt",
+        data: b"Sunset at the beach".to_vec(),
+    }
+    .into();
+    notes.content_type = "text/plain".to_string();
+
+    // Act
+    let result = app
+        .add_medium_item(&user, &medium_id, MediumItemTypeDto::Edit, notes)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::BAD_REQUEST),
+        "Expected 400 BAD REQUEST for a file that is no image"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_add_item_to_unknown_medium_fails(
+    #[future] app: TestApp,
+    user: User,
+    #[with("IMG_0001.JPG")] image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Act
+    let result = app
+        .add_medium_item(
+            &user,
+            &Uuid::new_v4(),
+            MediumItemTypeDto::Edit,
+            image.into(),
+        )
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::NOT_FOUND),
+        "Expected 404 NOT FOUND for an unknown medium"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use std::error::Error;
+
+use domain::user::User;
+use photonic_client::types::{DuplicateKindDto, ResolveDuplicatesInput};
+use reqwest::StatusCode;
+use rstest::*;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::integration::{
+    common::{
+        fixtures::{app, image, user, ImageFixture},
+        polling::{poll_until, PollingConfig},
+    },
+    test_app::TestApp,
+};
+
+// ============================================================================
+// DUPLICATE TESTS - POST /api/v1/medium, GET /api/v1/duplicates,
+//                   POST /api/v1/duplicates/resolve
+// ============================================================================
+// This file tests finding and resolving duplicates, focusing on:
+// - Rejecting uploads of files already in the library
+// - Clustering exact duplicates
+// - Moving the duplicates of the kept medium to the trash
+// - Resolving media that are no duplicates
+// ============================================================================
+
+/// Two media of the same file. Uploads of a file already in the library are
+/// rejected, so the first one is in the trash while the second is uploaded.
+async fn exact_duplicates(
+    app: &TestApp,
+    user: &User,
+    image: ImageFixture,
+) -> Result<(Uuid, Uuid), Box<dyn Error>> {
+    let original_id = app
+        .create_medium(user, image.clone().into())
+        .await?
+        .into_inner();
+    app.client_with_user(user)
+        .delete_medium(&original_id)
+        .await?;
+    app.wait_for_trash(user, &original_id).await?;
+
+    let copy_id = app.create_medium(user, image.into()).await?.into_inner();
+    app.client_with_user(user)
+        .restore_medium(&original_id)
+        .await?;
+
+    Ok((original_id, copy_id))
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_upload_of_a_file_already_in_the_library_is_rejected(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let medium_id = app
+        .create_medium(&user, image.clone().into())
+        .await?
+        .into_inner();
+    poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            async move { client.get_medium(&medium_id).await.ok() }
+        },
+        PollingConfig::quick("medium to be stored"),
+    )
+    .await?;
+
+    // Act
+    let result = app.create_medium(&user, image.into()).await;
+
+    // Assert: The conflict names the medium already in the library
+    let Err(photonic_client::Error::UnexpectedResponse(response)) = result else {
+        return Err("Expected UnexpectedResponse error".into());
+    };
+    assert_eq!(
+        response.status(),
+        StatusCode::CONFLICT,
+        "Expected 409 CONFLICT for a duplicate upload"
+    );
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["medium_id"], medium_id.to_string());
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_resolve_duplicates_moves_the_copies_to_the_trash(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange: Poll until both media are clustered
+    let (original_id, copy_id) = exact_duplicates(&app, &user, image).await?;
+    let cluster = poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            async move {
+                let clusters = client.get_duplicates().await.ok()?.into_inner();
+                clusters.into_iter().find(|cluster| {
+                    cluster.medium_ids.contains(&original_id)
+                        && cluster.medium_ids.contains(&copy_id)
+                })
+            }
+        },
+        PollingConfig::quick("duplicates to be clustered"),
+    )
+    .await
+    .expect("Duplicates should be clustered");
+    assert!(matches!(cluster.kind, DuplicateKindDto::Exact));
+
+    // Act
+    let response = app
+        .client_with_user(&user)
+        .resolve_duplicates(&ResolveDuplicatesInput {
+            keep_medium_id: original_id,
+            medium_ids: vec![copy_id],
+        })
+        .await?;
+
+    // Assert: The copy is in the trash and the cluster is gone
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    app.wait_for_trash(&user, &copy_id)
+        .await
+        .expect("Copy should be in the trash");
+    let clusters = app.client_with_user(&user).get_duplicates().await?;
+    assert!(clusters.is_empty(), "No duplicates should be left");
+    let original = app.client_with_user(&user).get_medium(&original_id).await?;
+    assert!(
+        original.deleted_at.is_none(),
+        "Kept medium should not be in the trash"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+// === Error Tests ===
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_resolve_media_that_are_no_duplicates_fails(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+    #[from(image)]
+    #[with("IMG_0001.JPG")]
+    other: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let keep_id = app.create_medium(&user, image.into()).await?.into_inner();
+    let other_id = app.create_medium(&user, other.into()).await?.into_inner();
+
+    // Act
+    let result = app
+        .client_with_user(&user)
+        .resolve_duplicates(&ResolveDuplicatesInput {
+            keep_medium_id: keep_id,
+            medium_ids: vec![other_id],
+        })
+        .await;
+
+    // Assert: The other medium stays in the library
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::BAD_REQUEST),
+        "Expected 400 BAD REQUEST for media that are no duplicates"
+    );
+    let trash = app.client_with_user(&user).get_trash().await?;
+    assert!(trash.is_empty(), "No medium should be moved to the trash");
+
+    app.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use std::{error::Error, time::Duration};
+
+use chrono::{DateTime, SecondsFormat, TimeDelta, Utc};
+use domain::user::User;
+use reqwest::StatusCode;
+use rstest::*;
+use serial_test::serial;
+
+use crate::integration::{
+    common::fixtures::{app, image, user, ImageFixture},
+    test_app::TestApp,
+};
+
+// ============================================================================
+// GEOTAG TESTS - POST /api/v1/medium/geotag
+// ============================================================================
+// This file tests geotagging media from a recorded track, focusing on:
+// - Proposing the locations of media taken along a GPX track
+// - Rejecting tracks that are neither GPX nor KML
+// - Rejecting tracks that cannot be parsed
+// ============================================================================
+
+const GPX: &str = "application/gpx+xml";
+
+/// A GPX track walking through Lisbon from ten minutes before to ten minutes
+/// after the given time
+fn gpx_track_around(time: DateTime<Utc>) -> Vec<u8> {
+    let point = |latitude: f64, longitude: f64, time: DateTime<Utc>| {
+        format!(
+            r#"<trkpt lat="{latitude}" lon="{longitude}"><time>{}</time></trkpt>"#,
+            time.to_rfc3339_opts(SecondsFormat::Secs, true)
+        )
+    };
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<gpx version="1.1" creator="photonic" xmlns="http://www.topografix.com/GPX/1/1">
+  <trk><trkseg>{}{}</trkseg></trk>
+</gpx>"#,
+        point(38.70, -9.14, time - TimeDelta::minutes(10)),
+        point(38.72, -9.12, time + TimeDelta::minutes(10)),
+    )
+    .into_bytes()
+}
+
+#[rstest]
+#[timeout(Duration::from_secs(30))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+async fn test_geotag_dry_run_proposes_locations_along_the_track(
+    #[future(awt)] app: TestApp,
+    user: User,
+    #[with("IMG_0001.JPG")] image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange: A photo without GPS coordinates
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+    let taken_at = app
+        .wait_for_medium_enrichment(&user, &medium_id)
+        .await?
+        .taken_at
+        .expect("Medium should have a capture date");
+
+    // Act
+    let response = app
+        .geotag_from_track(&user, GPX, gpx_track_around(taken_at), true)
+        .await?;
+
+    // Assert: The photo was taken halfway along the track
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.dry_run);
+    let assignment = response
+        .assignments
+        .iter()
+        .find(|assignment| assignment.medium_id == medium_id)
+        .expect("A location should be proposed for the medium");
+    assert!((assignment.location.latitude - 38.71).abs() < 1e-3);
+    assert!((assignment.location.longitude + 9.13).abs() < 1e-3);
+
+    let metadata = app.wait_for_metadata(&user, &medium_id).await?;
+    assert!(
+        metadata.location.is_none(),
+        "A dry run should not change the medium"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+// === Error Tests ===
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_geotag_from_unsupported_content_type_fails(
+    #[future] app: TestApp,
+    user: User,
+) -> Result<(), Box<dyn Error>> {
+    // Act
+    let result = app
+        .geotag_from_track(
+            &user,
+            "application/json",
+            gpx_track_around(Utc::now()),
+            true,
+        )
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::UNSUPPORTED_MEDIA_TYPE),
+        "Expected 415 UNSUPPORTED MEDIA TYPE for a track that is neither GPX nor KML"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_geotag_from_invalid_track_fails(
+    #[future] app: TestApp,
+    user: User,
+) -> Result<(), Box<dyn Error>> {
+    // Act
+    let result = app
+        .geotag_from_track(&user, GPX, b"<gpx><trk><trkseg>".to_vec(), true)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::BAD_REQUEST),
+        "Expected 400 BAD REQUEST for a track that cannot be parsed"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
@@ -38,7 +38,10 @@ async fn test_list_media_when_empty(
     // Act: List media when none exist
     let response = app
         .client_with_user(&user)
-        .get_all_media(None, None, None, None, None, None, None, None, None)
+        .get_all_media(
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None,
+        )
         .await?;
 
     // Assert: Should return empty list
@@ -70,7 +73,10 @@ async fn test_list_media_with_single_item(
             let client = app.client_with_user(&user);
             async move {
                 let response = client
-                    .get_all_media(None, None, None, None, None, None, None, None, None)
+                    .get_all_media(
+                        None, None, None, None, None, None, None, None, None, None, None, None,
+                        None, None, None,
+                    )
                     .await
                     .ok()?;
                 if response.len() == 1 {
@@ -120,7 +126,10 @@ async fn test_list_media_with_multiple_items(
             let client = app.client_with_user(&user);
             async move {
                 let response = client
-                    .get_all_media(None, None, None, None, None, None, None, None, None)
+                    .get_all_media(
+                        None, None, None, None, None, None, None, None, None, None, None, None,
+                        None, None, None,
+                    )
                     .await
                     .ok()?;
                 if response.len() == 5 {
@@ -176,7 +185,10 @@ async fn test_list_media_with_different_file_types(
     // Act: List all media
     let response = app
         .client_with_user(&user)
-        .get_all_media(None, None, None, None, None, None, None, None, None)
+        .get_all_media(
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None,
+        )
         .await?;
 
     // Assert: Should return both different file types
@@ -214,7 +226,10 @@ async fn test_list_media_only_shows_own_media(
             let client = app.client_with_user(&user1);
             async move {
                 let response = client
-                    .get_all_media(None, None, None, None, None, None, None, None, None)
+                    .get_all_media(
+                        None, None, None, None, None, None, None, None, None, None, None, None,
+                        None, None, None,
+                    )
                     .await
                     .ok()?;
                 if response.len() == 1 {
@@ -0,0 +1,187 @@
+use std::{error::Error, time::Duration};
+
+use domain::user::User;
+use photonic_client::types::MediumTypeDto;
+use reqwest::StatusCode;
+use rstest::*;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::integration::{
+    common::{
+        fixtures::{app, image, user, ImageFixture},
+        polling::{poll_until, PollingConfig},
+    },
+    test_app::TestApp,
+};
+
+// ============================================================================
+// LIVE PHOTO PAIRING TESTS - POST /api/v1/medium
+// ============================================================================
+// This file tests pairing the still and the video of a Live Photo, focusing on:
+// - Merging a video into the still sharing its content identifier
+// - Keeping a video with another content identifier on its own
+// ============================================================================
+
+/// A QuickTime movie without tracks that only carries the content identifier
+/// Apple writes into both files of a Live Photo
+fn live_photo_video(content_identifier: &str) -> Vec<u8> {
+    fn atom(kind: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut atom = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        atom.extend_from_slice(kind);
+        atom.extend_from_slice(payload);
+        atom
+    }
+
+    let mut movie_header = vec![0; 100];
+    movie_header[12..16].copy_from_slice(&600u32.to_be_bytes()); // time scale
+    movie_header[20..24].copy_from_slice(&0x0001_0000u32.to_be_bytes()); // rate
+    movie_header[24..26].copy_from_slice(&0x0100u16.to_be_bytes()); // volume
+    movie_header[36..40].copy_from_slice(&0x0001_0000u32.to_be_bytes()); // identity matrix
+    movie_header[52..56].copy_from_slice(&0x0001_0000u32.to_be_bytes());
+    movie_header[68..72].copy_from_slice(&0x4000_0000u32.to_be_bytes());
+    movie_header[96..100].copy_from_slice(&1u32.to_be_bytes()); // next track id
+
+    let key = b"com.apple.quicktime.content.identifier";
+    let mut handler = vec![0; 8];
+    handler.extend_from_slice(b"mdta");
+    handler.extend_from_slice(&[0; 13]);
+    let mut keys = vec![0, 0, 0, 0];
+    keys.extend_from_slice(&1u32.to_be_bytes());
+    keys.extend_from_slice(&((key.len() + 8) as u32).to_be_bytes());
+    keys.extend_from_slice(b"mdta");
+    keys.extend_from_slice(key);
+    // UTF-8 value of the first key
+    let mut value = vec![0, 0, 0, 1, 0, 0, 0, 0];
+    value.extend_from_slice(content_identifier.as_bytes());
+    let items = atom(&1u32.to_be_bytes(), &atom(b"data", &value));
+
+    let meta = [
+        atom(b"hdlr", &handler),
+        atom(b"keys", &keys),
+        atom(b"ilst", &items),
+    ]
+    .concat();
+    let movie = [atom(b"mvhd", &movie_header), atom(b"meta", &meta)].concat();
+
+    [atom(b"ftyp", b"qt  \0\0\0\0qt  "), atom(b"moov", &movie)].concat()
+}
+
+/// Wait for the content identifier the metadata extraction read from the medium
+async fn wait_for_content_identifier(app: &TestApp, medium_id: Uuid) -> String {
+    poll_until(
+        || async move {
+            sqlx::query_scalar::<_, Option<String>>(
+                "SELECT content_identifier FROM metadata WHERE medium_id = $1",
+            )
+            .bind(medium_id)
+            .fetch_optional(&app.db_pool)
+            .await
+            .ok()
+            .flatten()
+            .flatten()
+        },
+        PollingConfig::new(format!("content identifier of medium {}", medium_id))
+            .with_timeout(Duration::from_secs(20)),
+    )
+    .await
+    .expect("Medium should carry a content identifier")
+}
+
+#[rstest]
+#[timeout(Duration::from_secs(60))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+async fn test_video_is_paired_with_its_still(
+    #[future(awt)] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let still_id = app.create_medium(&user, image.into()).await?.into_inner();
+    let content_identifier = wait_for_content_identifier(&app, still_id).await;
+    let video = ImageFixture {
+        filename: "IMG_4598.MOV",
+        data: live_photo_video(&content_identifier),
+    };
+
+    // Act
+    let video_id = app.create_medium(&user, video.into()).await?.into_inner();
+
+    // Assert: The still becomes a Live Photo holding the video
+    let live_photo = poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            async move {
+                let medium = client.get_medium(&still_id).await.ok()?.into_inner();
+                matches!(medium.medium_type, MediumTypeDto::LivePhoto).then_some(medium)
+            }
+        },
+        PollingConfig::new("still to be paired with the video")
+            .with_timeout(Duration::from_secs(20)),
+    )
+    .await
+    .expect("Still should be paired with the video");
+
+    assert!(
+        live_photo
+            .items
+            .iter()
+            .any(|item| item.mime == "video/quicktime"),
+        "Live Photo should hold the video"
+    );
+    let result = app.client_with_user(&user).get_medium(&video_id).await;
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::NOT_FOUND),
+        "The video should be merged into the still"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[timeout(Duration::from_secs(60))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+async fn test_video_of_another_live_photo_is_not_paired(
+    #[future(awt)] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let still_id = app.create_medium(&user, image.into()).await?.into_inner();
+    wait_for_content_identifier(&app, still_id).await;
+    let video = ImageFixture {
+        filename: "IMG_4599.MOV",
+        data: live_photo_video(&Uuid::new_v4().to_string().to_uppercase()),
+    };
+
+    // Act
+    let video_id = app.create_medium(&user, video.into()).await?.into_inner();
+    wait_for_content_identifier(&app, video_id).await;
+
+    // Assert: Both stay media of their own
+    let still = app
+        .client_with_user(&user)
+        .get_medium(&still_id)
+        .await?
+        .into_inner();
+    assert!(
+        matches!(still.medium_type, MediumTypeDto::Photo),
+        "Still should not become a Live Photo"
+    );
+    let video = app
+        .client_with_user(&user)
+        .get_medium(&video_id)
+        .await?
+        .into_inner();
+    assert!(
+        matches!(video.medium_type, MediumTypeDto::Video),
+        "Video should stay a medium of its own"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
@@ -1,4 +1,11 @@
+mod add_medium_item_test;
 mod create_medium_test;
+mod duplicates_test;
+mod geotag_test;
 mod list_media_test;
+mod live_photo_test;
 mod metadata_extraction_test;
 mod move_to_permanent_storage_test;
+mod search_test;
+mod shift_capture_dates_test;
+mod trash_test;
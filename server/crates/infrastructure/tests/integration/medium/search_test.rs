@@ -0,0 +1,188 @@
+use std::{error::Error, time::Duration};
+
+use domain::user::User;
+use reqwest::StatusCode;
+use rstest::*;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::integration::{
+    common::{
+        fixtures::{app, image, user, ImageFixture},
+        polling::{poll_until, PollingConfig},
+    },
+    test_app::{medium::CreateMediumRequest, TestApp},
+};
+
+// ============================================================================
+// SEARCH TESTS - GET /api/v1/search, GET /api/v1/medium
+// ============================================================================
+// This file tests finding media by their metadata, focusing on:
+// - Search terms for the camera, the capture date, tags and the medium type
+// - Combining search terms
+// - Filtering the listing by tags
+// - Invalid search queries and filters
+// ============================================================================
+
+/// A library of a photo from an iPhone taken in 2023 and one from a Canon
+/// taken in 2024 and tagged with `beach/lisbon`
+async fn library(
+    app: &TestApp,
+    user: &User,
+    iphone: ImageFixture,
+    canon: ImageFixture,
+) -> Result<(Uuid, Uuid), Box<dyn Error>> {
+    let iphone_id = app.create_medium(user, iphone.into()).await?.into_inner();
+    let mut canon: CreateMediumRequest = canon.into();
+    canon.tags = vec!["beach/lisbon".to_string()];
+    let canon_id = app.create_medium(user, canon).await?.into_inner();
+
+    for medium_id in [iphone_id, canon_id] {
+        app.wait_for_medium_enrichment(user, &medium_id).await?;
+    }
+
+    Ok((iphone_id, canon_id))
+}
+
+#[rstest]
+#[case::camera("camera:canon", false, true)]
+#[case::camera_and_year("camera:iphone taken:2023", true, false)]
+#[case::tag("tag:beach", false, true)]
+#[case::medium_type("type:photo", true, true)]
+#[case::no_match("camera:canon taken:2023", false, false)]
+#[timeout(Duration::from_secs(30))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+async fn test_search_media(
+    #[future(awt)] app: TestApp,
+    user: User,
+    image: ImageFixture,
+    #[from(image)]
+    #[with("IMG_0001.JPG")]
+    canon: ImageFixture,
+    #[case] query: &str,
+    #[case] finds_iphone: bool,
+    #[case] finds_canon: bool,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let (iphone_id, canon_id) = library(&app, &user, image, canon).await?;
+    let mut expected: Vec<Uuid> = Vec::new();
+    if finds_iphone {
+        expected.push(iphone_id);
+    }
+    if finds_canon {
+        expected.push(canon_id);
+    }
+    expected.sort();
+
+    // Act + Assert: Poll until the tags have reached the read model
+    poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            let expected = expected.clone();
+            async move {
+                let media = client
+                    .search_media(None, None, None, None, query)
+                    .await
+                    .ok()?;
+                let mut found: Vec<Uuid> = media.iter().map(|m| m.id).collect();
+                found.sort();
+                (found == expected).then_some(())
+            }
+        },
+        PollingConfig::quick(format!("search '{}' to find {:?}", query, expected)),
+    )
+    .await
+    .expect("Search should find the expected media");
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[timeout(Duration::from_secs(30))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+async fn test_list_media_filtered_by_tag(
+    #[future(awt)] app: TestApp,
+    user: User,
+    image: ImageFixture,
+    #[from(image)]
+    #[with("IMG_0001.JPG")]
+    canon: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let (_, canon_id) = library(&app, &user, image, canon).await?;
+
+    // Act + Assert: A tag also matches the tags below it
+    poll_until(
+        || async {
+            let media = app
+                .list_media(&user, &[("tags", "beach,mountains")])
+                .await
+                .ok()?;
+            (media.len() == 1 && media[0].id == canon_id).then_some(())
+        },
+        PollingConfig::quick("listing to be filtered by tag"),
+    )
+    .await
+    .expect("Listing should only hold the tagged medium");
+
+    app.cleanup().await;
+    Ok(())
+}
+
+// === Error Tests ===
+
+#[rstest]
+#[case::unknown_field("colour:red")]
+#[case::missing_value("camera:")]
+#[case::invalid_number("iso:high")]
+#[case::unterminated_quote("\"sunset")]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_invalid_search_fails(
+    #[future] app: TestApp,
+    user: User,
+    #[case] query: &str,
+) -> Result<(), Box<dyn Error>> {
+    // Act
+    let result = app
+        .client_with_user(&user)
+        .search_media(None, None, None, None, query)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::BAD_REQUEST),
+        "Expected 400 BAD REQUEST for the search '{}'",
+        query
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_list_media_with_invalid_filter_fails(
+    #[future] app: TestApp,
+    user: User,
+) -> Result<(), Box<dyn Error>> {
+    // Act: Ratings go up to five stars
+    let result = app.list_media(&user, &[("min_rating", "6")]).await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::BAD_REQUEST),
+        "Expected 400 BAD REQUEST for an invalid filter"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use std::{error::Error, time::Duration};
+
+use chrono::TimeDelta;
+use domain::user::User;
+use photonic_client::types::ShiftCaptureDatesInput;
+use reqwest::StatusCode;
+use rstest::*;
+use serial_test::serial;
+
+use crate::integration::{
+    common::{
+        fixtures::{app, image, user, ImageFixture},
+        polling::{poll_until, PollingConfig},
+    },
+    test_app::TestApp,
+};
+
+// ============================================================================
+// SHIFT CAPTURE DATES TESTS - POST /api/v1/medium/shift-dates
+// ============================================================================
+// This file tests correcting the capture dates of many media, focusing on:
+// - Moving the capture dates of the selected media by an offset
+// - Rejecting shifts without criteria or without a shift
+// ============================================================================
+
+#[rstest]
+#[timeout(Duration::from_secs(30))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+async fn test_shift_capture_dates_by_an_offset(
+    #[future(awt)] app: TestApp,
+    user: User,
+    #[with("IMG_0001.JPG")] image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+    let taken_at = app
+        .wait_for_medium_enrichment(&user, &medium_id)
+        .await?
+        .taken_at
+        .expect("Medium should have a capture date");
+
+    // Act: The camera clock was an hour behind
+    let response = app
+        .client_with_user(&user)
+        .shift_capture_dates(&ShiftCaptureDatesInput {
+            camera_make: None,
+            camera_model: None,
+            end_date: None,
+            medium_ids: vec![medium_id],
+            offset_seconds: Some(3600),
+            start_date: None,
+            timezone: None,
+        })
+        .await?;
+
+    // Assert: Poll until the shift has run in the background
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    assert!(!response.shift_id.is_nil(), "Shift ID should not be nil");
+    let expected = taken_at + TimeDelta::hours(1);
+    poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            async move {
+                let medium = client.get_medium(&medium_id).await.ok()?.into_inner();
+                (medium.taken_at == Some(expected)).then_some(())
+            }
+        },
+        PollingConfig::new("capture date to be shifted").with_timeout(Duration::from_secs(10)),
+    )
+    .await
+    .expect("Capture date should be shifted by an hour");
+
+    app.cleanup().await;
+    Ok(())
+}
+
+// === Error Tests ===
+
+#[rstest]
+#[case::without_criteria(ShiftCaptureDatesInput {
+    camera_make: None,
+    camera_model: None,
+    end_date: None,
+    medium_ids: vec![],
+    offset_seconds: Some(3600),
+    start_date: None,
+    timezone: None,
+})]
+#[case::zero_offset(ShiftCaptureDatesInput {
+    camera_make: Some("Canon".to_string()),
+    camera_model: None,
+    end_date: None,
+    medium_ids: vec![],
+    offset_seconds: Some(0),
+    start_date: None,
+    timezone: None,
+})]
+#[case::without_shift(ShiftCaptureDatesInput {
+    camera_make: Some("Canon".to_string()),
+    camera_model: None,
+    end_date: None,
+    medium_ids: vec![],
+    offset_seconds: None,
+    start_date: None,
+    timezone: None,
+})]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_invalid_capture_date_shift_fails(
+    #[future] app: TestApp,
+    user: User,
+    #[case] input: ShiftCaptureDatesInput,
+) -> Result<(), Box<dyn Error>> {
+    // Act
+    let result = app
+        .client_with_user(&user)
+        .shift_capture_dates(&input)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::BAD_REQUEST),
+        "Expected 400 BAD REQUEST for an invalid shift"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use std::{error::Error, time::Duration};
+
+use domain::user::User;
+use reqwest::StatusCode;
+use rstest::*;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::integration::{
+    common::{
+        fixtures::{app, image, user, ImageFixture},
+        polling::{poll_until, PollingConfig},
+    },
+    test_app::TestApp,
+};
+
+// ============================================================================
+// TRASH TESTS - DELETE /api/v1/medium/{id}, POST /api/v1/medium/{id}/restore,
+//               GET /api/v1/medium/trash
+// ============================================================================
+// This file tests the trash, focusing on:
+// - Deleted media moving to the trash and out of the listing
+// - Restoring media from the trash
+// - Purging media once their retention has passed
+// - Deleting and restoring unknown media
+// ============================================================================
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_delete_medium_moves_it_to_the_trash(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+
+    // Act
+    let response = app
+        .client_with_user(&user)
+        .delete_medium(&medium_id)
+        .await?;
+
+    // Assert: The medium is in the trash and no longer listed
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let trashed = app
+        .wait_for_trash(&user, &medium_id)
+        .await
+        .expect("Medium should be in the trash");
+    assert!(trashed.deleted_at.is_some(), "Deletion date should be set");
+
+    let media = app
+        .client_with_user(&user)
+        .get_all_media(
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None,
+        )
+        .await?;
+    assert!(
+        media.iter().all(|m| m.id != medium_id),
+        "Trashed medium should not be listed"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_restore_medium_takes_it_out_of_the_trash(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+    app.client_with_user(&user)
+        .delete_medium(&medium_id)
+        .await?;
+    app.wait_for_trash(&user, &medium_id)
+        .await
+        .expect("Medium should be in the trash");
+
+    // Act
+    let response = app
+        .client_with_user(&user)
+        .restore_medium(&medium_id)
+        .await?;
+
+    // Assert: Poll until the medium is listed again
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            async move {
+                let media = client
+                    .get_all_media(
+                        None, None, None, None, None, None, None, None, None, None, None, None,
+                        None, None, None,
+                    )
+                    .await
+                    .ok()?;
+                media.iter().any(|m| m.id == medium_id).then_some(())
+            }
+        },
+        PollingConfig::quick("restored medium to be listed again"),
+    )
+    .await
+    .expect("Restored medium should be listed again");
+
+    let trash = app.client_with_user(&user).get_trash().await?;
+    assert!(trash.is_empty(), "Trash should be empty after restoring");
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[timeout(Duration::from_secs(30))]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+async fn test_purge_removes_media_past_their_retention(
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange: Purge everything in the trash every second
+    let app = TestApp::with_config(|config| {
+        config.storage.trash_retention_seconds = 0;
+        config.storage.cleanup_interval_seconds = 1;
+    })
+    .await;
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+
+    // Act
+    app.client_with_user(&user)
+        .delete_medium(&medium_id)
+        .await?;
+
+    // Assert: The medium is gone for good
+    poll_until(
+        || {
+            let client = app.client_with_user(&user);
+            async move {
+                let status = client.get_medium(&medium_id).await.err()?.status();
+                (status == Some(StatusCode::NOT_FOUND)).then_some(())
+            }
+        },
+        PollingConfig::long("trashed medium to be purged"),
+    )
+    .await
+    .expect("Trashed medium should be purged");
+
+    let trash = app.client_with_user(&user).get_trash().await?;
+    assert!(trash.is_empty(), "Trash should be empty after the purge");
+
+    app.cleanup().await;
+    Ok(())
+}
+
+// === Error Tests ===
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_delete_unknown_medium_fails(
+    #[future] app: TestApp,
+    user: User,
+) -> Result<(), Box<dyn Error>> {
+    // Act
+    let result = app
+        .client_with_user(&user)
+        .delete_medium(&Uuid::new_v4())
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::NOT_FOUND),
+        "Expected 404 NOT FOUND for an unknown medium"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_restore_medium_not_in_the_trash_fails(
+    #[future] app: TestApp,
+    user: User,
+    image: ImageFixture,
+) -> Result<(), Box<dyn Error>> {
+    // Arrange
+    let medium_id = app.create_medium(&user, image.into()).await?.into_inner();
+
+    // Act
+    let result = app.client_with_user(&user).restore_medium(&medium_id).await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::BAD_REQUEST),
+        "Expected 400 BAD REQUEST for a medium that is not in the trash"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+#[serial]
+#[awt]
+async fn test_restore_unknown_medium_fails(
+    #[future] app: TestApp,
+    user: User,
+) -> Result<(), Box<dyn Error>> {
+    // Act
+    let result = app
+        .client_with_user(&user)
+        .restore_medium(&Uuid::new_v4())
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.err().and_then(|e| e.status()),
+        Some(StatusCode::NOT_FOUND),
+        "Expected 404 NOT FOUND for an unknown medium"
+    );
+
+    app.cleanup().await;
+    Ok(())
+}
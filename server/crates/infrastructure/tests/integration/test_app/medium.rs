@@ -4,7 +4,10 @@ use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use domain::user::User;
 use photonic_client::{
-    types::{MediumDetailResponse, MediumMetadataDto, MediumTypeDto, StorageTierDto},
+    types::{
+        GeotagFromTrackResponse, MediumDetailResponse, MediumItemTypeDto, MediumListResponse,
+        MediumMetadataDto, MediumTypeDto, StorageTierDto,
+    },
     Error, ResponseValue,
 };
 use reqwest::{header, header::HeaderValue};
@@ -46,6 +49,8 @@ impl From<ImageFixture> for CreateMediumRequest {
         {
             "heic" => "image/heic",
             "dng" => "image/dng",
+            "jpg" | "jpeg" => "image/jpeg",
+            "mov" => "video/quicktime",
             _ => "application/octet-stream",
         }
         .to_string();
@@ -116,6 +121,94 @@ impl TestApp {
         }
     }
 
+    pub async fn list_media(
+        &self,
+        user: &User,
+        query: &[(&str, &str)],
+    ) -> Result<ResponseValue<Vec<MediumListResponse>>, Error> {
+        let url = format!("{}/api/v1/medium", self.base_url);
+
+        let client = self.client_with_user(user);
+        let client = client.client();
+        let request = client
+            .get(url)
+            .header(header::ACCEPT, HeaderValue::from_static("application/json"))
+            .query(query)
+            .build()?;
+        let result = client.execute(request).await;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    pub async fn add_medium_item(
+        &self,
+        user: &User,
+        medium_id: &Uuid,
+        format: MediumItemTypeDto,
+        request: CreateMediumRequest,
+    ) -> Result<ResponseValue<Uuid>, Error> {
+        let url = format!(
+            "{}/api/v1/medium/{}/item/{}",
+            self.base_url, medium_id, format
+        );
+
+        let mut query = vec![("filename", request.filename.to_string())];
+        if let Some(v) = &request.priority {
+            query.push(("priority", v.to_string()));
+        }
+
+        let client = self.client_with_user(user);
+        let client = client.client();
+        let request = client
+            .post(url)
+            .header(header::ACCEPT, HeaderValue::from_static("application/json"))
+            .header(
+                header::CONTENT_TYPE,
+                HeaderValue::from_str(&request.content_type).unwrap(),
+            )
+            .body(request.body)
+            .query(&query)
+            .build()?;
+        let result = client.execute(request).await;
+        let response = result?;
+        match response.status().as_u16() {
+            201u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    pub async fn geotag_from_track(
+        &self,
+        user: &User,
+        content_type: &str,
+        track: Vec<u8>,
+        dry_run: bool,
+    ) -> Result<ResponseValue<GeotagFromTrackResponse>, Error> {
+        let url = format!("{}/api/v1/medium/geotag", self.base_url);
+
+        let client = self.client_with_user(user);
+        let client = client.client();
+        let request = client
+            .post(url)
+            .header(header::ACCEPT, HeaderValue::from_static("application/json"))
+            .header(
+                header::CONTENT_TYPE,
+                HeaderValue::from_str(content_type).unwrap(),
+            )
+            .body(track)
+            .query(&[("dry_run", dry_run.to_string())])
+            .build()?;
+        let result = client.execute(request).await;
+        let response = result?;
+        match response.status().as_u16() {
+            200u16 => ResponseValue::from_response(response).await,
+            _ => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
     /// Wait for medium to be enriched with metadata (denormalized fields on Medium entity)
     pub async fn wait_for_medium_enrichment(
         &self,
@@ -200,4 +293,23 @@ impl TestApp {
         )
         .await
     }
+
+    /// Wait for the medium to show up in the trash
+    pub async fn wait_for_trash(
+        &self,
+        user: &User,
+        medium_id: &Uuid,
+    ) -> Result<MediumListResponse, String> {
+        poll_until(
+            || async {
+                let response = self.client_with_user(user).get_trash().await;
+                info!("Got trash response: {:?}", response);
+                response
+                    .ok()
+                    .and_then(|trash| trash.into_inner().into_iter().find(|m| m.id == *medium_id))
+            },
+            PollingConfig::quick(format!("medium {} to be in the trash", medium_id)),
+        )
+        .await
+    }
 }
@@ -1,7 +1,7 @@
 pub mod medium;
 pub mod user;
 
-use std::env;
+use std::{env, sync::Arc};
 
 use domain::user::User;
 use dotenv::dotenv;
@@ -23,6 +23,12 @@ pub struct TestApp {
 impl TestApp {
     /// Start a new test application with real server on random port
     pub async fn new() -> Self {
+        Self::with_config(|_| {}).await
+    }
+
+    /// Start a test application with an adjusted configuration, e.g. to run
+    /// background tasks more often
+    pub async fn with_config(configure: impl FnOnce(&mut GlobalConfig)) -> Self {
         // Load .env file for test configuration
         dotenv().ok();
 
@@ -30,9 +36,10 @@ impl TestApp {
         setup_test_tracing();
 
         // Load configuration
-        let config = GlobalConfig::load()
+        let mut config = GlobalConfig::load()
             .await
             .expect("Failed to load configuration");
+        configure(Arc::get_mut(&mut config).expect("Configuration is not shared yet"));
 
         let secret = env::var("JWT_SECRET").unwrap_or_else(|_| "test-secret-key".to_string());
         let key = EncodingKey::from_secret(secret.as_bytes());
@@ -89,10 +96,12 @@ impl TestApp {
 
     /// Clean up test data from the database
     pub async fn cleanup(&self) {
-        sqlx::query("TRUNCATE users, albums, media, medium_items, locations, media_tags CASCADE")
-            .execute(&self.db_pool)
-            .await
-            .expect("Failed to clean test database");
+        sqlx::query(
+            "TRUNCATE users, albums, smart_albums, media, medium_items, locations, media_tags CASCADE",
+        )
+        .execute(&self.db_pool)
+        .await
+        .expect("Failed to clean test database");
     }
 
     /// Shut down the server and release all resources.
@@ -28,6 +28,8 @@ impl TestApp {
             given_name: None,
             preferred_username: None,
             nickname: None,
+            zoneinfo: user.timezone.clone(),
+            groups: Vec::new(),
             quota: Some(user.quota.limit()),
         };
 
@@ -31,6 +31,7 @@ pub fn convert_openapi(input: &str, output: &str) -> Result<(), Whatever> {
 
     remove_null_types(&mut spec);
     replace_wildcard_content_types(&mut spec);
+    collapse_binary_request_bodies(&mut spec);
     convert_array_query_params_to_string(&mut spec);
 
     let fixed_yaml = serde_yaml::to_string(&spec).expect("Failed to serialize fixed YAML");
@@ -120,6 +121,50 @@ fn replace_wildcard_content_types(value: &mut Value) {
     }
 }
 
+/// The client only sends binary request bodies as `application/octet-stream`,
+/// so a body accepting binary content in several types is reduced to that one
+fn collapse_binary_request_bodies(value: &mut Value) {
+    match value {
+        Value::Mapping(map) => {
+            if let Some(Value::Mapping(body)) =
+                map.get_mut(Value::String("requestBody".to_string()))
+            {
+                if let Some(Value::Mapping(content)) =
+                    body.get_mut(Value::String("content".to_string()))
+                {
+                    let all_binary = content.values().all(|media_type| {
+                        media_type
+                            .get("schema")
+                            .and_then(|schema| schema.get("$ref"))
+                            .and_then(|reference| reference.as_str())
+                            == Some("#/components/schemas/Binary")
+                    });
+                    if all_binary {
+                        if let Some(media_type) = content.values().next().cloned() {
+                            content.clear();
+                            content.insert(
+                                Value::String("application/octet-stream".to_string()),
+                                media_type,
+                            );
+                        }
+                    }
+                }
+            }
+
+            // Recursively process all values
+            for (_, v) in map.iter_mut() {
+                collapse_binary_request_bodies(v);
+            }
+        }
+        Value::Sequence(seq) => {
+            for v in seq.iter_mut() {
+                collapse_binary_request_bodies(v);
+            }
+        }
+        _ => {}
+    }
+}
+
 fn convert_array_query_params_to_string(value: &mut Value) {
     match value {
         Value::Mapping(map) => {